@@ -1,16 +1,22 @@
 use std::path::PathBuf;
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Debug, Parser)]
 #[command(
     name = "sebi",
     version,
-    about = "Static execution-boundary inspection for Stylus WASM"
+    about = "Static execution-boundary inspection for Stylus WASM",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Path to the .wasm artifact
-    pub wasm_path: PathBuf,
+    #[arg(required = true)]
+    pub wasm_path: Option<PathBuf>,
 
     /// Output format
     #[arg(long, default_value = "json")]
@@ -23,6 +29,160 @@ pub struct Args {
     /// Optional git commit hash for tool metadata
     #[arg(long)]
     pub commit: Option<String>,
+
+    /// Include the full operator histogram in the signals
+    #[arg(long)]
+    pub histogram: bool,
+
+    /// Also report signals for functions reachable from the entrypoints
+    #[arg(long)]
+    pub reachability: bool,
+
+    /// Signals the rules are evaluated against (`reachable` implies --reachability)
+    #[arg(long, default_value = "module")]
+    pub rule_scope: RuleScopeArg,
+
+    /// Scan function bodies in parallel (needs a build with the `parallel` feature)
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// Evaluate R-SIZE-01 on the brotli-compressed size (needs a build with the `stylus-size` feature)
+    #[arg(long)]
+    pub compressed_size: bool,
+
+    /// Initial memory pages allowed for Stylus targets before warning
+    #[arg(long, value_name = "PAGES", default_value_t = sebi_core::wasm::stylus::DEFAULT_INITIAL_PAGES_LIMIT)]
+    pub stylus_initial_pages_limit: u64,
+
+    /// Declared memory maximum (pages) above which R-MEM-04 triggers
+    #[arg(long, value_name = "PAGES", default_value_t = sebi_core::rules::catalog::DEFAULT_MAX_PAGES_CEILING)]
+    pub max_pages_ceiling: u64,
+
+    /// Loop nesting depth at which R-LOOP-02 triggers
+    #[arg(long, value_name = "DEPTH", default_value_t = sebi_core::rules::catalog::DEFAULT_LOOP_DEPTH_THRESHOLD)]
+    pub loop_depth_threshold: u32,
+
+    /// `call_indirect` count at which R-CALL-02 triggers
+    #[arg(long, value_name = "N", default_value_t = sebi_core::rules::catalog::DEFAULT_CALL_INDIRECT_THRESHOLD)]
+    pub call_indirect_threshold: u64,
+
+    /// Defined-function count above which R-SIZE-02 triggers
+    #[arg(long, value_name = "N", default_value_t = sebi_core::rules::catalog::DEFAULT_FUNCTION_COUNT_THRESHOLD)]
+    pub function_count_threshold: u32,
+
+    /// Initialized data bytes above which R-DATA-01 triggers
+    #[arg(long, value_name = "BYTES", default_value_t = sebi_core::rules::catalog::DEFAULT_DATA_BYTES_THRESHOLD)]
+    pub data_bytes_threshold: u64,
+
+    /// Maximum entries reported per import/export list
+    #[arg(long, value_name = "N", default_value_t = sebi_core::signals::extract::DEFAULT_LIST_LIMIT)]
+    pub list_limit: usize,
+
+    /// Target profile for analysis warnings, required exports and the default
+    /// import allowlist (`auto` detects it from imports/exports)
+    #[arg(long, default_value = "auto")]
+    pub target: TargetArg,
+
+    /// Comma-separated import modules R-IMPORT-01 accepts (default: the
+    /// target profile's allowlist)
+    #[arg(long, value_name = "MODULES", value_delimiter = ',')]
+    pub import_allowlist: Option<Vec<String>>,
+
+    /// Comma-separated non-MVP proposals R-FEAT-01 accepts, e.g.
+    /// `bulk_memory,simd` (default: the target profile's set)
+    #[arg(long, value_name = "FEATURES", value_delimiter = ',')]
+    pub allowed_features: Option<Vec<String>>,
+
+    /// Also report catalog rules that were checked and passed (`rules.passed`)
+    #[arg(long)]
+    pub include_passed: bool,
+
+    /// Downgrade R-MEM-02 one severity level when every `memory.grow` lies in
+    /// a named allocator function (`__rust_alloc*`, `__rust_realloc`,
+    /// `*dlmalloc*`, `malloc`)
+    #[arg(long)]
+    pub allocator_heuristic: bool,
+
+    /// Comma-separated function-name globs to treat as the allocator
+    /// instead of the defaults; implies --allocator-heuristic
+    #[arg(long, value_name = "GLOBS", value_delimiter = ',')]
+    pub allocator_patterns: Option<Vec<String>>,
+
+    /// Catalog overrides file (YAML, or JSON with a `.json` extension):
+    /// per-rule disable, severity, message and threshold parameters
+    #[arg(long, value_name = "PATH")]
+    pub catalog: Option<PathBuf>,
+
+    /// Do not evaluate deprecated rules
+    #[arg(long)]
+    pub no_deprecated: bool,
+
+    /// Suppressions file (TOML) accepting reviewed findings, e.g.
+    /// `sebi-suppressions.toml`; accepted rules are reported as suppressed
+    #[arg(long, value_name = "PATH")]
+    pub suppressions: Option<PathBuf>,
+
+    /// Classification policy: `default` fails on MED and HIGH, `strict` on
+    /// any triggered rule, `high-only` on HIGH only, `weighted` on the
+    /// summed severity weights
+    #[arg(long, default_value = "default")]
+    pub policy: PolicyArg,
+
+    /// Weights of LOW, MED and HIGH rules for `--policy weighted`
+    #[arg(long, value_name = "LOW,MED,HIGH", value_delimiter = ',')]
+    pub score_weights: Option<Vec<u64>>,
+
+    /// Scores at which `--policy weighted` reaches RISK and HIGH_RISK
+    #[arg(long, value_name = "RISK,HIGH_RISK", value_delimiter = ',')]
+    pub score_thresholds: Option<Vec<u64>>,
+
+    /// Do not let low-confidence (heuristic) findings raise the
+    /// classification or exit code; they are still reported
+    #[arg(long)]
+    pub ignore_low_confidence: bool,
+
+    /// Number of MED rules that classify like one HIGH rule
+    #[arg(long, value_name = "N")]
+    pub med_count_for_high: Option<usize>,
+
+    /// Number of LOW rules that classify like one MED rule
+    #[arg(long, value_name = "N")]
+    pub low_count_for_med: Option<usize>,
+
+    /// Exit code when rules triggered but none fails the policy and the
+    /// classification is INFO
+    #[arg(long, value_name = "CODE", default_value_t = sebi_core::rules::classify::DEFAULT_INFO_EXIT_CODE)]
+    pub info_exit_code: i32,
+
+    /// Exit code when analysis did not complete and the classification is
+    /// UNKNOWN
+    #[arg(long, value_name = "CODE", default_value_t = sebi_core::rules::classify::DEFAULT_UNKNOWN_EXIT_CODE)]
+    pub unknown_exit_code: i32,
+
+    /// Earlier JSON report of the same contract (any schema version); only
+    /// rules it did not already trigger affect the classification and exit
+    /// code
+    #[arg(long, value_name = "PATH")]
+    pub baseline: Option<PathBuf>,
+
+    /// Report per-stage and per-rule timings under `analysis.stats`. Timings
+    /// vary between runs: the report is no longer byte-deterministic
+    #[arg(long)]
+    pub stats: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List the rule catalog with severities and deprecation status
+    Rules {
+        /// Catalog overrides file applied before listing
+        #[arg(long, value_name = "PATH")]
+        catalog: Option<PathBuf>,
+
+        /// Leave deprecated rules out of the listing
+        #[arg(long)]
+        no_deprecated: bool,
+    },
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -30,3 +190,25 @@ pub enum OutputFormat {
     Json,
     Text,
 }
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum RuleScopeArg {
+    Module,
+    Reachable,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum PolicyArg {
+    Default,
+    Strict,
+    HighOnly,
+    Weighted,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum TargetArg {
+    Auto,
+    Stylus,
+    Wasi,
+    Generic,
+}
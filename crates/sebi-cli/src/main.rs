@@ -1,21 +1,147 @@
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 use clap::Parser;
 
-use sebi_core::inspect;
-use sebi_core::report::{model::ToolInfo, render};
+use sebi_core::report::model::{ScoreThresholds, ScoreWeights, ToolInfo};
+use sebi_core::report::{compat, render};
+use sebi_core::rules::catalog;
+use sebi_core::rules::classify::{Escalation, Policy};
+use sebi_core::rules::eval::{DEFAULT_ALLOCATOR_PATTERNS, RuleScope};
+use sebi_core::rules::overrides::CatalogOverrides;
+use sebi_core::rules::suppress::Suppressions;
+use sebi_core::wasm::profile::TargetSelection;
+use sebi_core::{InspectOptions, inspect_with_options};
 
 mod args;
 
 fn main() -> Result<()> {
     let args = args::Args::parse();
 
+    if let Some(args::Command::Rules {
+        catalog,
+        no_deprecated,
+    }) = &args.command
+    {
+        let mut rules = catalog::catalog();
+        if *no_deprecated {
+            rules.retain(|def| def.deprecated.is_none());
+        }
+        if let Some(path) = catalog {
+            CatalogOverrides::from_path(path)?.apply_to_catalog(&mut rules);
+        }
+        print!("{}", render::render_catalog(&rules));
+        return Ok(());
+    }
+
     let tool = ToolInfo {
         name: env!("CARGO_PKG_NAME").to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         commit: args.commit.clone(),
     };
 
-    let report = inspect(&args.wasm_path, tool)?;
+    let catalog_overrides = args
+        .catalog
+        .as_deref()
+        .map(CatalogOverrides::from_path)
+        .transpose()?;
+    let suppressions = args
+        .suppressions
+        .as_deref()
+        .map(Suppressions::from_path)
+        .transpose()?;
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read baseline report {}", path.display()))?;
+            let loaded = compat::load_report(&json)
+                .with_context(|| format!("invalid baseline report {}", path.display()))?;
+            Some(loaded.value)
+        }
+        None => None,
+    };
+
+    let policy = match args.policy {
+        args::PolicyArg::Default => Policy::Default,
+        args::PolicyArg::Strict => Policy::Strict,
+        args::PolicyArg::HighOnly => Policy::HighOnly,
+        args::PolicyArg::Weighted => {
+            let weights = match args.score_weights.as_deref() {
+                None => ScoreWeights::default(),
+                Some(&[low, med, high]) => ScoreWeights { low, med, high },
+                Some(_) => bail!("--score-weights takes three values: LOW,MED,HIGH"),
+            };
+            let thresholds = match args.score_thresholds.as_deref() {
+                None => ScoreThresholds::default(),
+                Some(&[risk, high_risk]) => ScoreThresholds { risk, high_risk },
+                Some(_) => bail!("--score-thresholds takes two values: RISK,HIGH_RISK"),
+            };
+            Policy::Weighted {
+                weights,
+                thresholds,
+            }
+        }
+    };
+    if !matches!(policy, Policy::Weighted { .. })
+        && (args.score_weights.is_some() || args.score_thresholds.is_some())
+    {
+        bail!("--score-weights and --score-thresholds require --policy weighted");
+    }
+    if matches!(policy, Policy::Weighted { .. })
+        && (args.med_count_for_high.is_some() || args.low_count_for_med.is_some())
+    {
+        bail!("--med-count-for-high and --low-count-for-med do not apply to --policy weighted");
+    }
+
+    let opts = InspectOptions {
+        histogram: args.histogram,
+        reachability: args.reachability,
+        rule_scope: match args.rule_scope {
+            args::RuleScopeArg::Module => RuleScope::Module,
+            args::RuleScopeArg::Reachable => RuleScope::Reachable,
+        },
+        parallel: args.parallel,
+        compressed_size_rule: args.compressed_size,
+        stylus_initial_pages_limit: args.stylus_initial_pages_limit,
+        list_limit: args.list_limit,
+        target: match args.target {
+            args::TargetArg::Auto => TargetSelection::Auto,
+            args::TargetArg::Stylus => TargetSelection::Stylus,
+            args::TargetArg::Wasi => TargetSelection::Wasi,
+            args::TargetArg::Generic => TargetSelection::Generic,
+        },
+        import_allowlist: args.import_allowlist.clone(),
+        allowed_features: args.allowed_features.clone(),
+        max_pages_ceiling: args.max_pages_ceiling,
+        loop_depth_threshold: args.loop_depth_threshold,
+        call_indirect_threshold: args.call_indirect_threshold,
+        function_count_threshold: args.function_count_threshold,
+        data_bytes_threshold: args.data_bytes_threshold,
+        catalog_overrides,
+        exclude_deprecated: args.no_deprecated,
+        include_passed: args.include_passed,
+        allocator_patterns: args.allocator_patterns.clone().or_else(|| {
+            args.allocator_heuristic.then(|| {
+                DEFAULT_ALLOCATOR_PATTERNS
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect()
+            })
+        }),
+        suppressions,
+        stats: args.stats,
+        ignore_low_confidence: args.ignore_low_confidence,
+        policy,
+        escalation: Escalation {
+            med_count_for_high: args.med_count_for_high,
+            low_count_for_med: args.low_count_for_med,
+        },
+        info_exit_code: args.info_exit_code,
+        unknown_exit_code: args.unknown_exit_code,
+        baseline,
+        ..Default::default()
+    };
+
+    let wasm_path = args.wasm_path.as_deref().expect("clap requires wasm_path");
+    let report = inspect_with_options(wasm_path, tool, &opts)?;
 
     let output = match args.format {
         args::OutputFormat::Json => serde_json::to_string_pretty(&report)?,
@@ -31,12 +31,97 @@ fn safe_erc20_exits_0() {
 
 #[test]
 fn risk_contract_exits_1() {
+    // The contract binds its host functions under `env`, which R-IMPORT-01
+    // would rate HIGH on its own.
     sebi_cmd()
         .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env"])
         .assert()
         .code(1);
 }
 
+#[test]
+fn policy_flag_changes_exit_code() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env", "--policy", "high-only"])
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(0));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["classification"]["policy"], "high-only");
+    assert_eq!(parsed["classification"]["level"], "INFO");
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env", "--policy", "high-only"])
+        .args(["--info-exit-code", "78"])
+        .assert()
+        .code(78);
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--policy", "lenient"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'lenient'"));
+}
+
+#[test]
+fn weighted_policy_takes_weights_and_thresholds() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env", "--policy", "weighted"])
+        .args(["--score-weights", "1,5,9", "--score-thresholds", "6,9"])
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(0));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let score = &parsed["classification"]["score"];
+    assert_eq!(score["total"], 5);
+    assert_eq!(score["weights"]["med"], 5);
+    assert_eq!(score["thresholds"]["risk"], 6);
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--score-weights", "1,5,9"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("require --policy weighted"));
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--policy", "weighted", "--score-thresholds", "6"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("takes two values"));
+}
+
+#[test]
+fn med_count_for_high_escalates_exit_code() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env", "--med-count-for-high", "1"])
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(2));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["classification"]["level"], "HIGH_RISK");
+    assert_eq!(parsed["classification"]["escalated_from"], "RISK");
+    assert_eq!(
+        parsed["classification"]["escalation_rule"],
+        "med_count_for_high"
+    );
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--policy", "weighted", "--med-count-for-high", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "do not apply to --policy weighted",
+        ));
+}
+
 #[test]
 fn high_risk_contract_exits_2() {
     sebi_cmd()
@@ -61,6 +146,27 @@ fn high_risk_dex_router_exits_2() {
         .code(2);
 }
 
+#[test]
+fn truncated_artifact_exits_unknown() {
+    // The first 200 bytes of rust_counter_safe.wasm.
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_truncated.wasm"))
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(3));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["analysis"]["status"], "parse_error");
+    assert_eq!(parsed["classification"]["level"], "UNKNOWN");
+    assert_eq!(parsed["classification"]["exit_code"], 3);
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_truncated.wasm"))
+        .args(["--unknown-exit-code", "4", "--format", "text"])
+        .assert()
+        .code(4)
+        .stdout(predicate::str::contains("Classification: Unknown"));
+}
+
 #[test]
 fn json_output_is_valid() {
     let output = sebi_cmd()
@@ -99,6 +205,7 @@ fn json_classification_safe_for_safe_contract() {
 fn json_classification_risk_for_loop_contract() {
     let output = sebi_cmd()
         .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env"])
         .output()
         .expect("command should run");
 
@@ -146,7 +253,7 @@ fn json_schema_version_present() {
         .expect("command should run");
 
     let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
-    assert_eq!(parsed["schema_version"], "0.1.0");
+    assert_eq!(parsed["schema_version"], "0.15.0");
 }
 
 #[test]
@@ -186,6 +293,23 @@ fn text_output_contains_classification() {
         .stdout(predicate::str::contains("Classification: Safe"));
 }
 
+#[test]
+fn text_output_shows_memory_sizes() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .args(["--format", "text"])
+        .output()
+        .expect("command should run");
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let memory = stdout
+        .lines()
+        .find(|l| l.starts_with("Memory: "))
+        .expect("memory line");
+    assert!(memory.contains(" page"), "{memory}");
+    assert!(memory.contains("KiB") || memory.contains("MiB"), "{memory}");
+}
+
 #[test]
 fn text_output_shows_triggered_rules() {
     sebi_cmd()
@@ -200,6 +324,19 @@ fn text_output_shows_triggered_rules() {
         .stdout(predicate::str::contains("R-LOOP-01"));
 }
 
+#[test]
+fn text_output_shows_rule_remediation() {
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .args(["--format", "text"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("fix: Declare a maximum"))
+        .stdout(predicate::str::contains(
+            "docs: https://github.com/aspect-build/sebi/blob/main/docs/RULES.md#r-mem-01--",
+        ));
+}
+
 #[test]
 fn out_flag_writes_to_file() {
     let tmp = NamedTempFile::new().expect("create temp file");
@@ -230,7 +367,7 @@ fn out_flag_with_text_format() {
         .arg("--out")
         .arg(&out_path)
         .assert()
-        .code(1)
+        .code(2)
         .stdout(predicate::str::is_empty());
 
     let contents = std::fs::read_to_string(&out_path).expect("read output file");
@@ -262,6 +399,472 @@ fn no_commit_flag_leaves_null() {
     assert!(parsed["tool"]["commit"].is_null());
 }
 
+#[test]
+fn histogram_flag_adds_operator_counts() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--histogram")
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let histogram = parsed["signals"]["instructions"]["histogram"]
+        .as_object()
+        .expect("histogram should be an object");
+    assert!(histogram.contains_key("End"));
+}
+
+#[test]
+fn default_rule_scope_is_module_without_reachability() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["rules"]["scope"], "module");
+    assert!(parsed["signals"].get("reachability").is_none());
+}
+
+#[test]
+fn reachable_rule_scope_reports_reachability() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("stylus_dex_router_complex.wasm"))
+        .args(["--rule-scope", "reachable"])
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["rules"]["scope"], "reachable");
+
+    let reachability = &parsed["signals"]["reachability"];
+    assert_eq!(reachability["entrypoint_mode"], "user_entrypoint");
+    assert!(reachability["instructions"].is_object());
+}
+
+#[test]
+fn compressed_size_flag_matches_build_features() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .arg("--compressed-size")
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let warnings = parsed["analysis"]["warnings"].to_string();
+    if cfg!(feature = "stylus-size") {
+        assert!(parsed["artifact"]["compressed_size_bytes"].is_u64());
+        assert!(!warnings.contains("stylus-size"));
+    } else {
+        assert!(parsed["artifact"].get("compressed_size_bytes").is_none());
+        assert!(warnings.contains("built without `stylus-size`"));
+    }
+}
+
+#[test]
+fn list_limit_truncates_reported_lists() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .args(["--list-limit", "1"])
+        .output()
+        .expect("command should run");
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ie = &parsed["signals"]["imports_exports"];
+
+    assert_eq!(ie["imports"].as_array().map(Vec::len), Some(1));
+    assert_eq!(ie["imports_truncated"], true);
+    assert!(ie["import_count"].as_u64().unwrap() > 1);
+}
+
+#[test]
+fn stylus_initial_pages_limit_is_configurable() {
+    let warnings = |limit: &str| {
+        let output = sebi_cmd()
+            .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+            .args(["--stylus-initial-pages-limit", limit])
+            .output()
+            .expect("command should run");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        parsed["analysis"]["warnings"].to_string()
+    };
+
+    assert!(warnings("0").contains("exceeds the Stylus limit of 0 pages"));
+    assert!(!warnings("128").contains("exceeds the Stylus limit"));
+}
+
+#[test]
+fn max_pages_ceiling_is_configurable() {
+    let triggered = |ceiling: &str| {
+        let output = sebi_cmd()
+            .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+            .args(["--max-pages-ceiling", ceiling])
+            .output()
+            .expect("command should run");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        parsed["rules"]["triggered"].to_string()
+    };
+
+    // The contract declares a 4-page maximum.
+    assert!(triggered("3").contains("R-MEM-04"));
+    assert!(!triggered("4").contains("R-MEM-04"));
+}
+
+#[test]
+fn target_flag_selects_profile() {
+    let analysis = |target: &str| {
+        let output = sebi_cmd()
+            .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+            .args(["--target", target, "--stylus-initial-pages-limit", "0"])
+            .output()
+            .expect("command should run");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        parsed["analysis"].clone()
+    };
+
+    let auto = analysis("auto");
+    assert_eq!(auto["target"], "stylus");
+    assert!(auto["warnings"].to_string().contains("Stylus target"));
+
+    let generic = analysis("generic");
+    assert_eq!(generic["target"], "unknown");
+    assert_eq!(
+        generic["target_evidence"],
+        serde_json::json!(["selected explicitly"])
+    );
+    assert!(!generic["warnings"].to_string().contains("Stylus target"));
+    assert!(generic.get("stylus").is_none());
+}
+
+#[test]
+fn import_allowlist_flag_overrides_profile() {
+    let triggered = |extra: &[&str]| {
+        let output = sebi_cmd()
+            .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+            .args(extra)
+            .output()
+            .expect("command should run");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        parsed["rules"]["triggered"].to_string()
+    };
+
+    assert!(!triggered(&[]).contains("R-IMPORT-01"));
+    assert!(triggered(&["--import-allowlist", "console"]).contains("R-IMPORT-01"));
+}
+
+#[test]
+fn no_histogram_flag_omits_histogram() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(parsed["signals"]["instructions"].get("histogram").is_none());
+}
+
+#[test]
+fn catalog_overrides_file_applied() {
+    let mut overrides = tempfile::Builder::new()
+        .suffix(".yaml")
+        .tempfile()
+        .expect("create temp file");
+    std::io::Write::write_all(
+        &mut overrides,
+        b"catalog_version: acme-1\nruleset: acme\nrules:\n  R-LOOP-01:\n    severity: HIGH\n    message: loops need review\n",
+    )
+    .unwrap();
+
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--import-allowlist", "env", "--catalog"])
+        .arg(overrides.path())
+        .output()
+        .expect("command should run");
+
+    assert_eq!(output.status.code(), Some(2));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["rules"]["catalog"]["catalog_version"], "acme-1");
+    assert_eq!(parsed["rules"]["catalog"]["ruleset"], "acme");
+    let loop01 = &parsed["rules"]["triggered"][0];
+    assert_eq!(loop01["rule_id"], "R-LOOP-01");
+    assert_eq!(loop01["severity"], "High");
+    assert_eq!(loop01["message"], "loops need review");
+}
+
+#[test]
+fn suppressions_file_lowers_classification() {
+    let mut suppressions = tempfile::Builder::new()
+        .suffix(".toml")
+        .tempfile()
+        .expect("create temp file");
+    std::io::Write::write_all(
+        &mut suppressions,
+        b"[[suppressions]]\nrule = \"R-IMPORT-01\"\npath = \"**/cpp_kv_store_simple.wasm\"\nreason = \"env imports reviewed in AUD-7\"\n",
+    )
+    .unwrap();
+
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .arg("--suppressions")
+        .arg(suppressions.path())
+        .output()
+        .expect("command should run");
+
+    assert_eq!(output.status.code(), Some(1));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["rules"]["suppressed"][0]["rule_id"], "R-IMPORT-01");
+    assert_eq!(
+        parsed["rules"]["suppressed"][0]["reason"],
+        "env imports reviewed in AUD-7"
+    );
+    assert_eq!(parsed["classification"]["level"], "RISK");
+    assert_eq!(
+        parsed["classification"]["suppressed_rule_ids"],
+        serde_json::json!(["R-IMPORT-01"])
+    );
+    assert_eq!(parsed["classification"]["unsuppressed_level"], "HIGH_RISK");
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("cpp_kv_store_simple.wasm"))
+        .args(["--format", "text", "--suppressions"])
+        .arg(suppressions.path())
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "Suppressed rules (1, not classified):",
+        ))
+        .stdout(predicate::str::contains(
+            "reason: env imports reviewed in AUD-7",
+        ))
+        .stdout(predicate::str::contains(
+            "NOTE: would be HighRisk without suppressions (R-IMPORT-01)",
+        ));
+}
+
+#[test]
+fn baseline_flag_classifies_only_new_rules() {
+    let baseline = NamedTempFile::new().expect("create temp file");
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .arg("--out")
+        .arg(baseline.path())
+        .assert()
+        .code(2);
+
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .arg("--baseline")
+        .arg(baseline.path())
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(0));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["classification"]["level"], "SAFE");
+    assert_eq!(
+        parsed["classification"]["new_rule_ids"],
+        serde_json::json!([])
+    );
+    assert_eq!(
+        parsed["classification"]["preexisting_rule_ids"],
+        parsed["classification"]["triggered_rule_ids"]
+    );
+
+    std::fs::write(baseline.path(), "{}").unwrap();
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_registry_complex.wasm"))
+        .arg("--baseline")
+        .arg(baseline.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid baseline report"));
+}
+
+#[test]
+fn suppressions_without_reason_fail() {
+    let mut suppressions = tempfile::Builder::new()
+        .suffix(".toml")
+        .tempfile()
+        .expect("create temp file");
+    std::io::Write::write_all(
+        &mut suppressions,
+        b"[[suppressions]]\nrule = \"R-LOOP-01\"\n",
+    )
+    .unwrap();
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--suppressions")
+        .arg(suppressions.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing field `reason`"));
+}
+
+#[test]
+fn catalog_overrides_with_unknown_rule_fail() {
+    let mut overrides = tempfile::Builder::new()
+        .suffix(".json")
+        .tempfile()
+        .expect("create temp file");
+    std::io::Write::write_all(
+        &mut overrides,
+        br#"{ "catalog_version": "acme-1", "rules": { "R-NOPE-01": { "disabled": true } } }"#,
+    )
+    .unwrap();
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--catalog")
+        .arg(overrides.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown rule id `R-NOPE-01`"));
+}
+
+#[test]
+fn rules_subcommand_lists_catalog() {
+    let output = sebi_cmd()
+        .arg("rules")
+        .output()
+        .expect("command should run");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("R-MEM-01     Med  Missing declared memory maximum\n"));
+    assert!(stdout.contains("R-SIMD-01"));
+    assert!(!stdout.contains("deprecated:"));
+}
+
+#[test]
+fn rules_subcommand_applies_catalog_overrides() {
+    let mut overrides = tempfile::Builder::new()
+        .suffix(".yaml")
+        .tempfile()
+        .expect("create temp file");
+    std::io::Write::write_all(
+        &mut overrides,
+        b"catalog_version: acme-1\nrules:\n  R-MEM-01:\n    disabled: true\n",
+    )
+    .unwrap();
+
+    sebi_cmd()
+        .args(["rules", "--no-deprecated", "--catalog"])
+        .arg(overrides.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("R-MEM-01").not())
+        .stdout(predicate::str::contains("R-MEM-02"));
+}
+
+#[test]
+fn no_deprecated_flag_accepted() {
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--no-deprecated")
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn include_passed_reports_checked_rules() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .output()
+        .expect("command should run");
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(parsed["rules"].get("passed").is_none());
+
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--include-passed")
+        .output()
+        .expect("command should run");
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let passed = parsed["rules"]["passed"].as_array().unwrap();
+    assert_eq!(passed[0]["rule_id"], "R-MEM-01");
+    assert_eq!(passed[0]["evidence"]["signals.memory.has_max"], true);
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .args(["--include-passed", "--format", "text"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains(format!(
+            "Rules: {} passed, 0 triggered",
+            passed.len()
+        )));
+}
+
+#[test]
+fn stats_flag_adds_timings() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .arg("--stats")
+        .output()
+        .expect("command should run");
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let stats = &parsed["analysis"]["stats"];
+    assert!(stats["total_us"].is_u64());
+    assert!(stats["stages"]["scan_us"].is_u64());
+    assert_eq!(stats["rules"][0]["rule_id"], "R-MEM-01");
+
+    sebi_cmd()
+        .arg(fixtures_dir().join("rust_counter_safe.wasm"))
+        .args(["--stats", "--format", "text"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("Timing: ").and(predicate::str::contains(" ms (read ")));
+}
+
+#[test]
+fn allocator_heuristic_leaves_unnamed_modules_alone() {
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_token_bridge_complex.wasm"))
+        .arg("--allocator-patterns")
+        .arg("*,[")
+        .output()
+        .expect("command should run");
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mem02 = parsed["rules"]["triggered"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["rule_id"] == "R-MEM-02")
+        .unwrap();
+    assert_eq!(mem02["severity"], "High");
+    assert!(mem02["evidence"].get("allocator_only").is_none());
+    assert!(
+        parsed["analysis"]["warnings"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("invalid allocator pattern `[` ignored"))
+    );
+}
+
+#[test]
+fn ignore_low_confidence_keeps_exact_findings() {
+    // Every built-in rule is high confidence, so the verdict is unchanged.
+    let output = sebi_cmd()
+        .arg(fixtures_dir().join("cpp_token_bridge_complex.wasm"))
+        .arg("--ignore-low-confidence")
+        .output()
+        .expect("command should run");
+    assert_eq!(output.status.code(), Some(2));
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let triggered = parsed["rules"]["triggered"].as_array().unwrap();
+    assert!(!triggered.is_empty());
+    assert!(triggered.iter().all(|r| r["confidence"] == "high"));
+    assert_eq!(
+        parsed["classification"]["triggered_rule_ids"]
+            .as_array()
+            .unwrap()
+            .len(),
+        triggered.len()
+    );
+}
+
 #[test]
 fn missing_wasm_arg_fails() {
     sebi_cmd()
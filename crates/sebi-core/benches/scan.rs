@@ -0,0 +1,67 @@
+//! Instruction-scan throughput.
+//!
+//! Run with `cargo bench -p sebi-core` (add `--features parallel` to also
+//! measure the parallel scan). Inputs are the largest core fixture and a
+//! generated module with many small bodies, which is where per-operator
+//! overhead dominates.
+
+use std::path::PathBuf;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use sebi_core::wasm::parse::{ParseOptions, parse_wasm_with_options};
+
+fn largest_fixture() -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("call_indirect_heavy.wat");
+    wat::parse_file(path).expect("fixture compiles")
+}
+
+fn generated_module(functions: usize) -> Vec<u8> {
+    let mut wat = String::from("(module (type (func)) (table 1 funcref) (memory 1)");
+    for i in 0..functions {
+        wat.push_str(&format!(
+            "(func (local i32)
+               (block (loop
+                 (br_if 1 (i32.eqz (local.get 0)))
+                 (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                 (drop (i32.load (local.get 0)))
+                 (call_indirect (type 0) (i32.const 0))
+                 (br 0)))
+               (call {}))",
+            (i + 1) % functions
+        ));
+    }
+    wat.push(')');
+    wat::parse_str(&wat).expect("generated module compiles")
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let inputs = [
+        ("call_indirect_heavy", largest_fixture()),
+        ("generated_10k", generated_module(10_000)),
+    ];
+
+    let mut group = c.benchmark_group("scan");
+    for (name, wasm) in &inputs {
+        group.throughput(Throughput::Bytes(wasm.len() as u64));
+
+        group.bench_function(format!("{name}/serial"), |b| {
+            b.iter(|| parse_wasm_with_options(wasm, &ParseOptions::default()).unwrap())
+        });
+
+        #[cfg(feature = "parallel")]
+        group.bench_function(format!("{name}/parallel"), |b| {
+            let opts = ParseOptions {
+                parallel: true,
+                ..Default::default()
+            };
+            b.iter(|| parse_wasm_with_options(wasm, &opts).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan);
+criterion_main!(benches);
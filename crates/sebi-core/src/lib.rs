@@ -9,17 +9,193 @@ pub mod util;
 pub mod wasm;
 
 use anyhow::Result;
-use report::model::{Report, ToolInfo};
+use report::model::{
+    ClassificationInfo, ClassificationLevel, Confidence, Report, ToolInfo, micros,
+};
+use rules::classify::{Escalation, Policy};
+use rules::eval::{RuleEvaluator, RuleScope, TriggeredRule};
+use rules::overrides::CatalogOverrides;
+use rules::suppress::Suppressions;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+use wasm::profile::TargetSelection;
 
 pub const TOOL_NAME: &str = "SEBI";
 
 /// Schema version for generated JSON reports.
 /// Must be bumped when `report::model` changes semantically.
-pub const SCHEMA_VERSION: &str = "0.1.0";
+pub const SCHEMA_VERSION: &str = "0.15.0";
 
 /// Version of the authoritative rule catalog.
-pub const RULE_CATALOG_VERSION: &str = "0.1.0";
+pub const RULE_CATALOG_VERSION: &str = "0.19.0";
+
+/// Optional behaviour for a single inspection run.
+///
+/// Defaults reproduce the standard report exactly; every option is opt-in.
+#[derive(Debug, Clone)]
+pub struct InspectOptions {
+    /// Emit the full operator histogram under `signals.instructions.histogram`.
+    pub histogram: bool,
+
+    /// Maximum byte offsets reported per flagged operator kind
+    /// (`memory.grow`, `call_indirect`, `loop`).
+    pub site_limit: usize,
+
+    /// Static load/store offset (bytes) above which an access is counted in
+    /// `large_offset_access_count`, in addition to offsets past initial memory.
+    pub large_offset_threshold: u64,
+
+    /// Also report signals restricted to functions reachable from the
+    /// entrypoints under `signals.reachability`.
+    pub reachability: bool,
+
+    /// Signals the rules are evaluated against. `Reachable` implies
+    /// `reachability`; the scope used is reported as `rules.scope`.
+    pub rule_scope: RuleScope,
+
+    /// Scan function bodies on a thread pool. Requires the `parallel`
+    /// feature; ignored otherwise. The report is identical either way.
+    pub parallel: bool,
+
+    /// Evaluate R-SIZE-01 against the brotli-compressed size Stylus limits
+    /// at activation. Requires the `stylus-size` feature; without it the
+    /// raw size is used and a warning says so.
+    pub compressed_size_rule: bool,
+
+    /// Initial memory (pages) above which Stylus targets get a warning.
+    /// Defaults to the current Stylus mainnet page limit.
+    pub stylus_initial_pages_limit: u64,
+
+    /// Target profile applied during normalization; `Auto` picks it from
+    /// the detected target.
+    pub target: TargetSelection,
+
+    /// Maximum entries reported per import/export list. Longer lists are
+    /// truncated and flagged; counts stay exact.
+    pub list_limit: usize,
+
+    /// Import modules R-IMPORT-01 accepts. `None` uses the target
+    /// profile's allowlist; the generic profile has none, so the rule is off.
+    pub import_allowlist: Option<Vec<String>>,
+
+    /// Non-MVP proposals R-FEAT-01 accepts, in `wasmparser` spelling
+    /// (`simd`, `threads`, ...). `None` uses the target profile's set.
+    pub allowed_features: Option<Vec<String>>,
+
+    /// Declared memory maximum (pages) above which R-MEM-04 triggers.
+    /// Hosts differ in how much memory they can actually back.
+    pub max_pages_ceiling: u64,
+
+    /// `loop` nesting depth at which R-LOOP-02 triggers.
+    pub loop_depth_threshold: u32,
+
+    /// `call_indirect` count at which R-CALL-02 triggers.
+    pub call_indirect_threshold: u64,
+
+    /// Defined-function count above which R-SIZE-02 triggers.
+    pub function_count_threshold: u32,
+
+    /// Initialized data bytes above which R-DATA-01 triggers.
+    pub data_bytes_threshold: u64,
+
+    /// Per-rule overrides applied on top of the built-in catalog. Threshold
+    /// parameters set here take precedence over the fields above.
+    pub catalog_overrides: Option<CatalogOverrides>,
+
+    /// Skip deprecated rules entirely. By default they still evaluate and
+    /// count towards the classification, flagged `deprecated` in the report.
+    pub exclude_deprecated: bool,
+
+    /// Embedder-defined rules evaluated after the catalog, against the same
+    /// scoped signals. See [`RuleEvaluator`] for the contract they follow.
+    pub custom_rules: Vec<Arc<dyn RuleEvaluator>>,
+
+    /// Report catalog rules that were evaluated and did not trigger under
+    /// `rules.passed`, with the values they checked.
+    pub include_passed: bool,
+
+    /// Function-name globs (name section) identifying the allocator. When
+    /// set and every `memory.grow` lies in a matching function, R-MEM-02 is
+    /// downgraded one severity level and its evidence lists the matched
+    /// functions. See `rules::eval::DEFAULT_ALLOCATOR_PATTERNS`.
+    pub allocator_patterns: Option<Vec<String>>,
+
+    /// Accepted findings. Matching triggered rules are reported under
+    /// `rules.suppressed` instead of `rules.triggered` and do not count
+    /// towards the classification; expired entries only produce a warning.
+    pub suppressions: Option<Suppressions>,
+
+    /// Report per-stage and per-rule wall-clock timings under
+    /// `analysis.stats`. Timings differ between runs, so this opts out of
+    /// byte-identical reports.
+    pub stats: bool,
+
+    /// Leave low-confidence (heuristic) findings out of the classification
+    /// and exit code. They are still reported under `rules.triggered`.
+    pub ignore_low_confidence: bool,
+
+    /// Mapping from triggered severities to the classification level and
+    /// exit code; recorded as `classification.policy`.
+    pub policy: Policy,
+
+    /// Counts of MED or LOW rules that raise the classification as if one
+    /// rule of the next severity up had triggered. Disabled by default.
+    pub escalation: Escalation,
+
+    /// Exit code of an `INFO` classification, given when rules triggered but
+    /// the policy fails none of them.
+    pub info_exit_code: i32,
+
+    /// Exit code of an `UNKNOWN` classification, given when analysis did not
+    /// complete (`analysis.status` is `parse_error` or `unsupported`).
+    pub unknown_exit_code: i32,
+
+    /// Earlier report of the same contract, e.g. from the main branch. Only
+    /// rules it did not already trigger count towards the classification;
+    /// see [`rules::classify::classify_against_baseline`]. Load older
+    /// reports with [`report::compat::load_report`]. With
+    /// `ignore_low_confidence`, low-confidence rules are left out before the
+    /// comparison.
+    pub baseline: Option<Report>,
+}
+
+impl Default for InspectOptions {
+    fn default() -> Self {
+        Self {
+            histogram: false,
+            site_limit: wasm::scan::DEFAULT_SITE_LIMIT,
+            large_offset_threshold: wasm::scan::DEFAULT_LARGE_OFFSET_THRESHOLD,
+            reachability: false,
+            rule_scope: RuleScope::Module,
+            parallel: false,
+            compressed_size_rule: false,
+            stylus_initial_pages_limit: wasm::stylus::DEFAULT_INITIAL_PAGES_LIMIT,
+            target: TargetSelection::Auto,
+            list_limit: signals::extract::DEFAULT_LIST_LIMIT,
+            import_allowlist: None,
+            allowed_features: None,
+            max_pages_ceiling: rules::catalog::DEFAULT_MAX_PAGES_CEILING,
+            loop_depth_threshold: rules::catalog::DEFAULT_LOOP_DEPTH_THRESHOLD,
+            call_indirect_threshold: rules::catalog::DEFAULT_CALL_INDIRECT_THRESHOLD,
+            function_count_threshold: rules::catalog::DEFAULT_FUNCTION_COUNT_THRESHOLD,
+            data_bytes_threshold: rules::catalog::DEFAULT_DATA_BYTES_THRESHOLD,
+            catalog_overrides: None,
+            exclude_deprecated: false,
+            custom_rules: Vec::new(),
+            include_passed: false,
+            allocator_patterns: None,
+            suppressions: None,
+            stats: false,
+            ignore_low_confidence: false,
+            policy: Policy::Default,
+            escalation: Escalation::default(),
+            info_exit_code: rules::classify::DEFAULT_INFO_EXIT_CODE,
+            unknown_exit_code: rules::classify::DEFAULT_UNKNOWN_EXIT_CODE,
+            baseline: None,
+        }
+    }
+}
 
 /// Orchestrates the full inspection pipeline for a WASM artifact.
 ///
@@ -27,17 +203,189 @@ pub const RULE_CATALOG_VERSION: &str = "0.1.0";
 /// 1. **Load**: Read file and compute identity hashes.
 /// 2. **Parse**: Extract low-level WASM structures and instructions.
 /// 3. **Extract**: Transform structures into schema-stable signals.
-/// 4. **Evaluate**: Check signals against the rule catalog.
-/// 5. **Classify**: Derive a risk verdict and CI exit code.
+/// 4. **Evaluate**: Check signals against the rule catalog, with any
+///    catalog overrides applied, then run custom rule evaluators and set
+///    aside suppressed findings.
+/// 5. **Classify**: Derive a risk verdict and CI exit code, or `UNKNOWN`
+///    when analysis did not complete.
 /// 6. **Report**: Package all context into a final serializable report.
 pub fn inspect(path: &Path, tool: ToolInfo) -> Result<Report> {
+    inspect_with_options(path, tool, &InspectOptions::default())
+}
+
+/// Runs the inspection pipeline with non-default options.
+///
+/// See [`inspect`] for the pipeline stages.
+pub fn inspect_with_options(path: &Path, tool: ToolInfo, opts: &InspectOptions) -> Result<Report> {
+    let started = Instant::now();
     let artifact_ctx = wasm::read::read_artifact(path)?;
-    let raw = wasm::parse::parse_wasm(&artifact_ctx.bytes)?;
-    let signals = signals::extract::extract_signals(&raw.sections, &raw.instructions);
-    let triggered = rules::eval::evaluate_rules(&signals, &artifact_ctx, &raw.config);
-    let classification = rules::classify::classify(&triggered);
+    let read_done = Instant::now();
+    let parse_opts = wasm::parse::ParseOptions {
+        collect_histogram: opts.histogram,
+        site_limit: opts.site_limit,
+        large_offset_threshold: opts.large_offset_threshold,
+        reachability: opts.reachability || opts.rule_scope == RuleScope::Reachable,
+        parallel: opts.parallel,
+        stylus_initial_pages_limit: opts.stylus_initial_pages_limit,
+        target: opts.target,
+        import_allowlist: opts.import_allowlist.clone(),
+        allowed_features: opts.allowed_features.clone(),
+    };
+    let mut raw = wasm::parse::parse_wasm_with_options(&artifact_ctx.bytes, &parse_opts)?;
+    let parse_done = Instant::now();
+    let mut catalog = rules::catalog::catalog();
+    for (name, value) in [
+        ("MAX_PAGES_CEILING", opts.max_pages_ceiling),
+        ("LOOP_DEPTH_THRESHOLD", u64::from(opts.loop_depth_threshold)),
+        ("CALL_INDIRECT_THRESHOLD", opts.call_indirect_threshold),
+        (
+            "FUNCTION_COUNT_THRESHOLD",
+            u64::from(opts.function_count_threshold),
+        ),
+        ("DATA_BYTES_THRESHOLD", opts.data_bytes_threshold),
+    ] {
+        rules::catalog::set_param(&mut catalog, name, value);
+    }
+    if opts.exclude_deprecated {
+        catalog.retain(|def| def.deprecated.is_none());
+    }
+    let mut disabled = Vec::new();
+    if let Some(overrides) = &opts.catalog_overrides {
+        disabled = catalog.clone();
+        overrides.apply_to_catalog(&mut catalog);
+        disabled.retain(|def| !catalog.iter().any(|kept| kept.id == def.id));
+        raw.rules_catalog = overrides.catalog_info();
+    }
+    raw.rules_catalog.content_hash = rules::catalog::content_hash(&catalog);
+    if opts.compressed_size_rule {
+        raw.config.size_rule_compressed = true;
+        if artifact_ctx.compressed_size_bytes.is_none() {
+            raw.analysis.warnings.push(
+                "compressed size unavailable (built without `stylus-size`); R-SIZE-01 uses the raw size"
+                    .to_string(),
+            );
+        }
+    }
+    if let Some(patterns) = &opts.allocator_patterns {
+        for pattern in patterns {
+            if glob::Pattern::new(pattern).is_err() {
+                raw.analysis
+                    .warnings
+                    .push(format!("invalid allocator pattern `{pattern}` ignored"));
+            }
+        }
+        raw.config.allocator_patterns = Some(patterns.clone());
+    }
+    let extract_started = Instant::now();
+    let extract_opts = signals::extract::ExtractOptions {
+        list_limit: opts.list_limit,
+    };
+    let mut signals = signals::extract::extract_signals_with_options(
+        &raw.sections,
+        &raw.instructions,
+        &extract_opts,
+    );
+    signals.reachability = raw
+        .reachability
+        .as_ref()
+        .map(|r| signals::extract::extract_reachability(&raw.sections, r));
 
-    let report = Report::new(
+    let scoped = match opts.rule_scope {
+        RuleScope::Module => None,
+        RuleScope::Reachable => signals.reachable_scope(),
+    };
+    let (rule_signals, scope) = match &scoped {
+        Some(reachable) => (reachable, RuleScope::Reachable),
+        None => (&signals, RuleScope::Module),
+    };
+    let eval_started = Instant::now();
+    let ctx = rules::eval::EvaluationContext {
+        signals: rule_signals,
+        artifact: &artifact_ctx,
+        config: &raw.config,
+        analysis: &raw.analysis,
+        rules: &catalog,
+        facts: Some(&raw),
+    };
+    let evaluation = rules::eval::evaluate(&ctx);
+    let mut triggered = evaluation.triggered;
+    let rejected = rules::eval::apply_evaluators(&ctx, &opts.custom_rules, &mut triggered);
+    // Disabled rules are evaluated only to report what they would have
+    // changed; composites among them see the enabled rules' results.
+    let disabled_triggered = if disabled.is_empty() {
+        Vec::new()
+    } else {
+        let disabled_ctx = rules::eval::EvaluationContext {
+            rules: &disabled,
+            ..ctx
+        };
+        rules::eval::evaluate(&disabled_ctx).triggered
+    };
+    for id in rejected {
+        raw.analysis.warnings.push(format!(
+            "custom rule evaluator returned built-in rule id {id}; trigger ignored"
+        ));
+    }
+    let applied = match &opts.suppressions {
+        Some(suppressions) => {
+            suppressions.apply(&mut triggered, &artifact_ctx, &rules::suppress::today_utc())
+        }
+        None => Default::default(),
+    };
+    raw.analysis.warnings.extend(applied.warnings);
+    let mut suppressed: Vec<_> = applied.suppressed.iter().map(|s| s.rule.clone()).collect();
+    suppressed.extend(disabled_triggered);
+    let classify_started = Instant::now();
+    if let Some(baseline) = &opts.baseline {
+        let hash = &baseline.artifact.hash;
+        if hash.value != artifact_ctx.hash_hex {
+            raw.analysis.warnings.push(format!(
+                "baseline report is for a different artifact ({} {})",
+                hash.algorithm, hash.value
+            ));
+        }
+    }
+    let mut classification = classify_triggered(&triggered, opts);
+    if !suppressed.is_empty() {
+        let mut unsuppressed = triggered.clone();
+        unsuppressed.extend(suppressed.iter().cloned());
+        let mut would_be = classify_triggered(&unsuppressed, opts);
+        rules::classify::withhold_if_incomplete(
+            &mut would_be,
+            &raw.analysis,
+            opts.unknown_exit_code,
+        );
+        rules::classify::record_suppressed(&mut classification, &suppressed, would_be.level);
+    }
+    if triggered.is_empty() {
+        classification.reason = rules::classify::nothing_triggered_reason(
+            evaluation.evaluated,
+            applied.suppressed.len(),
+        );
+    }
+    rules::classify::withhold_if_incomplete(
+        &mut classification,
+        &raw.analysis,
+        opts.unknown_exit_code,
+    );
+    if opts.stats {
+        let done = Instant::now();
+        let parse = parse_done - read_done;
+        raw.analysis.stats = Some(report::model::AnalysisStats {
+            total_us: micros(done - started),
+            stages: report::model::StageTimings {
+                read_us: micros(read_done - started),
+                parse_us: micros(parse.saturating_sub(raw.scan_time)),
+                scan_us: micros(raw.scan_time),
+                extract_us: micros(eval_started - extract_started),
+                eval_us: micros(classify_started - eval_started),
+                classify_us: micros(done - classify_started),
+            },
+            rules: evaluation.timings.into_iter().map(Into::into).collect(),
+        });
+    }
+
+    let mut report = Report::new(
         tool,
         artifact_ctx.into_artifact(),
         signals,
@@ -46,6 +394,45 @@ pub fn inspect(path: &Path, tool: ToolInfo) -> Result<Report> {
         triggered,
         classification,
     );
+    report.rules.scope = scope.as_str().to_string();
+    report.rules.suppressed = applied.suppressed.into_iter().map(Into::into).collect();
+    if opts.include_passed {
+        report.rules.passed = evaluation.passed.into_iter().map(Into::into).collect();
+    }
 
     Ok(report)
 }
+
+/// Classifies `triggered` under the policy, baseline, escalation and exit
+/// code options of `opts`.
+fn classify_triggered(triggered: &[TriggeredRule], opts: &InspectOptions) -> ClassificationInfo {
+    let classify = |rules: &[TriggeredRule]| match &opts.baseline {
+        Some(baseline) => rules::classify::classify_against_baseline(rules, baseline, &opts.policy),
+        None => rules::classify::classify_with_policy(rules, &opts.policy),
+    };
+    // Low-confidence rules are dropped before the baseline comparison, so
+    // they count neither as new nor as preexisting.
+    let mut classification = if opts.ignore_low_confidence {
+        rules::classify::ignoring_low_confidence(triggered, classify)
+    } else {
+        classify(triggered)
+    };
+    let counted: Vec<_> = triggered
+        .iter()
+        .filter(|r| match &classification.new_rule_ids {
+            Some(new) => new.contains(&r.rule_id),
+            None => !opts.ignore_low_confidence || r.confidence != Confidence::Low,
+        })
+        .cloned()
+        .collect();
+    rules::classify::escalate(
+        &mut classification,
+        &counted,
+        &opts.policy,
+        &opts.escalation,
+    );
+    if classification.level == ClassificationLevel::Info {
+        classification.exit_code = opts.info_exit_code;
+    }
+    classification
+}
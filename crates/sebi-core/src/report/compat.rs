@@ -0,0 +1,116 @@
+//! Reading reports written by older SEBI versions.
+//!
+//! Fields added since a report was written deserialize to their defaults
+//! (`#[serde(default)]` on the model), and fields that were since removed
+//! are ignored. `missing_fields` lists what was defaulted, so consumers can
+//! tell "absent in an old report" from "observed as zero".
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::report::model::Report;
+use crate::signals::model::Signals;
+
+/// A document deserialized with defaults for the fields it lacked.
+#[derive(Debug, Clone)]
+pub struct Loaded<T> {
+    pub value: T,
+    /// Dotted paths filled from defaults, sorted. Array elements appear as
+    /// `[]`, e.g. `imports_exports.imports[].signature`.
+    pub missing_fields: Vec<String>,
+}
+
+/// Parses a report of any schema version up to the current one.
+pub fn load_report(json: &str) -> Result<Loaded<Report>> {
+    load(json).context("invalid SEBI report")
+}
+
+/// Parses a standalone `signals` object of any schema version.
+pub fn load_signals(json: &str) -> Result<Loaded<Signals>> {
+    load(json).context("invalid SEBI signals")
+}
+
+fn load<T: DeserializeOwned + Serialize>(json: &str) -> Result<Loaded<T>> {
+    let original: Value = serde_json::from_str(json)?;
+    let value: T = serde_json::from_value(original.clone())?;
+    let normalized = serde_json::to_value(&value)?;
+    Ok(Loaded {
+        missing_fields: missing_fields(&original, &normalized),
+        value,
+    })
+}
+
+/// Paths present in `normalized` but absent from `original`, sorted and
+/// deduplicated.
+pub fn missing_fields(original: &Value, normalized: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    collect_missing(original, normalized, "", &mut out);
+    out.sort();
+    out.dedup();
+    out
+}
+
+fn collect_missing(original: &Value, normalized: &Value, path: &str, out: &mut Vec<String>) {
+    match (original, normalized) {
+        (Value::Object(orig), Value::Object(norm)) => {
+            for (key, value) in norm {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match orig.get(key) {
+                    Some(orig_value) => collect_missing(orig_value, value, &child, out),
+                    None => out.push(child),
+                }
+            }
+        }
+        (Value::Array(orig), Value::Array(norm)) => {
+            let child = format!("{path}[]");
+            for (orig_item, norm_item) in orig.iter().zip(norm) {
+                collect_missing(orig_item, norm_item, &child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reports_defaulted_paths_including_array_items() {
+        let original = json!({ "a": 1, "list": [{ "x": 1 }, { "x": 2 }] });
+        let normalized = json!({
+            "a": 1,
+            "b": { "c": 0 },
+            "list": [{ "x": 1, "y": false }, { "x": 2, "y": false }],
+        });
+        assert_eq!(
+            missing_fields(&original, &normalized),
+            vec!["b", "list[].y"]
+        );
+    }
+
+    #[test]
+    fn signals_tolerate_missing_and_unknown_fields() {
+        let loaded = load_signals(
+            r#"{ "memory": { "memory_count": 1, "has_max": false }, "retired": true }"#,
+        )
+        .unwrap();
+
+        assert_eq!(loaded.value.memory.memory_count, 1);
+        assert_eq!(loaded.value.instructions.loop_count, 0);
+        assert!(
+            loaded
+                .missing_fields
+                .contains(&"memory.min_pages".to_string())
+        );
+        assert!(loaded.missing_fields.contains(&"call_graph".to_string()));
+        assert!(!loaded.missing_fields.iter().any(|f| f.contains("retired")));
+    }
+}
@@ -1,2 +1,3 @@
+pub mod compat;
 pub mod model;
 pub mod render;
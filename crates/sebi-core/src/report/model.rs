@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::SCHEMA_VERSION;
 use crate::rules::catalog::RuleId;
-use crate::rules::eval::TriggeredRule;
+use crate::rules::eval::{PassedRule, RuleScope, RuleTiming, TriggeredRule};
+use crate::rules::evidence::{Evidence, Finding};
+use crate::rules::suppress::SuppressedRule;
 use crate::signals::model::Signals;
 
 /// Top-level SEBI report.
@@ -35,20 +37,14 @@ impl Report {
     ) -> Self {
         triggered.sort_by(|a, b| a.rule_id.cmp(&b.rule_id));
 
-        let triggered_rule_ids: Vec<RuleId> = triggered.iter().map(|r| r.rule_id).collect();
+        let triggered_rule_ids: Vec<RuleId> = triggered.iter().map(|r| r.rule_id.clone()).collect();
 
         let rules = RulesInfo {
             catalog,
-            triggered: triggered
-                .into_iter()
-                .map(|r| TriggeredRuleInfo {
-                    rule_id: r.rule_id.to_string(),
-                    severity: format!("{:?}", r.severity),
-                    title: r.title,
-                    message: r.message,
-                    evidence: r.evidence,
-                })
-                .collect(),
+            scope: RuleScope::default().as_str().to_string(),
+            triggered: triggered.into_iter().map(TriggeredRuleInfo::from).collect(),
+            suppressed: Vec::new(),
+            passed: Vec::new(),
         };
 
         classification.triggered_rule_ids = triggered_rule_ids;
@@ -78,6 +74,9 @@ pub struct ToolInfo {
 pub struct ArtifactInfo {
     pub path: Option<String>,
     pub size_bytes: u64,
+    /// Brotli-compressed size (`stylus-size` builds only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compressed_size_bytes: Option<u64>,
     pub hash: ArtifactHash,
 }
 
@@ -90,16 +89,106 @@ pub struct ArtifactHash {
 
 /// Parsing/analysis status.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct AnalysisInfo {
     pub status: String,
     pub warnings: Vec<String>,
+    /// Deployment target inferred from imports and exports.
+    pub target: Target,
+    /// Sorted facts the target was inferred from (matched imports/exports).
+    pub target_evidence: Vec<String>,
+    /// Best-effort guess of the source toolchain.
+    pub toolchain: ToolchainInfo,
+    /// Stylus SDK the artifact was built with, when it identifies itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sdk: Option<SdkInfo>,
+    /// Stylus-specific findings; present only when `target` is Stylus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stylus: Option<StylusAnalysis>,
+    /// Wall-clock timings; present only when requested (`--stats`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<AnalysisStats>,
+}
+
+/// SDK name and version stamped into the artifact.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SdkInfo {
+    pub name: String,
+    /// Version string as stamped; empty when only the name is known.
+    pub version: String,
+}
+
+/// Target-specific consequences for Stylus programs.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct StylusAnalysis {
+    /// Float operators plus float constants.
+    pub float_instruction_count: u64,
+    /// Stylus activation rejects floating-point instructions.
+    pub float_blocks_activation: bool,
+    /// A start section is declared; Stylus programs must not have one.
+    pub has_start_function: bool,
+    /// `vm_hooks.pay_for_memory_grow` is imported.
+    pub pays_for_memory_grow: bool,
+    /// Export names of mutable globals, in export order.
+    pub exported_mutable_globals: Vec<String>,
+}
+
+/// Wall-clock timings of one inspection, in microseconds.
+///
+/// Durations vary from run to run: a report carrying them is not
+/// byte-deterministic.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct AnalysisStats {
+    /// Whole pipeline, from reading the artifact to classification.
+    pub total_us: u64,
+    pub stages: StageTimings,
+    /// Per catalog rule, in evaluation order. Custom rules are timed as
+    /// part of `stages.eval_us` only.
+    pub rules: Vec<RuleTimingInfo>,
+}
+
+/// Time spent in each pipeline stage, in microseconds.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct StageTimings {
+    pub read_us: u64,
+    /// Section parsing, excluding `scan_us`.
+    pub parse_us: u64,
+    /// Function body scanning and call graph analysis.
+    pub scan_us: u64,
+    pub extract_us: u64,
+    /// Catalog and custom rules, and suppressions.
+    pub eval_us: u64,
+    pub classify_us: u64,
+}
+
+/// Time one catalog rule took to evaluate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RuleTimingInfo {
+    pub rule_id: RuleId,
+    pub duration_us: u64,
+}
+
+impl From<RuleTiming> for RuleTimingInfo {
+    fn from(t: RuleTiming) -> Self {
+        Self {
+            rule_id: t.rule_id,
+            duration_us: micros(t.elapsed),
+        }
+    }
+}
+
+/// Whole microseconds in `d`, saturating.
+pub fn micros(d: std::time::Duration) -> u64 {
+    u64::try_from(d.as_micros()).unwrap_or(u64::MAX)
 }
 
 impl AnalysisInfo {
     pub fn ok() -> Self {
         Self {
             status: "ok".into(),
-            warnings: vec![],
+            ..Default::default()
         }
     }
 
@@ -107,6 +196,7 @@ impl AnalysisInfo {
         Self {
             status: "parse_error".into(),
             warnings: vec![msg.into()],
+            ..Default::default()
         }
     }
 
@@ -114,6 +204,86 @@ impl AnalysisInfo {
         Self {
             status: "unsupported".into(),
             warnings: vec![msg.into()],
+            ..Default::default()
+        }
+    }
+
+    /// Parsing stopped early or hit a payload it cannot analyze, so most
+    /// signals keep their defaults.
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self.status.as_str(), "parse_error" | "unsupported")
+    }
+}
+
+/// Source-toolchain guess (see `wasm::toolchain`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ToolchainInfo {
+    pub name: Toolchain,
+    pub confidence: Confidence,
+    /// Matched heuristics with their weights, in detection-table order.
+    pub evidence: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Toolchain {
+    Rust,
+    /// C or C++ (clang/Emscripten/wasi-sdk).
+    Cpp,
+    AssemblyScript,
+    #[default]
+    Unknown,
+}
+
+impl Toolchain {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Toolchain::Rust => "rust",
+            Toolchain::Cpp => "cpp",
+            Toolchain::AssemblyScript => "assemblyscript",
+            Toolchain::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+    #[default]
+    None,
+}
+
+impl Confidence {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Confidence::High => "high",
+            Confidence::Medium => "medium",
+            Confidence::Low => "low",
+            Confidence::None => "none",
+        }
+    }
+}
+
+/// Deployment target of the artifact (see `wasm::profile::detect_target`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Target {
+    Stylus,
+    Wasi,
+    #[default]
+    Unknown,
+}
+
+impl Target {
+    /// Human-readable name used in warnings.
+    pub fn label(self) -> &'static str {
+        match self {
+            Target::Stylus => "Stylus",
+            Target::Wasi => "WASI",
+            Target::Unknown => "Unknown",
         }
     }
 }
@@ -122,7 +292,27 @@ impl AnalysisInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RulesInfo {
     pub catalog: RulesCatalogInfo,
+    /// Signals the rules were evaluated against: `"module"` or `"reachable"`.
+    /// Reports predating the field were evaluated module-wide.
+    #[serde(default = "default_rule_scope")]
+    pub scope: String,
     pub triggered: Vec<TriggeredRuleInfo>,
+    /// Triggered rules accepted by a suppressions file, excluded from the
+    /// classification. Always present, empty without suppressions.
+    #[serde(default)]
+    pub suppressed: Vec<SuppressedRuleInfo>,
+    /// Catalog rules evaluated without triggering, with the values they
+    /// checked. Opt-in (`InspectOptions::include_passed`); omitted otherwise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passed: Vec<PassedRuleInfo>,
+}
+
+fn default_rule_scope() -> String {
+    RuleScope::default().as_str().to_string()
+}
+
+fn default_confidence() -> Confidence {
+    Confidence::High
 }
 
 /// Rule catalog metadata.
@@ -130,25 +320,135 @@ pub struct RulesInfo {
 pub struct RulesCatalogInfo {
     pub catalog_version: String,
     pub ruleset: String,
+    /// SHA-256 of the effective catalog (see `rules::catalog::content_hash`);
+    /// equal hashes mean the same rules ran with the same settings.
+    #[serde(default)]
+    pub content_hash: String,
 }
 
 /// Triggered rule entry included in report output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TriggeredRuleInfo {
-    pub rule_id: String,
+    pub rule_id: RuleId,
     pub severity: String,
+    /// `high` for exact rules, lower for heuristics. Reports predating the
+    /// field load it as `high`, which every rule of those catalogs was.
+    #[serde(default = "default_confidence")]
+    pub confidence: Confidence,
     pub title: String,
     pub message: String,
+    /// What to change to clear the rule. Always set by current versions;
+    /// reports predating the field load it as empty.
+    #[serde(default)]
+    pub remediation: String,
+    /// Link to the rule's documentation.
+    #[serde(default)]
+    pub docs_url: String,
+    /// Present only for deprecated rules: the replacement rule id or the
+    /// reason the rule is retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
     pub evidence: serde_json::Value,
+    /// One entry per instance of the condition, sorted by function index,
+    /// then offset. Omitted for rules that do not report instances.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<FindingInfo>,
+}
+
+impl From<TriggeredRule> for TriggeredRuleInfo {
+    fn from(r: TriggeredRule) -> Self {
+        Self {
+            rule_id: r.rule_id,
+            severity: format!("{:?}", r.severity),
+            confidence: r.confidence,
+            title: r.title,
+            message: r.message,
+            remediation: r.remediation,
+            docs_url: r.docs_url,
+            deprecated: r.deprecated,
+            evidence: r.evidence.to_value(),
+            findings: r.findings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A single instance of a triggered rule's condition.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FindingInfo {
+    pub function_index: u32,
+    pub byte_offset: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_name: Option<String>,
+    /// Evidence specific to this instance; omitted when there is none.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub evidence: serde_json::Map<String, serde_json::Value>,
+}
+
+impl From<Finding> for FindingInfo {
+    fn from(f: Finding) -> Self {
+        Self {
+            function_index: f.location.function_index,
+            byte_offset: f.location.byte_offset,
+            function_name: f.location.function_name,
+            evidence: f.evidence,
+        }
+    }
+}
+
+/// Catalog rule that was checked and passed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassedRuleInfo {
+    pub rule_id: RuleId,
+    pub severity: String,
+    pub evidence: serde_json::Value,
+}
+
+impl From<PassedRule> for PassedRuleInfo {
+    fn from(r: PassedRule) -> Self {
+        Self {
+            rule_id: r.rule_id,
+            severity: format!("{:?}", r.severity),
+            evidence: r.evidence,
+        }
+    }
+}
+
+/// Triggered rule accepted by a suppressions entry: the full rule entry
+/// plus the entry's justification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedRuleInfo {
+    #[serde(flatten)]
+    pub rule: TriggeredRuleInfo,
+    /// Reason given in the suppressions file.
+    pub reason: String,
+    /// Last day the suppression applies, if it expires.
+    pub expires: Option<String>,
+}
+
+impl From<SuppressedRule> for SuppressedRuleInfo {
+    fn from(s: SuppressedRule) -> Self {
+        Self {
+            rule: s.rule.into(),
+            reason: s.reason,
+            expires: s.expires,
+        }
+    }
 }
 
 /// Final classification level.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ClassificationLevel {
+    /// No rule counted towards the classification.
     Safe,
+    /// Rules triggered, but the policy fails none of them (e.g. LOW rules
+    /// under the default policy).
+    Info,
     Risk,
     HighRisk,
+    /// Analysis did not complete, so the signals are too partial to support
+    /// any of the levels above.
+    Unknown,
 }
 
 impl std::fmt::Display for ClassificationLevel {
@@ -170,6 +470,33 @@ pub struct ClassificationInfo {
     pub highest_severity: String,
     pub triggered_rule_ids: Vec<RuleId>,
     pub exit_code: i32,
+    /// Present only under the weighted policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<ScoreInfo>,
+    /// Triggered rules absent from the baseline report, which alone decide
+    /// `level`. Present only when classified against a baseline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_rule_ids: Option<Vec<RuleId>>,
+    /// Triggered rules the baseline report already had. Present only when
+    /// classified against a baseline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preexisting_rule_ids: Option<Vec<RuleId>>,
+    /// Level before a count-based escalation raised it. Present only when
+    /// escalated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalated_from: Option<ClassificationLevel>,
+    /// Escalation threshold that applied (`med_count_for_high` or
+    /// `low_count_for_med`). Present only when escalated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation_rule: Option<String>,
+    /// Rules that would have triggered but were suppressed or disabled in
+    /// the catalog overrides, sorted. Omitted when there are none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed_rule_ids: Vec<RuleId>,
+    /// Level had nothing been suppressed or disabled. Present only when
+    /// `suppressed_rule_ids` is not empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsuppressed_level: Option<ClassificationLevel>,
 }
 
 impl ClassificationInfo {
@@ -181,6 +508,56 @@ impl ClassificationInfo {
             highest_severity: "NONE".into(),
             triggered_rule_ids: vec![],
             exit_code: 0,
+            score: None,
+            new_rule_ids: None,
+            preexisting_rule_ids: None,
+            escalated_from: None,
+            escalation_rule: None,
+            suppressed_rule_ids: vec![],
+            unsuppressed_level: None,
+        }
+    }
+}
+
+/// Weighted score of the triggered rules, with the table that produced it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScoreInfo {
+    /// Sum of the weights of the triggered rules' severities.
+    pub total: u64,
+    pub weights: ScoreWeights,
+    pub thresholds: ScoreThresholds,
+}
+
+/// Score each triggered rule adds, by severity.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScoreWeights {
+    pub low: u64,
+    pub med: u64,
+    pub high: u64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            low: 1,
+            med: 3,
+            high: 7,
+        }
+    }
+}
+
+/// Lowest scores classified `RISK` and `HIGH_RISK`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScoreThresholds {
+    pub risk: u64,
+    pub high_risk: u64,
+}
+
+impl Default for ScoreThresholds {
+    fn default() -> Self {
+        Self {
+            risk: 3,
+            high_risk: 7,
         }
     }
 }
@@ -195,9 +572,18 @@ mod tests {
     fn dummy_signals() -> Signals {
         Signals {
             module: Default::default(),
+            functions: Default::default(),
+            sizes: Default::default(),
             memory: Default::default(),
+            tables: Default::default(),
+            globals: Default::default(),
+            data: Default::default(),
+            features: Default::default(),
+            hostio: Default::default(),
             imports_exports: Default::default(),
             instructions: Default::default(),
+            call_graph: Default::default(),
+            reachability: None,
         }
     }
 
@@ -205,9 +591,14 @@ mod tests {
         vec![TriggeredRule {
             rule_id: RuleId::RMem01,
             severity: Severity::Med,
+            confidence: Confidence::High,
             title: "Missing memory max".into(),
             message: "Memory has no declared maximum.".into(),
-            evidence: json!({"has_max": false}),
+            remediation: String::new(),
+            docs_url: String::new(),
+            deprecated: None,
+            evidence: json!({"has_max": false}).into(),
+            findings: vec![],
         }]
     }
 
@@ -222,6 +613,7 @@ mod tests {
             ArtifactInfo {
                 path: None,
                 size_bytes: 123,
+                compressed_size_bytes: None,
                 hash: ArtifactHash {
                     algorithm: "sha256".into(),
                     value: "abc".into(),
@@ -232,6 +624,7 @@ mod tests {
             RulesCatalogInfo {
                 catalog_version: "0.1.0".into(),
                 ruleset: "default".into(),
+                content_hash: "00".into(),
             },
             dummy_triggered(),
             ClassificationInfo::safe("default"),
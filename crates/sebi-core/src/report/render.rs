@@ -1,5 +1,9 @@
 use crate::TOOL_NAME;
-use crate::report::model::Report;
+use crate::report::model::{
+    AnalysisStats, ClassificationLevel, Confidence, FindingInfo, Report, TriggeredRuleInfo,
+};
+use crate::rules::catalog::RuleDef;
+use crate::signals::model::MemorySignals;
 
 pub fn render_text(report: &Report) -> String {
     let mut out = String::new();
@@ -8,13 +12,306 @@ pub fn render_text(report: &Report) -> String {
         "Artifact size: {} bytes\n",
         report.artifact.size_bytes
     ));
+    out.push_str(&format!(
+        "Memory: {}\n",
+        describe_memory(&report.signals.memory)
+    ));
     out.push_str(&format!(
         "Classification: {:?}\n",
         report.classification.level
     ));
-    out.push_str("Triggered rules:\n");
+    if report.classification.level == ClassificationLevel::Unknown {
+        out.push_str(&format!(
+            "Analysis {}: signals are partial, so no verdict is given\n",
+            report.analysis.status
+        ));
+    }
+    if let Some(level) = &report.classification.unsuppressed_level
+        && *level != report.classification.level
+    {
+        let ids: Vec<_> = report
+            .classification
+            .suppressed_rule_ids
+            .iter()
+            .map(|id| id.as_str())
+            .collect();
+        out.push_str(&format!(
+            "NOTE: would be {level:?} without suppressions ({})\n",
+            ids.join(", ")
+        ));
+    }
+    if let Some(score) = &report.classification.score {
+        out.push_str(&format!(
+            "Score: {} (risk at {}, high risk at {})\n",
+            score.total, score.thresholds.risk, score.thresholds.high_risk
+        ));
+    }
+    if !report.rules.passed.is_empty() {
+        out.push_str(&format!(
+            "Rules: {} passed, {} triggered\n",
+            report.rules.passed.len(),
+            report.rules.triggered.len()
+        ));
+    }
+    if report.rules.scope == "module" {
+        out.push_str("Triggered rules:\n");
+    } else {
+        out.push_str(&format!(
+            "Triggered rules ({} functions only):\n",
+            report.rules.scope
+        ));
+    }
     for r in &report.rules.triggered {
-        out.push_str(&format!("  - {} [{}] {}\n", r.rule_id, r.severity, r.title));
+        out.push_str(&format!("  - {}\n", describe_rule(r)));
+        if let Some(note) = &r.deprecated {
+            out.push_str(&format!("      deprecated: {note}\n"));
+        }
+        if !r.remediation.is_empty() {
+            out.push_str(&format!("      fix: {}\n", r.remediation));
+        }
+        if !r.docs_url.is_empty() {
+            out.push_str(&format!("      docs: {}\n", r.docs_url));
+        }
+        for f in &r.findings {
+            out.push_str(&format!("      at {}\n", describe_finding(f)));
+        }
+    }
+    if !report.rules.suppressed.is_empty() {
+        out.push_str(&format!(
+            "Suppressed rules ({}, not classified):\n",
+            report.rules.suppressed.len()
+        ));
+        for s in &report.rules.suppressed {
+            let r = &s.rule;
+            out.push_str(&format!("  - {}\n", describe_rule(r)));
+            out.push_str(&format!("      reason: {}\n", s.reason));
+            if let Some(expires) = &s.expires {
+                out.push_str(&format!("      expires: {expires}\n"));
+            }
+        }
+    }
+    if !report.rules.passed.is_empty() {
+        out.push_str("Passed rules:\n");
+        for r in &report.rules.passed {
+            out.push_str(&format!("  - {} [{}]\n", r.rule_id, r.severity));
+        }
+    }
+    if let Some(stats) = &report.analysis.stats {
+        out.push_str(&format!("Timing: {}\n", describe_stats(stats)));
     }
     out
 }
+
+/// `R-MEM-02 [High] title`, with the confidence appended when it is not high.
+fn describe_rule(r: &TriggeredRuleInfo) -> String {
+    let line = format!("{} [{}] {}", r.rule_id, r.severity, r.title);
+    if r.confidence == Confidence::High {
+        line
+    } else {
+        format!("{line} ({} confidence)", r.confidence.as_str())
+    }
+}
+
+/// `malloc (function 13), offset 1042`, followed by any instance evidence
+/// as `key: value` pairs.
+fn describe_finding(f: &FindingInfo) -> String {
+    let mut line = match &f.function_name {
+        Some(name) => format!("{name} (function {})", f.function_index),
+        None => format!("function {}", f.function_index),
+    };
+    line.push_str(&format!(", offset {}", f.byte_offset));
+    for (key, value) in &f.evidence {
+        match value.as_str() {
+            Some(text) => line.push_str(&format!("; {key}: {text}")),
+            None => line.push_str(&format!("; {key}: {value}")),
+        }
+    }
+    line
+}
+
+/// Lists catalog rules one per line: id, severity, title, and the
+/// deprecation note for retired rules.
+pub fn render_catalog(rules: &[RuleDef]) -> String {
+    let mut out = String::new();
+    for def in rules {
+        out.push_str(&format!(
+            "{:<12} {:<4} {}\n",
+            def.id.as_str(),
+            format!("{:?}", def.severity),
+            def.title
+        ));
+        if let Some(note) = def.deprecated {
+            out.push_str(&format!("      deprecated: {note}\n"));
+        }
+    }
+    out
+}
+
+/// Memory limits as pages with human-readable sizes, e.g.
+/// "2 pages / 128 KiB … 16 pages / 1 MiB".
+fn describe_memory(memory: &MemorySignals) -> String {
+    let Some(min_pages) = memory.min_pages else {
+        return "none".to_string();
+    };
+    let describe = |pages: u64, bytes: Option<u64>| {
+        let plural = if pages == 1 { "" } else { "s" };
+        match bytes {
+            Some(bytes) if !(memory.bytes_saturated && bytes == u64::MAX) => {
+                format!("{pages} page{plural} / {}", format_bytes(bytes))
+            }
+            _ => format!("{pages} page{plural}"),
+        }
+    };
+    let max = match memory.max_pages {
+        Some(max_pages) => describe(max_pages, memory.max_bytes),
+        None => "no max".to_string(),
+    };
+    format!("{} … {max}", describe(min_pages, memory.min_bytes))
+}
+
+/// Stage timings in milliseconds, e.g.
+/// "12.3 ms (read 0.1, parse 1.2, scan 8.0, extract 0.4, eval 2.5, classify 0.0)".
+fn describe_stats(stats: &AnalysisStats) -> String {
+    let ms = |us: u64| format!("{:.1}", us as f64 / 1000.0);
+    let s = &stats.stages;
+    format!(
+        "{} ms (read {}, parse {}, scan {}, extract {}, eval {}, classify {})",
+        ms(stats.total_us),
+        ms(s.read_us),
+        ms(s.parse_us),
+        ms(s.scan_us),
+        ms(s.extract_us),
+        ms(s.eval_us),
+        ms(s.classify_us)
+    )
+}
+
+/// Formats a byte count in the largest binary unit that divides it exactly.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [(&str, u64); 5] = [
+        ("TiB", 1 << 40),
+        ("GiB", 1 << 30),
+        ("MiB", 1 << 20),
+        ("KiB", 1 << 10),
+        ("bytes", 1),
+    ];
+    let (unit, size) = UNITS
+        .into_iter()
+        .find(|&(_, size)| bytes >= size && bytes.is_multiple_of(size))
+        .unwrap_or(("bytes", 1));
+    format!("{} {unit}", bytes / size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_described_in_pages_and_bytes() {
+        let memory = MemorySignals {
+            min_pages: Some(2),
+            max_pages: Some(16),
+            min_bytes: Some(2 * 65_536),
+            max_bytes: Some(16 * 65_536),
+            ..Default::default()
+        };
+        assert_eq!(
+            describe_memory(&memory),
+            "2 pages / 128 KiB … 16 pages / 1 MiB"
+        );
+
+        let memory = MemorySignals {
+            min_pages: Some(1),
+            min_bytes: Some(65_536),
+            ..Default::default()
+        };
+        assert_eq!(describe_memory(&memory), "1 page / 64 KiB … no max");
+        assert_eq!(describe_memory(&MemorySignals::default()), "none");
+    }
+
+    #[test]
+    fn catalog_listing_marks_deprecated_rules() {
+        let mut rules = crate::rules::catalog::catalog();
+        rules[0].deprecated = Some("superseded by R-MEM-04");
+        let out = render_catalog(&rules);
+
+        assert!(out.starts_with(
+            "R-MEM-01     Med  Missing declared memory maximum\n      deprecated: superseded by R-MEM-04\n"
+        ));
+        assert_eq!(out.matches("deprecated:").count(), 1);
+        assert_eq!(out.lines().count(), rules.len() + 1);
+    }
+
+    #[test]
+    fn rule_line_notes_confidence_below_high() {
+        let mut rule: TriggeredRuleInfo = serde_json::from_value(serde_json::json!({
+            "rule_id": "R-MEM-02",
+            "severity": "Med",
+            "title": "Runtime memory growth detected",
+            "message": "",
+            "evidence": {}
+        }))
+        .unwrap();
+        assert_eq!(
+            describe_rule(&rule),
+            "R-MEM-02 [Med] Runtime memory growth detected"
+        );
+
+        rule.confidence = Confidence::Medium;
+        assert_eq!(
+            describe_rule(&rule),
+            "R-MEM-02 [Med] Runtime memory growth detected (medium confidence)"
+        );
+    }
+
+    #[test]
+    fn findings_named_when_known() {
+        let mut finding = FindingInfo {
+            function_index: 13,
+            byte_offset: 1042,
+            function_name: Some("malloc".into()),
+            evidence: Default::default(),
+        };
+        assert_eq!(
+            describe_finding(&finding),
+            "malloc (function 13), offset 1042"
+        );
+
+        finding.function_name = None;
+        finding
+            .evidence
+            .insert("allocator_pattern".into(), "malloc".into());
+        assert_eq!(
+            describe_finding(&finding),
+            "function 13, offset 1042; allocator_pattern: malloc"
+        );
+    }
+
+    #[test]
+    fn stats_summarized_in_milliseconds() {
+        let stats = AnalysisStats {
+            total_us: 12_345,
+            stages: crate::report::model::StageTimings {
+                read_us: 100,
+                parse_us: 1_200,
+                scan_us: 8_000,
+                extract_us: 449,
+                eval_us: 2_550,
+                classify_us: 4,
+            },
+            rules: vec![],
+        };
+        assert_eq!(
+            describe_stats(&stats),
+            "12.3 ms (read 0.1, parse 1.2, scan 8.0, extract 0.4, eval 2.5, classify 0.0)"
+        );
+    }
+
+    #[test]
+    fn bytes_use_largest_exact_unit() {
+        assert_eq!(format_bytes(0), "0 bytes");
+        assert_eq!(format_bytes(3 * 65_536), "192 KiB");
+        assert_eq!(format_bytes(1 << 32), "4 GiB");
+        assert_eq!(format_bytes(1000), "1000 bytes");
+    }
+}
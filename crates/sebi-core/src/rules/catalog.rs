@@ -2,7 +2,35 @@
 //!
 //! This module is strictly declarative and contains no evaluation logic.
 
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::report::model::Confidence;
+
+/// Default R-SIZE-01 threshold on the raw artifact size.
+pub const DEFAULT_SIZE_THRESHOLD: u64 = 200_000;
+
+/// Default R-SIZE-01 threshold on the brotli-compressed size; Stylus rejects
+/// programs above 24 KiB compressed at activation.
+pub const DEFAULT_COMPRESSED_SIZE_THRESHOLD: u64 = 24 * 1024;
+
+/// Default R-MEM-04 ceiling on a declared memory maximum: 1,024 pages (64 MiB).
+pub const DEFAULT_MAX_PAGES_CEILING: u64 = 1_024;
+
+/// Default R-LOOP-02 threshold on `loop` nesting depth.
+pub const DEFAULT_LOOP_DEPTH_THRESHOLD: u32 = 3;
+
+/// Default R-CALL-02 threshold on the number of `call_indirect` sites.
+pub const DEFAULT_CALL_INDIRECT_THRESHOLD: u64 = 100;
+
+/// Default R-SIZE-02 threshold on the number of defined functions.
+pub const DEFAULT_FUNCTION_COUNT_THRESHOLD: u32 = 1_000;
+
+/// Default R-DATA-01 threshold on initialized data: 64 KiB.
+pub const DEFAULT_DATA_BYTES_THRESHOLD: u64 = 64 * 1024;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -12,47 +40,266 @@ pub enum Severity {
     High,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+impl Severity {
+    /// One level lower; `Low` stays `Low`.
+    pub fn downgraded(&self) -> Severity {
+        match self {
+            Severity::High => Severity::Med,
+            Severity::Med | Severity::Low => Severity::Low,
+        }
+    }
+}
+
+/// Rule identifier.
+///
+/// Built-in rules have one variant each; rules defined outside the
+/// built-in catalog use `Custom`. Serialized, parsed and displayed as the
+/// external id (`R-MEM-01`).
+///
+/// The derived `Ord` is the canonical rule order used throughout a report:
+/// built-in rules in catalog declaration order, then custom ids lexically.
+/// `Custom` must therefore stay the last variant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RuleId {
     RMem01,
     RMem02,
     RCall01,
     RLoop01,
     RSize01,
+    RTable01,
+    RImport01,
+    RWasi01,
+    RStart01,
+    RGlobal01,
+    RExport01,
+    RMem04,
+    RLoop02,
+    RLoop03,
+    RCall02,
+    RCall03,
+    RSize02,
+    RData01,
+    RFeat01,
+    RAtomic01,
+    RSimd01,
+    RAnalysis01,
+    RCombo01,
+    Custom(String),
 }
 
 impl RuleId {
-    pub fn as_str(&self) -> &'static str {
+    /// Every built-in rule id, in declaration order.
+    pub const BUILTIN: [RuleId; 23] = [
+        RuleId::RMem01,
+        RuleId::RMem02,
+        RuleId::RCall01,
+        RuleId::RLoop01,
+        RuleId::RSize01,
+        RuleId::RTable01,
+        RuleId::RImport01,
+        RuleId::RWasi01,
+        RuleId::RStart01,
+        RuleId::RGlobal01,
+        RuleId::RExport01,
+        RuleId::RMem04,
+        RuleId::RLoop02,
+        RuleId::RLoop03,
+        RuleId::RCall02,
+        RuleId::RCall03,
+        RuleId::RSize02,
+        RuleId::RData01,
+        RuleId::RFeat01,
+        RuleId::RAtomic01,
+        RuleId::RSimd01,
+        RuleId::RAnalysis01,
+        RuleId::RCombo01,
+    ];
+
+    pub fn as_str(&self) -> &str {
         match self {
             RuleId::RMem01 => "R-MEM-01",
             RuleId::RMem02 => "R-MEM-02",
             RuleId::RCall01 => "R-CALL-01",
             RuleId::RLoop01 => "R-LOOP-01",
             RuleId::RSize01 => "R-SIZE-01",
+            RuleId::RTable01 => "R-TABLE-01",
+            RuleId::RImport01 => "R-IMPORT-01",
+            RuleId::RWasi01 => "R-WASI-01",
+            RuleId::RStart01 => "R-START-01",
+            RuleId::RGlobal01 => "R-GLOBAL-01",
+            RuleId::RExport01 => "R-EXPORT-01",
+            RuleId::RMem04 => "R-MEM-04",
+            RuleId::RLoop02 => "R-LOOP-02",
+            RuleId::RLoop03 => "R-LOOP-03",
+            RuleId::RCall02 => "R-CALL-02",
+            RuleId::RCall03 => "R-CALL-03",
+            RuleId::RSize02 => "R-SIZE-02",
+            RuleId::RData01 => "R-DATA-01",
+            RuleId::RFeat01 => "R-FEAT-01",
+            RuleId::RAtomic01 => "R-ATOMIC-01",
+            RuleId::RSimd01 => "R-SIMD-01",
+            RuleId::RAnalysis01 => "R-ANALYSIS-01",
+            RuleId::RCombo01 => "R-COMBO-01",
+            RuleId::Custom(id) => id,
         }
     }
+
+    /// Whether this id names a rule of the built-in catalog.
+    pub fn is_builtin(&self) -> bool {
+        !matches!(self, RuleId::Custom(_))
+    }
 }
 
 impl std::fmt::Display for RuleId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            RuleId::RMem01 => "R-MEM-01",
-            RuleId::RMem02 => "R-MEM-02",
-            RuleId::RCall01 => "R-CALL-01",
-            RuleId::RLoop01 => "R-LOOP-01",
-            RuleId::RSize01 => "R-SIZE-01",
-        };
-        write!(f, "{s}")
+        f.pad(self.as_str())
+    }
+}
+
+impl std::str::FromStr for RuleId {
+    type Err = anyhow::Error;
+
+    /// Parses an external id. Ids of built-in rules map to their variant;
+    /// anything else made of ASCII letters, digits, `-`, `_` and `.` is a
+    /// custom id.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        if let Some(id) = Self::BUILTIN.iter().find(|id| id.as_str() == s) {
+            return Ok(id.clone());
+        }
+        let valid = !s.is_empty()
+            && s.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+        if !valid {
+            anyhow::bail!("invalid rule id `{s}`");
+        }
+        Ok(RuleId::Custom(s.to_string()))
+    }
+}
+
+impl PartialEq<str> for RuleId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RuleId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Serialize for RuleId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
     }
 }
 
+impl<'de> Deserialize<'de> for RuleId {
+    /// Also accepts the variant names (`RMem01`) that reports before schema
+    /// 0.5.0 used in `classification.triggered_rule_ids`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if let Some(id) = Self::BUILTIN.iter().find(|id| format!("{id:?}") == s) {
+            return Ok(id.clone());
+        }
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Named threshold parameters of a rule and their values, keyed by the
+/// names used in evidence and override files (`SIZE_THRESHOLD`, ...).
+pub type RuleParams = BTreeMap<&'static str, u64>;
+
 /// Static metadata for a risk classification rule.
 #[derive(Debug, Clone)]
 pub struct RuleDef {
     pub id: RuleId,
     pub severity: Severity,
+    /// How reliably a trigger identifies the issue. Exact rules are
+    /// `High`; heuristic rules must say how much less they are.
+    pub confidence: Confidence,
     pub title: &'static str,
-    pub message: &'static str,
+    pub message: Cow<'static, str>,
+    /// Threshold parameters, defaulted here and adjusted by options or
+    /// catalog overrides. Every trigger reports them in its evidence.
+    pub params: RuleParams,
+    /// What to change in the artifact or its build to clear the rule.
+    pub remediation: &'static str,
+    /// Link to the rule's section in `docs/RULES.md`.
+    pub docs_url: &'static str,
+    /// Set once a rule is retired: the replacement rule id, or why it was
+    /// dropped. Deprecated rules still evaluate unless excluded.
+    pub deprecated: Option<&'static str>,
+    /// Constituents of a composite rule, which triggers when every one of
+    /// their conditions holds. Empty for ordinary rules.
+    pub requires: &'static [RuleId],
+}
+
+impl RuleDef {
+    /// Whether the rule is a conjunction over other rules' conditions.
+    pub fn is_composite(&self) -> bool {
+        !self.requires.is_empty()
+    }
+
+    /// Value of a declared parameter.
+    ///
+    /// Panics if the rule does not declare `name`; parameter names are
+    /// fixed by the catalog.
+    pub fn param(&self, name: &str) -> u64 {
+        match self.params.get(name) {
+            Some(value) => *value,
+            None => panic!("{} declares no parameter {name}", self.id),
+        }
+    }
+}
+
+/// Sets parameter `name` on every rule declaring it. Returns whether any
+/// rule does.
+pub fn set_param(rules: &mut [RuleDef], name: &str, value: u64) -> bool {
+    let mut found = false;
+    for rule in rules {
+        if let Some(slot) = rule.params.get_mut(name) {
+            *slot = value;
+            found = true;
+        }
+    }
+    found
+}
+
+/// Hex SHA-256 of the effective catalog: every built-in rule's id, enabled
+/// state, severity and parameters, plus any custom rules, in rule id order.
+///
+/// Built-in rules absent from `rules` count as disabled. Titles and
+/// messages are not hashed; the hash tracks what decides a verdict.
+pub fn content_hash(rules: &[RuleDef]) -> String {
+    let builtin = RuleId::BUILTIN;
+    let mut entries: BTreeMap<&str, serde_json::Value> = builtin
+        .iter()
+        .map(|id| (id.as_str(), serde_json::json!({ "enabled": false })))
+        .collect();
+    for def in rules {
+        entries.insert(
+            def.id.as_str(),
+            serde_json::json!({
+                "enabled": true,
+                "severity": def.severity,
+                "params": def.params,
+            }),
+        );
+    }
+    // Object keys serialize sorted, so the encoding is canonical.
+    let canonical = serde_json::to_vec(&entries).expect("catalog serializes");
+    hex::encode(Sha256::digest(canonical))
+}
+
+/// Expands to the URL of a `docs/RULES.md` section.
+macro_rules! rules_doc {
+    ($anchor:literal) => {
+        concat!(
+            "https://github.com/aspect-build/sebi/blob/main/docs/RULES.md#",
+            $anchor
+        )
+    };
 }
 
 /// Returns the immutable SEBI rule catalog.
@@ -61,32 +308,335 @@ pub fn catalog() -> Vec<RuleDef> {
         RuleDef {
             id: RuleId::RMem01,
             severity: Severity::Med,
+            confidence: Confidence::High,
             title: "Missing declared memory maximum",
-            message: "Memory has no declared maximum; static bounding is reduced.",
+            message: Cow::Borrowed("Memory has no declared maximum; static bounding is reduced."),
+            remediation: "Declare a maximum in the memory type, e.g. via `-C link-arg=--max-memory=<bytes>` (Rust) or `-Wl,--max-memory=<bytes>` (C/C++).",
+            docs_url: rules_doc!("r-mem-01--missing-declared-memory-maximum"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
         },
         RuleDef {
             id: RuleId::RMem02,
             severity: Severity::High,
+            confidence: Confidence::High,
             title: "Runtime memory growth detected",
-            message: "memory.grow present; runtime memory expansion capability detected.",
+            message: Cow::Borrowed(
+                "memory.grow present; runtime memory expansion capability detected.",
+            ),
+            remediation: "Size memory statically and avoid allocators that grow on demand; if growth is required, bound it with a declared maximum and review every memory.grow site.",
+            docs_url: rules_doc!("r-mem-02--runtime-memory-growth-detected"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
         },
         RuleDef {
             id: RuleId::RCall01,
             severity: Severity::High,
+            confidence: Confidence::High,
             title: "Dynamic dispatch via function tables",
-            message: "call_indirect present; dynamic dispatch reduces call-graph predictability.",
+            message: Cow::Borrowed(
+                "call_indirect present; dynamic dispatch reduces call-graph predictability.",
+            ),
+            remediation: "Replace trait objects, function pointers or virtual calls with static dispatch (generics, enums, match) where the call targets are known.",
+            docs_url: rules_doc!("r-call-01--dynamic-dispatch-via-function-tables"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
         },
         RuleDef {
             id: RuleId::RLoop01,
             severity: Severity::Med,
+            confidence: Confidence::High,
             title: "Loop constructs detected",
-            message: "loop present; termination cannot always be proven statically.",
+            message: Cow::Borrowed("loop present; termination cannot always be proven statically."),
+            remediation: "Ensure every loop has an explicit, input-independent bound; prefer fixed-size iteration over data-dependent loops.",
+            docs_url: rules_doc!("r-loop-01--loop-constructs-detected"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
         },
         RuleDef {
             id: RuleId::RSize01,
             severity: Severity::Med,
+            confidence: Confidence::High,
             title: "Large WASM artifact",
-            message: "Artifact size exceeds threshold; complexity correlation signal.",
+            message: Cow::Borrowed(
+                "Artifact size exceeds threshold; complexity correlation signal.",
+            ),
+            remediation: "Build in release mode with size optimizations (`opt-level = \"z\"`, LTO, `wasm-opt -Oz`), strip debug info, and drop unused dependencies.",
+            docs_url: rules_doc!("r-size-01--large-wasm-artifact"),
+            params: RuleParams::from([
+                ("SIZE_THRESHOLD", DEFAULT_SIZE_THRESHOLD),
+                (
+                    "COMPRESSED_SIZE_THRESHOLD",
+                    DEFAULT_COMPRESSED_SIZE_THRESHOLD,
+                ),
+            ]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RTable01,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Host-visible function table",
+            message: Cow::Borrowed(
+                "funcref table is imported or exported; the host can change indirect-call targets.",
+            ),
+            remediation: "Keep the function table internal: remove the table import/export (e.g. drop `--export-table` / `--import-table` linker flags).",
+            docs_url: rules_doc!("r-table-01--host-visible-function-table"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RImport01,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Import from unexpected module",
+            message: Cow::Borrowed(
+                "Imports come from a module outside the allowlist; the target host may not provide them.",
+            ),
+            remediation: "Remove imports from modules the host does not provide, or add the module to the import allowlist if the target really supplies it.",
+            docs_url: rules_doc!("r-import-01--import-from-unexpected-module"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RWasi01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "WASI imports detected",
+            message: Cow::Borrowed(
+                "Imports from a WASI namespace; likely built for wasm32-wasi instead of a contract target.",
+            ),
+            remediation: "Build for the contract target (e.g. `wasm32-unknown-unknown`) instead of a WASI target, and avoid std APIs that pull in WASI imports.",
+            docs_url: rules_doc!("r-wasi-01--wasi-imports-detected"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RStart01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Start function declared",
+            message: Cow::Borrowed(
+                "start function runs implicitly at instantiation, outside any exported call.",
+            ),
+            remediation: "Move initialization into an explicit exported entrypoint and remove the start section.",
+            docs_url: rules_doc!("r-start-01--start-function-declared"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RGlobal01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Exported mutable global",
+            message: Cow::Borrowed(
+                "Mutable global is exported; the host can rewrite module state between calls.",
+            ),
+            remediation: "Stop exporting mutable globals; expose state through exported functions or make the global immutable.",
+            docs_url: rules_doc!("r-global-01--exported-mutable-global"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RExport01,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Required export missing",
+            message: Cow::Borrowed(
+                "An export the target host looks up is missing or has the wrong kind; the artifact cannot be activated.",
+            ),
+            remediation: "Export the entrypoint the host requires with the expected kind (Stylus: a `user_entrypoint` function), e.g. via the SDK's entrypoint macro.",
+            docs_url: rules_doc!("r-export-01--required-export-missing"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RMem04,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Declared memory maximum above ceiling",
+            message: Cow::Borrowed(
+                "Memory maximum exceeds the configured ceiling; the declared bound is too loose to be meaningful.",
+            ),
+            remediation: "Lower the declared memory maximum to what the contract actually needs, or raise the ceiling if the host can back it.",
+            docs_url: rules_doc!("r-mem-04--declared-memory-maximum-above-ceiling"),
+            params: RuleParams::from([("MAX_PAGES_CEILING", DEFAULT_MAX_PAGES_CEILING)]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RLoop02,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Deeply nested loops",
+            message: Cow::Borrowed(
+                "loop nesting reaches the depth threshold; execution cost may grow super-linearly with input.",
+            ),
+            remediation: "Flatten nested loops or cap their iteration counts so cost stays linear in input size.",
+            docs_url: rules_doc!("r-loop-02--deeply-nested-loops"),
+            params: RuleParams::from([(
+                "LOOP_DEPTH_THRESHOLD",
+                u64::from(DEFAULT_LOOP_DEPTH_THRESHOLD),
+            )]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RLoop03,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "memory.grow inside a loop",
+            message: Cow::Borrowed(
+                "memory.grow executes inside a loop; memory can grow in proportion to an input-controlled iteration count.",
+            ),
+            remediation: "Allocate up front outside the loop, or bound the loop so total growth is fixed.",
+            docs_url: rules_doc!("r-loop-03--memorygrow-inside-a-loop"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RCall02,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Pervasive dynamic dispatch",
+            message: Cow::Borrowed(
+                "call_indirect count reaches the threshold; dispatch is too pervasive for the call graph to be reviewed statically.",
+            ),
+            remediation: "Reduce dynamic dispatch: replace trait objects and function pointers with static dispatch, or split dispatch-heavy code out of the contract.",
+            docs_url: rules_doc!("r-call-02--pervasive-dynamic-dispatch"),
+            params: RuleParams::from([(
+                "CALL_INDIRECT_THRESHOLD",
+                DEFAULT_CALL_INDIRECT_THRESHOLD,
+            )]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RCall03,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Recursion detected",
+            message: Cow::Borrowed(
+                "Call graph contains a cycle; recursion repeats work without a loop, so loop-based bounding does not see it.",
+            ),
+            remediation: "Rewrite recursion as iteration with an explicit bound, or cap the recursion depth.",
+            docs_url: rules_doc!("r-call-03--recursion-detected"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RSize02,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Large number of functions",
+            message: Cow::Borrowed(
+                "Defined function count exceeds the threshold; a sprawling module is costly to review even when its byte size is small.",
+            ),
+            remediation: "Reduce code size: enable LTO, remove unused dependencies and generic instantiations, and split the module if it serves several purposes.",
+            docs_url: rules_doc!("r-size-02--large-number-of-functions"),
+            params: RuleParams::from([(
+                "FUNCTION_COUNT_THRESHOLD",
+                u64::from(DEFAULT_FUNCTION_COUNT_THRESHOLD),
+            )]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RData01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Oversized data section",
+            message: Cow::Borrowed(
+                "Initialized data exceeds the threshold; large embedded data raises deployment cost and can hide secondary payloads.",
+            ),
+            remediation: "Move large constant data off-chain or into storage, and strip embedded strings, tables and panic messages that are not needed.",
+            docs_url: rules_doc!("r-data-01--oversized-data-section"),
+            params: RuleParams::from([("DATA_BYTES_THRESHOLD", DEFAULT_DATA_BYTES_THRESHOLD)]),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RFeat01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "WebAssembly feature outside the allowed set",
+            message: Cow::Borrowed(
+                "Module uses proposals the target does not accept; hosts validating with those features off will reject it.",
+            ),
+            remediation: "Build without the listed proposals (target features), or add them to the allowed set if the target host enables them.",
+            docs_url: rules_doc!("r-feat-01--feature-outside-the-allowed-set"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RAtomic01,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Atomics or shared memory detected",
+            message: Cow::Borrowed(
+                "Atomic operators or shared memory present; concurrency primitives have no place in single-threaded contract execution.",
+            ),
+            remediation: "Build without the `atomics` target feature and shared memory; contracts run single-threaded and need no synchronization.",
+            docs_url: rules_doc!("r-atomic-01--atomics-or-shared-memory"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RSimd01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "SIMD instructions detected",
+            message: Cow::Borrowed(
+                "v128 operators present; vector code complicates deterministic execution and gas metering.",
+            ),
+            remediation: "Build without the `simd128` target feature, or allow SIMD explicitly if the host accepts it and its cost is understood.",
+            docs_url: rules_doc!("r-simd-01--simd-instructions"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RAnalysis01,
+            severity: Severity::Med,
+            confidence: Confidence::High,
+            title: "Analysis incomplete",
+            message: Cow::Borrowed(
+                "The module could not be fully analyzed; the absence of other findings verifies nothing.",
+            ),
+            remediation: "Check the artifact is a core WebAssembly module (not a component or a truncated file) and re-run; see the analysis warnings for the failing section.",
+            docs_url: rules_doc!("r-analysis-01--analysis-incomplete"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[],
+        },
+        RuleDef {
+            id: RuleId::RCombo01,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Unbounded dynamic memory growth under iteration",
+            message: Cow::Borrowed(
+                "Memory has no declared maximum and grows at runtime in a module that loops; nothing but gas bounds how far it grows.",
+            ),
+            remediation: "Declare a memory maximum, and bound or hoist the allocations made inside loops; clearing any one of R-MEM-01, R-MEM-02 or R-LOOP-01 clears this rule.",
+            docs_url: rules_doc!("r-combo-01--unbounded-dynamic-memory-growth-under-iteration"),
+            params: RuleParams::new(),
+            deprecated: None,
+            requires: &[RuleId::RMem01, RuleId::RMem02, RuleId::RLoop01],
         },
     ]
 }
@@ -103,13 +653,134 @@ mod tests {
 
         for rule in rules {
             assert!(
-                seen.insert(rule.id),
+                seen.insert(rule.id.clone()),
                 "Duplicate rule id detected: {:?}",
                 rule.id
             );
         }
     }
 
+    #[test]
+    fn every_rule_has_remediation_and_docs_url() {
+        for rule in catalog() {
+            assert!(
+                !rule.remediation.is_empty(),
+                "{} has no remediation",
+                rule.id
+            );
+            let anchor = rule.id.as_str().to_lowercase();
+            assert!(
+                rule.docs_url.contains(&format!("RULES.md#{anchor}-")),
+                "{} links to {}",
+                rule.id,
+                rule.docs_url
+            );
+        }
+    }
+
+    #[test]
+    fn composites_require_ordinary_builtin_rules() {
+        let rules = catalog();
+        for rule in rules.iter().filter(|def| def.is_composite()) {
+            for id in rule.requires {
+                let constituent = rules.iter().find(|def| def.id == *id).unwrap();
+                assert!(!constituent.is_composite(), "{} nests {id}", rule.id);
+            }
+            assert!(rule.requires.is_sorted(), "{}", rule.id);
+        }
+    }
+
+    #[test]
+    fn set_param_reaches_declaring_rule_only() {
+        let mut rules = catalog();
+        assert!(set_param(&mut rules, "LOOP_DEPTH_THRESHOLD", 7));
+        assert!(!set_param(&mut rules, "NO_SUCH_PARAM", 7));
+
+        for rule in &rules {
+            match rule.id {
+                RuleId::RLoop02 => assert_eq!(rule.param("LOOP_DEPTH_THRESHOLD"), 7),
+                _ => assert!(!rule.params.contains_key("LOOP_DEPTH_THRESHOLD")),
+            }
+        }
+    }
+
+    /// Changes whenever a built-in rule is added or its severity or
+    /// parameters change; bump `RULE_CATALOG_VERSION` along with it.
+    const BUILTIN_CONTENT_HASH: &str =
+        "73d626a71b56e272f76df439a9b7e5862ffc273d05a4b7f620185f3cff52b147";
+
+    #[test]
+    fn builtin_content_hash_is_pinned() {
+        assert_eq!(content_hash(&catalog()), BUILTIN_CONTENT_HASH);
+    }
+
+    #[test]
+    fn content_hash_tracks_effective_rules_not_order() {
+        let base = content_hash(&catalog());
+
+        let mut reversed = catalog();
+        reversed.reverse();
+        assert_eq!(content_hash(&reversed), base);
+
+        let mut rules = catalog();
+        rules.retain(|def| def.id != RuleId::RLoop01);
+        assert_ne!(content_hash(&rules), base);
+
+        let mut rules = catalog();
+        rules[0].severity = Severity::Low;
+        assert_ne!(content_hash(&rules), base);
+
+        let mut rules = catalog();
+        set_param(&mut rules, "DATA_BYTES_THRESHOLD", 1);
+        assert_ne!(content_hash(&rules), base);
+
+        let mut rules = catalog();
+        rules[0].message = Cow::Borrowed("reworded");
+        assert_eq!(content_hash(&rules), base);
+    }
+
+    #[test]
+    fn builtin_ids_match_catalog_order() {
+        let ids: Vec<RuleId> = catalog().into_iter().map(|def| def.id).collect();
+        assert_eq!(ids, RuleId::BUILTIN);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn rule_id_round_trips_through_str_and_serde() {
+        for id in RuleId::BUILTIN {
+            assert_eq!(id.to_string().parse::<RuleId>().unwrap(), id);
+            let json = serde_json::to_string(&id).unwrap();
+            assert_eq!(json, format!("\"{}\"", id.as_str()));
+            assert_eq!(serde_json::from_str::<RuleId>(&json).unwrap(), id);
+        }
+
+        let custom: RuleId = "ACME-NET-01".parse().unwrap();
+        assert_eq!(custom, RuleId::Custom("ACME-NET-01".to_string()));
+        assert!(!custom.is_builtin());
+        assert_eq!(serde_json::to_string(&custom).unwrap(), "\"ACME-NET-01\"");
+
+        assert!("".parse::<RuleId>().is_err());
+        assert!("R MEM 01".parse::<RuleId>().is_err());
+    }
+
+    #[test]
+    fn rule_id_reads_legacy_variant_names() {
+        let ids: Vec<RuleId> = serde_json::from_str(r#"["RMem01", "RLoop02"]"#).unwrap();
+        assert_eq!(ids, vec![RuleId::RMem01, RuleId::RLoop02]);
+    }
+
+    #[test]
+    fn custom_ids_sort_after_builtin_ids_lexically() {
+        let mut ids: Vec<RuleId> = ["Z-01", "R-SIMD-01", "A-01", "R-MEM-02", "R-CALL-01"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        ids.sort();
+        let ids: Vec<&str> = ids.iter().map(RuleId::as_str).collect();
+        assert_eq!(ids, ["R-MEM-02", "R-CALL-01", "R-SIMD-01", "A-01", "Z-01"]);
+    }
+
     #[test]
     fn severity_ordering_is_correct() {
         assert!(Severity::Low < Severity::Med);
@@ -13,22 +13,111 @@
 //! - Parsing WASM artifacts
 //! - Mutating signals
 //!
-//! The classification policy is intentionally simple and explainable:
+//! The default classification policy is intentionally simple and explainable:
 //!
 //!   - Any HIGH severity rule  → HIGH_RISK
 //!   - Else any MED severity    → RISK
+//!   - Else any LOW severity    → INFO
 //!   - Else                     → SAFE
 //!
-//! This policy is deterministic and does not depend on rule evaluation order.
+//! [`Policy`] names the alternatives: `strict` also fails on LOW rules,
+//! `high-only` passes anything below HIGH, and `weighted` sums a weight per
+//! triggered rule and compares the score against thresholds. Every policy is deterministic and
+//! does not depend on rule evaluation order. [`classify_ignoring_low_confidence`]
+//! applies a policy to the rules whose confidence is above `low` only.
+//!
+//! [`classify_against_baseline`] classifies only what a baseline report did
+//! not already have. [`escalate`] raises the level when enough lower-severity
+//! rules trigger together. When analysis did not complete, [`withhold_if_incomplete`] replaces
+//! whatever the policy derived with `UNKNOWN`.
 
-use crate::report::model::{ClassificationInfo, ClassificationLevel};
+use crate::report::model::{
+    AnalysisInfo, ClassificationInfo, ClassificationLevel, Confidence, FindingInfo, Report,
+    ScoreInfo, ScoreThresholds, ScoreWeights,
+};
 use crate::rules::catalog::Severity;
 use crate::rules::eval::TriggeredRule;
+use crate::rules::evidence::Location;
+
+/// Exit code of an `UNKNOWN` classification unless the caller picks another.
+pub const DEFAULT_UNKNOWN_EXIT_CODE: i32 = 3;
+
+/// Exit code of an `INFO` classification unless the caller picks another.
+pub const DEFAULT_INFO_EXIT_CODE: i32 = 0;
+
+/// Rule ids named in `reason` before the rest are counted as "+N more".
+const REASON_ID_LIMIT: usize = 3;
+
+/// How triggered rule severities map to a classification level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Any HIGH → HIGH_RISK, else any MED → RISK, else INFO.
+    #[default]
+    Default,
+    /// Any HIGH → HIGH_RISK, else any triggered rule, LOW included → RISK.
+    Strict,
+    /// Any HIGH → HIGH_RISK, else INFO: MED rules never fail a build.
+    HighOnly,
+    /// Each triggered rule adds its severity's weight to a score, which is
+    /// compared against the thresholds. Recorded as `classification.score`.
+    Weighted {
+        weights: ScoreWeights,
+        thresholds: ScoreThresholds,
+    },
+}
+
+/// Counts of triggered rules at which a lower severity is treated as the
+/// next one up. Unset thresholds, the default, never escalate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Escalation {
+    /// MED rules that together classify like one HIGH rule.
+    pub med_count_for_high: Option<usize>,
+    /// LOW rules that together classify like one MED rule.
+    pub low_count_for_med: Option<usize>,
+}
+
+impl Policy {
+    /// Name recorded in `classification.policy`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Policy::Default => "default",
+            Policy::Strict => "strict",
+            Policy::HighOnly => "high-only",
+            Policy::Weighted { .. } => "weighted",
+        }
+    }
+
+    /// The weighted policy with the default weights (LOW 1, MED 3, HIGH 7)
+    /// and thresholds (RISK at 3, HIGH_RISK at 7).
+    pub fn weighted() -> Self {
+        Policy::Weighted {
+            weights: ScoreWeights::default(),
+            thresholds: ScoreThresholds::default(),
+        }
+    }
+
+    fn level(self, highest: &Severity, score: Option<&ScoreInfo>) -> ClassificationLevel {
+        if let Some(score) = score {
+            return if score.total >= score.thresholds.high_risk {
+                ClassificationLevel::HighRisk
+            } else if score.total >= score.thresholds.risk {
+                ClassificationLevel::Risk
+            } else {
+                ClassificationLevel::Safe
+            };
+        }
+        match (self, highest) {
+            (_, Severity::High) => ClassificationLevel::HighRisk,
+            (Policy::Default, Severity::Med) | (Policy::Strict, _) => ClassificationLevel::Risk,
+            _ => ClassificationLevel::Safe,
+        }
+    }
+}
 
 /// Derives a final classification from triggered rules.
 ///
 /// This function applies the default SEBI classification policy
-/// to a list of already-triggered rules.
+/// to a list of already-triggered rules; see [`classify_with_policy`].
 ///
 /// Determinism guarantees:
 /// - Same `triggered` input → identical `ClassificationInfo`
@@ -37,12 +126,38 @@ use crate::rules::eval::TriggeredRule;
 ///
 /// Exit code mapping:
 /// - SAFE      → 0
+/// - INFO      → [`DEFAULT_INFO_EXIT_CODE`]
 /// - RISK      → 1
 /// - HIGH_RISK → 2
+/// - UNKNOWN   → [`DEFAULT_UNKNOWN_EXIT_CODE`], see [`withhold_if_incomplete`]
 pub fn classify(triggered: &[TriggeredRule]) -> ClassificationInfo {
-    // No triggered rules implies SAFE under default policy.
+    classify_with_policy(triggered, &Policy::Default)
+}
+
+/// Derives a final classification from triggered rules under `policy`,
+/// whose name is recorded in `ClassificationInfo::policy`.
+pub fn classify_with_policy(triggered: &[TriggeredRule], policy: &Policy) -> ClassificationInfo {
+    let score = match *policy {
+        Policy::Weighted {
+            weights,
+            thresholds,
+        } => Some(ScoreInfo {
+            total: triggered
+                .iter()
+                .map(|r| weight(&weights, &r.severity))
+                .sum(),
+            weights,
+            thresholds,
+        }),
+        _ => None,
+    };
+
+    // No triggered rules implies SAFE under every policy.
     if triggered.is_empty() {
-        return ClassificationInfo::safe("default");
+        return ClassificationInfo {
+            score,
+            ..ClassificationInfo::safe(policy.as_str())
+        };
     }
 
     // Compute the highest observed severity across all triggered rules.
@@ -54,48 +169,340 @@ pub fn classify(triggered: &[TriggeredRule]) -> ClassificationInfo {
         .cloned()
         .unwrap_or(Severity::Low);
 
-    let level = if triggered.iter().any(|r| r.severity == Severity::High) {
-        ClassificationLevel::HighRisk
-    } else if triggered.iter().any(|r| r.severity == Severity::Med) {
-        ClassificationLevel::Risk
-    } else {
-        ClassificationLevel::Safe
+    // Triggered rules the policy does not fail are INFO, never SAFE.
+    let level = match policy.level(&highest, score.as_ref()) {
+        ClassificationLevel::Safe => ClassificationLevel::Info,
+        level => level,
     };
 
-    // CI-compatible exit code derived strictly from classification level.
-    let exit_code = match level {
-        ClassificationLevel::Safe => 0,
-        ClassificationLevel::Risk => 1,
-        ClassificationLevel::HighRisk => 2,
-    };
+    let exit_code = exit_code(&level);
 
-    let mut triggered_rule_ids: Vec<_> = triggered.iter().map(|r| r.rule_id).collect();
-    triggered_rule_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    let mut triggered_rule_ids: Vec<_> = triggered.iter().map(|r| r.rule_id.clone()).collect();
+    triggered_rule_ids.sort();
 
     ClassificationInfo {
+        reason: reason(&level, policy, triggered, score.as_ref()),
         level,
-        policy: "default".to_string(),
-        reason: "classification derived from triggered rules".to_string(),
+        policy: policy.as_str().to_string(),
         highest_severity: format!("{:?}", highest),
         triggered_rule_ids,
         exit_code,
+        score,
+        new_rule_ids: None,
+        preexisting_rule_ids: None,
+        escalated_from: None,
+        escalation_rule: None,
+        suppressed_rule_ids: vec![],
+        unsuppressed_level: None,
+    }
+}
+
+/// CI-compatible exit code derived strictly from classification level.
+fn exit_code(level: &ClassificationLevel) -> i32 {
+    match level {
+        ClassificationLevel::Safe => 0,
+        ClassificationLevel::Info => DEFAULT_INFO_EXIT_CODE,
+        ClassificationLevel::Risk => 1,
+        ClassificationLevel::HighRisk => 2,
+        ClassificationLevel::Unknown => DEFAULT_UNKNOWN_EXIT_CODE,
+    }
+}
+
+/// `HIGH_RISK: 2 HIGH rules (R-MEM-02, R-CALL-01) and 1 MED rule triggered
+/// under policy 'default'`. Only the highest severity's ids are named, in
+/// canonical order and capped at [`REASON_ID_LIMIT`].
+fn reason(
+    level: &ClassificationLevel,
+    policy: &Policy,
+    triggered: &[TriggeredRule],
+    score: Option<&ScoreInfo>,
+) -> String {
+    let mut groups = Vec::new();
+    for severity in [Severity::High, Severity::Med, Severity::Low] {
+        let mut ids: Vec<_> = triggered
+            .iter()
+            .filter(|r| r.severity == severity)
+            .map(|r| &r.rule_id)
+            .collect();
+        if ids.is_empty() {
+            continue;
+        }
+        let noun = if ids.len() == 1 { "rule" } else { "rules" };
+        let mut group = format!("{} {} {noun}", ids.len(), severity_label(&severity));
+        if groups.is_empty() {
+            ids.sort();
+            let named: Vec<_> = ids
+                .iter()
+                .take(REASON_ID_LIMIT)
+                .map(|id| id.as_str())
+                .collect();
+            group.push_str(&format!(" ({}", named.join(", ")));
+            if ids.len() > REASON_ID_LIMIT {
+                group.push_str(&format!(" +{} more", ids.len() - REASON_ID_LIMIT));
+            }
+            group.push(')');
+        }
+        groups.push(group);
+    }
+    let groups = match groups.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    };
+    let mut text = format!(
+        "{level}: {groups} triggered under policy '{}'",
+        policy.as_str()
+    );
+    if let Some(score) = score {
+        text.push_str(&format!(", score {}", score.total));
+    }
+    text
+}
+
+/// `reason` when no rule triggered, given how many catalog rules were
+/// evaluated and how many triggers were suppressed.
+pub fn nothing_triggered_reason(evaluated: usize, suppressed: usize) -> String {
+    if suppressed == 0 {
+        format!("no rules triggered ({evaluated} evaluated)")
+    } else {
+        format!("no rules triggered ({evaluated} evaluated, {suppressed} suppressed)")
+    }
+}
+
+fn severity_label(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Low => "LOW",
+        Severity::Med => "MED",
+        Severity::High => "HIGH",
+    }
+}
+
+fn weight(weights: &ScoreWeights, severity: &Severity) -> u64 {
+    match severity {
+        Severity::Low => weights.low,
+        Severity::Med => weights.med,
+        Severity::High => weights.high,
+    }
+}
+
+/// Like [`classify_with_policy`], but low-confidence rules do not count
+/// towards the level, highest severity or exit code. They stay in the
+/// triggered rules and their ids.
+pub fn classify_ignoring_low_confidence(
+    triggered: &[TriggeredRule],
+    policy: &Policy,
+) -> ClassificationInfo {
+    ignoring_low_confidence(triggered, |counted| classify_with_policy(counted, policy))
+}
+
+/// Applies `classify` to the triggered rules that are not low-confidence,
+/// then records every triggered rule id and notes how many were ignored.
+/// Lets the low-confidence filter run ahead of any classifier, such as
+/// [`classify_against_baseline`].
+pub fn ignoring_low_confidence(
+    triggered: &[TriggeredRule],
+    classify: impl FnOnce(&[TriggeredRule]) -> ClassificationInfo,
+) -> ClassificationInfo {
+    let counted: Vec<_> = triggered
+        .iter()
+        .filter(|r| r.confidence != Confidence::Low)
+        .cloned()
+        .collect();
+    let mut info = classify(&counted);
+    // Ignored rules are still triggered rules.
+    info.triggered_rule_ids = triggered.iter().map(|r| r.rule_id.clone()).collect();
+    info.triggered_rule_ids.sort();
+    if counted.len() < triggered.len() {
+        info.reason = format!(
+            "{}; {} low-confidence rule(s) ignored",
+            info.reason,
+            triggered.len() - counted.len()
+        );
+    }
+    info
+}
+
+/// Like [`classify_with_policy`], but only triggered rules that are new
+/// relative to `baseline` count towards the level, highest severity and
+/// exit code. Both partitions are recorded in the classification.
+///
+/// A rule is preexisting when the baseline triggered the same rule id and,
+/// if both sides report findings, every current finding is at a baseline
+/// location; one new site makes the whole rule new. A baseline without
+/// triggered rules classifies like [`classify_with_policy`].
+pub fn classify_against_baseline(
+    current: &[TriggeredRule],
+    baseline: &Report,
+    policy: &Policy,
+) -> ClassificationInfo {
+    let (preexisting, new): (Vec<_>, Vec<_>) = current
+        .iter()
+        .cloned()
+        .partition(|r| is_preexisting(r, baseline));
+    let mut info = classify_with_policy(&new, policy);
+    info.triggered_rule_ids = current.iter().map(|r| r.rule_id.clone()).collect();
+    info.triggered_rule_ids.sort();
+    if !preexisting.is_empty() {
+        if new.is_empty() {
+            info.reason = "no new rules triggered".to_string();
+        }
+        info.reason = format!(
+            "{}; {} preexisting in baseline",
+            info.reason,
+            preexisting.len()
+        );
+    }
+    let ids = |rules: &[TriggeredRule]| {
+        let mut ids: Vec<_> = rules.iter().map(|r| r.rule_id.clone()).collect();
+        ids.sort();
+        ids
+    };
+    info.new_rule_ids = Some(ids(&new));
+    info.preexisting_rule_ids = Some(ids(&preexisting));
+    info
+}
+
+fn is_preexisting(rule: &TriggeredRule, baseline: &Report) -> bool {
+    let Some(known) = baseline
+        .rules
+        .triggered
+        .iter()
+        .find(|b| b.rule_id == rule.rule_id)
+    else {
+        return false;
+    };
+    // Reports before 0.8.0 carry no findings; match on the rule id alone.
+    if rule.findings.is_empty() || known.findings.is_empty() {
+        return true;
+    }
+    rule.findings
+        .iter()
+        .all(|f| known.findings.iter().any(|k| same_site(&f.location, k)))
+}
+
+/// Function names survive reordering between builds, so they are compared
+/// in place of indices when both sides have one.
+fn same_site(location: &Location, known: &FindingInfo) -> bool {
+    let same_function = match (&location.function_name, &known.function_name) {
+        (Some(a), Some(b)) => a == b,
+        _ => location.function_index == known.function_index,
+    };
+    same_function && location.byte_offset == known.byte_offset
+}
+
+/// Raises `info` to the level `policy` gives the next severity up when the
+/// `counted` rules (those `info` was derived from) reach an `escalation`
+/// count. Escalated severities do not count again: LOW rules escalating to
+/// MED never add up to HIGH. The weighted policy already counts every rule
+/// and is left as is.
+pub fn escalate(
+    info: &mut ClassificationInfo,
+    counted: &[TriggeredRule],
+    policy: &Policy,
+    escalation: &Escalation,
+) {
+    if matches!(policy, Policy::Weighted { .. }) {
+        return;
     }
+    let count = |severity: Severity| counted.iter().filter(|r| r.severity == severity).count();
+    let candidates = [
+        (
+            "med_count_for_high",
+            escalation.med_count_for_high,
+            Severity::Med,
+            Severity::High,
+        ),
+        (
+            "low_count_for_med",
+            escalation.low_count_for_med,
+            Severity::Low,
+            Severity::Med,
+        ),
+    ];
+    for (rule, threshold, from, to) in candidates {
+        let Some(threshold) = threshold else {
+            continue;
+        };
+        let n = count(from.clone());
+        if n == 0 || n < threshold {
+            continue;
+        }
+        let level = policy.level(&to, None);
+        if exit_code(&level) <= info.exit_code {
+            continue;
+        }
+        let noun = if n == 1 { "rule" } else { "rules" };
+        info.reason = format!(
+            "{}; escalated from {} by {rule} = {threshold} ({n} {} {noun})",
+            info.reason,
+            info.level,
+            severity_label(&from)
+        );
+        info.escalated_from = Some(std::mem::replace(&mut info.level, level));
+        info.exit_code = exit_code(&info.level);
+        info.escalation_rule = Some(rule.to_string());
+        return;
+    }
+}
+
+/// Records the rules kept out of `info` by suppressions or disabled catalog
+/// rules, and `unsuppressed_level`, the level had they counted. Nothing is
+/// recorded when `suppressed` is empty.
+pub fn record_suppressed(
+    info: &mut ClassificationInfo,
+    suppressed: &[TriggeredRule],
+    unsuppressed_level: ClassificationLevel,
+) {
+    if suppressed.is_empty() {
+        return;
+    }
+    info.suppressed_rule_ids = suppressed.iter().map(|r| r.rule_id.clone()).collect();
+    info.suppressed_rule_ids.sort();
+    info.suppressed_rule_ids.dedup();
+    info.unsuppressed_level = Some(unsuppressed_level);
+}
+
+/// Replaces the verdict with `UNKNOWN` and `exit_code` when `analysis` did
+/// not complete. Triggered rule ids, highest severity and score are kept,
+/// and `reason` names the level the partial signals would have given.
+pub fn withhold_if_incomplete(
+    info: &mut ClassificationInfo,
+    analysis: &AnalysisInfo,
+    exit_code: i32,
+) {
+    if !analysis.is_incomplete() {
+        return;
+    }
+    info.reason = format!(
+        "UNKNOWN: analysis status '{}'; partial signals would give {} under policy '{}'",
+        analysis.status, info.level, info.policy
+    );
+    info.level = ClassificationLevel::Unknown;
+    info.exit_code = exit_code;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::report::model::{ArtifactHash, ArtifactInfo, RulesCatalogInfo, ToolInfo};
     use crate::rules::catalog::{RuleId, Severity};
     use crate::rules::eval::TriggeredRule;
+    use crate::rules::evidence::Finding;
+    use crate::signals::model::Signals;
     use serde_json::json;
 
     fn tr(id: RuleId, sev: Severity) -> TriggeredRule {
         TriggeredRule {
             rule_id: id,
             severity: sev,
+            confidence: Confidence::High,
             title: "t".into(),
             message: "m".into(),
-            evidence: json!({}),
+            remediation: String::new(),
+            docs_url: String::new(),
+            deprecated: None,
+            evidence: json!({}).into(),
+            findings: vec![],
         }
     }
 
@@ -163,13 +570,13 @@ mod tests {
     }
 
     #[test]
-    fn only_low_results_in_safe() {
+    fn only_low_results_in_info() {
         let triggered = vec![
             tr(RuleId::RMem01, Severity::Low),
             tr(RuleId::RLoop01, Severity::Low),
         ];
         let c = classify(&triggered);
-        assert_eq!(c.level, ClassificationLevel::Safe);
+        assert_eq!(c.level, ClassificationLevel::Info);
         assert_eq!(c.exit_code, 0);
         assert_eq!(c.highest_severity, "Low");
     }
@@ -185,11 +592,11 @@ mod tests {
 
         let c = classify(&triggered);
 
-        // Sorted by canonical external rule id:
-        // R-CALL-01, R-LOOP-01, R-MEM-02
+        // Canonical rule order is catalog declaration order:
+        // R-MEM-02, R-CALL-01, R-LOOP-01
         assert_eq!(
             c.triggered_rule_ids,
-            vec![RuleId::RCall01, RuleId::RLoop01, RuleId::RMem02]
+            vec![RuleId::RMem02, RuleId::RCall01, RuleId::RLoop01]
         );
     }
 
@@ -205,4 +612,396 @@ mod tests {
 
         assert_eq!(c1, c2);
     }
+
+    #[test]
+    fn low_confidence_rules_can_be_ignored() {
+        let mut guess = tr(RuleId::RMem02, Severity::High);
+        guess.confidence = Confidence::Low;
+        let triggered = vec![guess, tr(RuleId::RLoop01, Severity::Med)];
+
+        assert_eq!(classify(&triggered).level, ClassificationLevel::HighRisk);
+
+        let c = classify_ignoring_low_confidence(&triggered, &Policy::Default);
+        assert_eq!(c.level, ClassificationLevel::Risk);
+        assert_eq!(c.exit_code, 1);
+        assert_eq!(c.highest_severity, "Med");
+        assert_eq!(c.triggered_rule_ids, vec![RuleId::RMem02, RuleId::RLoop01]);
+        assert!(c.reason.ends_with("1 low-confidence rule(s) ignored"));
+
+        let c = classify_ignoring_low_confidence(&triggered[1..], &Policy::Default);
+        assert_eq!(
+            c.reason,
+            "RISK: 1 MED rule (R-LOOP-01) triggered under policy 'default'"
+        );
+    }
+
+    #[test]
+    fn strict_policy_fails_on_any_rule() {
+        let low = [tr(RuleId::RMem01, Severity::Low)];
+        let c = classify_with_policy(&low, &Policy::Strict);
+        assert_eq!(c.level, ClassificationLevel::Risk);
+        assert_eq!(c.exit_code, 1);
+        assert_eq!(c.policy, "strict");
+
+        let high = [tr(RuleId::RMem02, Severity::High)];
+        assert_eq!(classify_with_policy(&high, &Policy::Strict).exit_code, 2);
+        assert_eq!(classify_with_policy(&[], &Policy::Strict).exit_code, 0);
+    }
+
+    #[test]
+    fn high_only_policy_passes_med_rules() {
+        let med = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RLoop01, Severity::Low),
+        ];
+        let c = classify_with_policy(&med, &Policy::HighOnly);
+        assert_eq!(c.level, ClassificationLevel::Info);
+        assert_eq!(c.exit_code, 0);
+        assert_eq!(c.highest_severity, "Med");
+        assert_eq!(c.policy, "high-only");
+
+        let high = [tr(RuleId::RMem02, Severity::High)];
+        let c = classify_with_policy(&high, &Policy::HighOnly);
+        assert_eq!(c.level, ClassificationLevel::HighRisk);
+    }
+
+    #[test]
+    fn default_policy_matches_classify() {
+        let triggered = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RLoop01, Severity::Low),
+        ];
+        assert_eq!(
+            classify_with_policy(&triggered, &Policy::Default),
+            classify(&triggered)
+        );
+    }
+
+    #[test]
+    fn weighted_policy_accumulates_severities() {
+        let med = |id| tr(id, Severity::Med);
+        let one = classify_with_policy(&[med(RuleId::RMem01)], &Policy::weighted());
+        assert_eq!(one.level, ClassificationLevel::Risk);
+        assert_eq!(one.policy, "weighted");
+        assert_eq!(one.score.unwrap().total, 3);
+
+        // Three MED rules outweigh the HIGH_RISK threshold; one does not.
+        let three = [
+            med(RuleId::RMem01),
+            med(RuleId::RLoop01),
+            med(RuleId::RCall01),
+        ];
+        let c = classify_with_policy(&three, &Policy::weighted());
+        assert_eq!(c.level, ClassificationLevel::HighRisk);
+        assert_eq!(c.exit_code, 2);
+        assert_eq!(
+            c.reason,
+            "HIGH_RISK: 3 MED rules (R-MEM-01, R-CALL-01, R-LOOP-01) triggered under policy 'weighted', score 9"
+        );
+        assert_eq!(c.highest_severity, "Med");
+
+        let lenient = Policy::Weighted {
+            weights: ScoreWeights::default(),
+            thresholds: ScoreThresholds {
+                risk: 10,
+                high_risk: 20,
+            },
+        };
+        let c = classify_with_policy(&three, &lenient);
+        assert_eq!(c.level, ClassificationLevel::Info);
+        assert_eq!(c.score.unwrap().thresholds.risk, 10);
+    }
+
+    #[test]
+    fn weighted_policy_scores_zero_without_rules() {
+        let c = classify_with_policy(&[], &Policy::weighted());
+        assert_eq!(c.level, ClassificationLevel::Safe);
+        assert_eq!(c.reason, "no rules triggered");
+        let score = c.score.unwrap();
+        assert_eq!(score.total, 0);
+        assert_eq!(score.weights, ScoreWeights::default());
+        // Other policies carry no score.
+        assert!(classify(&[]).score.is_none());
+    }
+
+    #[test]
+    fn reason_names_highest_severity_rules() {
+        let triggered = vec![
+            tr(RuleId::RLoop01, Severity::Med),
+            tr(RuleId::RCall01, Severity::High),
+            tr(RuleId::RSize01, Severity::Low),
+            tr(RuleId::RMem02, Severity::High),
+            tr(RuleId::RMem01, Severity::Med),
+        ];
+        assert_eq!(
+            classify(&triggered).reason,
+            "HIGH_RISK: 2 HIGH rules (R-MEM-02, R-CALL-01), 2 MED rules and 1 LOW rule \
+             triggered under policy 'default'"
+        );
+        assert_eq!(
+            classify_with_policy(&triggered[2..3], &Policy::Strict).reason,
+            "RISK: 1 LOW rule (R-SIZE-01) triggered under policy 'strict'"
+        );
+    }
+
+    #[test]
+    fn reason_caps_named_rules() {
+        let triggered: Vec<_> = [
+            RuleId::RMem01,
+            RuleId::RMem02,
+            RuleId::RCall01,
+            RuleId::RLoop01,
+            RuleId::RSize01,
+        ]
+        .into_iter()
+        .map(|id| tr(id, Severity::Med))
+        .collect();
+        assert_eq!(
+            classify(&triggered).reason,
+            "RISK: 5 MED rules (R-MEM-01, R-MEM-02, R-CALL-01 +2 more) triggered under policy 'default'"
+        );
+    }
+
+    #[test]
+    fn nothing_triggered_reason_counts_evaluated_and_suppressed() {
+        assert_eq!(
+            nothing_triggered_reason(22, 0),
+            "no rules triggered (22 evaluated)"
+        );
+        assert_eq!(
+            nothing_triggered_reason(22, 2),
+            "no rules triggered (22 evaluated, 2 suppressed)"
+        );
+    }
+
+    #[test]
+    fn incomplete_analysis_withholds_the_verdict() {
+        let triggered = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RAnalysis01, Severity::Med),
+        ];
+        let mut c = classify(&triggered);
+        withhold_if_incomplete(&mut c, &AnalysisInfo::ok(), DEFAULT_UNKNOWN_EXIT_CODE);
+        assert_eq!(c.level, ClassificationLevel::Risk);
+
+        withhold_if_incomplete(
+            &mut c,
+            &AnalysisInfo::parse_error("truncated"),
+            DEFAULT_UNKNOWN_EXIT_CODE,
+        );
+        assert_eq!(c.level, ClassificationLevel::Unknown);
+        assert_eq!(c.exit_code, 3);
+        assert_eq!(c.highest_severity, "Med");
+        assert_eq!(
+            c.triggered_rule_ids,
+            vec![RuleId::RMem01, RuleId::RAnalysis01]
+        );
+        assert_eq!(
+            c.reason,
+            "UNKNOWN: analysis status 'parse_error'; partial signals would give RISK under policy 'default'"
+        );
+        assert_eq!(serde_json::to_value(&c.level).unwrap(), "UNKNOWN");
+
+        let mut c = classify(&[]);
+        withhold_if_incomplete(&mut c, &AnalysisInfo::unsupported("component"), 0);
+        assert_eq!(c.level, ClassificationLevel::Unknown);
+        assert_eq!(c.exit_code, 0);
+    }
+
+    #[test]
+    fn record_suppressed_sorts_ids_and_keeps_the_level() {
+        let mut c = classify(&[]);
+        record_suppressed(&mut c, &[], ClassificationLevel::Risk);
+        assert!(c.suppressed_rule_ids.is_empty());
+        assert!(c.unsuppressed_level.is_none());
+
+        let suppressed = [
+            tr(RuleId::RMem02, Severity::High),
+            tr(RuleId::RCall01, Severity::High),
+            tr(RuleId::RMem02, Severity::High),
+        ];
+        record_suppressed(&mut c, &suppressed, ClassificationLevel::HighRisk);
+        assert_eq!(c.level, ClassificationLevel::Safe);
+        assert_eq!(c.suppressed_rule_ids, vec![RuleId::RMem02, RuleId::RCall01]);
+        assert_eq!(c.unsuppressed_level, Some(ClassificationLevel::HighRisk));
+    }
+
+    fn baseline(triggered: Vec<TriggeredRule>) -> Report {
+        Report::new(
+            ToolInfo {
+                name: "sebi".into(),
+                version: "1.0.0".into(),
+                commit: None,
+            },
+            ArtifactInfo {
+                path: None,
+                size_bytes: 0,
+                compressed_size_bytes: None,
+                hash: ArtifactHash {
+                    algorithm: "sha256".into(),
+                    value: "00".into(),
+                },
+            },
+            Signals::default(),
+            AnalysisInfo::ok(),
+            RulesCatalogInfo::default(),
+            triggered,
+            ClassificationInfo::safe("default"),
+        )
+    }
+
+    fn at(rule: TriggeredRule, sites: &[(u32, u64)]) -> TriggeredRule {
+        rule.with_findings(
+            sites
+                .iter()
+                .map(|&(function_index, byte_offset)| Finding {
+                    location: Location {
+                        function_index,
+                        byte_offset,
+                        function_name: None,
+                    },
+                    evidence: Default::default(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn baseline_partitions_by_rule_id() {
+        let current = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RCall01, Severity::High),
+        ];
+        let c = classify_against_baseline(
+            &current,
+            &baseline(vec![tr(RuleId::RCall01, Severity::High)]),
+            &Policy::Default,
+        );
+        assert_eq!(c.level, ClassificationLevel::Risk);
+        assert_eq!(c.exit_code, 1);
+        assert_eq!(c.highest_severity, "Med");
+        assert_eq!(c.triggered_rule_ids, vec![RuleId::RMem01, RuleId::RCall01]);
+        assert_eq!(c.new_rule_ids, Some(vec![RuleId::RMem01]));
+        assert_eq!(c.preexisting_rule_ids, Some(vec![RuleId::RCall01]));
+        assert_eq!(
+            c.reason,
+            "RISK: 1 MED rule (R-MEM-01) triggered under policy 'default'; 1 preexisting in baseline"
+        );
+
+        let c =
+            classify_against_baseline(&current[1..], &baseline(current.to_vec()), &Policy::Default);
+        assert_eq!(c.level, ClassificationLevel::Safe);
+        assert_eq!(c.exit_code, 0);
+        assert_eq!(
+            c.reason,
+            "no new rules triggered; 1 preexisting in baseline"
+        );
+    }
+
+    #[test]
+    fn baseline_partitions_multi_finding_rules_by_location() {
+        let base = baseline(vec![at(
+            tr(RuleId::RMem02, Severity::High),
+            &[(1, 10), (2, 20)],
+        )]);
+
+        let moved = [at(tr(RuleId::RMem02, Severity::High), &[(2, 20), (3, 30)])];
+        let c = classify_against_baseline(&moved, &base, &Policy::Default);
+        assert_eq!(c.level, ClassificationLevel::HighRisk);
+        assert_eq!(c.new_rule_ids, Some(vec![RuleId::RMem02]));
+
+        let fewer = [at(tr(RuleId::RMem02, Severity::High), &[(2, 20)])];
+        let c = classify_against_baseline(&fewer, &base, &Policy::Default);
+        assert_eq!(c.level, ClassificationLevel::Safe);
+        assert_eq!(c.preexisting_rule_ids, Some(vec![RuleId::RMem02]));
+
+        // A baseline without findings (before 0.8.0) matches by rule id.
+        let legacy = baseline(vec![tr(RuleId::RMem02, Severity::High)]);
+        let c = classify_against_baseline(&moved, &legacy, &Policy::Default);
+        assert_eq!(c.level, ClassificationLevel::Safe);
+    }
+
+    #[test]
+    fn empty_baseline_classifies_normally() {
+        let current = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RCall01, Severity::High),
+        ];
+        let weighted = Policy::weighted();
+        let c = classify_against_baseline(&current, &baseline(vec![]), &weighted);
+        let plain = classify_with_policy(&current, &weighted);
+        assert_eq!(c.level, plain.level);
+        assert_eq!(c.exit_code, plain.exit_code);
+        assert_eq!(c.reason, plain.reason);
+        assert_eq!(c.score, plain.score);
+        assert_eq!(c.new_rule_ids, Some(plain.triggered_rule_ids));
+        assert_eq!(c.preexisting_rule_ids, Some(vec![]));
+    }
+
+    #[test]
+    fn escalation_raises_level_on_rule_counts() {
+        let meds = [
+            tr(RuleId::RMem01, Severity::Med),
+            tr(RuleId::RLoop01, Severity::Med),
+            tr(RuleId::RLoop02, Severity::Med),
+        ];
+        let escalation = Escalation {
+            med_count_for_high: Some(3),
+            low_count_for_med: None,
+        };
+
+        let mut c = classify(&meds);
+        escalate(&mut c, &meds, &Policy::Default, &Escalation::default());
+        assert_eq!(c.level, ClassificationLevel::Risk);
+        assert!(c.escalated_from.is_none());
+
+        escalate(&mut c, &meds[..2], &Policy::Default, &escalation);
+        assert_eq!(c.level, ClassificationLevel::Risk);
+
+        escalate(&mut c, &meds, &Policy::Default, &escalation);
+        assert_eq!(c.level, ClassificationLevel::HighRisk);
+        assert_eq!(c.exit_code, 2);
+        assert_eq!(c.highest_severity, "Med");
+        assert_eq!(c.escalated_from, Some(ClassificationLevel::Risk));
+        assert_eq!(c.escalation_rule.as_deref(), Some("med_count_for_high"));
+        assert_eq!(
+            c.reason,
+            "RISK: 3 MED rules (R-MEM-01, R-LOOP-01, R-LOOP-02) triggered under policy 'default'; \
+             escalated from RISK by med_count_for_high = 3 (3 MED rules)"
+        );
+
+        // high-only passes MED rules, but three of them count as a HIGH.
+        let mut c = classify_with_policy(&meds, &Policy::HighOnly);
+        escalate(&mut c, &meds, &Policy::HighOnly, &escalation);
+        assert_eq!(c.escalated_from, Some(ClassificationLevel::Info));
+        assert_eq!(c.level, ClassificationLevel::HighRisk);
+
+        let weighted = Policy::weighted();
+        let mut c = classify_with_policy(&meds[..1], &weighted);
+        escalate(&mut c, &meds[..1], &weighted, &escalation);
+        assert!(c.escalated_from.is_none());
+    }
+
+    #[test]
+    fn escalated_lows_do_not_chain_to_high() {
+        let lows = [
+            tr(RuleId::RSize01, Severity::Low),
+            tr(RuleId::RSize02, Severity::Low),
+        ];
+        let escalation = Escalation {
+            med_count_for_high: Some(1),
+            low_count_for_med: Some(2),
+        };
+        let mut c = classify(&lows);
+        escalate(&mut c, &lows, &Policy::Default, &escalation);
+        assert_eq!(c.level, ClassificationLevel::Risk);
+        assert_eq!(c.escalated_from, Some(ClassificationLevel::Info));
+        assert_eq!(c.escalation_rule.as_deref(), Some("low_count_for_med"));
+
+        // Already RISK under strict: nothing to raise.
+        let mut c = classify_with_policy(&lows, &Policy::Strict);
+        escalate(&mut c, &lows, &Policy::Strict, &escalation);
+        assert!(c.escalated_from.is_none());
+        assert!(c.escalation_rule.is_none());
+    }
 }
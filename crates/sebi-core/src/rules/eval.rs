@@ -15,17 +15,68 @@
 //! - Classifying overall risk level
 //! - Mutating signals
 //! - Performing probabilistic inference
+//!
+//! Embedders add their own checks through [`RuleEvaluator`]; their triggers
+//! are merged with the catalog's by [`apply_evaluators`].
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use serde_json::json;
 
 use crate::{
-    rules::catalog::{RuleId, Severity, catalog},
-    signals::model::Signals,
+    report::model::{AnalysisInfo, Confidence},
+    rules::{
+        catalog::{RuleDef, RuleId, Severity, catalog},
+        evidence::*,
+    },
+    signals::{
+        extract::WASM_PAGE_SIZE,
+        model::{InstructionSite, Signals},
+    },
     util::deterministic,
-    wasm::parse::ParseConfig,
+    wasm::parse::{ParseConfig, RawWasmFacts},
+    wasm::profile::is_wasi_module,
     wasm::read::ArtifactContext,
 };
 
+/// Offending `(module, name)` pairs quoted in R-IMPORT-01 evidence; the
+/// per-module counts stay exact.
+pub const MAX_IMPORT_EVIDENCE: usize = 16;
+
+/// Entries in the `locations` evidence of R-MEM-02, R-CALL-01 and
+/// R-LOOP-01.
+pub const MAX_LOCATION_EVIDENCE: usize = 16;
+
+/// Leading `analysis.warnings` quoted in R-ANALYSIS-01 evidence.
+pub const MAX_ANALYSIS_WARNING_EVIDENCE: usize = 4;
+
+/// Allocator function names matched by default when the R-MEM-02
+/// allocator heuristic is enabled (`ParseConfig::allocator_patterns`).
+pub const DEFAULT_ALLOCATOR_PATTERNS: &[&str] =
+    &["__rust_alloc*", "__rust_realloc", "*dlmalloc*", "malloc"];
+
+/// Which signals rules are evaluated against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RuleScope {
+    /// All functions in the module (the default).
+    #[default]
+    Module,
+    /// Only functions reachable from the entrypoints
+    /// (see `signals.reachability`).
+    Reachable,
+}
+
+impl RuleScope {
+    /// Stable identifier reported under `rules.scope`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleScope::Module => "module",
+            RuleScope::Reachable => "reachable",
+        }
+    }
+}
+
 /// A rule that has been triggered after evaluating signals.
 ///
 /// This struct is purely interpretive and contains:
@@ -39,12 +90,122 @@ use crate::{
 pub struct TriggeredRule {
     pub rule_id: RuleId,
     pub severity: Severity,
+    pub confidence: Confidence,
     pub title: String,
     pub message: String,
+    pub remediation: String,
+    pub docs_url: String,
+    /// Replacement rule id or retirement note when the rule is deprecated.
+    pub deprecated: Option<String>,
+    pub evidence: RuleEvidence,
+    /// Individual instances of the condition, for rules about instruction
+    /// sites; empty otherwise. Sorted by function index, then offset.
+    pub findings: Vec<Finding>,
+}
+
+impl TriggeredRule {
+    /// Attaches the individual instances of the condition.
+    pub fn with_findings(mut self, findings: Vec<Finding>) -> Self {
+        self.findings = findings;
+        self
+    }
+}
+
+/// A catalog rule that was evaluated and did not trigger.
+///
+/// Carries the key values the rule checked and its parameters, as
+/// positive evidence that the check ran.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PassedRule {
+    pub rule_id: RuleId,
+    pub severity: Severity,
     pub evidence: serde_json::Value,
 }
 
-/// Applies the SEBI rule catalog to the provided signals.
+/// Outcome of evaluating the catalog, both lists sorted by `RuleId`.
+///
+/// Rules that were not evaluated (R-IMPORT-01 without an allowlist) appear
+/// in neither list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Evaluation {
+    pub triggered: Vec<TriggeredRule>,
+    pub passed: Vec<PassedRule>,
+    /// Catalog rules evaluated, composites included.
+    pub evaluated: usize,
+    /// Time spent on each evaluated rule, in catalog order. Varies run to
+    /// run; reported only under `analysis.stats`.
+    pub timings: Vec<RuleTiming>,
+}
+
+/// Wall-clock time one catalog rule took to evaluate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub rule_id: RuleId,
+    pub elapsed: Duration,
+}
+
+/// Everything rule evaluation reads: the signals the rules are scoped to,
+/// the artifact, the effective configuration, the analysis status, and the
+/// effective catalog with its thresholds. Catalog rules and every
+/// [`RuleEvaluator`] see the same context.
+#[derive(Debug, Clone, Copy)]
+pub struct EvaluationContext<'a> {
+    pub signals: &'a Signals,
+    pub artifact: &'a ArtifactContext,
+    pub config: &'a ParseConfig,
+    /// Target, toolchain and warnings gathered while parsing.
+    pub analysis: &'a AnalysisInfo,
+    /// Effective catalog; rules missing from it are not evaluated.
+    pub rules: &'a [RuleDef],
+    /// Raw section and instruction facts behind `signals`, when the caller
+    /// has them. Always module-wide, whatever the rule scope.
+    pub facts: Option<&'a RawWasmFacts>,
+}
+
+/// A programmatic rule set evaluated alongside the built-in catalog.
+///
+/// Triggers must carry a `RuleId::Custom` id together with their own
+/// severity, title, message, remediation and evidence; they are reported
+/// and classified exactly like catalog rules. Built-in ids are reserved for
+/// the catalog.
+///
+/// SEBI cannot check an evaluator's determinism: it must return the same
+/// triggers for the same context, with evidence that does not depend on
+/// iteration order, time or environment. Custom rules are not covered by
+/// `rules.catalog.content_hash`.
+pub trait RuleEvaluator: std::fmt::Debug + Send + Sync {
+    fn evaluate(&self, ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule>;
+}
+
+/// Runs `evaluators` in order and merges their triggers into `triggered`,
+/// which is then re-sorted.
+///
+/// A rule id already present is kept once, first trigger wins, so catalog
+/// results take precedence over evaluators and earlier evaluators over
+/// later ones. Triggers with a built-in id are dropped; their ids are
+/// returned so the caller can report them.
+pub fn apply_evaluators(
+    ctx: &EvaluationContext<'_>,
+    evaluators: &[Arc<dyn RuleEvaluator>],
+    triggered: &mut Vec<TriggeredRule>,
+) -> Vec<RuleId> {
+    let mut rejected = Vec::new();
+    for evaluator in evaluators {
+        for rule in evaluator.evaluate(ctx) {
+            if rule.rule_id.is_builtin() {
+                rejected.push(rule.rule_id);
+            } else if !triggered.iter().any(|r| r.rule_id == rule.rule_id) {
+                triggered.push(rule);
+            }
+        }
+    }
+    deterministic::sort_triggered_rules(triggered);
+    rejected.sort();
+    rejected.dedup();
+    rejected
+}
+
+/// Applies `ctx.rules` to the signals in `ctx`.
 ///
 /// Returns a deterministically sorted list of triggered rules.
 ///
@@ -52,93 +213,800 @@ pub struct TriggeredRule {
 /// - Same inputs → identical rule set
 /// - Output order sorted by `RuleId`
 /// - No hidden randomness
-pub fn evaluate_rules(
-    signals: &Signals,
-    artifact: &ArtifactContext,
-    cfg: &ParseConfig,
-) -> Vec<TriggeredRule> {
+pub fn evaluate_rules(ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule> {
+    evaluate(ctx).triggered
+}
+
+/// Same as [`evaluate_rules`], also returning the rules that were checked
+/// and passed.
+pub fn evaluate(ctx: &EvaluationContext<'_>) -> Evaluation {
+    let EvaluationContext {
+        signals,
+        artifact,
+        config: cfg,
+        analysis,
+        rules,
+        ..
+    } = *ctx;
     let mut out = Vec::new();
+    let mut passed = Vec::new();
+    let mut timings = Vec::new();
+    let mut evaluated = 0;
 
-    for def in catalog() {
+    for rule in rules {
+        let started = Instant::now();
+        let def = rule.clone();
+        let triggered_before = out.len();
         match def.id {
             RuleId::RMem01 => {
                 if !signals.memory.has_max {
                     out.push(build_trigger(
                         def,
-                        json!({
-                            "signals.memory.has_max": signals.memory.has_max,
-                            "signals.memory.min_pages": signals.memory.min_pages,
-                        }),
+                        MemNoMaxEvidence {
+                            has_max: signals.memory.has_max,
+                            min_pages: signals.memory.min_pages,
+                        },
                     ));
                 }
             }
 
             RuleId::RMem02 => {
-                if signals.instructions.has_memory_grow {
-                    out.push(build_trigger(def, json!({
-                        "signals.instructions.has_memory_grow": signals.instructions.has_memory_grow,
-                        "signals.instructions.memory_grow_count": signals.instructions.memory_grow_count,
-                    })));
+                let ins = &signals.instructions;
+                if ins.has_memory_grow {
+                    let mut evidence = MemGrowEvidence {
+                        has_memory_grow: ins.has_memory_grow,
+                        memory_grow_count: ins.memory_grow_count,
+                        memory_grow_const_count: ins.memory_grow_const_count,
+                        memory_grow_dynamic_count: ins.memory_grow_dynamic_count,
+                        estimated_max_pages_static: signals.memory.estimated_max_pages_static,
+                        growth_open_ended: signals.memory.growth_open_ended,
+                        estimate_within_max: signals.memory.estimate_within_max,
+                        // memory.size + memory.grow together is the dynamic-allocator pattern.
+                        memory_size_count: ins.has_memory_size.then_some(ins.memory_size_count),
+                        by_export: by_export(signals, "memory_grow"),
+                        locations: locations(signals, &ins.memory_grow_sites),
+                        allocator_only: None,
+                        allocator_functions: None,
+                        severity_downgraded_from: None,
+                    };
+                    let mut def = def;
+                    let mut findings = findings(signals, &ins.memory_grow_sites);
+                    if let Some(matched) = allocator_only_functions(ctx) {
+                        for finding in &mut findings {
+                            let index = finding.location.function_index;
+                            if let Some(f) = matched.iter().find(|f| f.function_index == index) {
+                                finding
+                                    .evidence
+                                    .insert("allocator_pattern".into(), json!(f.pattern));
+                            }
+                        }
+                        evidence.allocator_only = Some(true);
+                        evidence.allocator_functions = Some(matched);
+                        evidence.severity_downgraded_from = Some(def.severity.clone());
+                        def.severity = def.severity.downgraded();
+                        // Attribution rests on function names alone.
+                        def.confidence = Confidence::Medium;
+                    }
+                    out.push(build_trigger(def, evidence).with_findings(findings));
                 }
             }
 
             RuleId::RCall01 => {
-                if signals.instructions.has_call_indirect {
-                    out.push(build_trigger(def, json!({
-                        "signals.instructions.has_call_indirect": signals.instructions.has_call_indirect,
-                        "signals.instructions.call_indirect_count": signals.instructions.call_indirect_count,
-                    })));
+                let ins = &signals.instructions;
+                if ins.has_call_indirect {
+                    out.push(
+                        build_trigger(
+                            def,
+                            CallIndirectEvidence {
+                                has_call_indirect: ins.has_call_indirect,
+                                call_indirect_count: ins.call_indirect_count,
+                                call_indirect_distinct_types: ins
+                                    .call_indirect_distinct_types
+                                    .clone(),
+                                call_indirect_tables: ins.call_indirect_tables.clone(),
+                                has_table_mutation: ins.has_table_mutation,
+                                by_export: by_export(signals, "call_indirect"),
+                                locations: locations(signals, &ins.call_indirect_sites),
+                            },
+                        )
+                        .with_findings(findings(signals, &ins.call_indirect_sites)),
+                    );
                 }
             }
 
             RuleId::RLoop01 => {
-                if signals.instructions.has_loop {
+                let ins = &signals.instructions;
+                if ins.has_loop {
+                    out.push(
+                        build_trigger(
+                            def,
+                            LoopEvidence {
+                                has_loop: ins.has_loop,
+                                loop_count: ins.loop_count,
+                                by_export: by_export(signals, "loop"),
+                                locations: locations(signals, &ins.loop_sites),
+                            },
+                        )
+                        .with_findings(findings(signals, &ins.loop_sites)),
+                    );
+                }
+            }
+
+            RuleId::RSize01 => {
+                let size_threshold = def.param("SIZE_THRESHOLD");
+                let compressed_size_threshold = def.param("COMPRESSED_SIZE_THRESHOLD");
+                let compressed = artifact
+                    .compressed_size_bytes
+                    .filter(|_| cfg.size_rule_compressed);
+                let exceeded = match compressed {
+                    Some(size) => size > compressed_size_threshold,
+                    None => artifact.size_bytes > size_threshold,
+                };
+                if exceeded {
                     out.push(build_trigger(
                         def,
-                        json!({
-                            "signals.instructions.has_loop": signals.instructions.has_loop,
-                            "signals.instructions.loop_count": signals.instructions.loop_count,
-                        }),
+                        SizeEvidence {
+                            size_bytes: artifact.size_bytes,
+                            compressed_size_bytes: compressed,
+                            size_threshold,
+                            compressed_size_threshold,
+                        },
                     ));
                 }
             }
 
-            RuleId::RSize01 => {
-                if artifact.size_bytes > cfg.size_threshold_bytes {
+            RuleId::RTable01 => {
+                if signals.tables.has_host_visible_funcref_table {
+                    // Every host-visible table, with its element type.
+                    let tables = signals
+                        .tables
+                        .tables
+                        .iter()
+                        .filter(|t| t.imported || t.exported)
+                        .cloned()
+                        .collect();
+                    out.push(build_trigger(
+                        def,
+                        HostTableEvidence {
+                            has_host_visible_funcref_table: true,
+                            tables,
+                            has_table_mutation: signals.instructions.has_table_mutation,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RImport01 => {
+                let Some(allowlist) = &cfg.import_allowlist else {
+                    continue;
+                };
+                let allowed = |module: &str| allowlist.iter().any(|a| a == module);
+                let modules: Vec<_> = signals
+                    .imports_exports
+                    .modules
+                    .iter()
+                    .filter(|m| !allowed(&m.module))
+                    .cloned()
+                    .collect();
+                if !modules.is_empty() {
+                    let imports = signals
+                        .imports_exports
+                        .imports
+                        .iter()
+                        .flatten()
+                        .filter(|i| !allowed(&i.module))
+                        .take(MAX_IMPORT_EVIDENCE)
+                        .map(|i| ImportRef {
+                            module: i.module.clone(),
+                            name: i.name.clone(),
+                        })
+                        .collect();
+                    out.push(build_trigger(
+                        def,
+                        ImportEvidence {
+                            import_allowlist: allowlist.clone(),
+                            modules,
+                            imports,
+                            imports_truncated: signals
+                                .imports_exports
+                                .imports_truncated
+                                .then_some(true),
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RWasi01 => {
+                // Decided on the uncapped module counts; only imports count,
+                // exports with WASI-looking names do not.
+                let modules: Vec<_> = signals
+                    .imports_exports
+                    .modules
+                    .iter()
+                    .filter(|m| is_wasi_module(&m.module))
+                    .cloned()
+                    .collect();
+                if !modules.is_empty() {
+                    let imports = signals
+                        .imports_exports
+                        .imports
+                        .iter()
+                        .flatten()
+                        .filter(|i| is_wasi_module(&i.module))
+                        .map(|i| ImportRef {
+                            module: i.module.clone(),
+                            name: i.name.clone(),
+                        })
+                        .collect();
+                    out.push(build_trigger(
+                        def,
+                        WasiEvidence {
+                            modules,
+                            imports,
+                            imports_truncated: signals
+                                .imports_exports
+                                .imports_truncated
+                                .then_some(true),
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RStart01 => {
+                if signals.module.has_start {
+                    out.push(build_trigger(
+                        def,
+                        StartEvidence {
+                            has_start: true,
+                            start_function: signals.module.start_function,
+                            start_function_name: signals.module.start_function_name.clone(),
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RGlobal01 => {
+                if signals.globals.exported_mutable_global_count > 0 {
+                    out.push(build_trigger(
+                        def,
+                        MutableGlobalEvidence {
+                            exported_mutable_global_count: signals
+                                .globals
+                                .exported_mutable_global_count,
+                            exported_mutable_globals: signals
+                                .globals
+                                .exported_mutable_globals
+                                .clone(),
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RExport01 => {
+                // Resolved against the full export section, not the capped list.
+                let unmet: Vec<_> = cfg
+                    .required_exports
+                    .iter()
+                    .filter(|c| !c.satisfied())
+                    .cloned()
+                    .collect();
+                if !unmet.is_empty() {
+                    out.push(build_trigger(
+                        def,
+                        RequiredExportEvidence {
+                            required_exports: required_exports(cfg),
+                            unmet_required_exports: unmet,
+                            export_count: signals.imports_exports.export_count,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RMem04 => {
+                // No maximum at all is R-MEM-01's finding.
+                let ceiling = def.param("MAX_PAGES_CEILING");
+                if let Some(max_pages) = signals.memory.max_pages
+                    && max_pages > ceiling
+                {
+                    out.push(build_trigger(
+                        def,
+                        MemCeilingEvidence {
+                            max_pages,
+                            max_bytes: signals.memory.max_bytes,
+                            max_bytes_ceiling: ceiling.saturating_mul(WASM_PAGE_SIZE),
+                            max_pages_ceiling: ceiling,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RLoop02 => {
+                let threshold = def.param("LOOP_DEPTH_THRESHOLD");
+                if u64::from(signals.functions.max_loop_depth) >= threshold {
+                    out.push(build_trigger(
+                        def,
+                        LoopDepthEvidence {
+                            max_loop_depth: signals.functions.max_loop_depth,
+                            deepest_loop_function: signals.functions.deepest_loop_function,
+                            loop_depth_threshold: threshold,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RLoop03 => {
+                let ins = &signals.instructions;
+                if ins.memory_grow_in_loop_count > 0 {
+                    out.push(
+                        build_trigger(
+                            def,
+                            GrowInLoopEvidence {
+                                memory_grow_in_loop_count: ins.memory_grow_in_loop_count,
+                                memory_grow_in_loop_sites: ins.memory_grow_in_loop_sites.clone(),
+                            },
+                        )
+                        .with_findings(findings(signals, &ins.memory_grow_in_loop_sites)),
+                    );
+                }
+            }
+
+            RuleId::RCall02 => {
+                let ins = &signals.instructions;
+                let threshold = def.param("CALL_INDIRECT_THRESHOLD");
+                if ins.call_indirect_count >= threshold {
+                    out.push(build_trigger(
+                        def,
+                        IndirectCountEvidence {
+                            call_indirect_count: ins.call_indirect_count,
+                            call_indirect_distinct_types: ins.call_indirect_distinct_types.clone(),
+                            call_indirect_indices_capped: ins.call_indirect_indices_capped,
+                            call_indirect_threshold: threshold,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RCall03 => {
+                let cg = &signals.call_graph;
+                if cg.has_recursion {
+                    let kind = match (
+                        cg.self_recursive_count > 0,
+                        cg.mutual_recursion_group_count > 0,
+                    ) {
+                        (true, true) => "direct_and_mutual",
+                        (true, false) => "direct",
+                        _ => "mutual",
+                    };
+                    out.push(build_trigger(
+                        def,
+                        RecursionEvidence {
+                            recursive_function_count: cg.recursive_function_count,
+                            recursive_functions: cg.recursive_functions.clone(),
+                            self_recursive_functions: cg.self_recursive_functions.clone(),
+                            mutual_recursion_groups: cg.mutual_recursion_groups.clone(),
+                            recursion_kind: kind.to_string(),
+                            recursive_function_names: cg.recursive_function_names.clone(),
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RSize02 => {
+                let threshold = def.param("FUNCTION_COUNT_THRESHOLD");
+                if u64::from(signals.module.function_count) > threshold {
+                    out.push(build_trigger(
+                        def,
+                        FunctionCountEvidence {
+                            function_count: signals.module.function_count,
+                            function_count_threshold: threshold,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RData01 => {
+                let threshold = def.param("DATA_BYTES_THRESHOLD");
+                if signals.data.total_bytes > threshold {
+                    out.push(build_trigger(
+                        def,
+                        DataSizeEvidence {
+                            segment_count: signals.data.segment_count,
+                            total_bytes: signals.data.total_bytes,
+                            largest_segment_bytes: signals.data.largest_segment_bytes,
+                            data_bytes_threshold: threshold,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RFeat01 => {
+                let disallowed: Vec<_> = signals
+                    .features
+                    .used
+                    .iter()
+                    .filter(|f| !cfg.allowed_features.contains(&f.name))
+                    .cloned()
+                    .collect();
+                if !disallowed.is_empty() {
+                    out.push(build_trigger(
+                        def,
+                        FeatureEvidence {
+                            allowed_features: cfg.allowed_features.clone(),
+                            disallowed_features: disallowed,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RAtomic01 => {
+                if signals.instructions.has_atomics || signals.memory.has_shared_memory {
+                    out.push(build_trigger(
+                        def,
+                        AtomicsEvidence {
+                            has_atomics: signals.instructions.has_atomics,
+                            atomic_op_count: signals.instructions.atomic_op_count,
+                            has_shared_memory: signals.memory.has_shared_memory,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RSimd01 => {
+                if signals.instructions.has_simd {
+                    out.push(build_trigger(
+                        def,
+                        SimdEvidence {
+                            has_simd: signals.instructions.has_simd,
+                            simd_op_count: signals.instructions.simd_op_count,
+                        },
+                    ));
+                }
+            }
+
+            RuleId::RAnalysis01 => {
+                if analysis.is_incomplete() {
                     out.push(build_trigger(
                         def,
-                        json!({
-                            "artifact.size_bytes": artifact.size_bytes,
-                            "SIZE_THRESHOLD": cfg.size_threshold_bytes,
-                        }),
+                        AnalysisEvidence {
+                            status: analysis.status.clone(),
+                            warnings: analysis
+                                .warnings
+                                .iter()
+                                .take(MAX_ANALYSIS_WARNING_EVIDENCE)
+                                .cloned()
+                                .collect(),
+                        },
                     ));
                 }
             }
+
+            // Composites are evaluated below; the built-in engine has no
+            // trigger condition for custom ids.
+            RuleId::RCombo01 | RuleId::Custom(_) => continue,
+        }
+        if out.len() == triggered_before {
+            passed.push(build_pass(rule, ctx));
+        }
+        evaluated += 1;
+        timings.push(RuleTiming {
+            rule_id: rule.id.clone(),
+            elapsed: started.elapsed(),
+        });
+    }
+
+    // Every ordinary rule has run, so composites see the same constituents
+    // whatever the catalog order.
+    for rule in rules.iter().filter(|def| def.is_composite()) {
+        let started = Instant::now();
+        let mut constituents = Vec::new();
+        let mut unmet = Vec::new();
+        for id in rule.requires {
+            match constituent_trigger(id, ctx, &out) {
+                Some(trigger) => constituents.push(ConstituentEvidence {
+                    rule_id: trigger.rule_id,
+                    evidence: trigger.evidence,
+                }),
+                None => unmet.push(id),
+            }
+        }
+        if unmet.is_empty() {
+            out.push(build_trigger(
+                rule.clone(),
+                CompositeEvidence { constituents },
+            ));
+        } else {
+            passed.push(PassedRule {
+                rule_id: rule.id.clone(),
+                severity: rule.severity.clone(),
+                evidence: json!({ "requires": rule.requires, "unmet": unmet }),
+            });
         }
+        evaluated += 1;
+        timings.push(RuleTiming {
+            rule_id: rule.id.clone(),
+            elapsed: started.elapsed(),
+        });
     }
 
     deterministic::sort_triggered_rules(&mut out);
-    out
+    passed.sort_by(|a, b| a.rule_id.cmp(&b.rule_id));
+    Evaluation {
+        triggered: out,
+        passed,
+        evaluated,
+        timings,
+    }
+}
+
+/// Evaluates the built-in catalog with the pre-[`EvaluationContext`]
+/// signature.
+#[deprecated(
+    since = "0.1.0",
+    note = "build an `EvaluationContext` and call `evaluate_rules`"
+)]
+pub fn evaluate_rules_legacy(
+    signals: &Signals,
+    artifact: &ArtifactContext,
+    cfg: &ParseConfig,
+) -> Vec<TriggeredRule> {
+    #[allow(deprecated)]
+    evaluate_rules_with_catalog(signals, artifact, cfg, &catalog())
+}
+
+/// Evaluates an adjusted catalog with the pre-[`EvaluationContext`]
+/// signature.
+#[deprecated(
+    since = "0.1.0",
+    note = "build an `EvaluationContext` and call `evaluate_rules`"
+)]
+pub fn evaluate_rules_with_catalog(
+    signals: &Signals,
+    artifact: &ArtifactContext,
+    cfg: &ParseConfig,
+    rules: &[RuleDef],
+) -> Vec<TriggeredRule> {
+    let analysis = AnalysisInfo::default();
+    evaluate_rules(&EvaluationContext {
+        signals,
+        artifact,
+        config: cfg,
+        analysis: &analysis,
+        rules,
+        facts: None,
+    })
+}
+
+/// The trigger of composite constituent `id` if its condition holds.
+///
+/// Taken from `triggered` when the effective catalog has the rule. A
+/// constituent that is disabled or excluded is evaluated on its own with
+/// its built-in definition: a composite is a conjunction of conditions, not
+/// of reported findings.
+fn constituent_trigger(
+    id: &RuleId,
+    ctx: &EvaluationContext<'_>,
+    triggered: &[TriggeredRule],
+) -> Option<TriggeredRule> {
+    if ctx.rules.iter().any(|def| def.id == *id) {
+        return triggered.iter().find(|r| r.rule_id == *id).cloned();
+    }
+    let def = catalog().into_iter().find(|def| def.id == *id)?;
+    let rules = std::slice::from_ref(&def);
+    evaluate(&EvaluationContext { rules, ..*ctx })
+        .triggered
+        .pop()
+}
+
+/// Functions containing `memory.grow` when the allocator heuristic is on and
+/// every one of them has a name-section name matching an allocator pattern.
+///
+/// `None` when the heuristic is off, raw facts are unavailable, or any grow
+/// site lies in an unnamed or non-allocator function. The check is
+/// module-wide, so it also holds under the reachable rule scope.
+fn allocator_only_functions(ctx: &EvaluationContext<'_>) -> Option<Vec<AllocatorFunction>> {
+    let patterns: Vec<glob::Pattern> = ctx
+        .config
+        .allocator_patterns
+        .as_ref()?
+        .iter()
+        .filter_map(|p| glob::Pattern::new(p).ok())
+        .collect();
+    let facts = ctx.facts?;
+    let mut matched = Vec::new();
+    for function in &facts.instructions.functions {
+        if function.memory_grow_count == 0 {
+            continue;
+        }
+        let name = facts.sections.function_names.get(&function.index)?;
+        let pattern = patterns.iter().find(|p| p.matches(name))?;
+        matched.push(AllocatorFunction {
+            function_index: function.index,
+            function_name: name.clone(),
+            pattern: pattern.as_str().to_string(),
+            memory_grow_count: function.memory_grow_count,
+        });
+    }
+    (!matched.is_empty()).then_some(matched)
+}
+
+/// The functions containing `capability`, if attributed.
+fn by_export(signals: &Signals, capability: &str) -> Option<Vec<String>> {
+    signals
+        .instructions
+        .by_export
+        .as_ref()
+        .and_then(|m| m.get(capability))
+        .cloned()
+}
+
+/// Where the flagged instructions are, in module order.
+fn locations(signals: &Signals, sites: &[InstructionSite]) -> Vec<Location> {
+    sites
+        .iter()
+        .take(MAX_LOCATION_EVIDENCE)
+        .map(|site| location(signals, site))
+        .collect()
+}
+
+/// One finding per reported site. Sites are capped by the scan's site
+/// limit; the rule's counts stay exact.
+fn findings(signals: &Signals, sites: &[InstructionSite]) -> Vec<Finding> {
+    sites
+        .iter()
+        .map(|site| Finding {
+            location: location(signals, site),
+            evidence: Default::default(),
+        })
+        .collect()
+}
+
+fn location(signals: &Signals, site: &InstructionSite) -> Location {
+    Location {
+        function_index: site.function_index,
+        byte_offset: site.offset,
+        function_name: signals
+            .instructions
+            .site_function_names
+            .get(&site.function_index)
+            .cloned(),
+    }
+}
+
+/// `REQUIRED_EXPORTS` as the target profile declares them.
+fn required_exports(cfg: &ParseConfig) -> Vec<ExportRef> {
+    cfg.required_exports
+        .iter()
+        .map(|c| ExportRef {
+            name: c.name.clone(),
+            kind: c.kind.clone(),
+        })
+        .collect()
+}
+/// The values a passed rule checked: its trigger inputs, without the
+/// detail (sites, lists) only a trigger needs.
+fn checked_values(id: &RuleId, ctx: &EvaluationContext<'_>) -> serde_json::Value {
+    let EvaluationContext {
+        signals: s,
+        artifact,
+        config: cfg,
+        analysis,
+        ..
+    } = *ctx;
+    let module_names = || {
+        s.imports_exports
+            .modules
+            .iter()
+            .map(|m| m.module.as_str())
+            .collect::<Vec<_>>()
+    };
+    match id {
+        RuleId::RMem01 => json!({ "signals.memory.has_max": s.memory.has_max }),
+        RuleId::RMem02 => {
+            json!({ "signals.instructions.has_memory_grow": s.instructions.has_memory_grow })
+        }
+        RuleId::RCall01 => {
+            json!({ "signals.instructions.has_call_indirect": s.instructions.has_call_indirect })
+        }
+        RuleId::RLoop01 => json!({ "signals.instructions.has_loop": s.instructions.has_loop }),
+        RuleId::RSize01 => {
+            let mut values = json!({ "artifact.size_bytes": artifact.size_bytes });
+            if let Some(size) = artifact.compressed_size_bytes {
+                values["artifact.compressed_size_bytes"] = json!(size);
+            }
+            values
+        }
+        RuleId::RTable01 => json!({
+            "signals.tables.has_host_visible_funcref_table": s.tables.has_host_visible_funcref_table,
+        }),
+        RuleId::RImport01 => json!({
+            "IMPORT_ALLOWLIST": cfg.import_allowlist,
+            "signals.imports_exports.modules": module_names(),
+        }),
+        RuleId::RWasi01 => json!({ "signals.imports_exports.modules": module_names() }),
+        RuleId::RStart01 => json!({ "signals.module.has_start": s.module.has_start }),
+        RuleId::RGlobal01 => json!({
+            "signals.globals.exported_mutable_global_count": s.globals.exported_mutable_global_count,
+        }),
+        RuleId::RExport01 => json!({
+            "REQUIRED_EXPORTS": cfg
+                .required_exports
+                .iter()
+                .map(|c| json!({ "name": c.name, "kind": c.kind }))
+                .collect::<Vec<_>>(),
+        }),
+        RuleId::RMem04 => json!({ "signals.memory.max_pages": s.memory.max_pages }),
+        RuleId::RLoop02 => {
+            json!({ "signals.functions.max_loop_depth": s.functions.max_loop_depth })
+        }
+        RuleId::RLoop03 => json!({
+            "signals.instructions.memory_grow_in_loop_count": s.instructions.memory_grow_in_loop_count,
+        }),
+        RuleId::RCall02 => json!({
+            "signals.instructions.call_indirect_count": s.instructions.call_indirect_count,
+        }),
+        RuleId::RCall03 => {
+            json!({ "signals.call_graph.has_recursion": s.call_graph.has_recursion })
+        }
+        RuleId::RSize02 => {
+            json!({ "signals.module.function_count": s.module.function_count })
+        }
+        RuleId::RData01 => json!({ "signals.data.total_bytes": s.data.total_bytes }),
+        RuleId::RFeat01 => json!({
+            "ALLOWED_FEATURES": cfg.allowed_features,
+            "signals.features.used": s.features.used.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        }),
+        RuleId::RAtomic01 => json!({
+            "signals.instructions.has_atomics": s.instructions.has_atomics,
+            "signals.memory.has_shared_memory": s.memory.has_shared_memory,
+        }),
+        RuleId::RSimd01 => json!({ "signals.instructions.has_simd": s.instructions.has_simd }),
+        RuleId::RAnalysis01 => json!({ "analysis.status": analysis.status }),
+        RuleId::RCombo01 | RuleId::Custom(_) => json!({}),
+    }
 }
 
-/// construct a `TriggeredRule` from a `RuleDef`.
-fn build_trigger(
-    def: crate::rules::catalog::RuleDef,
-    evidence: serde_json::Value,
-) -> TriggeredRule {
+fn build_pass(def: &RuleDef, ctx: &EvaluationContext<'_>) -> PassedRule {
+    let mut evidence = checked_values(&def.id, ctx);
+    insert_params(&mut evidence, def);
+    PassedRule {
+        rule_id: def.id.clone(),
+        severity: def.severity.clone(),
+        evidence,
+    }
+}
+
+/// Adds the rule's parameters (thresholds) to `evidence`.
+fn insert_params(evidence: &mut serde_json::Value, def: &RuleDef) {
+    if let Some(fields) = evidence.as_object_mut() {
+        for (name, value) in &def.params {
+            fields.insert((*name).to_string(), json!(value));
+        }
+    }
+}
+
+/// Constructs a `TriggeredRule` from a `RuleDef` and the rule's evidence,
+/// which carries the effective parameters as typed fields.
+fn build_trigger(def: RuleDef, evidence: impl Into<RuleEvidence>) -> TriggeredRule {
     TriggeredRule {
         rule_id: def.id,
         severity: def.severity,
+        confidence: def.confidence,
         title: def.title.to_string(),
-        message: def.message.to_string(),
-        evidence,
+        message: def.message.into_owned(),
+        remediation: def.remediation.to_string(),
+        docs_url: def.docs_url.to_string(),
+        deprecated: def.deprecated.map(str::to_string),
+        evidence: evidence.into(),
+        findings: Vec::new(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rules::catalog::set_param;
     use crate::signals::model::*;
+    use crate::wasm::profile::RequiredExportCheck;
     use crate::wasm::read::ArtifactContext;
 
     fn base_signals() -> Signals {
@@ -146,18 +1014,32 @@ mod tests {
             module: ModuleSignals {
                 function_count: 0,
                 section_count: None,
+                ..Default::default()
             },
+            functions: FunctionSignals::default(),
+            sizes: SizeSignals::default(),
             memory: MemorySignals {
                 memory_count: 1,
                 min_pages: Some(1),
                 max_pages: Some(10),
                 has_max: true,
+                ..Default::default()
             },
+            tables: TableSignals::default(),
+            globals: GlobalSignals::default(),
+            data: DataSignals::default(),
+            features: FeatureSignals::default(),
+            hostio: HostioSignals::default(),
             imports_exports: ImportExportSignals {
                 import_count: 0,
                 export_count: 0,
+                unused_import_count: 0,
                 imports: Some(vec![]),
                 exports: Some(vec![]),
+                imports_truncated: false,
+                exports_truncated: false,
+                modules: vec![],
+                export_aliases: vec![],
             },
             instructions: InstructionSignals {
                 has_memory_grow: false,
@@ -166,7 +1048,10 @@ mod tests {
                 call_indirect_count: 0,
                 has_loop: false,
                 loop_count: 0,
+                ..Default::default()
             },
+            call_graph: CallGraphSignals::default(),
+            reachability: None,
         }
     }
 
@@ -177,21 +1062,46 @@ mod tests {
             size_bytes: size,
             hash_alg: "sha256".into(),
             hash_hex: "00".into(),
+            compressed_size_bytes: None,
         }
     }
 
     fn cfg() -> ParseConfig {
         ParseConfig {
-            size_threshold_bytes: 100,
+            size_rule_compressed: false,
+            allowed_features: vec!["sign_extension".to_string()],
+            import_allowlist: None,
+            required_exports: vec![],
+            allocator_patterns: None,
         }
     }
 
+    fn run(s: &Signals, art: &ArtifactContext, cfg: &ParseConfig) -> Vec<TriggeredRule> {
+        run_with(s, art, cfg, &catalog())
+    }
+
+    fn run_with(
+        s: &Signals,
+        art: &ArtifactContext,
+        cfg: &ParseConfig,
+        rules: &[RuleDef],
+    ) -> Vec<TriggeredRule> {
+        evaluate_rules(&EvaluationContext {
+            signals: s,
+            artifact: art,
+            config: cfg,
+            analysis: &AnalysisInfo::default(),
+            rules,
+            facts: None,
+        })
+    }
+
     #[test]
     fn triggers_memory_missing_max() {
         let mut s = base_signals();
         s.memory.has_max = false;
 
-        let rules = evaluate_rules(&s, &artifact(10), &cfg());
+        let rules = run(&s, &artifact(10), &cfg());
 
         assert!(rules.iter().any(|r| r.rule_id == RuleId::RMem01));
     }
@@ -201,43 +1111,1107 @@ mod tests {
         let mut s = base_signals();
         s.instructions.has_memory_grow = true;
 
-        let rules = evaluate_rules(&s, &artifact(10), &cfg());
+        let rules = run(&s, &artifact(10), &cfg());
 
         assert!(rules.iter().any(|r| r.rule_id == RuleId::RMem02));
     }
 
     #[test]
-    fn triggers_call_indirect() {
+    fn memory_grow_evidence_includes_memory_size_when_present() {
         let mut s = base_signals();
-        s.instructions.has_call_indirect = true;
+        s.instructions.has_memory_grow = true;
+        s.instructions.memory_grow_count = 1;
 
-        let rules = evaluate_rules(&s, &artifact(10), &cfg());
+        let rules = run(&s, &artifact(10), &cfg());
+        let mem02 = rules.iter().find(|r| r.rule_id == RuleId::RMem02).unwrap();
+        assert!(
+            mem02
+                .evidence
+                .to_value()
+                .get("signals.instructions.memory_size_count")
+                .is_none()
+        );
 
-        assert!(rules.iter().any(|r| r.rule_id == RuleId::RCall01));
+        s.instructions.has_memory_size = true;
+        s.instructions.memory_size_count = 2;
+
+        let rules = run(&s, &artifact(10), &cfg());
+        let mem02 = rules.iter().find(|r| r.rule_id == RuleId::RMem02).unwrap();
+        assert_eq!(
+            mem02.evidence.to_value()["signals.instructions.memory_size_count"],
+            json!(2)
+        );
     }
 
     #[test]
-    fn triggers_loop() {
+    fn triggers_host_visible_table() {
         let mut s = base_signals();
-        s.instructions.has_loop = true;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RTable01));
 
-        let rules = evaluate_rules(&s, &artifact(10), &cfg());
+        s.tables = TableSignals {
+            table_count: 2,
+            tables: vec![
+                TableItem {
+                    index: 0,
+                    element_type: "funcref".into(),
+                    min: 1,
+                    max: None,
+                    imported: true,
+                    exported: false,
+                    import_name: Some("env.table".into()),
+                    export_names: vec![],
+                },
+                TableItem {
+                    index: 1,
+                    element_type: "funcref".into(),
+                    min: 2,
+                    max: Some(2),
+                    imported: false,
+                    exported: false,
+                    import_name: None,
+                    export_names: vec![],
+                },
+            ],
+            has_host_visible_funcref_table: true,
+        };
 
-        assert!(rules.iter().any(|r| r.rule_id == RuleId::RLoop01));
+        let rules = run(&s, &artifact(10), &cfg());
+        let table01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RTable01)
+            .unwrap();
+        assert_eq!(table01.severity, Severity::High);
+        let RuleEvidence::HostTable(evidence) = &table01.evidence else {
+            panic!("unexpected evidence: {:?}", table01.evidence);
+        };
+        assert_eq!(evidence.tables.len(), 1);
+        assert_eq!(evidence.tables[0].import_name.as_deref(), Some("env.table"));
     }
 
     #[test]
-    fn triggers_size_rule() {
+    fn triggers_import_outside_allowlist() {
+        let mut s = base_signals();
+        let import = |module: &str, name: &str| ImportItem {
+            module: module.into(),
+            name: name.into(),
+            kind: "func".into(),
+            referenced: None,
+            signature: None,
+        };
+        let mut imports = vec![import("vm_hooks", "read_args")];
+        imports.extend((0..20).map(|i| import("env", &format!("f{i}"))));
+        s.imports_exports.imports = Some(imports);
+        s.imports_exports.import_count = 21;
+        s.imports_exports.modules = vec![
+            ImportModuleItem {
+                module: "env".into(),
+                count: 20,
+            },
+            ImportModuleItem {
+                module: "vm_hooks".into(),
+                count: 1,
+            },
+        ];
+
+        // No allowlist: the rule is off.
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RImport01));
+
+        let stylus_cfg = ParseConfig {
+            import_allowlist: Some(vec!["console".into(), "vm_hooks".into()]),
+            ..cfg()
+        };
+        let rules = run(&s, &artifact(10), &stylus_cfg);
+        let import01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RImport01)
+            .unwrap();
+        assert_eq!(import01.severity, Severity::High);
+        let RuleEvidence::Import(evidence) = &import01.evidence else {
+            panic!("unexpected evidence: {:?}", import01.evidence);
+        };
+        assert_eq!(evidence.modules.len(), 1);
+        assert_eq!(evidence.modules[0].count, 20);
+        assert_eq!(evidence.imports.len(), MAX_IMPORT_EVIDENCE);
+        assert_eq!(
+            evidence.imports[0],
+            ImportRef {
+                module: "env".into(),
+                name: "f0".into(),
+            }
+        );
+        assert_eq!(evidence.imports_truncated, None);
+        // The canonical form quotes the pairs as objects.
+        assert_eq!(
+            import01.evidence.to_value()["signals.imports_exports.imports"][0],
+            json!({ "module": "env", "name": "f0" })
+        );
+
+        // Allowing `env` clears it.
+        let permissive_cfg = ParseConfig {
+            import_allowlist: Some(vec!["env".into(), "vm_hooks".into()]),
+            ..cfg()
+        };
+        let rules = run(&s, &artifact(10), &permissive_cfg);
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RImport01));
+    }
+
+    #[test]
+    fn triggers_wasi_imports_not_exports() {
+        let mut s = base_signals();
+        s.imports_exports.exports = Some(vec![ExportItem {
+            name: "wasi_snapshot_preview1.fd_write".into(),
+            kind: "func".into(),
+            index: Some(0),
+            defined_function_index: Some(0),
+            signature: None,
+        }]);
+        s.imports_exports.export_count = 1;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RWasi01));
+
+        s.imports_exports.imports = Some(vec![ImportItem {
+            module: "wasi_snapshot_preview1".into(),
+            name: "fd_write".into(),
+            kind: "func".into(),
+            referenced: Some(true),
+            signature: None,
+        }]);
+        s.imports_exports.import_count = 1;
+        s.imports_exports.modules = vec![ImportModuleItem {
+            module: "wasi_snapshot_preview1".into(),
+            count: 1,
+        }];
+        let rules = run(&s, &artifact(10), &cfg());
+        let wasi01 = rules.iter().find(|r| r.rule_id == RuleId::RWasi01).unwrap();
+        assert_eq!(wasi01.severity, Severity::Med);
+        assert_eq!(
+            wasi01.evidence.to_value()["signals.imports_exports.imports"],
+            json!([{ "module": "wasi_snapshot_preview1", "name": "fd_write" }])
+        );
+    }
+
+    #[test]
+    fn triggers_start_function() {
+        let mut s = base_signals();
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RStart01));
+
+        s.module.has_start = true;
+        s.module.start_function = Some(3);
+        let rules = run(&s, &artifact(10), &cfg());
+        let start01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RStart01)
+            .unwrap();
+        assert_eq!(start01.severity, Severity::Med);
+        assert_eq!(
+            start01.evidence.to_value()["signals.module.start_function"],
+            3
+        );
+        assert!(
+            start01
+                .evidence
+                .to_value()
+                .get("signals.module.start_function_name")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn triggers_exported_mutable_global() {
+        let mut s = base_signals();
+        s.globals.global_count = 2;
+        s.globals.mutable_global_count = 2;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RGlobal01));
+
+        s.globals.exported_mutable_global_count = 1;
+        s.globals.exported_mutable_globals = vec![GlobalExportItem {
+            name: "counter".into(),
+            index: 1,
+            val_type: "i64".into(),
+        }];
+        let rules = run(&s, &artifact(10), &cfg());
+        let global01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RGlobal01)
+            .unwrap();
+        assert_eq!(global01.severity, Severity::Med);
+        assert_eq!(
+            global01.evidence.to_value()["signals.globals.exported_mutable_globals"],
+            json!([{ "name": "counter", "index": 1, "val_type": "i64" }])
+        );
+    }
+
+    #[test]
+    fn triggers_unmet_required_export() {
         let s = base_signals();
-        let rules = evaluate_rules(&s, &artifact(1000), &cfg());
+        let check = |found_kind: Option<&str>| RequiredExportCheck {
+            name: "user_entrypoint".into(),
+            kind: "func".into(),
+            found_kind: found_kind.map(String::from),
+        };
 
-        assert!(rules.iter().any(|r| r.rule_id == RuleId::RSize01));
+        // No required exports (generic profile) or all present: no trigger.
+        for required_exports in [vec![], vec![check(Some("func"))]] {
+            let cfg = ParseConfig {
+                required_exports,
+                ..cfg()
+            };
+            let rules = run(&s, &artifact(10), &cfg);
+            assert!(!rules.iter().any(|r| r.rule_id == RuleId::RExport01));
+        }
+
+        for found in [None, Some("global")] {
+            let cfg = ParseConfig {
+                required_exports: vec![check(found)],
+                ..cfg()
+            };
+            let rules = run(&s, &artifact(10), &cfg);
+            let export01 = rules
+                .iter()
+                .find(|r| r.rule_id == RuleId::RExport01)
+                .unwrap();
+            assert_eq!(export01.severity, Severity::High);
+            assert_eq!(
+                export01.evidence.to_value()["REQUIRED_EXPORTS"],
+                json!([{ "name": "user_entrypoint", "kind": "func" }])
+            );
+            assert_eq!(
+                export01.evidence.to_value()["unmet_required_exports"][0]["found_kind"],
+                json!(found)
+            );
+        }
+    }
+
+    #[test]
+    fn triggers_max_pages_above_ceiling() {
+        let mut s = base_signals();
+        let mem04 = |s: &Signals| {
+            run(s, &artifact(10), &cfg())
+                .into_iter()
+                .find(|r| r.rule_id == RuleId::RMem04)
+        };
+
+        // At the ceiling, or no maximum at all (R-MEM-01's case): no trigger.
+        s.memory.max_pages = Some(1_024);
+        assert!(mem04(&s).is_none());
+        s.memory.max_pages = None;
+        s.memory.has_max = false;
+        assert!(mem04(&s).is_none());
+
+        s.memory.max_pages = Some(65_536);
+        s.memory.max_bytes = Some(65_536 * WASM_PAGE_SIZE);
+        s.memory.has_max = true;
+        let rule = mem04(&s).unwrap();
+        assert_eq!(rule.severity, Severity::Med);
+        assert_eq!(rule.evidence.to_value()["signals.memory.max_pages"], 65_536);
+        assert_eq!(rule.evidence.to_value()["MAX_PAGES_CEILING"], 1_024);
+        assert_eq!(
+            rule.evidence.to_value()["MAX_BYTES_CEILING"],
+            64 * 1024 * 1024
+        );
+    }
+
+    #[test]
+    fn triggers_deep_loop_nesting_at_threshold() {
+        let mut s = base_signals();
+        s.functions.max_loop_depth = 2;
+        s.functions.deepest_loop_function = Some(4);
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RLoop02));
+
+        s.functions.max_loop_depth = 3;
+        let rules = run(&s, &artifact(10), &cfg());
+        let loop02 = rules.iter().find(|r| r.rule_id == RuleId::RLoop02).unwrap();
+        assert_eq!(loop02.severity, Severity::Med);
+        assert_eq!(
+            loop02.evidence.to_value()["signals.functions.max_loop_depth"],
+            3
+        );
+        assert_eq!(loop02.evidence.to_value()["LOOP_DEPTH_THRESHOLD"], 3);
+        assert_eq!(
+            loop02.evidence.to_value()["signals.functions.deepest_loop_function"],
+            4
+        );
+    }
+
+    #[test]
+    fn triggers_memory_grow_in_loop_without_subsuming_mem02() {
+        let mut s = base_signals();
+        s.instructions.has_memory_grow = true;
+        s.instructions.memory_grow_count = 2;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RMem02));
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RLoop03));
+
+        s.instructions.memory_grow_in_loop_count = 1;
+        s.instructions.memory_grow_in_loop_sites = vec![InstructionSite {
+            function_index: 3,
+            offset: 42,
+        }];
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RMem02));
+        let loop03 = rules.iter().find(|r| r.rule_id == RuleId::RLoop03).unwrap();
+        assert_eq!(loop03.severity, Severity::High);
+        assert_eq!(
+            loop03.evidence.to_value()["signals.instructions.memory_grow_in_loop_count"],
+            1
+        );
+        assert_eq!(
+            loop03.evidence.to_value()["signals.instructions.memory_grow_in_loop_sites"],
+            json!([{ "function_index": 3, "offset": 42 }])
+        );
+    }
+
+    #[test]
+    fn triggers_pervasive_call_indirect_at_threshold() {
+        let mut s = base_signals();
+        s.instructions.has_call_indirect = true;
+        s.instructions.call_indirect_count = 99;
+        s.instructions.call_indirect_distinct_types = vec![0, 2];
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RCall01));
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RCall02));
+
+        s.instructions.call_indirect_count = 100;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RCall01));
+        let call02 = rules.iter().find(|r| r.rule_id == RuleId::RCall02).unwrap();
+        assert_eq!(call02.severity, Severity::High);
+        assert_eq!(
+            call02.evidence.to_value()["signals.instructions.call_indirect_count"],
+            100
+        );
+        assert_eq!(call02.evidence.to_value()["CALL_INDIRECT_THRESHOLD"], 100);
+        assert_eq!(
+            call02.evidence.to_value()["signals.instructions.call_indirect_distinct_types"],
+            json!([0, 2])
+        );
+        assert_eq!(
+            call02.evidence.to_value()["signals.instructions.call_indirect_indices_capped"],
+            false
+        );
+    }
+
+    #[test]
+    fn triggers_recursion_with_kind() {
+        let mut s = base_signals();
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RCall03));
+
+        s.call_graph.has_recursion = true;
+        s.call_graph.recursive_function_count = 3;
+        s.call_graph.recursive_functions = vec![0, 1, 2];
+        s.call_graph.self_recursive_count = 1;
+        s.call_graph.self_recursive_functions = vec![0];
+        let rules = run(&s, &artifact(10), &cfg());
+        let call03 = rules.iter().find(|r| r.rule_id == RuleId::RCall03).unwrap();
+        assert_eq!(call03.severity, Severity::Med);
+        assert_eq!(call03.evidence.to_value()["recursion_kind"], "direct");
+        assert!(
+            call03
+                .evidence
+                .to_value()
+                .get("signals.call_graph.recursive_function_names")
+                .is_none()
+        );
+
+        s.call_graph.mutual_recursion_group_count = 1;
+        s.call_graph.mutual_recursion_groups = vec![vec![1, 2]];
+        s.call_graph.recursive_function_names = [(1, "ping".to_string())].into();
+        let rules = run(&s, &artifact(10), &cfg());
+        let call03 = rules.iter().find(|r| r.rule_id == RuleId::RCall03).unwrap();
+        assert_eq!(
+            call03.evidence.to_value()["recursion_kind"],
+            "direct_and_mutual"
+        );
+        assert_eq!(
+            call03.evidence.to_value()["signals.call_graph.recursive_function_names"],
+            json!({ "1": "ping" })
+        );
+    }
+
+    #[test]
+    fn triggers_function_count_above_threshold() {
+        let mut s = base_signals();
+        s.module.function_count = 1_000;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RSize02));
+
+        s.module.function_count = 1_001;
+        let rules = run(&s, &artifact(10), &cfg());
+        let size02 = rules.iter().find(|r| r.rule_id == RuleId::RSize02).unwrap();
+        assert_eq!(size02.severity, Severity::Med);
+        assert_eq!(
+            size02.evidence.to_value()["signals.module.function_count"],
+            1_001
+        );
+        assert_eq!(
+            size02.evidence.to_value()["FUNCTION_COUNT_THRESHOLD"],
+            1_000
+        );
+    }
+
+    #[test]
+    fn triggers_data_bytes_above_threshold() {
+        let mut s = base_signals();
+        s.data.segment_count = 2;
+        s.data.total_bytes = 64 * 1024;
+        s.data.largest_segment_bytes = 60 * 1024;
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RData01));
+
+        s.data.total_bytes += 1;
+        let rules = run(&s, &artifact(10), &cfg());
+        let data01 = rules.iter().find(|r| r.rule_id == RuleId::RData01).unwrap();
+        assert_eq!(data01.severity, Severity::Med);
+        assert_eq!(data01.evidence.to_value()["signals.data.segment_count"], 2);
+        assert_eq!(
+            data01.evidence.to_value()["signals.data.total_bytes"],
+            64 * 1024 + 1
+        );
+        assert_eq!(
+            data01.evidence.to_value()["signals.data.largest_segment_bytes"],
+            60 * 1024
+        );
+        assert_eq!(
+            data01.evidence.to_value()["DATA_BYTES_THRESHOLD"],
+            64 * 1024
+        );
+    }
+
+    #[test]
+    fn triggers_features_outside_allowed_set() {
+        let feature = |name: &str, instruction_count: u64, sections: &[&str]| FeatureUseItem {
+            name: name.to_string(),
+            instruction_count,
+            sections: sections.iter().map(|s| s.to_string()).collect(),
+        };
+        let mut s = base_signals();
+        s.features.used = vec![feature("sign_extension", 4, &[])];
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RFeat01));
+
+        s.features.used = vec![
+            feature("sign_extension", 4, &[]),
+            feature("threads", 2, &["memory"]),
+        ];
+        let rules = run(&s, &artifact(10), &cfg());
+        let feat01 = rules.iter().find(|r| r.rule_id == RuleId::RFeat01).unwrap();
+        assert_eq!(feat01.severity, Severity::Med);
+        assert_eq!(
+            feat01.evidence.to_value()["ALLOWED_FEATURES"],
+            json!(["sign_extension"])
+        );
+        assert_eq!(
+            feat01.evidence.to_value()["disallowed_features"],
+            json!([{ "name": "threads", "instruction_count": 2, "sections": ["memory"] }])
+        );
+    }
+
+    #[test]
+    fn triggers_atomics_or_shared_memory() {
+        let mut s = base_signals();
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RAtomic01));
+
+        // A shared memory alone is enough: the host may supply the atomics.
+        s.memory.has_shared_memory = true;
+        let rules = run(&s, &artifact(10), &cfg());
+        let atomic01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RAtomic01)
+            .unwrap();
+        assert_eq!(atomic01.severity, Severity::High);
+        assert_eq!(
+            atomic01.evidence.to_value()["signals.instructions.has_atomics"],
+            false
+        );
+        assert_eq!(
+            atomic01.evidence.to_value()["signals.instructions.atomic_op_count"],
+            0
+        );
+        assert_eq!(
+            atomic01.evidence.to_value()["signals.memory.has_shared_memory"],
+            true
+        );
+
+        s.memory.has_shared_memory = false;
+        s.instructions.has_atomics = true;
+        s.instructions.atomic_op_count = 3;
+        let rules = run(&s, &artifact(10), &cfg());
+        let atomic01 = rules
+            .iter()
+            .find(|r| r.rule_id == RuleId::RAtomic01)
+            .unwrap();
+        assert_eq!(
+            atomic01.evidence.to_value()["signals.instructions.atomic_op_count"],
+            3
+        );
+        assert_eq!(
+            atomic01.evidence.to_value()["signals.memory.has_shared_memory"],
+            false
+        );
+    }
+
+    #[test]
+    fn triggers_simd() {
+        let mut s = base_signals();
+        let rules = run(&s, &artifact(10), &cfg());
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RSimd01));
+
+        s.instructions.has_simd = true;
+        s.instructions.simd_op_count = 8;
+        let rules = run(&s, &artifact(10), &cfg());
+        let simd01 = rules.iter().find(|r| r.rule_id == RuleId::RSimd01).unwrap();
+        assert_eq!(simd01.severity, Severity::Med);
+        assert_eq!(
+            simd01.evidence.to_value()["signals.instructions.simd_op_count"],
+            8
+        );
+    }
+
+    #[test]
+    fn locations_are_bounded_and_named_when_known() {
+        let mut s = base_signals();
+        s.instructions.has_loop = true;
+        s.instructions.loop_sites = (0..20)
+            .map(|i| InstructionSite {
+                function_index: i / 10,
+                offset: 100 + u64::from(i),
+            })
+            .collect();
+        s.instructions
+            .site_function_names
+            .insert(1, "hot_path".to_string());
+
+        let rules = run(&s, &artifact(10), &cfg());
+        let loop01 = rules.iter().find(|r| r.rule_id == RuleId::RLoop01).unwrap();
+        let evidence = loop01.evidence.to_value();
+        let locations = evidence["locations"].as_array().unwrap();
+
+        assert_eq!(locations.len(), MAX_LOCATION_EVIDENCE);
+        assert_eq!(
+            locations[0],
+            json!({ "function_index": 0, "byte_offset": 100 })
+        );
+        assert_eq!(
+            locations[10],
+            json!({ "function_index": 1, "function_name": "hot_path", "byte_offset": 110 })
+        );
+    }
+
+    #[test]
+    fn findings_list_every_site_in_order() {
+        let mut s = base_signals();
+        s.instructions.has_loop = true;
+        s.instructions.loop_sites = (0..20)
+            .rev()
+            .map(|i| InstructionSite {
+                function_index: i / 10,
+                offset: 100 + u64::from(i),
+            })
+            .collect();
+        s.instructions
+            .site_function_names
+            .insert(1, "hot_path".to_string());
+
+        let rules = run(&s, &artifact(10), &cfg());
+        let loop01 = rules.iter().find(|r| r.rule_id == RuleId::RLoop01).unwrap();
+
+        assert_eq!(loop01.findings.len(), 20);
+        assert!(
+            loop01
+                .findings
+                .is_sorted_by_key(|f| (f.location.function_index, f.location.byte_offset))
+        );
+        assert_eq!(
+            loop01.findings[10].location.function_name.as_deref(),
+            Some("hot_path")
+        );
+        // Rules without sites have none.
+        assert!(
+            rules
+                .iter()
+                .filter(|r| r.rule_id != RuleId::RLoop01)
+                .all(|r| r.findings.is_empty())
+        );
+    }
+
+    #[test]
+    fn triggers_analysis_incomplete() {
+        let s = base_signals();
+        let (art, cfg, rules) = (artifact(10), cfg(), catalog());
+        let eval = |analysis: &AnalysisInfo| {
+            evaluate_rules(&EvaluationContext {
+                signals: &s,
+                artifact: &art,
+                config: &cfg,
+                analysis,
+                rules: &rules,
+                facts: None,
+            })
+            .into_iter()
+            .find(|r| r.rule_id == RuleId::RAnalysis01)
+        };
+
+        assert!(eval(&AnalysisInfo::ok()).is_none());
+        assert!(eval(&AnalysisInfo::default()).is_none());
+
+        let rule = eval(&AnalysisInfo::parse_error("unexpected end")).unwrap();
+        assert_eq!(rule.severity, Severity::Med);
+        assert_eq!(rule.evidence.to_value()["analysis.status"], "parse_error");
+        assert_eq!(
+            rule.evidence.to_value()["analysis.warnings"],
+            json!(["unexpected end"])
+        );
+
+        let mut unsupported = AnalysisInfo::unsupported("component");
+        unsupported
+            .warnings
+            .extend((0..10).map(|i| format!("warning {i}")));
+        let rule = eval(&unsupported).unwrap();
+        let RuleEvidence::Analysis(evidence) = &rule.evidence else {
+            panic!("unexpected evidence: {:?}", rule.evidence);
+        };
+        assert_eq!(evidence.status, "unsupported");
+        assert_eq!(evidence.warnings.len(), MAX_ANALYSIS_WARNING_EVIDENCE);
+        assert_eq!(evidence.warnings[0], "component");
+    }
+
+    #[test]
+    fn allocator_only_growth_downgrades_mem02() {
+        use crate::wasm::scan::FunctionFacts;
+
+        let mut s = base_signals();
+        s.instructions.has_memory_grow = true;
+        s.instructions.memory_grow_count = 2;
+        let grows = |index, memory_grow_count| FunctionFacts {
+            index,
+            memory_grow_count,
+            ..Default::default()
+        };
+        let mut facts = RawWasmFacts::default();
+        facts.instructions.functions = vec![grows(1, 0), grows(2, 1), grows(3, 1)];
+        facts.sections.function_names = [(1, "main"), (2, "__rust_alloc"), (3, "dlmalloc_grow")]
+            .into_iter()
+            .map(|(i, n)| (i, n.to_string()))
+            .collect();
+
+        let (art, rules, analysis) = (artifact(10), catalog(), AnalysisInfo::ok());
+        let mem02 = |cfg: &ParseConfig, facts: &RawWasmFacts| {
+            evaluate_rules(&EvaluationContext {
+                signals: &s,
+                artifact: &art,
+                config: cfg,
+                analysis: &analysis,
+                rules: &rules,
+                facts: Some(facts),
+            })
+            .into_iter()
+            .find(|r| r.rule_id == RuleId::RMem02)
+            .unwrap()
+        };
+        let enabled = ParseConfig {
+            allocator_patterns: Some(
+                DEFAULT_ALLOCATOR_PATTERNS
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect(),
+            ),
+            ..cfg()
+        };
+
+        // Off by default.
+        let rule = mem02(&cfg(), &facts);
+        assert_eq!(rule.severity, Severity::High);
+        assert!(rule.evidence.to_value().get("allocator_only").is_none());
+
+        let rule = mem02(&enabled, &facts);
+        assert_eq!(rule.severity, Severity::Med);
+        assert_eq!(rule.evidence.to_value()["allocator_only"], true);
+        assert_eq!(rule.evidence.to_value()["severity_downgraded_from"], "HIGH");
+        assert_eq!(
+            rule.evidence.to_value()["allocator_functions"],
+            json!([
+                { "function_index": 2, "function_name": "__rust_alloc", "pattern": "__rust_alloc*", "memory_grow_count": 1 },
+                { "function_index": 3, "function_name": "dlmalloc_grow", "pattern": "*dlmalloc*", "memory_grow_count": 1 },
+            ])
+        );
+
+        // One grow outside the allocator keeps the rule as is.
+        let mut mixed = facts.clone();
+        mixed.instructions.functions[0].memory_grow_count = 1;
+        assert_eq!(mem02(&enabled, &mixed).severity, Severity::High);
+
+        // So does a grow site without a name-section name.
+        let mut stripped = facts.clone();
+        stripped.sections.function_names.clear();
+        let rule = mem02(&enabled, &stripped);
+        assert_eq!(rule.severity, Severity::High);
+        assert!(
+            rule.evidence
+                .to_value()
+                .get("allocator_functions")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn evaluate_reports_checked_rules_that_passed() {
+        let mut s = base_signals();
+        s.instructions.has_loop = true;
+        let (art, cfg, rules) = (artifact(10), cfg(), catalog());
+        let analysis = AnalysisInfo::ok();
+        let evaluation = evaluate(&EvaluationContext {
+            signals: &s,
+            artifact: &art,
+            config: &cfg,
+            analysis: &analysis,
+            rules: &rules,
+            facts: None,
+        });
+
+        let triggered: Vec<RuleId> = evaluation
+            .triggered
+            .iter()
+            .map(|r| r.rule_id.clone())
+            .collect();
+        let passed: Vec<RuleId> = evaluation
+            .passed
+            .iter()
+            .map(|r| r.rule_id.clone())
+            .collect();
+        assert_eq!(triggered, [RuleId::RLoop01]);
+        // Everything else except R-IMPORT-01, which has no allowlist here.
+        let expected: Vec<RuleId> = RuleId::BUILTIN
+            .into_iter()
+            .filter(|id| *id != RuleId::RLoop01 && *id != RuleId::RImport01)
+            .collect();
+        assert_eq!(passed, expected);
+        // R-IMPORT-01 is skipped without an allowlist.
+        assert_eq!(evaluation.evaluated, rules.len() - 1);
+
+        let loop02 =
+            &evaluation.passed[passed.iter().position(|id| *id == RuleId::RLoop02).unwrap()];
+        assert_eq!(loop02.severity, Severity::Med);
+        assert_eq!(
+            loop02.evidence,
+            json!({ "signals.functions.max_loop_depth": 0, "LOOP_DEPTH_THRESHOLD": 3 })
+        );
+        assert_eq!(
+            evaluation.passed[0].evidence,
+            json!({ "signals.memory.has_max": true })
+        );
+    }
+
+    #[test]
+    fn triggered_evidence_carries_rule_params() {
+        let mut s = base_signals();
+        s.memory.max_pages = Some(65_536);
+        s.functions.max_loop_depth = 10;
+        s.instructions.call_indirect_count = 1_000;
+        s.module.function_count = 100_000;
+        s.data.total_bytes = u64::MAX;
+
+        let mut rules = catalog();
+        set_param(&mut rules, "LOOP_DEPTH_THRESHOLD", 2);
+        let triggered = run_with(&s, &artifact(u64::MAX), &cfg(), &rules);
+        let with_params: Vec<_> = rules.iter().filter(|def| !def.params.is_empty()).collect();
+        assert_eq!(
+            triggered
+                .iter()
+                .filter(|r| with_params.iter().any(|def| def.id == r.rule_id))
+                .count(),
+            with_params.len()
+        );
+
+        for rule in &triggered {
+            let def = rules.iter().find(|def| def.id == rule.rule_id).unwrap();
+            let evidence = rule.evidence.to_value();
+            for (name, value) in &def.params {
+                assert_eq!(evidence[*name], json!(value), "{:?} {name}", rule.rule_id);
+            }
+        }
+    }
+
+    #[test]
+    fn triggers_unbounded_growth_under_iteration() {
+        let mut s = base_signals();
+        s.memory.has_max = false;
+        s.instructions.has_memory_grow = true;
+        let combo = |rules: &[TriggeredRule]| {
+            rules
+                .iter()
+                .find(|r| r.rule_id == RuleId::RCombo01)
+                .cloned()
+        };
+        assert!(combo(&run(&s, &artifact(10), &cfg())).is_none());
+
+        s.instructions.has_loop = true;
+        let rules = run(&s, &artifact(10), &cfg());
+        let combo01 = combo(&rules).unwrap();
+        assert_eq!(combo01.severity, Severity::High);
+        let RuleEvidence::Composite(evidence) = &combo01.evidence else {
+            panic!("unexpected evidence: {:?}", combo01.evidence);
+        };
+        let ids: Vec<_> = evidence.constituents.iter().map(|c| &c.rule_id).collect();
+        assert_eq!(ids, [&RuleId::RMem01, &RuleId::RMem02, &RuleId::RLoop01]);
+        let mem02 = rules.iter().find(|r| r.rule_id == RuleId::RMem02).unwrap();
+        assert_eq!(evidence.constituents[1].evidence, mem02.evidence);
+
+        // Catalog order does not matter, and disabling a constituent does
+        // not disable the composite.
+        let mut reversed = catalog();
+        reversed.reverse();
+        assert_eq!(run_with(&s, &artifact(10), &cfg(), &reversed), rules);
+        let mut without_loop01 = catalog();
+        without_loop01.retain(|def| def.id != RuleId::RLoop01);
+        let rules = run_with(&s, &artifact(10), &cfg(), &without_loop01);
+        assert!(!rules.iter().any(|r| r.rule_id == RuleId::RLoop01));
+        assert_eq!(combo(&rules).unwrap().evidence, combo01.evidence);
+    }
+
+    #[test]
+    fn triggers_call_indirect() {
+        let mut s = base_signals();
+        s.instructions.has_call_indirect = true;
+
+        let rules = run(&s, &artifact(10), &cfg());
+
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RCall01));
+    }
+
+    #[test]
+    fn call_indirect_evidence_includes_table_mutation() {
+        let mut s = base_signals();
+        s.instructions.has_call_indirect = true;
+        s.instructions.has_table_mutation = true;
+
+        let rules = run(&s, &artifact(10), &cfg());
+        let call = rules.iter().find(|r| r.rule_id == RuleId::RCall01).unwrap();
+
+        assert_eq!(
+            call.evidence.to_value()["signals.instructions.has_table_mutation"],
+            json!(true)
+        );
+    }
+
+    #[test]
+    fn memory_grow_evidence_includes_containing_exports() {
+        let mut s = base_signals();
+        s.instructions.has_memory_grow = true;
+        s.instructions.by_export = Some(
+            [("memory_grow".to_string(), vec!["deploy".to_string()])]
+                .into_iter()
+                .collect(),
+        );
+
+        let rules = run(&s, &artifact(10), &cfg());
+        let mem02 = rules.iter().find(|r| r.rule_id == RuleId::RMem02).unwrap();
+
+        assert_eq!(
+            mem02.evidence.to_value()["signals.instructions.by_export.memory_grow"],
+            json!(["deploy"])
+        );
+    }
+
+    #[test]
+    fn triggers_loop() {
+        let mut s = base_signals();
+        s.instructions.has_loop = true;
+
+        let rules = run(&s, &artifact(10), &cfg());
+
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RLoop01));
+    }
+
+    /// Catalog with R-SIZE-01 thresholds small enough for test artifacts.
+    fn small_size_catalog() -> Vec<RuleDef> {
+        let mut rules = catalog();
+        set_param(&mut rules, "SIZE_THRESHOLD", 100);
+        set_param(&mut rules, "COMPRESSED_SIZE_THRESHOLD", 50);
+        rules
+    }
+
+    #[test]
+    fn triggers_size_rule() {
+        let s = base_signals();
+        let rules = run_with(&s, &artifact(1000), &cfg(), &small_size_catalog());
+
+        assert!(rules.iter().any(|r| r.rule_id == RuleId::RSize01));
+    }
+
+    #[test]
+    fn size_rule_can_use_compressed_size() {
+        let s = base_signals();
+        let compressed_cfg = ParseConfig {
+            size_rule_compressed: true,
+            ..cfg()
+        };
+        let size = |raw, compressed| ArtifactContext {
+            compressed_size_bytes: Some(compressed),
+            ..artifact(raw)
+        };
+        let rules = small_size_catalog();
+        let eval =
+            |artifact: &ArtifactContext, cfg: &ParseConfig| run_with(&s, artifact, cfg, &rules);
+        let size_rule =
+            |rules: Vec<TriggeredRule>| rules.into_iter().find(|r| r.rule_id == RuleId::RSize01);
+
+        // Large but compressible: passes on the compressed figure.
+        assert!(size_rule(eval(&size(1000, 20), &compressed_cfg)).is_none());
+
+        // Small but incompressible: fails, citing both sizes.
+        let rule = size_rule(eval(&size(90, 80), &compressed_cfg)).unwrap();
+        assert_eq!(rule.evidence.to_value()["artifact.size_bytes"], 90);
+        assert_eq!(
+            rule.evidence.to_value()["artifact.compressed_size_bytes"],
+            80
+        );
+        assert_eq!(rule.evidence.to_value()["COMPRESSED_SIZE_THRESHOLD"], 50);
+        assert_eq!(rule.evidence.to_value()["SIZE_THRESHOLD"], 100);
+
+        // Without opting in, the raw size decides and the compressed one is omitted.
+        let rule = size_rule(eval(&size(1000, 20), &cfg())).unwrap();
+        assert_eq!(rule.evidence.to_value()["SIZE_THRESHOLD"], 100);
+        assert!(
+            rule.evidence
+                .to_value()
+                .get("artifact.compressed_size_bytes")
+                .is_none()
+        );
+
+        // Opting in without a compressed size falls back to the raw size.
+        assert!(size_rule(eval(&artifact(1000), &compressed_cfg)).is_some());
+    }
+
+    #[test]
+    fn thresholds_come_from_the_catalog_entry() {
+        let mut s = base_signals();
+        s.functions.max_loop_depth = 3;
+        let loop02 = |rules: &[RuleDef]| {
+            run_with(&s, &artifact(10), &cfg(), rules)
+                .into_iter()
+                .find(|r| r.rule_id == RuleId::RLoop02)
+        };
+
+        let rule = loop02(&catalog()).unwrap();
+        assert_eq!(rule.evidence.to_value()["LOOP_DEPTH_THRESHOLD"], 3);
+
+        let mut rules = catalog();
+        set_param(&mut rules, "LOOP_DEPTH_THRESHOLD", 4);
+        assert!(loop02(&rules).is_none());
+
+        // Rules left out of the catalog are not evaluated.
+        let mut rules = catalog();
+        rules.retain(|def| def.id != RuleId::RLoop02);
+        assert!(loop02(&rules).is_none());
+    }
+
+    #[test]
+    fn deprecated_rules_still_trigger_and_classify() {
+        let mut s = base_signals();
+        s.memory.has_max = false;
+        s.instructions.has_memory_grow = true;
+
+        let active = run(&s, &artifact(10), &cfg());
+        let mut rules = catalog();
+        for def in &mut rules {
+            if def.id == RuleId::RMem02 {
+                def.deprecated = Some("R-LOOP-03");
+            }
+        }
+        let marked = run_with(&s, &artifact(10), &cfg(), &rules);
+
+        let mem02 = marked.iter().find(|r| r.rule_id == RuleId::RMem02).unwrap();
+        assert_eq!(mem02.deprecated.as_deref(), Some("R-LOOP-03"));
+        assert!(
+            marked
+                .iter()
+                .all(|r| r.rule_id == RuleId::RMem02 || r.deprecated.is_none())
+        );
+        assert_eq!(
+            crate::rules::classify::classify(&marked),
+            crate::rules::classify::classify(&active)
+        );
+    }
+
+    /// Triggers a fixed list of ids at MED.
+    #[derive(Debug)]
+    struct Fixed(Vec<RuleId>);
+
+    impl RuleEvaluator for Fixed {
+        fn evaluate(&self, ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule> {
+            self.0
+                .iter()
+                .map(|id| TriggeredRule {
+                    rule_id: id.clone(),
+                    severity: Severity::Med,
+                    confidence: Confidence::High,
+                    title: "custom".into(),
+                    message: "custom check".into(),
+                    remediation: String::new(),
+                    docs_url: String::new(),
+                    deprecated: None,
+                    evidence: json!({ "signals.module.function_count": ctx.signals.module.function_count })
+                        .into(),
+                    findings: vec![],
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn evaluators_merge_dedupe_and_sort() {
+        let mut s = base_signals();
+        s.instructions.has_loop = true;
+        let (art, cfg, rules) = (artifact(10), cfg(), catalog());
+        let analysis = AnalysisInfo::default();
+        let ctx = EvaluationContext {
+            signals: &s,
+            artifact: &art,
+            config: &cfg,
+            analysis: &analysis,
+            rules: &rules,
+            facts: None,
+        };
+        let custom = |id: &str| RuleId::Custom(id.to_string());
+        let evaluators: Vec<Arc<dyn RuleEvaluator>> = vec![
+            Arc::new(Fixed(vec![custom("Z-01"), RuleId::RMem01, custom("A-01")])),
+            Arc::new(Fixed(vec![custom("A-01")])),
+        ];
+
+        let mut triggered = evaluate_rules(&ctx);
+        let rejected = apply_evaluators(&ctx, &evaluators, &mut triggered);
+
+        assert_eq!(rejected, vec![RuleId::RMem01]);
+        let ids: Vec<&str> = triggered.iter().map(|r| r.rule_id.as_str()).collect();
+        assert_eq!(ids, ["R-LOOP-01", "A-01", "Z-01"]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn legacy_signatures_match_evaluate_rules() {
+        let mut s = base_signals();
+        s.memory.has_max = false;
+        s.instructions.has_loop = true;
+        let expected = run(&s, &artifact(10), &cfg());
+
+        assert_eq!(evaluate_rules_legacy(&s, &artifact(10), &cfg()), expected);
+        assert_eq!(
+            evaluate_rules_with_catalog(&s, &artifact(10), &cfg(), &catalog()),
+            expected
+        );
     }
 
     #[test]
     fn no_rules_triggered_when_clean() {
         let s = base_signals();
-        let rules = evaluate_rules(&s, &artifact(10), &cfg());
+        let rules = run(&s, &artifact(10), &cfg());
 
         assert!(rules.is_empty());
     }
@@ -248,8 +2222,8 @@ mod tests {
         s.memory.has_max = false;
         s.instructions.has_loop = true;
 
-        let r1 = evaluate_rules(&s, &artifact(10), &cfg());
-        let r2 = evaluate_rules(&s, &artifact(10), &cfg());
+        let r1 = run(&s, &artifact(10), &cfg());
+        let r2 = run(&s, &artifact(10), &cfg());
 
         assert_eq!(
             serde_json::to_string(&r1).unwrap(),
@@ -0,0 +1,503 @@
+//! Typed evidence attached to triggered rules.
+//!
+//! Each built-in rule has its own evidence struct whose fields are named
+//! after the schema paths they echo (`signals.memory.has_max`, ...) and
+//! after the rule's parameters (`LOOP_DEPTH_THRESHOLD`, ...). The serialized
+//! form is a flat JSON object with those keys, which is what reports carry
+//! under `rules.triggered[].evidence`.
+//!
+//! Responsibilities:
+//! - Define one evidence shape per built-in rule
+//! - Give every shape the same canonical JSON form
+//! - Carry free-form evidence for custom rules
+//!
+//! Non-responsibilities:
+//! - Deciding whether a rule triggers
+//! - Reading signals
+
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    rules::catalog::{RuleId, Severity},
+    signals::model::{
+        FeatureUseItem, GlobalExportItem, ImportModuleItem, InstructionSite, TableItem,
+    },
+    wasm::profile::RequiredExportCheck,
+};
+
+/// Evidence with a canonical JSON form.
+///
+/// The form is the struct's serialization as a `serde_json::Value`, whose
+/// object keys are sorted, so it does not depend on field order.
+pub trait Evidence: Serialize {
+    fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("evidence serializes to JSON")
+    }
+}
+
+/// Where a flagged instruction is (`locations` evidence).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub function_index: u32,
+    pub byte_offset: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_name: Option<String>,
+}
+
+/// One instance of a rule's condition: where it is, plus what is specific
+/// to that instance. Reported under `rules.triggered[].findings`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    #[serde(flatten)]
+    pub location: Location,
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub evidence: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An allocator function the R-MEM-02 allocator heuristic matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AllocatorFunction {
+    pub function_index: u32,
+    pub function_name: String,
+    pub pattern: String,
+    pub memory_grow_count: u64,
+}
+
+/// An import named in R-IMPORT-01 or R-WASI-01 evidence.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportRef {
+    pub module: String,
+    pub name: String,
+}
+
+/// A required export as the target profile declares it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportRef {
+    pub name: String,
+    pub kind: String,
+}
+
+/// R-MEM-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemNoMaxEvidence {
+    #[serde(rename = "signals.memory.has_max")]
+    pub has_max: bool,
+    #[serde(rename = "signals.memory.min_pages")]
+    pub min_pages: Option<u64>,
+}
+
+/// R-MEM-02.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemGrowEvidence {
+    #[serde(rename = "signals.instructions.has_memory_grow")]
+    pub has_memory_grow: bool,
+    #[serde(rename = "signals.instructions.memory_grow_count")]
+    pub memory_grow_count: u64,
+    #[serde(rename = "signals.instructions.memory_grow_const_count")]
+    pub memory_grow_const_count: u64,
+    #[serde(rename = "signals.instructions.memory_grow_dynamic_count")]
+    pub memory_grow_dynamic_count: u64,
+    #[serde(rename = "signals.memory.estimated_max_pages_static")]
+    pub estimated_max_pages_static: Option<u64>,
+    #[serde(rename = "signals.memory.growth_open_ended")]
+    pub growth_open_ended: bool,
+    /// Only when a maximum is declared.
+    #[serde(
+        rename = "signals.memory.estimate_within_max",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub estimate_within_max: Option<bool>,
+    /// Only when `memory.size` is also present.
+    #[serde(
+        rename = "signals.instructions.memory_size_count",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub memory_size_count: Option<u64>,
+    #[serde(
+        rename = "signals.instructions.by_export.memory_grow",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub by_export: Option<Vec<String>>,
+    pub locations: Vec<Location>,
+    /// Set by the allocator heuristic, together with the two fields below.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocator_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocator_functions: Option<Vec<AllocatorFunction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity_downgraded_from: Option<Severity>,
+}
+
+/// R-CALL-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CallIndirectEvidence {
+    #[serde(rename = "signals.instructions.has_call_indirect")]
+    pub has_call_indirect: bool,
+    #[serde(rename = "signals.instructions.call_indirect_count")]
+    pub call_indirect_count: u64,
+    #[serde(rename = "signals.instructions.call_indirect_distinct_types")]
+    pub call_indirect_distinct_types: Vec<u32>,
+    #[serde(rename = "signals.instructions.call_indirect_tables")]
+    pub call_indirect_tables: Vec<u32>,
+    #[serde(rename = "signals.instructions.has_table_mutation")]
+    pub has_table_mutation: bool,
+    #[serde(
+        rename = "signals.instructions.by_export.call_indirect",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub by_export: Option<Vec<String>>,
+    pub locations: Vec<Location>,
+}
+
+/// R-LOOP-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoopEvidence {
+    #[serde(rename = "signals.instructions.has_loop")]
+    pub has_loop: bool,
+    #[serde(rename = "signals.instructions.loop_count")]
+    pub loop_count: u64,
+    #[serde(
+        rename = "signals.instructions.by_export.loop",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub by_export: Option<Vec<String>>,
+    pub locations: Vec<Location>,
+}
+
+/// R-SIZE-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SizeEvidence {
+    #[serde(rename = "artifact.size_bytes")]
+    pub size_bytes: u64,
+    #[serde(
+        rename = "artifact.compressed_size_bytes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub compressed_size_bytes: Option<u64>,
+    #[serde(rename = "SIZE_THRESHOLD")]
+    pub size_threshold: u64,
+    #[serde(rename = "COMPRESSED_SIZE_THRESHOLD")]
+    pub compressed_size_threshold: u64,
+}
+
+/// R-TABLE-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HostTableEvidence {
+    #[serde(rename = "signals.tables.has_host_visible_funcref_table")]
+    pub has_host_visible_funcref_table: bool,
+    /// Host-visible tables only.
+    #[serde(rename = "signals.tables.tables")]
+    pub tables: Vec<TableItem>,
+    #[serde(rename = "signals.instructions.has_table_mutation")]
+    pub has_table_mutation: bool,
+}
+
+/// R-IMPORT-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportEvidence {
+    #[serde(rename = "IMPORT_ALLOWLIST")]
+    pub import_allowlist: Vec<String>,
+    /// Modules outside the allowlist.
+    #[serde(rename = "signals.imports_exports.modules")]
+    pub modules: Vec<ImportModuleItem>,
+    /// Offending imports, up to `MAX_IMPORT_EVIDENCE`.
+    #[serde(rename = "signals.imports_exports.imports")]
+    pub imports: Vec<ImportRef>,
+    /// Only when the import list was truncated.
+    #[serde(
+        rename = "signals.imports_exports.imports_truncated",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub imports_truncated: Option<bool>,
+}
+
+/// R-WASI-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WasiEvidence {
+    #[serde(rename = "signals.imports_exports.modules")]
+    pub modules: Vec<ImportModuleItem>,
+    #[serde(rename = "signals.imports_exports.imports")]
+    pub imports: Vec<ImportRef>,
+    #[serde(
+        rename = "signals.imports_exports.imports_truncated",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub imports_truncated: Option<bool>,
+}
+
+/// R-START-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StartEvidence {
+    #[serde(rename = "signals.module.has_start")]
+    pub has_start: bool,
+    #[serde(rename = "signals.module.start_function")]
+    pub start_function: Option<u32>,
+    #[serde(
+        rename = "signals.module.start_function_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_function_name: Option<String>,
+}
+
+/// R-GLOBAL-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MutableGlobalEvidence {
+    #[serde(rename = "signals.globals.exported_mutable_global_count")]
+    pub exported_mutable_global_count: u32,
+    #[serde(rename = "signals.globals.exported_mutable_globals")]
+    pub exported_mutable_globals: Vec<GlobalExportItem>,
+}
+
+/// R-EXPORT-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequiredExportEvidence {
+    #[serde(rename = "REQUIRED_EXPORTS")]
+    pub required_exports: Vec<ExportRef>,
+    pub unmet_required_exports: Vec<RequiredExportCheck>,
+    #[serde(rename = "signals.imports_exports.export_count")]
+    pub export_count: u32,
+}
+
+/// R-MEM-04.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemCeilingEvidence {
+    #[serde(rename = "signals.memory.max_pages")]
+    pub max_pages: u64,
+    #[serde(rename = "signals.memory.max_bytes")]
+    pub max_bytes: Option<u64>,
+    #[serde(rename = "MAX_BYTES_CEILING")]
+    pub max_bytes_ceiling: u64,
+    #[serde(rename = "MAX_PAGES_CEILING")]
+    pub max_pages_ceiling: u64,
+}
+
+/// R-LOOP-02.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoopDepthEvidence {
+    #[serde(rename = "signals.functions.max_loop_depth")]
+    pub max_loop_depth: u32,
+    #[serde(
+        rename = "signals.functions.deepest_loop_function",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deepest_loop_function: Option<u32>,
+    #[serde(rename = "LOOP_DEPTH_THRESHOLD")]
+    pub loop_depth_threshold: u64,
+}
+
+/// R-LOOP-03.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GrowInLoopEvidence {
+    #[serde(rename = "signals.instructions.memory_grow_in_loop_count")]
+    pub memory_grow_in_loop_count: u64,
+    #[serde(rename = "signals.instructions.memory_grow_in_loop_sites")]
+    pub memory_grow_in_loop_sites: Vec<InstructionSite>,
+}
+
+/// R-CALL-02.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IndirectCountEvidence {
+    #[serde(rename = "signals.instructions.call_indirect_count")]
+    pub call_indirect_count: u64,
+    #[serde(rename = "signals.instructions.call_indirect_distinct_types")]
+    pub call_indirect_distinct_types: Vec<u32>,
+    #[serde(rename = "signals.instructions.call_indirect_indices_capped")]
+    pub call_indirect_indices_capped: bool,
+    #[serde(rename = "CALL_INDIRECT_THRESHOLD")]
+    pub call_indirect_threshold: u64,
+}
+
+/// R-CALL-03.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecursionEvidence {
+    #[serde(rename = "signals.call_graph.recursive_function_count")]
+    pub recursive_function_count: u32,
+    #[serde(rename = "signals.call_graph.recursive_functions")]
+    pub recursive_functions: Vec<u32>,
+    #[serde(rename = "signals.call_graph.self_recursive_functions")]
+    pub self_recursive_functions: Vec<u32>,
+    #[serde(rename = "signals.call_graph.mutual_recursion_groups")]
+    pub mutual_recursion_groups: Vec<Vec<u32>>,
+    /// `direct`, `mutual` or `direct_and_mutual`.
+    pub recursion_kind: String,
+    #[serde(
+        rename = "signals.call_graph.recursive_function_names",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub recursive_function_names: BTreeMap<u32, String>,
+}
+
+/// R-SIZE-02.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FunctionCountEvidence {
+    #[serde(rename = "signals.module.function_count")]
+    pub function_count: u32,
+    #[serde(rename = "FUNCTION_COUNT_THRESHOLD")]
+    pub function_count_threshold: u64,
+}
+
+/// R-DATA-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DataSizeEvidence {
+    #[serde(rename = "signals.data.segment_count")]
+    pub segment_count: u32,
+    #[serde(rename = "signals.data.total_bytes")]
+    pub total_bytes: u64,
+    #[serde(rename = "signals.data.largest_segment_bytes")]
+    pub largest_segment_bytes: u64,
+    #[serde(rename = "DATA_BYTES_THRESHOLD")]
+    pub data_bytes_threshold: u64,
+}
+
+/// R-FEAT-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FeatureEvidence {
+    #[serde(rename = "ALLOWED_FEATURES")]
+    pub allowed_features: Vec<String>,
+    pub disallowed_features: Vec<FeatureUseItem>,
+}
+
+/// R-ATOMIC-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AtomicsEvidence {
+    #[serde(rename = "signals.instructions.has_atomics")]
+    pub has_atomics: bool,
+    #[serde(rename = "signals.instructions.atomic_op_count")]
+    pub atomic_op_count: u64,
+    #[serde(rename = "signals.memory.has_shared_memory")]
+    pub has_shared_memory: bool,
+}
+
+/// R-SIMD-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SimdEvidence {
+    #[serde(rename = "signals.instructions.has_simd")]
+    pub has_simd: bool,
+    #[serde(rename = "signals.instructions.simd_op_count")]
+    pub simd_op_count: u64,
+}
+
+/// R-ANALYSIS-01.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AnalysisEvidence {
+    #[serde(rename = "analysis.status")]
+    pub status: String,
+    /// The first `MAX_ANALYSIS_WARNING_EVIDENCE` warnings.
+    #[serde(rename = "analysis.warnings")]
+    pub warnings: Vec<String>,
+}
+
+/// A constituent of a composite rule: its id and its evidence as it
+/// would be reported had it triggered on its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConstituentEvidence {
+    pub rule_id: RuleId,
+    pub evidence: RuleEvidence,
+}
+
+/// Composite rules (R-COMBO-01): every constituent, in rule id order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompositeEvidence {
+    pub constituents: Vec<ConstituentEvidence>,
+}
+
+macro_rules! rule_evidence {
+    ($($variant:ident($ty:ident)),* $(,)?) => {
+        /// Evidence of a triggered rule: the typed evidence of a built-in
+        /// rule, or free-form JSON supplied by a custom rule.
+        ///
+        /// Serializes to the canonical JSON form (see [`Evidence`]).
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum RuleEvidence {
+            $($variant($ty),)*
+            /// Free-form evidence from a [`RuleEvaluator`](crate::rules::eval::RuleEvaluator).
+            Other(serde_json::Value),
+        }
+
+        $(
+            impl Evidence for $ty {}
+
+            impl From<$ty> for RuleEvidence {
+                fn from(evidence: $ty) -> Self {
+                    RuleEvidence::$variant(evidence)
+                }
+            }
+        )*
+
+        impl Evidence for RuleEvidence {
+            fn to_value(&self) -> serde_json::Value {
+                match self {
+                    $(RuleEvidence::$variant(evidence) => evidence.to_value(),)*
+                    RuleEvidence::Other(value) => value.clone(),
+                }
+            }
+        }
+    };
+}
+
+rule_evidence! {
+    MemNoMax(MemNoMaxEvidence),
+    MemGrow(MemGrowEvidence),
+    CallIndirect(CallIndirectEvidence),
+    Loop(LoopEvidence),
+    Size(SizeEvidence),
+    HostTable(HostTableEvidence),
+    Import(ImportEvidence),
+    Wasi(WasiEvidence),
+    Start(StartEvidence),
+    MutableGlobal(MutableGlobalEvidence),
+    RequiredExport(RequiredExportEvidence),
+    MemCeiling(MemCeilingEvidence),
+    LoopDepth(LoopDepthEvidence),
+    GrowInLoop(GrowInLoopEvidence),
+    IndirectCount(IndirectCountEvidence),
+    Recursion(RecursionEvidence),
+    FunctionCount(FunctionCountEvidence),
+    DataSize(DataSizeEvidence),
+    Feature(FeatureEvidence),
+    Atomics(AtomicsEvidence),
+    Simd(SimdEvidence),
+    Analysis(AnalysisEvidence),
+    Composite(CompositeEvidence),
+}
+
+impl From<serde_json::Value> for RuleEvidence {
+    fn from(value: serde_json::Value) -> Self {
+        RuleEvidence::Other(value)
+    }
+}
+
+impl Serialize for RuleEvidence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_to_flat_schema_keys() {
+        let evidence = RuleEvidence::from(LoopDepthEvidence {
+            max_loop_depth: 4,
+            deepest_loop_function: None,
+            loop_depth_threshold: 3,
+        });
+        assert_eq!(
+            serde_json::to_string(&evidence).unwrap(),
+            r#"{"LOOP_DEPTH_THRESHOLD":3,"signals.functions.max_loop_depth":4}"#
+        );
+    }
+
+    #[test]
+    fn other_passes_json_through() {
+        let value = json!({ "custom.key": [1, 2] });
+        let evidence = RuleEvidence::from(value.clone());
+        assert_eq!(evidence.to_value(), value);
+        assert_eq!(serde_json::to_value(&evidence).unwrap(), value);
+    }
+}
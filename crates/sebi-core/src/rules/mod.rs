@@ -1,3 +1,6 @@
 pub mod catalog;
 pub mod classify;
 pub mod eval;
+pub mod evidence;
+pub mod overrides;
+pub mod suppress;
@@ -0,0 +1,286 @@
+//! Catalog overrides loaded from reviewed configuration.
+//!
+//! An override file adjusts the built-in catalog per rule: disable it,
+//! change its severity or message, or change its threshold parameters.
+//! The built-in catalog stays the base; overrides are applied on top.
+//!
+//! ```yaml
+//! catalog_version: acme-2026.1
+//! ruleset: acme
+//! rules:
+//!   R-LOOP-01:
+//!     disabled: true
+//!   R-DATA-01:
+//!     severity: HIGH
+//!     params:
+//!       DATA_BYTES_THRESHOLD: 131072
+//! ```
+//!
+//! Responsibilities:
+//! - Parse YAML or JSON override files
+//! - Reject unknown rule ids, fields and parameters
+//! - Apply the adjustments to a catalog ahead of evaluation
+//!
+//! Non-responsibilities:
+//! - Defining new trigger conditions
+//! - Classifying overall risk level
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use crate::{
+    report::model::RulesCatalogInfo,
+    rules::catalog::{RuleDef, Severity, catalog},
+};
+
+/// Ruleset name reported when the override file does not set one.
+pub const DEFAULT_OVERRIDE_RULESET: &str = "custom";
+
+/// Per-rule adjustments applied on top of the built-in catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogOverrides {
+    /// Replaces `rules.catalog.catalog_version` in the report.
+    pub catalog_version: String,
+
+    /// Replaces `rules.catalog.ruleset`; defaults to
+    /// [`DEFAULT_OVERRIDE_RULESET`].
+    #[serde(default)]
+    pub ruleset: Option<String>,
+
+    /// Overrides keyed by rule id (`R-MEM-01`, ...).
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleOverride>,
+}
+
+/// Adjustments to a single catalog rule. Unset fields keep the built-in
+/// value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleOverride {
+    /// Drop the rule from evaluation entirely.
+    #[serde(default)]
+    pub disabled: bool,
+
+    /// Replacement severity (`LOW`, `MED` or `HIGH`).
+    #[serde(default)]
+    pub severity: Option<Severity>,
+
+    /// Replacement message.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// Threshold parameters, keyed by the names the rule declares in
+    /// `RuleDef::params` (`DATA_BYTES_THRESHOLD`, ...).
+    #[serde(default)]
+    pub params: BTreeMap<String, u64>,
+}
+
+impl CatalogOverrides {
+    /// Loads and validates an override file. Files ending in `.json` are
+    /// read as JSON, anything else as YAML.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read catalog overrides {}", path.display()))?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let parsed = if is_json {
+            Self::from_json(&text)
+        } else {
+            Self::from_yaml(&text)
+        };
+        parsed.with_context(|| format!("invalid catalog overrides {}", path.display()))
+    }
+
+    /// Parses and validates overrides from JSON.
+    pub fn from_json(text: &str) -> Result<Self> {
+        let overrides: Self = serde_json::from_str(text)?;
+        overrides.validate()?;
+        Ok(overrides)
+    }
+
+    /// Parses and validates overrides from YAML.
+    pub fn from_yaml(text: &str) -> Result<Self> {
+        let overrides: Self = serde_yaml::from_str(text)?;
+        overrides.validate()?;
+        Ok(overrides)
+    }
+
+    /// Rejects unknown rule ids and parameters the rule does not declare.
+    pub fn validate(&self) -> Result<()> {
+        if self.catalog_version.trim().is_empty() {
+            bail!("`catalog_version` must not be empty");
+        }
+        let rules = catalog();
+        for (id, rule) in &self.rules {
+            let Some(def) = rules.iter().find(|def| def.id.as_str() == id) else {
+                bail!("unknown rule id `{id}`");
+            };
+            for name in rule.params.keys() {
+                if def.params.contains_key(name.as_str()) {
+                    continue;
+                }
+                if def.params.is_empty() {
+                    bail!("{id} takes no parameters, got `{name}`");
+                }
+                let known: Vec<_> = def.params.keys().copied().collect();
+                bail!(
+                    "unknown parameter `{name}` for {id}; expected one of: {}",
+                    known.join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Catalog identity reported in place of the built-in one. The content
+    /// hash is filled in once the overrides are applied.
+    pub fn catalog_info(&self) -> RulesCatalogInfo {
+        RulesCatalogInfo {
+            catalog_version: self.catalog_version.clone(),
+            ruleset: self
+                .ruleset
+                .clone()
+                .unwrap_or_else(|| DEFAULT_OVERRIDE_RULESET.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Drops disabled rules from `rules` and applies severity, message and
+    /// parameter overrides to the rest.
+    pub fn apply_to_catalog(&self, rules: &mut Vec<RuleDef>) {
+        rules.retain(|def| !self.get(def).is_some_and(|o| o.disabled));
+        for def in rules.iter_mut() {
+            let Some(o) = self.get(def) else {
+                continue;
+            };
+            if let Some(severity) = &o.severity {
+                def.severity = severity.clone();
+            }
+            if let Some(message) = &o.message {
+                def.message = Cow::Owned(message.clone());
+            }
+            for (name, &value) in &o.params {
+                if let Some(slot) = def.params.get_mut(name.as_str()) {
+                    *slot = value;
+                }
+            }
+        }
+    }
+
+    fn get(&self, def: &RuleDef) -> Option<&RuleOverride> {
+        self.rules.get(def.id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::catalog::RuleId;
+
+    #[test]
+    fn yaml_and_json_parse_identically() {
+        let yaml = CatalogOverrides::from_yaml(
+            r#"
+catalog_version: acme-1
+ruleset: acme
+rules:
+  R-LOOP-01:
+    disabled: true
+  R-DATA-01:
+    severity: HIGH
+    message: too much data
+    params:
+      DATA_BYTES_THRESHOLD: 1024
+"#,
+        )
+        .unwrap();
+        let json = CatalogOverrides::from_json(
+            r#"{
+                "catalog_version": "acme-1",
+                "ruleset": "acme",
+                "rules": {
+                    "R-LOOP-01": { "disabled": true },
+                    "R-DATA-01": {
+                        "severity": "HIGH",
+                        "message": "too much data",
+                        "params": { "DATA_BYTES_THRESHOLD": 1024 }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(yaml, json);
+        assert_eq!(yaml.rules["R-DATA-01"].severity, Some(Severity::High));
+    }
+
+    #[test]
+    fn rejects_unknown_rule_ids_fields_and_params() {
+        let err = CatalogOverrides::from_yaml("catalog_version: x\nrules:\n  R-NOPE-01: {}\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown rule id `R-NOPE-01`"));
+
+        let err = CatalogOverrides::from_yaml(
+            "catalog_version: x\nrules:\n  R-MEM-01:\n    enabled: false\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown field `enabled`"));
+
+        let err = CatalogOverrides::from_yaml("catalog_version: x\nextra: 1\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `extra`"));
+
+        let err = CatalogOverrides::from_yaml(
+            "catalog_version: x\nrules:\n  R-DATA-01:\n    params:\n      LOOP_DEPTH_THRESHOLD: 2\n",
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown parameter `LOOP_DEPTH_THRESHOLD` for R-DATA-01")
+        );
+
+        let err = CatalogOverrides::from_yaml(
+            "catalog_version: x\nrules:\n  R-MEM-01:\n    params:\n      SIZE_THRESHOLD: 2\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("R-MEM-01 takes no parameters"));
+
+        assert!(CatalogOverrides::from_yaml("rules: {}\n").is_err());
+        assert!(CatalogOverrides::from_yaml("catalog_version: ' '\n").is_err());
+    }
+
+    #[test]
+    fn applies_to_catalog() {
+        let overrides = CatalogOverrides::from_yaml(
+            "catalog_version: x\nrules:\n  R-LOOP-01:\n    disabled: true\n  R-MEM-01:\n    severity: LOW\n    message: reviewed\n  R-LOOP-02:\n    params:\n      LOOP_DEPTH_THRESHOLD: 5\n",
+        )
+        .unwrap();
+        let mut rules = catalog();
+        overrides.apply_to_catalog(&mut rules);
+        let find = |id: RuleId| rules.iter().find(|def| def.id == id);
+
+        assert_eq!(rules.len(), catalog().len() - 1);
+        assert!(find(RuleId::RLoop01).is_none());
+        let mem01 = find(RuleId::RMem01).unwrap();
+        assert_eq!(mem01.severity, Severity::Low);
+        assert_eq!(mem01.message, "reviewed");
+        assert_eq!(
+            find(RuleId::RLoop02).unwrap().param("LOOP_DEPTH_THRESHOLD"),
+            5
+        );
+        let mem02 = find(RuleId::RMem02).unwrap();
+        assert_eq!(mem02.severity, Severity::High);
+    }
+
+    #[test]
+    fn catalog_info_defaults_ruleset() {
+        let overrides = CatalogOverrides::from_yaml("catalog_version: acme-1\n").unwrap();
+        let info = overrides.catalog_info();
+        assert_eq!(info.catalog_version, "acme-1");
+        assert_eq!(info.ruleset, DEFAULT_OVERRIDE_RULESET);
+    }
+}
@@ -0,0 +1,397 @@
+//! Accepted findings loaded from a reviewed suppressions file.
+//!
+//! A suppression moves a triggered rule out of the classification and into
+//! `rules.suppressed`, where it stays visible together with the reason it
+//! was accepted. Every entry needs a reason; an expiry date is optional.
+//!
+//! ```toml
+//! [[suppressions]]
+//! rule = "R-CALL-01"
+//! artifact_sha256 = "9f2c…"
+//! reason = "vtable dispatch reviewed in AUD-142"
+//! expires = "2025-12-31"
+//!
+//! [[suppressions]]
+//! rule = "R-LOOP-01"
+//! path = "target/**/*.wasm"
+//! reason = "bounded iteration, see AUD-97"
+//! ```
+//!
+//! Responsibilities:
+//! - Parse and validate suppressions files
+//! - Match triggered rules by rule id, optionally scoped to an artifact
+//!   hash and/or path glob
+//! - Ignore expired entries with a warning
+//!
+//! Non-responsibilities:
+//! - Disabling rules (see catalog overrides)
+//! - Classifying overall risk level
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use crate::{
+    rules::{catalog::RuleId, eval::TriggeredRule},
+    wasm::read::ArtifactContext,
+};
+
+/// Conventional file name for a suppressions file.
+pub const DEFAULT_SUPPRESSIONS_FILE: &str = "sebi-suppressions.toml";
+
+/// A reviewed list of accepted findings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suppressions {
+    #[serde(default)]
+    pub suppressions: Vec<Suppression>,
+}
+
+/// One accepted finding. Without `artifact_sha256` or `path` it applies to
+/// every artifact; with both, the artifact must match both.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suppression {
+    /// Rule id the entry accepts (`R-CALL-01`, or a custom id).
+    pub rule: RuleId,
+
+    /// Hex SHA-256 of the one artifact the entry applies to.
+    #[serde(default)]
+    pub artifact_sha256: Option<String>,
+
+    /// Glob over the artifact path as passed to SEBI (`target/**/*.wasm`).
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// Why the finding is accepted; echoed in the report.
+    pub reason: String,
+
+    /// Last day (`YYYY-MM-DD`, UTC) the entry applies.
+    #[serde(default)]
+    pub expires: Option<String>,
+}
+
+/// A triggered rule an entry accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuppressedRule {
+    pub rule: TriggeredRule,
+    pub reason: String,
+    pub expires: Option<String>,
+}
+
+/// Result of [`Suppressions::apply`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Applied {
+    /// Accepted rules, in canonical rule order.
+    pub suppressed: Vec<SuppressedRule>,
+    /// One line per expired entry that would otherwise have matched.
+    pub warnings: Vec<String>,
+}
+
+impl Suppressions {
+    /// Loads and validates a TOML suppressions file.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read suppressions {}", path.display()))?;
+        Self::from_toml(&text).with_context(|| format!("invalid suppressions {}", path.display()))
+    }
+
+    /// Parses and validates suppressions from TOML.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let parsed: Self = toml::from_str(text)?;
+        parsed.validate()?;
+        Ok(parsed)
+    }
+
+    /// Rejects entries without a reason and malformed hashes, globs and
+    /// dates.
+    pub fn validate(&self) -> Result<()> {
+        for (i, entry) in self.suppressions.iter().enumerate() {
+            let at = || format!("suppression {} ({})", i + 1, entry.rule);
+            if entry.reason.trim().is_empty() {
+                bail!("{}: `reason` must not be empty", at());
+            }
+            if let Some(hash) = &entry.artifact_sha256
+                && (hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()))
+            {
+                bail!("{}: `artifact_sha256` must be 64 hex digits", at());
+            }
+            if let Some(glob) = &entry.path {
+                glob::Pattern::new(glob).with_context(|| format!("{}: bad `path` glob", at()))?;
+            }
+            if let Some(date) = &entry.expires
+                && !is_iso_date(date)
+            {
+                bail!(
+                    "{}: `expires` must be a YYYY-MM-DD date, got `{date}`",
+                    at()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves triggered rules accepted by an unexpired entry out of
+    /// `triggered`. `today` is a `YYYY-MM-DD` date; an entry expiring on
+    /// `today` still applies.
+    ///
+    /// The first matching entry supplies the reason.
+    pub fn apply(
+        &self,
+        triggered: &mut Vec<TriggeredRule>,
+        artifact: &ArtifactContext,
+        today: &str,
+    ) -> Applied {
+        let mut applied = Applied::default();
+        let mut kept = Vec::with_capacity(triggered.len());
+
+        for rule in triggered.drain(..) {
+            let candidates = self
+                .suppressions
+                .iter()
+                .filter(|entry| entry.rule == rule.rule_id && entry.matches(artifact));
+            let mut accepted = None;
+            for entry in candidates {
+                match &entry.expires {
+                    Some(date) if date.as_str() < today => {
+                        applied.warnings.push(format!(
+                            "suppression for {} expired on {date}; finding reported (reason was: {})",
+                            rule.rule_id, entry.reason
+                        ));
+                    }
+                    _ => {
+                        accepted = Some(entry);
+                        break;
+                    }
+                }
+            }
+            match accepted {
+                Some(entry) => applied.suppressed.push(SuppressedRule {
+                    rule,
+                    reason: entry.reason.clone(),
+                    expires: entry.expires.clone(),
+                }),
+                None => kept.push(rule),
+            }
+        }
+
+        *triggered = kept;
+        applied
+    }
+}
+
+impl Suppression {
+    fn matches(&self, artifact: &ArtifactContext) -> bool {
+        if let Some(hash) = &self.artifact_sha256
+            && !hash.eq_ignore_ascii_case(&artifact.hash_hex)
+        {
+            return false;
+        }
+        if let Some(glob) = &self.path {
+            let Some(path) = &artifact.path else {
+                return false;
+            };
+            // Validated on load.
+            let Ok(pattern) = glob::Pattern::new(glob) else {
+                return false;
+            };
+            if !pattern.matches(path) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+pub fn today_utc() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let (y, m, d) = civil_from_days((secs / 86_400) as i64);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian date (Howard Hinnant's
+/// `civil_from_days`).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+fn is_iso_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() != 10 || b[4] != b'-' || b[7] != b'-' {
+        return false;
+    }
+    let digits = |r: std::ops::Range<usize>| {
+        b[r.clone()]
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then(|| s[r].parse::<u32>().unwrap())
+    };
+    matches!(
+        (digits(0..4), digits(5..7), digits(8..10)),
+        (Some(_), Some(1..=12), Some(1..=31))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::model::Confidence;
+    use crate::rules::catalog::Severity;
+    use serde_json::json;
+
+    const HASH: &str = "9f2c000000000000000000000000000000000000000000000000000000000001";
+
+    fn artifact(path: &str) -> ArtifactContext {
+        ArtifactContext {
+            path: Some(path.to_string()),
+            bytes: vec![],
+            size_bytes: 10,
+            hash_alg: "sha256".into(),
+            hash_hex: HASH.into(),
+            compressed_size_bytes: None,
+        }
+    }
+
+    fn tr(id: RuleId) -> TriggeredRule {
+        TriggeredRule {
+            rule_id: id,
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "t".into(),
+            message: "m".into(),
+            remediation: String::new(),
+            docs_url: String::new(),
+            deprecated: None,
+            evidence: json!({}).into(),
+            findings: vec![],
+        }
+    }
+
+    fn ids(rules: &[TriggeredRule]) -> Vec<&str> {
+        rules.iter().map(|r| r.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn matches_rule_hash_and_path_scopes() {
+        let s = Suppressions::from_toml(&format!(
+            r#"
+[[suppressions]]
+rule = "R-LOOP-01"
+reason = "bounded loops, AUD-97"
+
+[[suppressions]]
+rule = "R-CALL-01"
+artifact_sha256 = "{HASH}"
+reason = "vtable dispatch reviewed in AUD-142"
+expires = "2025-12-31"
+
+[[suppressions]]
+rule = "R-MEM-02"
+path = "build/**/*.wasm"
+reason = "allocator reviewed"
+"#
+        ))
+        .unwrap();
+
+        let mut triggered = vec![tr(RuleId::RMem02), tr(RuleId::RCall01), tr(RuleId::RLoop01)];
+        let applied = s.apply(
+            &mut triggered,
+            &artifact("build/release/a.wasm"),
+            "2025-12-31",
+        );
+        assert!(triggered.is_empty());
+        assert!(applied.warnings.is_empty());
+        let reasons: Vec<&str> = applied
+            .suppressed
+            .iter()
+            .map(|r| r.reason.as_str())
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                "allocator reviewed",
+                "vtable dispatch reviewed in AUD-142",
+                "bounded loops, AUD-97"
+            ]
+        );
+
+        // Another artifact only matches the unscoped entry.
+        let mut other = artifact("dist/a.wasm");
+        other.hash_hex = "00".repeat(32);
+        let mut triggered = vec![tr(RuleId::RMem02), tr(RuleId::RCall01), tr(RuleId::RLoop01)];
+        let applied = s.apply(&mut triggered, &other, "2025-01-01");
+        assert_eq!(ids(&triggered), ["R-MEM-02", "R-CALL-01"]);
+        assert_eq!(applied.suppressed.len(), 1);
+    }
+
+    #[test]
+    fn expired_entries_are_ignored_with_warning() {
+        let s = Suppressions::from_toml(
+            "[[suppressions]]\nrule = \"R-CALL-01\"\nreason = \"AUD-142\"\nexpires = \"2025-12-31\"\n",
+        )
+        .unwrap();
+        let mut triggered = vec![tr(RuleId::RCall01)];
+        let applied = s.apply(&mut triggered, &artifact("a.wasm"), "2026-01-01");
+
+        assert_eq!(ids(&triggered), ["R-CALL-01"]);
+        assert!(applied.suppressed.is_empty());
+        assert_eq!(
+            applied.warnings,
+            [
+                "suppression for R-CALL-01 expired on 2025-12-31; finding reported (reason was: AUD-142)"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        let bad = [
+            (
+                "rule = \"R-CALL-01\"\nreason = \" \"",
+                "`reason` must not be empty",
+            ),
+            ("rule = \"R-CALL-01\"", "missing field `reason`"),
+            (
+                "rule = \"R-CALL-01\"\nreason = \"r\"\nartifact_sha256 = \"abc\"",
+                "64 hex digits",
+            ),
+            (
+                "rule = \"R-CALL-01\"\nreason = \"r\"\npath = \"[\"",
+                "bad `path` glob",
+            ),
+            (
+                "rule = \"R-CALL-01\"\nreason = \"r\"\nexpires = \"2025-13-01\"",
+                "YYYY-MM-DD",
+            ),
+            (
+                "rule = \"R-CALL-01\"\nreason = \"r\"\nscope = \"x\"",
+                "unknown field `scope`",
+            ),
+        ];
+        for (entry, expected) in bad {
+            let err = Suppressions::from_toml(&format!("[[suppressions]]\n{entry}\n")).unwrap_err();
+            assert!(format!("{err:#}").contains(expected), "{entry}: {err:#}");
+        }
+    }
+
+    #[test]
+    fn civil_dates_from_epoch_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(20_453), (2025, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert!(is_iso_date(&today_utc()));
+    }
+}
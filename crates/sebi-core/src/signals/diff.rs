@@ -0,0 +1,217 @@
+//! Field-level comparison of two `Signals` snapshots.
+//!
+//! Independent of rules and classification: library consumers can track
+//! signal drift across contract versions with `SignalsDiff::between` alone.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::signals::model::{ImportItem, Signals};
+
+/// Differences between two `Signals`, deterministically ordered.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct SignalsDiff {
+    /// Changed scalar fields, sorted by path.
+    pub changes: Vec<FieldChange>,
+    /// Imports only in the new signals, sorted by (module, name, kind).
+    pub added_imports: Vec<ImportKey>,
+    /// Imports only in the old signals, sorted by (module, name, kind).
+    pub removed_imports: Vec<ImportKey>,
+    /// Either import list was truncated, so added/removed entries may be
+    /// incomplete; `import_count` changes remain exact.
+    pub imports_truncated: bool,
+}
+
+/// One changed field, addressed by its path under `signals`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Identity of an import entry.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImportKey {
+    pub module: String,
+    pub name: String,
+    pub kind: String,
+}
+
+impl SignalsDiff {
+    /// Compares memory bounds, instruction counters, import/export counts
+    /// and import entries of `old` and `new`.
+    pub fn between(old: &Signals, new: &Signals) -> Self {
+        let mut changes = Vec::new();
+        diff_scalars(
+            "memory",
+            &to_value(&old.memory),
+            &to_value(&new.memory),
+            &mut changes,
+        );
+        diff_scalars(
+            "instructions",
+            &to_value(&old.instructions),
+            &to_value(&new.instructions),
+            &mut changes,
+        );
+
+        let (o, n) = (&old.imports_exports, &new.imports_exports);
+        for (field, old_count, new_count) in [
+            (
+                "imports_exports.import_count",
+                o.import_count,
+                n.import_count,
+            ),
+            (
+                "imports_exports.export_count",
+                o.export_count,
+                n.export_count,
+            ),
+            (
+                "imports_exports.unused_import_count",
+                o.unused_import_count,
+                n.unused_import_count,
+            ),
+        ] {
+            if old_count != new_count {
+                changes.push(FieldChange {
+                    field: field.to_string(),
+                    old: old_count.into(),
+                    new: new_count.into(),
+                });
+            }
+        }
+        changes.sort_by(|a, b| a.field.cmp(&b.field));
+
+        let old_imports = import_keys(o.imports.as_deref());
+        let new_imports = import_keys(n.imports.as_deref());
+
+        Self {
+            changes,
+            added_imports: new_imports.difference(&old_imports).cloned().collect(),
+            removed_imports: old_imports.difference(&new_imports).cloned().collect(),
+            imports_truncated: o.imports_truncated || n.imports_truncated,
+        }
+    }
+
+    /// True if no compared field or import entry changed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.added_imports.is_empty() && self.removed_imports.is_empty()
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).expect("signals serialize to JSON")
+}
+
+/// Records changed scalar (non-array, non-object) fields of two objects.
+/// Lists such as sites and rankings are not compared.
+fn diff_scalars(prefix: &str, old: &Value, new: &Value, out: &mut Vec<FieldChange>) {
+    let (Value::Object(old), Value::Object(new)) = (old, new) else {
+        return;
+    };
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        let old_value = old.get(key).unwrap_or(&Value::Null);
+        let new_value = new.get(key).unwrap_or(&Value::Null);
+        let scalar = |v: &Value| !v.is_array() && !v.is_object();
+        if scalar(old_value) && scalar(new_value) && old_value != new_value {
+            out.push(FieldChange {
+                field: format!("{prefix}.{key}"),
+                old: old_value.clone(),
+                new: new_value.clone(),
+            });
+        }
+    }
+}
+
+fn import_keys(imports: Option<&[ImportItem]>) -> BTreeSet<ImportKey> {
+    imports
+        .unwrap_or_default()
+        .iter()
+        .map(|i| ImportKey {
+            module: i.module.clone(),
+            name: i.name.clone(),
+            kind: i.kind.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(module: &str, name: &str) -> ImportItem {
+        ImportItem {
+            module: module.into(),
+            name: name.into(),
+            kind: "func".into(),
+            referenced: None,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn identical_signals_have_empty_diff() {
+        let signals = Signals::default();
+        assert!(SignalsDiff::between(&signals, &signals).is_empty());
+    }
+
+    #[test]
+    fn reports_scalar_and_count_changes_sorted() {
+        let old = Signals::default();
+        let mut new = Signals::default();
+        new.memory.max_pages = Some(16);
+        new.instructions.loop_count = 3;
+        new.instructions.has_loop = true;
+        new.imports_exports.export_count = 2;
+
+        let diff = SignalsDiff::between(&old, &new);
+        let fields: Vec<_> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "imports_exports.export_count",
+                "instructions.has_loop",
+                "instructions.loop_count",
+                "memory.max_pages",
+            ]
+        );
+        assert_eq!(diff.changes[3].old, Value::Null);
+        assert_eq!(diff.changes[3].new, Value::from(16));
+    }
+
+    #[test]
+    fn import_entries_compared_as_sets() {
+        let mut old = Signals::default();
+        old.imports_exports.imports = Some(vec![
+            import("vm_hooks", "write_result"),
+            import("vm_hooks", "read_args"),
+        ]);
+        let mut new = Signals::default();
+        new.imports_exports.imports = Some(vec![
+            import("vm_hooks", "read_args"),
+            import("vm_hooks", "emit_log"),
+        ]);
+
+        let diff = SignalsDiff::between(&old, &new);
+        assert_eq!(diff.added_imports.len(), 1);
+        assert_eq!(diff.added_imports[0].name, "emit_log");
+        assert_eq!(diff.removed_imports[0].name, "write_result");
+        assert!(!diff.imports_truncated);
+
+        // Reordering alone is not a change.
+        let mut reordered = old.clone();
+        reordered
+            .imports_exports
+            .imports
+            .as_mut()
+            .unwrap()
+            .reverse();
+        assert!(SignalsDiff::between(&old, &reordered).is_empty());
+    }
+}
@@ -1,5 +1,37 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+
 use crate::signals::model::*;
-use crate::wasm::{scan::InstructionFacts, sections::SectionFacts};
+use crate::wasm::{
+    hostio::{self, Capability},
+    reachability::{ReachabilityFacts, STYLUS_ENTRYPOINT},
+    scan::{self, FunctionFacts, InstructionFacts, MAX_RANKED_FUNCTIONS},
+    sections::SectionFacts,
+    stylus::VM_HOOKS_MODULE,
+};
+
+/// Number of functions listed in `functions.largest_functions`.
+pub const LARGEST_FUNCTIONS_LIMIT: usize = 5;
+
+/// Default maximum entries per import/export list.
+pub const DEFAULT_LIST_LIMIT: usize = 1_000;
+
+/// Knobs for `extract_signals_with_options`.
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    /// Maximum entries emitted per import/export list. Longer lists keep
+    /// their first entries and set `imports_truncated`/`exports_truncated`;
+    /// counts stay exact.
+    pub list_limit: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            list_limit: DEFAULT_LIST_LIMIT,
+        }
+    }
+}
 
 /// Transforms low-level parsing facts into a stable `Signals` schema.
 ///
@@ -7,31 +39,76 @@ use crate::wasm::{scan::InstructionFacts, sections::SectionFacts};
 /// representation. This function contains no policy or analysis logic,
 /// ensuring a strict boundary between extraction and interpretation.
 pub fn extract_signals(sections: &SectionFacts, instr: &InstructionFacts) -> Signals {
+    extract_signals_with_options(sections, instr, &ExtractOptions::default())
+}
+
+/// Like [`extract_signals`], with explicit options.
+pub fn extract_signals_with_options(
+    sections: &SectionFacts,
+    instr: &InstructionFacts,
+    opts: &ExtractOptions,
+) -> Signals {
+    let exported_functions = exported_function_count(sections);
+
     Signals {
         module: ModuleSignals {
             function_count: sections.function_count,
             section_count: None, // Reserved for future section tracking.
+            tag_count: sections.tag_count,
+            exported_function_count: exported_functions,
+            internal_function_count: sections.function_count.saturating_sub(exported_functions),
+            has_start: sections.start_function.is_some(),
+            start_function: sections.start_function,
+            start_function_name: sections
+                .start_function
+                .and_then(|index| function_name(sections, index)),
         },
 
-        memory: MemorySignals {
-            memory_count: sections.memory_count,
-            min_pages: sections.memory_min_pages,
-            max_pages: sections.memory_max_pages,
-            has_max: sections.memory_has_max,
+        functions: function_signals(sections, instr),
+
+        sizes: SizeSignals {
+            code_bytes: sections.section_bytes.code,
+            data_bytes: sections.section_bytes.data,
+            custom_bytes: sections.section_bytes.custom,
+            other_bytes: sections.section_bytes.other,
+        },
+
+        memory: memory_signals(sections, instr),
+
+        tables: table_signals(sections),
+
+        globals: global_signals(sections),
+
+        features: feature_signals(sections, instr),
+
+        data: DataSignals {
+            segment_count: sections.data.segment_count,
+            active_segment_count: sections.data.active_segment_count,
+            passive_segment_count: sections.data.passive_segment_count,
+            total_bytes: sections.data.total_bytes,
+            largest_segment_bytes: sections.data.largest_segment_bytes,
         },
 
         imports_exports: ImportExportSignals {
             import_count: sections.import_count,
             export_count: sections.export_count,
+            unused_import_count: sections
+                .imports
+                .iter()
+                .filter(|i| i.referenced == Some(false))
+                .count() as u32,
             // Assumes lists are deterministically sorted at the SectionFacts layer.
             imports: Some(
                 sections
                     .imports
                     .iter()
+                    .take(opts.list_limit)
                     .map(|i| ImportItem {
                         module: i.module.clone(),
                         name: i.name.clone(),
                         kind: i.kind.clone(),
+                        referenced: i.referenced,
+                        signature: i.signature.clone(),
                     })
                     .collect(),
             ),
@@ -39,25 +116,467 @@ pub fn extract_signals(sections: &SectionFacts, instr: &InstructionFacts) -> Sig
                 sections
                     .exports
                     .iter()
+                    .take(opts.list_limit)
                     .map(|e| ExportItem {
                         name: e.name.clone(),
                         kind: e.kind.clone(),
+                        index: Some(e.index),
+                        defined_function_index: e.defined_function_index,
+                        signature: e.signature.clone(),
                     })
                     .collect(),
             ),
+            imports_truncated: sections.imports.len() > opts.list_limit,
+            exports_truncated: sections.exports.len() > opts.list_limit,
+            modules: sections
+                .import_modules
+                .iter()
+                .map(|(module, count)| ImportModuleItem {
+                    module: module.clone(),
+                    count: *count,
+                })
+                .collect(),
+            export_aliases: sections
+                .export_aliases()
+                .into_iter()
+                .map(|(function_index, names)| ExportAliasGroup {
+                    function_index,
+                    names,
+                })
+                .collect(),
         },
 
-        instructions: InstructionSignals {
-            has_memory_grow: instr.has_memory_grow,
-            memory_grow_count: instr.memory_grow_count,
-            has_call_indirect: instr.has_call_indirect,
-            call_indirect_count: instr.call_indirect_count,
-            has_loop: instr.has_loop,
-            loop_count: instr.loop_count,
+        hostio: hostio_signals(sections),
+
+        instructions: instruction_signals(sections, instr),
+
+        call_graph: call_graph_signals(sections, instr),
+
+        reachability: None,
+    }
+}
+
+/// Defined functions exported under any name, each counted once.
+fn exported_function_count(sections: &SectionFacts) -> u32 {
+    sections
+        .exports
+        .iter()
+        .filter(|e| e.kind == "func")
+        .filter_map(|e| e.defined_function_index)
+        .collect::<BTreeSet<_>>()
+        .len() as u32
+}
+
+/// Summarizes per-function facts without listing every function.
+fn function_signals(sections: &SectionFacts, instr: &InstructionFacts) -> FunctionSignals {
+    let functions = &instr.functions;
+    let count_where =
+        |pred: fn(&FunctionFacts) -> bool| functions.iter().filter(|f| pred(f)).count() as u32;
+    let total: u64 = functions.iter().map(|f| f.body_size).sum();
+
+    FunctionSignals {
+        max_body_size: functions.iter().map(|f| f.body_size).max().unwrap_or(0),
+        avg_body_size: total.checked_div(functions.len() as u64).unwrap_or(0),
+        max_loop_depth: functions
+            .iter()
+            .map(|f| f.max_loop_depth)
+            .max()
+            .unwrap_or(0),
+        deepest_loop_function: functions
+            .iter()
+            .filter(|f| f.max_loop_depth > 0)
+            .min_by_key(|f| (Reverse(f.max_loop_depth), f.index))
+            .map(|f| f.index),
+        functions_with_loops: count_where(|f| f.loop_count > 0),
+        functions_with_memory_grow: count_where(|f| f.memory_grow_count > 0),
+        functions_with_call_indirect: count_where(|f| f.call_indirect_count > 0),
+        largest_functions: instr
+            .rank_functions(LARGEST_FUNCTIONS_LIMIT, |f| f.body_size)
+            .into_iter()
+            .map(|(index, body_size)| FunctionSizeItem {
+                index,
+                name: function_name(sections, index),
+                body_size,
+            })
+            .collect(),
+    }
+}
+
+/// `name`-section name of a function, else its first export name.
+fn function_name(sections: &SectionFacts, index: u32) -> Option<String> {
+    sections.function_names.get(&index).cloned().or_else(|| {
+        sections
+            .exports
+            .iter()
+            .find(|e| e.kind == "func" && e.index == index)
+            .map(|e| e.name.clone())
+    })
+}
+
+/// Maps reachability facts to their public representation.
+pub fn extract_reachability(
+    sections: &SectionFacts,
+    facts: &ReachabilityFacts,
+) -> ReachabilitySignals {
+    ReachabilitySignals {
+        entrypoint_mode: if facts.stylus_entrypoint {
+            STYLUS_ENTRYPOINT.to_string()
+        } else {
+            "exports".to_string()
         },
+        entrypoints: facts.entrypoints.clone(),
+        reachable_function_count: facts.reachable_function_count,
+        dead_function_count: facts.dead_function_count,
+        dead_functions: facts.dead_functions.clone(),
+        instructions: instruction_signals(sections, &facts.instructions),
+        call_graph: call_graph_signals(sections, &facts.instructions),
+    }
+}
+
+fn instruction_signals(sections: &SectionFacts, instr: &InstructionFacts) -> InstructionSignals {
+    InstructionSignals {
+        has_memory_grow: instr.has_memory_grow,
+        memory_grow_count: instr.memory_grow_count,
+        memory_grow_const_count: instr.memory_grow_const_count,
+        memory_grow_dynamic_count: instr.memory_grow_dynamic_count,
+        memory_grow_in_loop_count: instr.memory_grow_in_loop_count,
+        has_memory_size: instr.has_memory_size,
+        memory_size_count: instr.memory_size_count,
+        has_call_indirect: instr.has_call_indirect,
+        call_indirect_count: instr.call_indirect_count,
+        call_indirect_distinct_types: instr.call_indirect_types.iter().copied().collect(),
+        call_indirect_tables: instr.call_indirect_tables.iter().copied().collect(),
+        call_indirect_indices_capped: instr.call_indirect_indices_capped,
+        has_loop: instr.has_loop,
+        loop_count: instr.loop_count,
+        suspicious_infinite_loop_count: instr.suspicious_infinite_loop_count,
+        has_exceptions: instr.has_exception_ops || sections.tag_count > 0,
+        exception_op_count: instr.exception_op_count,
+        try_count: instr.try_count,
+        throw_count: instr.throw_count,
+        has_tail_call: instr.has_tail_call,
+        return_call_count: instr.return_call_count,
+        return_call_indirect_count: instr.return_call_indirect_count,
+        call_count: instr.call_count,
+        call_import_count: instr.call_import_count,
+        call_defined_count: instr.call_defined_count,
+        distinct_callee_count: instr.distinct_callees.len() as u64,
+        distinct_callees_capped: instr.distinct_callees_capped,
+        has_float: instr.has_float,
+        float_op_count: instr.float_op_count,
+        float_const_count: instr.float_const_count,
+        has_atomics: instr.has_atomics,
+        atomic_op_count: instr.atomic_op_count,
+        has_simd: instr.has_simd,
+        simd_op_count: instr.simd_op_count,
+        has_bulk_memory: instr.has_bulk_memory,
+        memory_copy_count: instr.memory_copy_count,
+        memory_fill_count: instr.memory_fill_count,
+        memory_init_count: instr.memory_init_count,
+        data_drop_count: instr.data_drop_count,
+        table_init_count: instr.table_init_count,
+        has_table_mutation: instr.has_table_mutation,
+        table_set_count: instr.table_set_count,
+        table_grow_count: instr.table_grow_count,
+        table_copy_count: instr.table_copy_count,
+        table_fill_count: instr.table_fill_count,
+        unreachable_count: instr.unreachable_count,
+        trap_heaviest_functions: function_metrics(
+            instr.rank_functions(MAX_RANKED_FUNCTIONS, |f| f.unreachable_count),
+        ),
+        br_table_count: instr.br_table_count,
+        br_table_max_targets: instr.br_table_max_targets,
+        max_locals: instr.max_locals,
+        large_locals_function_count: instr.large_locals_function_count,
+        max_estimated_stack_depth: instr.max_estimated_stack_depth,
+        large_offset_access_count: instr.large_offset_access_count,
+        max_static_offset: instr.max_static_offset,
+        estimated_static_cost: instr.estimated_static_cost,
+        costliest_functions: function_metrics(
+            instr.rank_functions(MAX_RANKED_FUNCTIONS, |f| f.static_cost),
+        ),
+        memory_grow_sites: instruction_sites(&instr.memory_grow_sites),
+        memory_grow_in_loop_sites: instruction_sites(&instr.memory_grow_in_loop_sites),
+        call_indirect_sites: instruction_sites(&instr.call_indirect_sites),
+        loop_sites: instruction_sites(&instr.loop_sites),
+        site_function_names: [
+            &instr.memory_grow_sites,
+            &instr.memory_grow_in_loop_sites,
+            &instr.call_indirect_sites,
+            &instr.loop_sites,
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|s| Some((s.function_index, function_name(sections, s.function_index)?)))
+        .collect(),
+        entrypoint_dispatch_branches: entrypoint_dispatch_branches(sections, instr),
+        by_export: by_export(sections, instr),
+        histogram: instr.histogram.as_ref().map(|h| {
+            h.iter()
+                .map(|(name, count)| (name.to_string(), *count))
+                .collect()
+        }),
+    }
+}
+
+fn call_graph_signals(sections: &SectionFacts, instr: &InstructionFacts) -> CallGraphSignals {
+    CallGraphSignals {
+        has_recursion: instr.recursion.has_recursion(),
+        recursive_function_count: instr.recursion.recursive_function_count,
+        self_recursive_count: instr.recursion.self_recursive_count,
+        self_recursive_functions: instr.recursion.self_recursive_functions.clone(),
+        mutual_recursion_group_count: instr.recursion.mutual_group_count,
+        recursive_functions: instr.recursion.recursive_functions.clone(),
+        mutual_recursion_groups: instr.recursion.mutual_groups.clone(),
+        recursive_function_names: instr
+            .recursion
+            .recursive_functions
+            .iter()
+            .filter_map(|&index| Some((index, function_name(sections, index)?)))
+            .collect(),
+        indirect_caller_count: instr.recursion.indirect_caller_count,
+    }
+}
+
+fn instruction_sites(sites: &[scan::InstructionSite]) -> Vec<InstructionSite> {
+    sites
+        .iter()
+        .map(|s| InstructionSite {
+            function_index: s.function_index,
+            offset: s.offset,
+        })
+        .collect()
+}
+
+/// Declared memory limits plus the static growth estimate.
+fn memory_signals(sections: &SectionFacts, instr: &InstructionFacts) -> MemorySignals {
+    let estimated_max_pages_static = sections
+        .memory_min_pages
+        .map(|min| min.saturating_add(instr.memory_grow_const_pages));
+    let growth_open_ended = instr.memory_grow_dynamic_count > 0;
+    let estimate_within_max = match (estimated_max_pages_static, sections.memory_max_pages) {
+        (Some(estimate), Some(max)) => Some(!growth_open_ended && estimate <= max),
+        _ => None,
+    };
+
+    let imported_memory_count = sections.memories.iter().filter(|m| m.imported).count() as u32;
+
+    let mut bytes_saturated = false;
+    let mut to_bytes = |pages: u64| {
+        pages.checked_mul(WASM_PAGE_SIZE).unwrap_or_else(|| {
+            bytes_saturated = true;
+            u64::MAX
+        })
+    };
+    let min_bytes = sections.memory_min_pages.map(&mut to_bytes);
+    let max_bytes = sections.memory_max_pages.map(&mut to_bytes);
+
+    MemorySignals {
+        memory_count: sections.memory_count,
+        imported_memory_count,
+        defined_memory_count: sections.memory_count.saturating_sub(imported_memory_count),
+        memory_is_imported: sections.memories.first().map(|m| m.imported),
+        memory_declaration_conflict: sections.memory_declaration_conflict,
+        min_pages: sections.memory_min_pages,
+        max_pages: sections.memory_max_pages,
+        min_bytes,
+        max_bytes,
+        bytes_saturated,
+        has_max: sections.memory_has_max,
+        has_shared_memory: sections.has_shared_memory,
+        is_memory64: sections.has_memory64,
+        estimated_max_pages_static,
+        growth_open_ended,
+        estimate_within_max,
+    }
+}
+
+/// Bytes per WebAssembly page (64 KiB).
+pub const WASM_PAGE_SIZE: u64 = 65_536;
+
+/// Capability profile of the `vm_hooks` imports.
+fn hostio_signals(sections: &SectionFacts) -> HostioSignals {
+    let mut signals = HostioSignals::default();
+    let caps = &mut signals.capabilities;
+
+    for import in sections
+        .imports
+        .iter()
+        .filter(|i| i.module == VM_HOOKS_MODULE)
+    {
+        signals.hostio_count += 1;
+        let flag = match hostio::capability(&import.name) {
+            Some(Capability::EntrypointIo) => &mut caps.entrypoint_io,
+            Some(Capability::Storage) => &mut caps.storage,
+            Some(Capability::TransientStorage) => &mut caps.transient_storage,
+            Some(Capability::ExternalCalls) => &mut caps.external_calls,
+            Some(Capability::Delegatecall) => &mut caps.delegatecall,
+            Some(Capability::Create) => &mut caps.create,
+            Some(Capability::Logging) => &mut caps.logging,
+            Some(Capability::Environment) => &mut caps.environment,
+            Some(Capability::Crypto) => &mut caps.crypto,
+            Some(Capability::MemoryAccounting) => &mut caps.memory_accounting,
+            None => {
+                signals.unknown_hostios.push(import.name.clone());
+                continue;
+            }
+        };
+        *flag = true;
+    }
+
+    signals.unknown_hostios.sort();
+    signals.unknown_hostios.dedup();
+    signals
+}
+
+/// Per-table facts, with export status resolved against the export list.
+fn table_signals(sections: &SectionFacts) -> TableSignals {
+    // Exports are sorted by name, so each table's names come out sorted.
+    let mut export_names: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    for e in sections.exports.iter().filter(|e| e.kind == "table") {
+        export_names
+            .entry(e.index)
+            .or_default()
+            .push(e.name.clone());
+    }
+
+    let mut has_host_visible_funcref_table = false;
+    let tables = sections
+        .tables
+        .iter()
+        .map(|t| {
+            let names = export_names.get(&t.index).cloned().unwrap_or_default();
+            let exported = !names.is_empty();
+            has_host_visible_funcref_table |= t.is_funcref && (t.imported || exported);
+            TableItem {
+                index: t.index,
+                element_type: t.element_type.clone(),
+                min: t.min,
+                max: t.max,
+                imported: t.imported,
+                exported,
+                import_name: t.import_name.clone(),
+                export_names: names,
+            }
+        })
+        .collect();
+
+    TableSignals {
+        table_count: sections.tables.len() as u32,
+        tables,
+        has_host_visible_funcref_table,
+    }
+}
+
+fn global_signals(sections: &SectionFacts) -> GlobalSignals {
+    let exported_mutable_globals: Vec<_> = sections
+        .exported_mutable_globals()
+        .into_iter()
+        .map(|(name, g)| GlobalExportItem {
+            name: name.to_string(),
+            index: g.index,
+            val_type: g.val_type.clone(),
+        })
+        .collect();
+
+    GlobalSignals {
+        global_count: sections.globals.len() as u32,
+        mutable_global_count: sections.globals.iter().filter(|g| g.mutable).count() as u32,
+        exported_mutable_global_count: exported_mutable_globals.len() as u32,
+        exported_mutable_globals,
+    }
+}
+
+/// Merges operator- and section-level proposal observations per proposal.
+fn feature_signals(sections: &SectionFacts, instr: &InstructionFacts) -> FeatureSignals {
+    let mut used: BTreeMap<&str, FeatureUseItem> = BTreeMap::new();
+    let observed = instr
+        .proposal_op_counts
+        .iter()
+        .map(|(&name, &count)| (name, count, None))
+        .chain(
+            sections
+                .section_features()
+                .into_iter()
+                .map(|(name, section)| (name, 0, Some(section))),
+        );
+    for (name, count, section) in observed {
+        let item = used.entry(name).or_insert_with(|| FeatureUseItem {
+            name: name.to_string(),
+            instruction_count: 0,
+            sections: Vec::new(),
+        });
+        item.instruction_count += count;
+        item.sections.extend(section.map(str::to_string));
+    }
+    FeatureSignals {
+        used: used.into_values().collect(),
     }
 }
 
+/// Dispatch estimate of the function exported as `user_entrypoint`, if it
+/// is a defined function.
+fn entrypoint_dispatch_branches(sections: &SectionFacts, instr: &InstructionFacts) -> Option<u32> {
+    let entry = sections.function_index_for_export(STYLUS_ENTRYPOINT)?;
+    instr
+        .functions
+        .iter()
+        .find(|f| f.index == entry)
+        .map(|f| f.dispatch_branches)
+}
+
+/// Labels the functions containing each tracked capability.
+///
+/// Exported functions are labeled by every export name they carry; other
+/// functions by their `name`-section name, falling back to `func[N]`.
+fn by_export(
+    sections: &SectionFacts,
+    instr: &InstructionFacts,
+) -> Option<BTreeMap<String, Vec<String>>> {
+    let mut export_names: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for e in sections.exports.iter().filter(|e| e.kind == "func") {
+        export_names.entry(e.index).or_default().push(&e.name);
+    }
+
+    let labels = |f: &FunctionFacts| -> Vec<String> {
+        match export_names.get(&f.index) {
+            Some(names) => names.iter().map(|n| n.to_string()).collect(),
+            None => vec![match sections.function_names.get(&f.index) {
+                Some(name) => name.clone(),
+                None => format!("func[{}]", f.index),
+            }],
+        }
+    };
+
+    let count = |f: &FunctionFacts, capability: &str| match capability {
+        "call_indirect" => f.call_indirect_count,
+        "loop" => f.loop_count,
+        _ => f.memory_grow_count,
+    };
+
+    let mut out = BTreeMap::new();
+    for capability in ["call_indirect", "loop", "memory_grow"] {
+        let found: BTreeSet<String> = instr
+            .functions
+            .iter()
+            .filter(|f| count(f, capability) > 0)
+            .flat_map(labels)
+            .collect();
+        if !found.is_empty() {
+            out.insert(capability.to_string(), found.into_iter().collect());
+        }
+    }
+
+    (!out.is_empty()).then_some(out)
+}
+
+fn function_metrics(ranked: Vec<(u32, u64)>) -> Vec<FunctionMetric> {
+    ranked
+        .into_iter()
+        .map(|(index, value)| FunctionMetric { index, value })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,16 +597,25 @@ mod tests {
                     module: "z_mod".into(),
                     name: "a".into(),
                     kind: "func".into(),
+                    function_index: None,
+                    referenced: None,
+                    signature: None,
                 },
                 ImportFact {
                     module: "a_mod".into(),
                     name: "z".into(),
                     kind: "func".into(),
+                    function_index: None,
+                    referenced: None,
+                    signature: None,
                 },
                 ImportFact {
                     module: "a_mod".into(),
                     name: "a".into(),
                     kind: "func".into(),
+                    function_index: None,
+                    referenced: None,
+                    signature: None,
                 },
             ],
             exports: vec![
@@ -95,10 +623,16 @@ mod tests {
                 ExportFact {
                     name: "z".into(),
                     kind: "func".into(),
+                    index: 3,
+                    defined_function_index: None,
+                    signature: None,
                 },
                 ExportFact {
                     name: "a".into(),
                     kind: "func".into(),
+                    index: 4,
+                    defined_function_index: None,
+                    signature: None,
                 },
             ],
             ..Default::default()
@@ -113,6 +647,12 @@ mod tests {
             call_indirect_count: 15,
             has_loop: false,
             loop_count: 0,
+            call_count: 7,
+            call_import_count: 3,
+            call_defined_count: 4,
+            distinct_callees: [0, 2, 5].into(),
+            distinct_callees_capped: false,
+            ..Default::default()
         }
     }
 
@@ -140,6 +680,144 @@ mod tests {
         assert_eq!(signals.instructions.call_indirect_count, 15);
         assert!(!signals.instructions.has_loop);
         assert_eq!(signals.instructions.loop_count, 0);
+        assert_eq!(signals.instructions.call_count, 7);
+        assert_eq!(signals.instructions.call_import_count, 3);
+        assert_eq!(signals.instructions.call_defined_count, 4);
+        assert_eq!(signals.instructions.distinct_callee_count, 3);
+        assert!(!signals.instructions.distinct_callees_capped);
+    }
+
+    #[test]
+    fn by_export_labels_exported_named_and_anonymous_functions() {
+        let mut sections = build_sections();
+        sections.function_names.insert(5, "helper".into());
+
+        let instr = InstructionFacts {
+            functions: vec![
+                // exported as "z"
+                FunctionFacts {
+                    index: 3,
+                    memory_grow_count: 1,
+                    ..Default::default()
+                },
+                FunctionFacts {
+                    index: 5,
+                    memory_grow_count: 2,
+                    loop_count: 1,
+                    ..Default::default()
+                },
+                FunctionFacts {
+                    index: 6,
+                    call_indirect_count: 1,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let by_export = extract_signals(&sections, &instr)
+            .instructions
+            .by_export
+            .unwrap();
+
+        assert_eq!(by_export["memory_grow"], vec!["helper", "z"]);
+        assert_eq!(by_export["loop"], vec!["helper"]);
+        assert_eq!(by_export["call_indirect"], vec!["func[6]"]);
+    }
+
+    #[test]
+    fn memory_byte_sizes_derived_and_saturated() {
+        let memory = extract_signals(&build_sections(), &build_instr()).memory;
+        assert_eq!(memory.min_bytes, Some(65_536));
+        assert_eq!(memory.max_bytes, Some(256 * 65_536));
+        assert!(!memory.bytes_saturated);
+
+        // memory64 allows page counts whose byte size overflows u64.
+        let mut sections = build_sections();
+        sections.memory_max_pages = Some(1 << 48);
+        let memory = extract_signals(&sections, &build_instr()).memory;
+        assert_eq!(memory.min_bytes, Some(65_536));
+        assert_eq!(memory.max_bytes, Some(u64::MAX));
+        assert!(memory.bytes_saturated);
+    }
+
+    #[test]
+    fn hostio_capabilities_and_unknown_names() {
+        let hostio = |name: &str| ImportFact {
+            module: "vm_hooks".into(),
+            name: name.into(),
+            kind: "func".into(),
+            function_index: None,
+            referenced: None,
+            signature: None,
+        };
+        let mut sections = build_sections();
+        sections.imports.extend([
+            hostio("storage_load_bytes32"),
+            hostio("call_contract"),
+            hostio("teleport"),
+            hostio("create2"),
+        ]);
+
+        let signals = extract_signals(&sections, &build_instr()).hostio;
+
+        assert_eq!(signals.hostio_count, 4);
+        assert_eq!(
+            signals.capabilities,
+            HostCapabilities {
+                storage: true,
+                external_calls: true,
+                create: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(signals.unknown_hostios, vec!["teleport"]);
+    }
+
+    #[test]
+    fn by_export_omitted_without_tracked_capabilities() {
+        let signals = extract_signals(&build_sections(), &build_instr());
+        assert!(signals.instructions.by_export.is_none());
+    }
+
+    #[test]
+    fn function_aggregates_rank_largest_bodies_stably() {
+        let mut sections = build_sections();
+        sections.function_names.insert(4, "big".into());
+        let function = |index, body_size, loop_count, max_loop_depth| FunctionFacts {
+            index,
+            body_size,
+            loop_count,
+            max_loop_depth,
+            ..Default::default()
+        };
+        let instr = InstructionFacts {
+            functions: vec![
+                function(2, 10, 0, 0),
+                function(3, 30, 2, 2),
+                function(4, 50, 1, 1),
+                function(5, 30, 0, 0),
+                function(6, 1, 0, 0),
+                function(7, 2, 0, 0),
+                function(8, 3, 0, 0),
+            ],
+            ..Default::default()
+        };
+
+        let functions = extract_signals(&sections, &instr).functions;
+        assert_eq!(functions.max_body_size, 50);
+        assert_eq!(functions.avg_body_size, 18);
+        assert_eq!(functions.max_loop_depth, 2);
+        assert_eq!(functions.deepest_loop_function, Some(3));
+        assert_eq!(functions.functions_with_loops, 2);
+
+        let ranked: Vec<_> = functions
+            .largest_functions
+            .iter()
+            .map(|f| (f.index, f.body_size))
+            .collect();
+        assert_eq!(ranked, vec![(4, 50), (3, 30), (5, 30), (2, 10), (8, 3)]);
+        assert_eq!(functions.largest_functions[0].name.as_deref(), Some("big"));
     }
 
     #[test]
@@ -184,6 +862,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::field_reassign_with_default)]
     fn extract_signals_handles_missing_memory_bounds() {
         let mut sections = SectionFacts::default();
         sections.memory_count = 1;
@@ -219,6 +898,7 @@ mod tests {
             call_indirect_count: u64::MAX,
             has_loop: true,
             loop_count: u64::MAX,
+            ..Default::default()
         };
 
         let signals = extract_signals(&SectionFacts::default(), &instr);
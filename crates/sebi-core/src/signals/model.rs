@@ -1,42 +1,294 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Raw observations extracted from a WASM artifact.
 /// Maps to the `signals` object in the SEBI report schema.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct Signals {
     pub module: ModuleSignals,
+    pub functions: FunctionSignals,
+    pub sizes: SizeSignals,
     pub memory: MemorySignals,
+    pub tables: TableSignals,
+    pub globals: GlobalSignals,
+    pub data: DataSignals,
+    pub features: FeatureSignals,
     pub imports_exports: ImportExportSignals,
+    pub hostio: HostioSignals,
     pub instructions: InstructionSignals,
+    pub call_graph: CallGraphSignals,
+    /// Reachable-only view; present only when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachability: Option<ReachabilitySignals>,
+}
+
+impl Signals {
+    /// These signals with `instructions` and `call_graph` restricted to
+    /// reachable functions, or `None` if reachability was not computed.
+    pub fn reachable_scope(&self) -> Option<Signals> {
+        let reachable = self.reachability.as_ref()?;
+        Some(Signals {
+            instructions: reachable.instructions.clone(),
+            call_graph: reachable.call_graph.clone(),
+            reachability: None,
+            ..self.clone()
+        })
+    }
 }
 
 /// Structural facts derived from WASM sections.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct ModuleSignals {
     /// Count of defined functions; excludes imports.
     pub function_count: u32,
     pub section_count: Option<u32>,
+    /// Exception tags (imported + defined).
+    pub tag_count: u32,
+    /// Defined functions with at least one export; aliases count once and
+    /// re-exported imports are excluded.
+    pub exported_function_count: u32,
+    /// Defined functions without an export.
+    pub internal_function_count: u32,
+    /// The module declares a start function, run at instantiation.
+    pub has_start: bool,
+    /// Function index of the start function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_function: Option<u32>,
+    /// `name`-section name of the start function, else its first export name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_function_name: Option<String>,
+}
+
+/// Aggregates over defined function bodies.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FunctionSignals {
+    /// Largest encoded body size in bytes, local declarations included.
+    pub max_body_size: u64,
+    /// Mean body size in bytes, rounded down; 0 without functions.
+    pub avg_body_size: u64,
+    /// Deepest `loop` nesting in any single function.
+    pub max_loop_depth: u32,
+    /// Function with the deepest `loop` nesting, lowest index on ties;
+    /// absent without loops.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deepest_loop_function: Option<u32>,
+    pub functions_with_loops: u32,
+    pub functions_with_memory_grow: u32,
+    pub functions_with_call_indirect: u32,
+    /// Largest bodies, sorted by size descending then index ascending.
+    pub largest_functions: Vec<FunctionSizeItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunctionSizeItem {
+    /// Module-level function index.
+    pub index: u32,
+    /// `name`-section name, else the first export name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub body_size: u64,
+}
+
+/// Artifact composition by section category, in bytes.
+///
+/// Section payloads only: the module header and each section's id and size
+/// prefix are not counted, so the sum is slightly below `artifact.size_bytes`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SizeSignals {
+    pub code_bytes: u64,
+    pub data_bytes: u64,
+    /// Custom sections (`name`, `producers`, debug info, ...).
+    pub custom_bytes: u64,
+    /// All other sections.
+    pub other_bytes: u64,
 }
 
 /// Declared memory boundaries and configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct MemorySignals {
     pub memory_count: u32,
+    pub imported_memory_count: u32,
+    pub defined_memory_count: u32,
+    /// Whether memory index 0 is supplied by the host; `None` without memory.
+    pub memory_is_imported: Option<bool>,
+    /// Memories are both imported and defined (see analysis warnings).
+    pub memory_declaration_conflict: bool,
     /// Size in 64 KiB pages.
     pub min_pages: Option<u64>,
     /// Size in 64 KiB pages. `None` indicates no upper bound.
     pub max_pages: Option<u64>,
+    /// `min_pages` in bytes; derived, page fields are authoritative.
+    pub min_bytes: Option<u64>,
+    /// `max_pages` in bytes; derived, page fields are authoritative.
+    pub max_bytes: Option<u64>,
+    /// A byte size overflowed `u64` (memory64) and was saturated.
+    pub bytes_saturated: bool,
     pub has_max: bool,
+    /// Any memory (imported or defined) declared `shared` (threads proposal).
+    pub has_shared_memory: bool,
+    /// Any memory uses a 64-bit address space (memory64 proposal).
+    pub is_memory64: bool,
+    /// `min_pages` plus the literal operands of every constant `memory.grow`
+    /// in the module. A lower bound when `growth_open_ended` is set.
+    pub estimated_max_pages_static: Option<u64>,
+    /// A `memory.grow` with a computed operand makes the static estimate
+    /// unbounded.
+    pub growth_open_ended: bool,
+    /// Whether the static estimate stays within the declared maximum; `None`
+    /// without a declared maximum.
+    pub estimate_within_max: Option<bool>,
+}
+
+/// Declared tables, in table-index order (imported tables first).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct TableSignals {
+    pub table_count: u32,
+    pub tables: Vec<TableItem>,
+    /// A `funcref` table is imported or exported, so the host can change
+    /// what `call_indirect` dispatches to.
+    pub has_host_visible_funcref_table: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TableItem {
+    pub index: u32,
+    /// Element reference type, e.g. "funcref" or "externref".
+    pub element_type: String,
+    /// Size in elements.
+    pub min: u64,
+    /// Size in elements. `None` indicates no upper bound.
+    pub max: Option<u64>,
+    pub imported: bool,
+    pub exported: bool,
+    /// `module.name` of the import, for imported tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_name: Option<String>,
+    /// Names the table is exported under, sorted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub export_names: Vec<String>,
+}
+
+/// Globals, and the mutable ones exported to the host.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct GlobalSignals {
+    /// Globals declared or imported.
+    pub global_count: u32,
+    pub mutable_global_count: u32,
+    /// Exports of mutable globals; aliases of one global count separately.
+    pub exported_mutable_global_count: u32,
+    /// Exports of mutable globals, sorted by export name.
+    pub exported_mutable_globals: Vec<GlobalExportItem>,
+}
+
+/// Data segments and the bytes they initialize.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DataSignals {
+    pub segment_count: u32,
+    /// Segments copied into memory at instantiation.
+    pub active_segment_count: u32,
+    /// Segments applied only by `memory.init`.
+    pub passive_segment_count: u32,
+    /// Initialized bytes summed over all segments.
+    pub total_bytes: u64,
+    pub largest_segment_bytes: u64,
+}
+
+/// WebAssembly proposals beyond the MVP that the module uses.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FeatureSignals {
+    /// One entry per proposal, sorted by name.
+    pub used: Vec<FeatureUseItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeatureUseItem {
+    /// Proposal name in `wasmparser` spelling, e.g. "simd".
+    pub name: String,
+    /// Operators from this proposal across all function bodies.
+    pub instruction_count: u64,
+    /// Sections whose declarations require the proposal, e.g. "memory".
+    pub sections: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GlobalExportItem {
+    /// Export name.
+    pub name: String,
+    /// Index in the global index space (imports first).
+    pub index: u32,
+    /// Value type, e.g. "i32".
+    pub val_type: String,
+}
+
+/// Host capabilities granted by `vm_hooks` imports (Stylus hostios).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HostioSignals {
+    /// Imports from `vm_hooks`, known or not.
+    pub hostio_count: u32,
+    pub capabilities: HostCapabilities,
+    /// `vm_hooks` imports missing from the hostio catalog, sorted.
+    pub unknown_hostios: Vec<String>,
+}
+
+/// Whether any imported hostio grants each capability.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct HostCapabilities {
+    pub entrypoint_io: bool,
+    pub storage: bool,
+    pub transient_storage: bool,
+    pub external_calls: bool,
+    pub delegatecall: bool,
+    pub create: bool,
+    pub logging: bool,
+    pub environment: bool,
+    pub crypto: bool,
+    pub memory_accounting: bool,
 }
 
 /// Summary of external interfaces.
 /// Lists are sorted deterministically if present.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct ImportExportSignals {
     pub import_count: u32,
     pub export_count: u32,
+    /// Func imports that no code calls.
+    pub unused_import_count: u32,
     pub imports: Option<Vec<ImportItem>>,
     pub exports: Option<Vec<ExportItem>>,
+    /// `imports` holds only the first entries; `import_count` is exact.
+    pub imports_truncated: bool,
+    /// `exports` holds only the first entries; `export_count` is exact.
+    pub exports_truncated: bool,
+    /// Import count per import module, sorted by module name.
+    pub modules: Vec<ImportModuleItem>,
+    /// Functions exported under several names, sorted by function index.
+    pub export_aliases: Vec<ExportAliasGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportAliasGroup {
+    pub function_index: u32,
+    /// Export names sharing the function, sorted.
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportModuleItem {
+    pub module: String,
+    pub count: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +297,12 @@ pub struct ImportItem {
     pub name: String,
     /// External kind: e.g., "func", "memory", "table", "global", "tag".
     pub kind: String,
+    /// For func imports, whether any code calls the function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced: Option<bool>,
+    /// For func imports, the signature in canonical form, e.g. `() -> i64`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,15 +310,199 @@ pub struct ExportItem {
     pub name: String,
     /// External kind: e.g., "func", "memory", "table", "global", "tag".
     pub kind: String,
+    /// Index in the index space of `kind` (imports first).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    /// For exported defined functions, the position among defined functions
+    /// (code-section order). `None` for re-exported imports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defined_function_index: Option<u32>,
+    /// For func exports, the signature in canonical form, e.g. `(i32) -> i32`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 /// Capability indicators detected during function body scanning.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct InstructionSignals {
     pub has_memory_grow: bool,
     pub memory_grow_count: u64,
+    /// `memory.grow` fed directly by a literal constant.
+    pub memory_grow_const_count: u64,
+    /// `memory.grow` fed by a computed value.
+    pub memory_grow_dynamic_count: u64,
+    /// `memory.grow` nested inside at least one `loop`.
+    pub memory_grow_in_loop_count: u64,
+    pub has_memory_size: bool,
+    pub memory_size_count: u64,
     pub has_call_indirect: bool,
     pub call_indirect_count: u64,
+    /// Distinct type indices used by `call_indirect`, ascending.
+    pub call_indirect_distinct_types: Vec<u32>,
+    /// Distinct table indices used by `call_indirect`, ascending.
+    pub call_indirect_tables: Vec<u32>,
+    /// True if the index lists hit the tracking cap and are incomplete.
+    pub call_indirect_indices_capped: bool,
     pub has_loop: bool,
     pub loop_count: u64,
+    /// Loops with an unconditional back-edge and no observable exit (heuristic).
+    pub suspicious_infinite_loop_count: u64,
+    /// Exception-handling operators or exception tags are present.
+    pub has_exceptions: bool,
+    pub exception_op_count: u64,
+    /// `try` / `try_table` blocks.
+    pub try_count: u64,
+    /// `throw` / `throw_ref` / `rethrow`.
+    pub throw_count: u64,
+    pub has_tail_call: bool,
+    /// Direct tail calls (`return_call`).
+    pub return_call_count: u64,
+    /// Indirect tail calls (`return_call_indirect`, `return_call_ref`).
+    pub return_call_indirect_count: u64,
+    pub call_count: u64,
+    /// Direct calls targeting imported (host) functions.
+    pub call_import_count: u64,
+    /// Direct calls targeting functions defined in the module.
+    pub call_defined_count: u64,
+    /// Number of distinct direct-call targets (call fan-out).
+    pub distinct_callee_count: u64,
+    /// True if `distinct_callee_count` hit the tracking cap and is a lower bound.
+    pub distinct_callees_capped: bool,
+    pub has_float: bool,
+    /// Scalar float operators excluding constants.
+    pub float_op_count: u64,
+    /// `f32.const` / `f64.const` occurrences.
+    pub float_const_count: u64,
+    pub has_atomics: bool,
+    /// Atomic memory operators and fences.
+    pub atomic_op_count: u64,
+    pub has_simd: bool,
+    /// Vector (`v128`) operators.
+    pub simd_op_count: u64,
+    /// Any of `memory.copy`, `memory.fill`, `memory.init`, `data.drop`, `table.init`.
+    pub has_bulk_memory: bool,
+    pub memory_copy_count: u64,
+    pub memory_fill_count: u64,
+    pub memory_init_count: u64,
+    pub data_drop_count: u64,
+    pub table_init_count: u64,
+    /// Any of `table.set`, `table.grow`, `table.copy`, `table.fill`.
+    pub has_table_mutation: bool,
+    pub table_set_count: u64,
+    pub table_grow_count: u64,
+    pub table_copy_count: u64,
+    pub table_fill_count: u64,
+    /// `unreachable` occurrences (explicit traps).
+    pub unreachable_count: u64,
+    /// Functions with the most `unreachable` instructions, highest first.
+    pub trap_heaviest_functions: Vec<FunctionMetric>,
+    pub br_table_count: u64,
+    /// Largest `br_table` target count observed, excluding the default label.
+    pub br_table_max_targets: u32,
+    /// Largest declared-locals count in a single function (params excluded).
+    pub max_locals: u64,
+    /// Functions declaring more than 256 locals.
+    pub large_locals_function_count: u32,
+    /// Estimated peak operand-stack depth across all functions (heuristic).
+    pub max_estimated_stack_depth: u32,
+    /// Memory accesses whose static offset exceeds initial memory or the
+    /// configured threshold (heuristic for raw pointer arithmetic).
+    pub large_offset_access_count: u64,
+    /// Largest static load/store offset observed, in bytes.
+    pub max_static_offset: u64,
+    /// Heuristic sum of static per-operator weights; an ordering signal, not a gas estimate.
+    pub estimated_static_cost: u64,
+    /// Functions with the highest static cost, highest first.
+    pub costliest_functions: Vec<FunctionMetric>,
+    /// First `memory.grow` locations (capped, module order).
+    pub memory_grow_sites: Vec<InstructionSite>,
+    /// First `memory.grow` locations inside a `loop` (capped, module order).
+    pub memory_grow_in_loop_sites: Vec<InstructionSite>,
+    /// First `call_indirect` locations (capped, module order).
+    pub call_indirect_sites: Vec<InstructionSite>,
+    /// First `loop` locations (capped, module order).
+    pub loop_sites: Vec<InstructionSite>,
+    /// Names of the functions holding the listed `memory.grow`,
+    /// `call_indirect` and `loop` sites: `name`-section name, else the first
+    /// export name. Functions without either are omitted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub site_function_names: BTreeMap<u32, String>,
+    /// Estimated selector-dispatch branches in `user_entrypoint` (heuristic
+    /// proxy for external methods); `None` without a defined entrypoint.
+    pub entrypoint_dispatch_branches: Option<u32>,
+    /// Functions directly containing each tracked capability (`memory_grow`,
+    /// `call_indirect`, `loop`), labeled by export name, else name-section
+    /// name, else `func[N]`. Keys and labels sorted; omitted if none apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_export: Option<BTreeMap<String, Vec<String>>>,
+    /// Opt-in operator histogram keyed by operator name, sorted by key.
+    /// Omitted unless requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram: Option<BTreeMap<String, u64>>,
+}
+
+/// Location of a flagged instruction in the artifact.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstructionSite {
+    /// Module-level index of the containing function.
+    pub function_index: u32,
+    /// Module-relative byte offset of the opcode.
+    pub offset: u64,
+}
+
+/// A per-function measurement used in rankings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunctionMetric {
+    /// Module-level function index.
+    pub index: u32,
+    pub value: u64,
+}
+
+/// Recursion detected in the direct call graph.
+///
+/// Function indices are module-level (imported functions come first).
+/// Listed indices are capped; counts are exact.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct CallGraphSignals {
+    pub has_recursion: bool,
+    /// Functions participating in any call cycle.
+    pub recursive_function_count: u32,
+    /// Functions calling themselves directly.
+    pub self_recursive_count: u32,
+    /// Directly self-recursive function indices, ascending.
+    pub self_recursive_functions: Vec<u32>,
+    /// Cycles spanning more than one function.
+    pub mutual_recursion_group_count: u32,
+    /// Recursive function indices, ascending.
+    pub recursive_functions: Vec<u32>,
+    /// Members of each mutual-recursion cycle, ordered by lowest index.
+    pub mutual_recursion_groups: Vec<Vec<u32>>,
+    /// Names of the listed recursive functions: `name`-section name, else the
+    /// first export name. Functions without either are omitted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub recursive_function_names: BTreeMap<u32, String>,
+    /// Functions containing `call_indirect`, whose call edges are unknown.
+    pub indirect_caller_count: u32,
+}
+
+/// Functions reachable from the entrypoints and the signals of that subset.
+///
+/// Function indices are module-level; listed dead functions are capped.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ReachabilitySignals {
+    /// `"user_entrypoint"` (Stylus) or `"exports"` (all exported functions).
+    pub entrypoint_mode: String,
+    /// Root function indices (including the start function), ascending.
+    pub entrypoints: Vec<u32>,
+    pub reachable_function_count: u32,
+    /// Defined functions that no entrypoint can reach.
+    pub dead_function_count: u32,
+    pub dead_functions: Vec<u32>,
+    /// Instruction signals over reachable functions only.
+    pub instructions: InstructionSignals,
+    /// Call-graph signals over reachable functions only.
+    pub call_graph: CallGraphSignals,
 }
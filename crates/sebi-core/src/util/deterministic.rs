@@ -4,6 +4,7 @@
 //! identical JSON report outputs.
 
 use crate::rules::eval::TriggeredRule;
+use crate::rules::evidence::Finding;
 use crate::wasm::sections::{ExportFact, ImportFact};
 
 /// Sorts imports by `(module, name, kind)`.
@@ -21,19 +22,25 @@ pub fn sort_exports(exports: &mut [ExportFact]) {
     exports.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.kind.cmp(&b.kind)));
 }
 
-/// Sorts triggered rules by canonical rule identifier string.
-///
-/// Ordering is based on the stable external rule ID
-/// (e.g. "R-CALL-01", "R-MEM-02") rather than enum
-/// discriminant order. This preserves schema-level
-/// determinism even if enum variants are reordered.
+/// Sorts triggered rules into the canonical rule order (see `RuleId`):
+/// built-in rules in catalog declaration order, then custom ids lexically.
+/// Each rule's findings are sorted too (see [`sort_findings`]).
 pub fn sort_triggered_rules(rules: &mut [TriggeredRule]) {
-    rules.sort_by(|a, b| a.rule_id.to_string().cmp(&b.rule_id.to_string()));
+    rules.sort_by(|a, b| a.rule_id.cmp(&b.rule_id));
+    for rule in rules {
+        sort_findings(&mut rule.findings);
+    }
+}
+
+/// Sorts findings by `(function_index, byte_offset)`.
+pub fn sort_findings(findings: &mut [Finding]) {
+    findings.sort_by_key(|f| (f.location.function_index, f.location.byte_offset));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::report::model::Confidence;
     use crate::rules::catalog::{RuleId, Severity};
     use crate::rules::eval::TriggeredRule;
     use crate::wasm::sections::{ExportFact, ImportFact};
@@ -46,21 +53,33 @@ mod tests {
                 module: "env".into(),
                 name: "memory".into(),
                 kind: "memory".into(),
+                function_index: None,
+                referenced: None,
+                signature: None,
             },
             ImportFact {
                 module: "env".into(),
                 name: "abort".into(),
                 kind: "func".into(),
+                function_index: None,
+                referenced: None,
+                signature: None,
             },
             ImportFact {
                 module: "wasi".into(),
                 name: "fd_write".into(),
                 kind: "func".into(),
+                function_index: None,
+                referenced: None,
+                signature: None,
             },
             ImportFact {
                 module: "env".into(),
                 name: "abort".into(),
                 kind: "global".into(),
+                function_index: None,
+                referenced: None,
+                signature: None,
             },
         ];
 
@@ -88,14 +107,23 @@ mod tests {
             ExportFact {
                 name: "memory".into(),
                 kind: "memory".into(),
+                index: 0,
+                defined_function_index: None,
+                signature: None,
             },
             ExportFact {
                 name: "_start".into(),
                 kind: "func".into(),
+                index: 0,
+                defined_function_index: None,
+                signature: None,
             },
             ExportFact {
                 name: "_start".into(),
                 kind: "global".into(),
+                index: 0,
+                defined_function_index: None,
+                signature: None,
             },
         ];
 
@@ -122,31 +150,47 @@ mod tests {
             TriggeredRule {
                 rule_id: RuleId::RLoop01,
                 severity: Severity::Med,
+                confidence: Confidence::High,
                 title: "Loop".into(),
                 message: "loop present".into(),
-                evidence: json!({}),
+                remediation: String::new(),
+                docs_url: String::new(),
+                deprecated: None,
+                evidence: json!({}).into(),
+                findings: vec![],
             },
             TriggeredRule {
                 rule_id: RuleId::RMem02,
                 severity: Severity::High,
+                confidence: Confidence::High,
                 title: "Mem grow".into(),
                 message: "memory.grow".into(),
-                evidence: json!({}),
+                remediation: String::new(),
+                docs_url: String::new(),
+                deprecated: None,
+                evidence: json!({}).into(),
+                findings: vec![],
             },
             TriggeredRule {
                 rule_id: RuleId::RCall01,
                 severity: Severity::High,
+                confidence: Confidence::High,
                 title: "Call indirect".into(),
                 message: "call_indirect".into(),
-                evidence: json!({}),
+                remediation: String::new(),
+                docs_url: String::new(),
+                deprecated: None,
+                evidence: json!({}).into(),
+                findings: vec![],
             },
         ];
 
         sort_triggered_rules(&mut rules);
 
-        let ids: Vec<RuleId> = rules.iter().map(|r| r.rule_id).collect();
+        let ids: Vec<RuleId> = rules.iter().map(|r| r.rule_id.clone()).collect();
 
-        assert_eq!(ids, vec![RuleId::RCall01, RuleId::RLoop01, RuleId::RMem02,]);
+        // Declaration order, not lexical: R-MEM-02 sorts before R-CALL-01.
+        assert_eq!(ids, vec![RuleId::RMem02, RuleId::RCall01, RuleId::RLoop01]);
     }
 
     #[test]
@@ -156,16 +200,26 @@ mod tests {
                 TriggeredRule {
                     rule_id: RuleId::RMem02,
                     severity: Severity::High,
+                    confidence: Confidence::High,
                     title: "Mem grow".into(),
                     message: "memory.grow".into(),
-                    evidence: json!({}),
+                    remediation: String::new(),
+                    docs_url: String::new(),
+                    deprecated: None,
+                    evidence: json!({}).into(),
+                    findings: vec![],
                 },
                 TriggeredRule {
                     rule_id: RuleId::RMem01,
                     severity: Severity::Med,
+                    confidence: Confidence::High,
                     title: "Missing max".into(),
                     message: "no max".into(),
-                    evidence: json!({}),
+                    remediation: String::new(),
+                    docs_url: String::new(),
+                    deprecated: None,
+                    evidence: json!({}).into(),
+                    findings: vec![],
                 },
             ]
         };
@@ -178,4 +232,25 @@ mod tests {
 
         assert_eq!(first, second);
     }
+
+    #[test]
+    fn sort_findings_orders_by_function_then_offset() {
+        use crate::rules::evidence::Location;
+        let finding = |function_index, byte_offset| Finding {
+            location: Location {
+                function_index,
+                byte_offset,
+                function_name: None,
+            },
+            evidence: Default::default(),
+        };
+        let mut findings = vec![finding(3, 10), finding(1, 90), finding(3, 5)];
+        sort_findings(&mut findings);
+
+        let order: Vec<_> = findings
+            .iter()
+            .map(|f| (f.location.function_index, f.location.byte_offset))
+            .collect();
+        assert_eq!(order, vec![(1, 90), (3, 5), (3, 10)]);
+    }
 }
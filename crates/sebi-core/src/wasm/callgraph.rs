@@ -0,0 +1,339 @@
+//! Direct call-graph analysis for SEBI.
+//!
+//! This module builds a lightweight graph from the per-function `call`
+//! targets recorded by `wasm::scan`, detects cycles (recursion) and computes
+//! reachability from entrypoints.
+//!
+//! Key design principles:
+//! - Adjacency lists only: memory is linear in the number of distinct call edges
+//! - Only defined functions are nodes; calls into imports are leaves
+//! - `call_indirect` is an unknown edge and is never assumed to close a cycle;
+//!   for reachability it conservatively reaches every table-referenced function
+//! - Iterative traversal, so deep graphs cannot overflow the native stack
+//! - Deterministic output (ascending function indices)
+
+use std::collections::BTreeSet;
+
+use crate::wasm::scan::FunctionFacts;
+
+/// Upper bound on the number of function indices listed in recursion facts.
+///
+/// Counts remain exact; only the listed indices are truncated.
+pub const MAX_LISTED_FUNCTIONS: usize = 64;
+
+/// Recursion facts derived from the direct call graph.
+///
+/// All indices are module-level function indices.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecursionFacts {
+    /// Number of functions that participate in at least one call cycle.
+    pub recursive_function_count: u32,
+
+    /// Number of functions that call themselves directly.
+    pub self_recursive_count: u32,
+
+    /// Functions that call themselves directly, ascending (capped at
+    /// `MAX_LISTED_FUNCTIONS`).
+    pub self_recursive_functions: Vec<u32>,
+
+    /// Number of cycles spanning more than one function (mutual recursion).
+    pub mutual_group_count: u32,
+
+    /// Recursive functions, ascending (capped at `MAX_LISTED_FUNCTIONS`).
+    pub recursive_functions: Vec<u32>,
+
+    /// Mutual-recursion groups, each ascending, ordered by their lowest index
+    /// (both the groups and their members are capped at `MAX_LISTED_FUNCTIONS`).
+    pub mutual_groups: Vec<Vec<u32>>,
+
+    /// Number of functions containing `call_indirect` (unresolved edges).
+    pub indirect_caller_count: u32,
+}
+
+impl RecursionFacts {
+    pub fn has_recursion(&self) -> bool {
+        self.recursive_function_count > 0
+    }
+}
+
+const UNVISITED: u32 = u32::MAX;
+
+/// Detects direct and mutual recursion among defined functions.
+///
+/// Uses an iterative Tarjan strongly-connected-components pass. A function
+/// is recursive if it belongs to a component with more than one member or
+/// calls itself directly.
+///
+/// `functions` must be in defined-function order, as produced by the scan.
+pub fn find_recursion(functions: &[FunctionFacts]) -> RecursionFacts {
+    let mut facts = RecursionFacts {
+        indirect_caller_count: functions.iter().filter(|f| f.has_call_indirect).count() as u32,
+        ..Default::default()
+    };
+
+    let Some(base) = functions.first().map(|f| f.index) else {
+        return facts;
+    };
+    let n = functions.len();
+    let node_of = |callee: u32| -> Option<usize> {
+        let node = callee.checked_sub(base)? as usize;
+        (node < n).then_some(node)
+    };
+
+    let adjacency: Vec<Vec<usize>> = functions
+        .iter()
+        .map(|f| f.callees.iter().filter_map(|&c| node_of(c)).collect())
+        .collect();
+
+    let mut order = vec![UNVISITED; n];
+    let mut low = vec![0u32; n];
+    let mut on_stack = vec![false; n];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut frames: Vec<(usize, usize)> = Vec::new();
+    let mut next_order = 0u32;
+    let mut recursive = vec![false; n];
+    let mut groups: Vec<Vec<u32>> = Vec::new();
+
+    for root in 0..n {
+        if order[root] != UNVISITED {
+            continue;
+        }
+
+        order[root] = next_order;
+        low[root] = next_order;
+        next_order += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        frames.push((root, 0));
+
+        while let Some(&mut (v, ref mut edge)) = frames.last_mut() {
+            if let Some(&w) = adjacency[v].get(*edge) {
+                *edge += 1;
+                if order[w] == UNVISITED {
+                    order[w] = next_order;
+                    low[w] = next_order;
+                    next_order += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    frames.push((w, 0));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(order[w]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+
+            if low[v] == order[v] {
+                let mut component = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+
+                if component.len() > 1 {
+                    for &w in &component {
+                        recursive[w] = true;
+                    }
+                    let mut group: Vec<u32> =
+                        component.iter().map(|&w| functions[w].index).collect();
+                    group.sort_unstable();
+                    group.truncate(MAX_LISTED_FUNCTIONS);
+                    groups.push(group);
+                }
+            }
+        }
+    }
+
+    for (node, edges) in adjacency.iter().enumerate() {
+        if edges.contains(&node) {
+            recursive[node] = true;
+            facts.self_recursive_count += 1;
+            if facts.self_recursive_functions.len() < MAX_LISTED_FUNCTIONS {
+                facts.self_recursive_functions.push(functions[node].index);
+            }
+        }
+    }
+
+    facts.mutual_group_count = groups.len() as u32;
+    groups.sort();
+    groups.truncate(MAX_LISTED_FUNCTIONS);
+    facts.mutual_groups = groups;
+
+    for (node, is_recursive) in recursive.iter().enumerate() {
+        if *is_recursive {
+            facts.recursive_function_count += 1;
+            if facts.recursive_functions.len() < MAX_LISTED_FUNCTIONS {
+                facts.recursive_functions.push(functions[node].index);
+            }
+        }
+    }
+
+    facts
+}
+
+/// Returns the defined functions reachable from `roots`, ascending.
+///
+/// Edges are direct calls. A reachable function containing an indirect call
+/// makes every function in `table_functions` reachable, since any of them
+/// may be the target. Imported roots and callees are leaves and are not
+/// included in the result.
+///
+/// `functions` must be in defined-function order, as produced by the scan.
+pub fn reachable_from(
+    functions: &[FunctionFacts],
+    roots: impl IntoIterator<Item = u32>,
+    table_functions: &BTreeSet<u32>,
+) -> BTreeSet<u32> {
+    let Some(base) = functions.first().map(|f| f.index) else {
+        return BTreeSet::new();
+    };
+    let n = functions.len();
+    let node_of = |index: u32| -> Option<usize> {
+        let node = index.checked_sub(base)? as usize;
+        (node < n).then_some(node)
+    };
+
+    let mut reached = vec![false; n];
+    let mut worklist: Vec<usize> = Vec::new();
+    let mut visit = |index: u32, worklist: &mut Vec<usize>| {
+        if let Some(node) = node_of(index)
+            && !reached[node]
+        {
+            reached[node] = true;
+            worklist.push(node);
+        }
+    };
+
+    for root in roots {
+        visit(root, &mut worklist);
+    }
+
+    let mut table_reached = false;
+    while let Some(node) = worklist.pop() {
+        for &callee in &functions[node].callees {
+            visit(callee, &mut worklist);
+        }
+        if functions[node].has_call_indirect && !table_reached {
+            table_reached = true;
+            for &target in table_functions {
+                visit(target, &mut worklist);
+            }
+        }
+    }
+
+    reached
+        .iter()
+        .enumerate()
+        .filter(|&(_, &r)| r)
+        .map(|(node, _)| functions[node].index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn func(index: u32, callees: &[u32]) -> FunctionFacts {
+        FunctionFacts {
+            index,
+            callees: callees.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detects_direct_self_recursion() {
+        let facts = find_recursion(&[func(1, &[0, 1]), func(2, &[1])]);
+
+        assert!(facts.has_recursion());
+        assert_eq!(facts.recursive_function_count, 1);
+        assert_eq!(facts.self_recursive_count, 1);
+        assert_eq!(facts.self_recursive_functions, vec![1]);
+        assert_eq!(facts.mutual_group_count, 0);
+        assert_eq!(facts.recursive_functions, vec![1]);
+    }
+
+    #[test]
+    fn detects_mutual_recursion_group() {
+        // 0 -> 1 -> 2 -> 0, plus 3 -> 0 outside the cycle
+        let facts = find_recursion(&[func(0, &[1]), func(1, &[2]), func(2, &[0]), func(3, &[0])]);
+
+        assert_eq!(facts.recursive_function_count, 3);
+        assert_eq!(facts.self_recursive_count, 0);
+        assert!(facts.self_recursive_functions.is_empty());
+        assert_eq!(facts.mutual_group_count, 1);
+        assert_eq!(facts.mutual_groups, vec![vec![0, 1, 2]]);
+        assert_eq!(facts.recursive_functions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn acyclic_diamond_is_not_recursive() {
+        // 0 -> {1, 2} -> 3
+        let facts = find_recursion(&[func(0, &[1, 2]), func(1, &[3]), func(2, &[3]), func(3, &[])]);
+
+        assert!(!facts.has_recursion());
+        assert!(facts.recursive_functions.is_empty());
+    }
+
+    #[test]
+    fn calls_to_imports_are_leaves() {
+        // Defined functions start at index 2; index 0 and 1 are imports.
+        let facts = find_recursion(&[func(2, &[0, 1]), func(3, &[0])]);
+
+        assert!(!facts.has_recursion());
+    }
+
+    #[test]
+    fn counts_indirect_callers_without_assuming_cycles() {
+        let facts = find_recursion(&[FunctionFacts {
+            index: 0,
+            has_call_indirect: true,
+            ..Default::default()
+        }]);
+
+        assert_eq!(facts.indirect_caller_count, 1);
+        assert!(!facts.has_recursion());
+    }
+
+    #[test]
+    fn long_call_chain_does_not_overflow() {
+        let n = 50_000u32;
+        let mut functions: Vec<FunctionFacts> = (0..n).map(|i| func(i, &[i + 1])).collect();
+        functions.last_mut().unwrap().callees = vec![0];
+
+        let facts = find_recursion(&functions);
+
+        assert_eq!(facts.recursive_function_count, n);
+        assert_eq!(facts.recursive_functions.len(), MAX_LISTED_FUNCTIONS);
+        assert_eq!(facts.mutual_group_count, 1);
+    }
+
+    #[test]
+    fn reachability_follows_direct_calls_only_from_roots() {
+        // 1 (import 0 callee) -> 2 -> 3; 4 is dead and calls 2.
+        let functions = [func(1, &[0, 2]), func(2, &[3]), func(3, &[]), func(4, &[2])];
+
+        let reached = reachable_from(&functions, [1], &BTreeSet::new());
+        assert_eq!(reached.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let reached = reachable_from(&functions, [0], &BTreeSet::new());
+        assert!(reached.is_empty());
+    }
+
+    #[test]
+    fn indirect_call_reaches_all_table_functions() {
+        let mut dispatcher = func(0, &[]);
+        dispatcher.has_call_indirect = true;
+        let functions = [dispatcher, func(1, &[3]), func(2, &[]), func(3, &[])];
+
+        let reached = reachable_from(&functions, [0], &[1].into());
+        assert_eq!(reached.into_iter().collect::<Vec<_>>(), vec![0, 1, 3]);
+    }
+}
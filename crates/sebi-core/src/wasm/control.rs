@@ -0,0 +1,180 @@
+//! Structured control-flow tracking for the instruction scan.
+//!
+//! Maintains the stack of open control frames (`block`, `loop`, `if`, ...)
+//! while operators are read in order, so the scan can resolve branch depths
+//! to frames without building a control-flow graph.
+//!
+//! ## Infinite-loop heuristic
+//!
+//! A `loop` is reported as a *suspicious infinite loop* when, by its `end`:
+//! - its body contains an unconditional `br` back to the loop header placed
+//!   directly in the loop body (not inside a nested `block`/`if`), and
+//! - nothing inside the loop can leave it: no branch (`br`, `br_if`,
+//!   `br_table`, `br_on_*`) targeting a frame outside the loop, no
+//!   `return`/`return_call*`, no `unreachable`, no `throw`/`rethrow`, and
+//!   no call to an imported function (hosts may terminate execution).
+//!
+//! The heuristic is intentionally conservative: any possible exit clears the
+//! flag, so false negatives are expected while false positives require a
+//! loop that truly has no way out short of a trap in a defined callee.
+
+use wasmparser::Operator;
+
+use crate::wasm::scan::ScanContext;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrameKind {
+    Loop,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    kind: FrameKind,
+    /// Unconditional `br` to this loop's header directly in its body.
+    back_edge: bool,
+    /// Some operator inside this frame may leave it.
+    exits: bool,
+}
+
+impl Frame {
+    fn new(kind: FrameKind) -> Self {
+        Self {
+            kind,
+            back_edge: false,
+            exits: false,
+        }
+    }
+}
+
+/// Control-frame stack for a single function body.
+#[derive(Debug, Clone)]
+pub struct ControlStack {
+    frames: Vec<Frame>,
+    suspicious_infinite_loops: u32,
+    open_loops: u32,
+    max_loop_depth: u32,
+}
+
+impl Default for ControlStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ControlStack {
+    /// Creates a stack holding the implicit function-body frame.
+    pub fn new() -> Self {
+        Self {
+            frames: vec![Frame::new(FrameKind::Other)],
+            suspicious_infinite_loops: 0,
+            open_loops: 0,
+            max_loop_depth: 0,
+        }
+    }
+
+    /// Current nesting depth (the function body counts as 1).
+    pub fn depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Deepest nesting of `loop` frames seen so far (0 without loops).
+    pub fn max_loop_depth(&self) -> u32 {
+        self.max_loop_depth
+    }
+
+    /// Whether the next operator sits inside at least one open `loop`.
+    pub fn in_loop(&self) -> bool {
+        self.open_loops > 0
+    }
+
+    /// Loops closed so far that matched the infinite-loop heuristic.
+    pub fn suspicious_infinite_loops(&self) -> u32 {
+        self.suspicious_infinite_loops
+    }
+
+    /// Updates the stack for one operator.
+    pub fn on_operator(&mut self, op: &Operator<'_>, ctx: &ScanContext) {
+        match op {
+            Operator::Loop { .. } => {
+                self.frames.push(Frame::new(FrameKind::Loop));
+                self.open_loops += 1;
+                self.max_loop_depth = self.max_loop_depth.max(self.open_loops);
+            }
+            Operator::Block { .. }
+            | Operator::If { .. }
+            | Operator::Try { .. }
+            | Operator::TryTable { .. } => self.frames.push(Frame::new(FrameKind::Other)),
+            Operator::End | Operator::Delegate { .. } => self.close_frame(),
+
+            Operator::Br { relative_depth } => self.on_branch(*relative_depth, true),
+            Operator::BrIf { relative_depth }
+            | Operator::BrOnNull { relative_depth }
+            | Operator::BrOnNonNull { relative_depth }
+            | Operator::BrOnCast { relative_depth, .. }
+            | Operator::BrOnCastFail { relative_depth, .. } => {
+                self.on_branch(*relative_depth, false)
+            }
+            Operator::BrTable { targets } => {
+                for depth in targets.targets().flatten() {
+                    self.on_branch(depth, false);
+                }
+                self.on_branch(targets.default(), false);
+            }
+
+            Operator::Return
+            | Operator::ReturnCall { .. }
+            | Operator::ReturnCallIndirect { .. }
+            | Operator::ReturnCallRef { .. }
+            | Operator::Unreachable
+            | Operator::Throw { .. }
+            | Operator::ThrowRef
+            | Operator::Rethrow { .. } => self.mark_all_exiting(),
+
+            Operator::Call { function_index } if *function_index < ctx.imported_function_count => {
+                self.mark_all_exiting()
+            }
+
+            _ => {}
+        }
+    }
+
+    fn close_frame(&mut self) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+        if frame.kind == FrameKind::Loop {
+            self.open_loops -= 1;
+            if frame.back_edge && !frame.exits {
+                self.suspicious_infinite_loops += 1;
+            }
+        }
+    }
+
+    /// Records a branch `relative_depth` frames up from the innermost frame.
+    fn on_branch(&mut self, relative_depth: u32, unconditional: bool) {
+        let top = self.frames.len().saturating_sub(1);
+        let Some(target) = top.checked_sub(relative_depth as usize) else {
+            return;
+        };
+
+        // Every frame nested inside the target is left by this branch.
+        for frame in &mut self.frames[target + 1..] {
+            frame.exits = true;
+        }
+
+        let frame = &mut self.frames[target];
+        if unconditional && target == top && frame.kind == FrameKind::Loop {
+            frame.back_edge = true;
+        } else if frame.kind != FrameKind::Loop {
+            // Branching to a block's end leaves the target frame too.
+            frame.exits = true;
+        }
+    }
+
+    fn mark_all_exiting(&mut self) {
+        for frame in &mut self.frames {
+            frame.exits = true;
+        }
+    }
+}
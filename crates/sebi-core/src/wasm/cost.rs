@@ -0,0 +1,147 @@
+//! Static cost model for the instruction scan.
+//!
+//! Assigns a fixed weight to each operator by category and lets the scan sum
+//! them per function. The result is a deterministic, ink/gas-like ordering
+//! signal for reviewers; it is **not** a prediction of runtime cost:
+//! - loops are counted once (no trip-count estimation)
+//! - host calls are weighted uniformly regardless of the host function
+//! - operand-sized operations (`memory.copy`, ...) use a flat weight
+//!
+//! The weight table is part of the rule catalog contract: changing any weight
+//! requires bumping `RULE_CATALOG_VERSION`.
+
+use wasmparser::Operator;
+
+use crate::wasm::operators::{self, OperatorInfo};
+use crate::wasm::scan::ScanContext;
+
+/// Structural markers with no execution cost: `end`, `else`, `nop`.
+pub const WEIGHT_FREE: u64 = 0;
+/// Integer arithmetic, constants, locals, globals, stack manipulation.
+pub const WEIGHT_BASE: u64 = 1;
+/// Branches and block constructs.
+pub const WEIGHT_CONTROL: u64 = 2;
+/// Scalar floating-point operators.
+pub const WEIGHT_FLOAT: u64 = 3;
+/// Linear-memory loads and stores.
+pub const WEIGHT_MEMORY: u64 = 4;
+/// SIMD vector operators.
+pub const WEIGHT_SIMD: u64 = 4;
+/// Atomic memory operators.
+pub const WEIGHT_ATOMIC: u64 = 8;
+/// Direct call to a defined function.
+pub const WEIGHT_CALL: u64 = 10;
+/// Indirect calls through a table or reference.
+pub const WEIGHT_CALL_INDIRECT: u64 = 20;
+/// Bulk memory and table operators (operand-sized work).
+pub const WEIGHT_BULK: u64 = 20;
+/// Direct call to an imported (host) function.
+pub const WEIGHT_HOST_CALL: u64 = 25;
+/// `memory.grow`.
+pub const WEIGHT_MEMORY_GROW: u64 = 100;
+
+/// Returns the static weight of a single operator.
+pub fn weight(op: &Operator<'_>, ctx: &ScanContext) -> u64 {
+    match op {
+        Operator::End | Operator::Else | Operator::Nop => WEIGHT_FREE,
+
+        Operator::Call { function_index } => {
+            if *function_index < ctx.imported_function_count {
+                WEIGHT_HOST_CALL
+            } else {
+                WEIGHT_CALL
+            }
+        }
+        Operator::CallIndirect { .. } | Operator::CallRef { .. } => WEIGHT_CALL_INDIRECT,
+
+        Operator::MemoryGrow { .. } => WEIGHT_MEMORY_GROW,
+        Operator::MemoryCopy { .. }
+        | Operator::MemoryFill { .. }
+        | Operator::MemoryInit { .. }
+        | Operator::TableCopy { .. }
+        | Operator::TableFill { .. }
+        | Operator::TableInit { .. }
+        | Operator::TableGrow { .. } => WEIGHT_BULK,
+
+        Operator::Block { .. }
+        | Operator::Loop { .. }
+        | Operator::If { .. }
+        | Operator::Br { .. }
+        | Operator::BrIf { .. }
+        | Operator::BrTable { .. }
+        | Operator::Return
+        | Operator::Unreachable => WEIGHT_CONTROL,
+
+        other => category_weight(&operators::describe(other)),
+    }
+}
+
+/// Weight of an operator that [`weight`] does not single out, from its
+/// family alone.
+pub fn category_weight(info: &OperatorInfo) -> u64 {
+    if info.is_simd() {
+        WEIGHT_SIMD
+    } else if info.is_atomic() {
+        WEIGHT_ATOMIC
+    } else if info.is_memory_access() {
+        WEIGHT_MEMORY
+    } else if info.is_float() {
+        WEIGHT_FLOAT
+    } else {
+        WEIGHT_BASE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmparser::MemArg;
+
+    fn memarg() -> MemArg {
+        MemArg {
+            align: 2,
+            max_align: 2,
+            offset: 0,
+            memory: 0,
+        }
+    }
+
+    #[test]
+    fn host_calls_outweigh_internal_calls() {
+        let ctx = ScanContext {
+            imported_function_count: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            weight(&Operator::Call { function_index: 1 }, &ctx),
+            WEIGHT_HOST_CALL
+        );
+        assert_eq!(
+            weight(&Operator::Call { function_index: 2 }, &ctx),
+            WEIGHT_CALL
+        );
+    }
+
+    #[test]
+    fn categories_map_to_documented_weights() {
+        let ctx = ScanContext::default();
+
+        assert_eq!(weight(&Operator::End, &ctx), WEIGHT_FREE);
+        assert_eq!(weight(&Operator::I32Add, &ctx), WEIGHT_BASE);
+        assert_eq!(weight(&Operator::F64Mul, &ctx), WEIGHT_FLOAT);
+        assert_eq!(
+            weight(&Operator::I32Load { memarg: memarg() }, &ctx),
+            WEIGHT_MEMORY
+        );
+        assert_eq!(
+            weight(&Operator::F32Store { memarg: memarg() }, &ctx),
+            WEIGHT_MEMORY
+        );
+        assert_eq!(weight(&Operator::Return, &ctx), WEIGHT_CONTROL);
+        assert_eq!(
+            weight(&Operator::MemoryGrow { mem: 0 }, &ctx),
+            WEIGHT_MEMORY_GROW
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Stylus hostio catalog for SEBI.
+//!
+//! Stylus programs reach the outside world only through `vm_hooks`
+//! imports, so the imported hostio names are a complete list of what a
+//! contract can do. This module maps each known hostio to the capability
+//! it grants; names missing from the table are reported as unknown so
+//! drift against newer Stylus releases stays visible.
+
+/// What a hostio lets a program do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Capability {
+    /// Calldata in, return data out, early exit
+    EntrypointIo,
+    /// Persistent storage reads and writes
+    Storage,
+    /// EIP-1153 transient storage
+    TransientStorage,
+    /// `CALL` / `STATICCALL` and their return data
+    ExternalCalls,
+    /// `DELEGATECALL`
+    Delegatecall,
+    /// `CREATE` / `CREATE2`
+    Create,
+    /// Event emission
+    Logging,
+    /// Block, transaction, message and account reads
+    Environment,
+    /// Native hashing
+    Crypto,
+    /// Memory-growth payment
+    MemoryAccounting,
+}
+
+/// Known `vm_hooks` hostios, sorted by name.
+const HOSTIOS: &[(&str, Capability)] = &[
+    ("account_balance", Capability::Environment),
+    ("account_code", Capability::Environment),
+    ("account_code_size", Capability::Environment),
+    ("account_codehash", Capability::Environment),
+    ("block_basefee", Capability::Environment),
+    ("block_coinbase", Capability::Environment),
+    ("block_gas_limit", Capability::Environment),
+    ("block_number", Capability::Environment),
+    ("block_timestamp", Capability::Environment),
+    ("call_contract", Capability::ExternalCalls),
+    ("chainid", Capability::Environment),
+    ("contract_address", Capability::Environment),
+    ("create1", Capability::Create),
+    ("create2", Capability::Create),
+    ("delegate_call_contract", Capability::Delegatecall),
+    ("emit_log", Capability::Logging),
+    ("evm_gas_left", Capability::Environment),
+    ("evm_ink_left", Capability::Environment),
+    ("exit_early", Capability::EntrypointIo),
+    ("msg_reentrant", Capability::Environment),
+    ("msg_sender", Capability::Environment),
+    ("msg_value", Capability::Environment),
+    ("native_keccak256", Capability::Crypto),
+    ("pay_for_memory_grow", Capability::MemoryAccounting),
+    ("read_args", Capability::EntrypointIo),
+    ("read_return_data", Capability::ExternalCalls),
+    ("return_data_size", Capability::ExternalCalls),
+    ("static_call_contract", Capability::ExternalCalls),
+    ("storage_cache_bytes32", Capability::Storage),
+    ("storage_flush_cache", Capability::Storage),
+    ("storage_load_bytes32", Capability::Storage),
+    ("storage_store_bytes32", Capability::Storage),
+    ("transient_load_bytes32", Capability::TransientStorage),
+    ("transient_store_bytes32", Capability::TransientStorage),
+    ("tx_gas_price", Capability::Environment),
+    ("tx_ink_price", Capability::Environment),
+    ("tx_origin", Capability::Environment),
+    ("write_result", Capability::EntrypointIo),
+];
+
+/// Capability granted by the `vm_hooks` import `name`, if it is known.
+pub fn capability(name: &str) -> Option<Capability> {
+    HOSTIOS
+        .binary_search_by(|(hostio, _)| (*hostio).cmp(name))
+        .ok()
+        .map(|i| HOSTIOS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted_for_lookup() {
+        assert!(HOSTIOS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn classifies_known_and_unknown_names() {
+        assert_eq!(
+            capability("storage_load_bytes32"),
+            Some(Capability::Storage)
+        );
+        assert_eq!(
+            capability("delegate_call_contract"),
+            Some(Capability::Delegatecall)
+        );
+        assert_eq!(capability("create2"), Some(Capability::Create));
+        assert_eq!(capability("teleport"), None);
+    }
+}
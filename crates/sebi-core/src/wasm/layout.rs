@@ -0,0 +1,171 @@
+//! Section layout checks for SEBI.
+//!
+//! The module format fixes the order of known sections and allows each at
+//! most once (custom sections excepted). `wasmparser::Parser` stops at the
+//! first violation with a generic "section out of order" error, so this
+//! module walks the raw section headers independently and names every
+//! duplicate or misplaced section with its byte offset.
+//!
+//! Key design principles:
+//! - Header walk only: section payloads are skipped, never decoded
+//! - Never fails: malformed headers end the walk and are left to the parser
+//! - Components and unknown section ids are ignored
+//! - Deterministic output (module order)
+
+use wasmparser::BinaryReader;
+
+/// Module preamble: `\0asm` magic followed by version 1 (core module layer).
+const MODULE_PREAMBLE: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+/// Id of custom sections, which may appear anywhere and repeat.
+const CUSTOM_SECTION: u8 = 0;
+
+/// Canonical position of a known non-custom section id.
+///
+/// Tag and DataCount sections were added after the original numbering, so
+/// their positions do not follow their ids.
+fn canonical_rank(id: u8) -> Option<u8> {
+    Some(match id {
+        1 => 1,   // type
+        2 => 2,   // import
+        3 => 3,   // function
+        4 => 4,   // table
+        5 => 5,   // memory
+        13 => 6,  // tag
+        6 => 7,   // global
+        7 => 8,   // export
+        8 => 9,   // start
+        9 => 10,  // element
+        12 => 11, // data count
+        10 => 12, // code
+        11 => 13, // data
+        _ => return None,
+    })
+}
+
+fn section_name(id: u8) -> &'static str {
+    match id {
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "data count",
+        13 => "tag",
+        _ => "unknown",
+    }
+}
+
+/// Returns one warning per duplicate or out-of-order section, in module order.
+pub fn check_section_layout(bytes: &[u8]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if !bytes.starts_with(&MODULE_PREAMBLE) {
+        return warnings;
+    }
+
+    let mut reader = BinaryReader::new(&bytes[MODULE_PREAMBLE.len()..], MODULE_PREAMBLE.len());
+    let mut seen = [false; 14];
+    // Highest-ranked section so far, as (rank, id).
+    let mut last: Option<(u8, u8)> = None;
+
+    while !reader.eof() {
+        let offset = reader.original_position();
+        let Ok(id) = reader.read_u8() else { break };
+        let Ok(size) = reader.read_var_u32() else {
+            break;
+        };
+        if reader.read_bytes(size as usize).is_err() {
+            break;
+        }
+
+        if id == CUSTOM_SECTION {
+            continue;
+        }
+        let Some(rank) = canonical_rank(id) else {
+            continue;
+        };
+
+        if seen[id as usize] {
+            warnings.push(format!(
+                "duplicate {} section at offset {offset:#x}",
+                section_name(id)
+            ));
+        } else if let Some((last_rank, last_id)) = last
+            && rank < last_rank
+        {
+            warnings.push(format!(
+                "{} section at offset {offset:#x} is out of order (after {} section)",
+                section_name(id),
+                section_name(last_id)
+            ));
+        }
+
+        seen[id as usize] = true;
+        if last.is_none_or(|(last_rank, _)| rank > last_rank) {
+            last = Some((rank, id));
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Module preamble followed by empty sections with the given ids.
+    fn module_with_sections(ids: &[u8]) -> Vec<u8> {
+        let mut bytes = MODULE_PREAMBLE.to_vec();
+        for &id in ids {
+            bytes.extend([id, 0x00]);
+        }
+        bytes
+    }
+
+    #[test]
+    fn canonical_layout_has_no_warnings() {
+        // type, import, function, table, memory, tag, global, export, start,
+        // element, data count, code, data, with custom sections interleaved
+        let bytes = module_with_sections(&[0, 1, 2, 3, 4, 5, 13, 6, 7, 0, 8, 9, 12, 10, 11, 0]);
+        assert!(check_section_layout(&bytes).is_empty());
+
+        let wasm = wat::parse_str("(module (memory 1) (func (export \"f\")))").unwrap();
+        assert!(check_section_layout(&wasm).is_empty());
+    }
+
+    #[test]
+    fn duplicate_section_named_with_offset() {
+        let bytes = module_with_sections(&[5, 5]);
+        assert_eq!(
+            check_section_layout(&bytes),
+            vec!["duplicate memory section at offset 0xa"]
+        );
+    }
+
+    #[test]
+    fn out_of_order_section_names_predecessor() {
+        let bytes = module_with_sections(&[7, 1, 10, 3]);
+        assert_eq!(
+            check_section_layout(&bytes),
+            vec![
+                "type section at offset 0xa is out of order (after export section)",
+                "function section at offset 0xe is out of order (after code section)",
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated_or_foreign_input_is_ignored() {
+        let mut bytes = module_with_sections(&[5]);
+        bytes.extend([5, 0x10]); // declared size past the end
+        assert!(check_section_layout(&bytes).is_empty());
+
+        assert!(check_section_layout(b"not wasm").is_empty());
+    }
+}
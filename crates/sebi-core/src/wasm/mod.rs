@@ -1,5 +1,15 @@
+pub mod callgraph;
+pub mod control;
+pub mod cost;
+pub mod hostio;
+pub mod layout;
+pub mod operators;
 pub mod parse;
+pub mod profile;
+pub mod reachability;
 pub mod read;
 pub mod scan;
 pub mod sections;
+pub mod stack;
 pub mod stylus;
+pub mod toolchain;
@@ -0,0 +1,200 @@
+//! Operator classification for the instruction scan.
+//!
+//! Every operator known to `wasmparser` is described by its variant name and
+//! the WebAssembly proposal that introduced it. The table is generated from
+//! `wasmparser::for_each_operator!`, so new operators are covered without
+//! hand-maintained opcode lists.
+//!
+//! Families (floating point, SIMD, atomics, ...) are derived from these two
+//! attributes rather than by enumerating individual operators.
+
+use wasmparser::{MemArg, Operator};
+
+/// Static description of a single operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorInfo {
+    /// `wasmparser` variant name, e.g. `"I32Add"`.
+    pub name: &'static str,
+
+    /// Proposal identifier as used by `wasmparser`, e.g. `"mvp"`, `"simd"`.
+    pub proposal: &'static str,
+}
+
+macro_rules! define_describe {
+    ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident ($($ann:tt)*) )*) => {
+        /// Describes an operator by name and originating proposal.
+        pub fn describe(op: &Operator<'_>) -> OperatorInfo {
+            match op {
+                $(
+                    Operator::$op { .. } => OperatorInfo {
+                        name: stringify!($op),
+                        proposal: stringify!($proposal),
+                    },
+                )*
+                #[allow(unreachable_patterns)]
+                _ => OperatorInfo {
+                    name: "Unknown",
+                    proposal: "unknown",
+                },
+            }
+        }
+    };
+}
+
+wasmparser::for_each_operator!(define_describe);
+
+/// Yields `Some(*memarg)` if the operator's immediates include a `memarg`.
+macro_rules! memarg_field {
+    ($op:ident $variant:ident) => {
+        None
+    };
+    ($op:ident $variant:ident memarg: $ty:ty $(, $($rest:tt)*)?) => {
+        match $op {
+            Operator::$variant { memarg, .. } => Some(*memarg),
+            _ => None,
+        }
+    };
+    ($op:ident $variant:ident $arg:ident: $ty:ty $(, $($rest:tt)*)?) => {
+        memarg_field!($op $variant $($($rest)*)?)
+    };
+}
+
+macro_rules! define_memarg {
+    ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident ($($ann:tt)*) )*) => {
+        /// Returns the memory immediate of loads, stores, atomics and lane
+        /// accesses; `None` for operators that do not address linear memory
+        /// through a `memarg`.
+        pub fn memarg(op: &Operator<'_>) -> Option<MemArg> {
+            match op {
+                $(
+                    Operator::$op { .. } => memarg_field!(op $op $($($arg: $argty),*)?),
+                )*
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }
+        }
+    };
+}
+
+wasmparser::for_each_operator!(define_memarg);
+
+impl OperatorInfo {
+    /// True for vector operators (`simd` and `relaxed_simd` proposals).
+    pub fn is_simd(&self) -> bool {
+        matches!(self.proposal, "simd" | "relaxed_simd")
+    }
+
+    /// True for scalar floating-point operators: arithmetic, comparisons,
+    /// conversions to/from floats, and float loads/stores.
+    ///
+    /// Vector float lanes (`f32x4.*`) are classified as SIMD instead.
+    pub fn is_float(&self) -> bool {
+        !self.is_simd() && (self.name.contains("F32") || self.name.contains("F64"))
+    }
+
+    /// True for atomic memory operators and fences (`threads` proposal and
+    /// its shared-everything extension).
+    pub fn is_atomic(&self) -> bool {
+        matches!(self.proposal, "threads" | "shared_everything_threads")
+    }
+
+    /// True for plain and atomic linear-memory loads and stores.
+    pub fn is_memory_access(&self) -> bool {
+        self.name.contains("Load") || self.name.contains("Store")
+    }
+
+    /// True for `f32.const` / `f64.const`.
+    pub fn is_float_const(&self) -> bool {
+        matches!(self.name, "F32Const" | "F64Const")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmparser::Ieee32;
+
+    fn arg(offset: u64) -> MemArg {
+        MemArg {
+            align: 0,
+            max_align: 0,
+            offset,
+            memory: 0,
+        }
+    }
+
+    #[test]
+    fn memarg_extracted_from_memory_accesses() {
+        let ops = [
+            Operator::I32Store { memarg: arg(1) },
+            Operator::I64Load8U { memarg: arg(2) },
+            Operator::I32AtomicRmwAdd { memarg: arg(3) },
+            Operator::MemoryAtomicWait32 { memarg: arg(4) },
+            Operator::V128Load8Lane {
+                memarg: arg(5),
+                lane: 0,
+            },
+        ];
+        let offsets: Vec<u64> = ops.iter().map(|op| memarg(op).unwrap().offset).collect();
+        assert_eq!(offsets, vec![1, 2, 3, 4, 5]);
+
+        assert!(memarg(&Operator::I32Add).is_none());
+        assert!(memarg(&Operator::MemoryGrow { mem: 0 }).is_none());
+        assert!(memarg(&Operator::AtomicFence).is_none());
+    }
+
+    #[test]
+    fn describes_mvp_operator() {
+        let info = describe(&Operator::I32Add);
+        assert_eq!(info.name, "I32Add");
+        assert_eq!(info.proposal, "mvp");
+        assert!(!info.is_float());
+    }
+
+    #[test]
+    fn classifies_float_families() {
+        assert!(describe(&Operator::F64Add).is_float());
+        assert!(describe(&Operator::F32Lt).is_float());
+        assert!(describe(&Operator::I32TruncF32S).is_float());
+        assert!(describe(&Operator::F64ConvertI64U).is_float());
+        assert!(describe(&Operator::I64TruncSatF64U).is_float());
+
+        let load = Operator::F32Load {
+            memarg: MemArg {
+                align: 2,
+                max_align: 2,
+                offset: 0,
+                memory: 0,
+            },
+        };
+        assert!(describe(&load).is_float());
+
+        let konst = describe(&Operator::F32Const {
+            value: Ieee32::from(0.5f32),
+        });
+        assert!(konst.is_float());
+        assert!(konst.is_float_const());
+    }
+
+    #[test]
+    fn classifies_atomic_family() {
+        assert!(describe(&Operator::AtomicFence).is_atomic());
+        let notify = Operator::MemoryAtomicNotify {
+            memarg: MemArg {
+                align: 2,
+                max_align: 2,
+                offset: 0,
+                memory: 0,
+            },
+        };
+        assert!(describe(&notify).is_atomic());
+        assert!(!describe(&Operator::I32Add).is_atomic());
+    }
+
+    #[test]
+    fn vector_float_lanes_are_not_scalar_floats() {
+        let info = describe(&Operator::F32x4Add);
+        assert!(info.is_simd());
+        assert!(!info.is_float());
+    }
+}
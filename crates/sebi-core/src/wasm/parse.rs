@@ -1,23 +1,89 @@
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
 use wasmparser::{Parser, Payload};
 
 use crate::report::model::{AnalysisInfo, RulesCatalogInfo};
-use crate::wasm::{scan, sections, stylus};
+use crate::wasm::profile::{self, RequiredExportCheck, TargetSelection};
+use crate::wasm::{callgraph, layout, reachability, scan, sections, stylus, toolchain};
 
 /// Parsing-time configuration that influences downstream policy signals.
 ///
 /// Note: This is *not* the rules engine. It only supplies values that
-/// rules may reference (e.g., size thresholds).
-#[derive(Debug, Clone)]
+/// rules may reference (e.g., the import allowlist). Numeric thresholds are
+/// rule parameters declared in `rules::catalog`.
+#[derive(Debug, Clone, Default)]
 pub struct ParseConfig {
-    /// Threshold (bytes) used by size-based rule triggers.
-    pub size_threshold_bytes: u64,
+    /// Evaluate R-SIZE-01 against the compressed size when it is known.
+    pub size_rule_compressed: bool,
+
+    /// Import modules R-IMPORT-01 accepts; `None` disables the rule.
+    /// Set from `ParseOptions::import_allowlist` or the target profile.
+    pub import_allowlist: Option<Vec<String>>,
+
+    /// Non-MVP proposals R-FEAT-01 accepts. Set from
+    /// `ParseOptions::allowed_features` or the target profile.
+    pub allowed_features: Vec<String>,
+
+    /// Exports the target profile requires, resolved against the export
+    /// section for R-EXPORT-01. Empty under the generic profile.
+    pub required_exports: Vec<RequiredExportCheck>,
+
+    /// Function-name globs identifying the allocator for R-MEM-02. `Some`
+    /// enables the allocator heuristic; `None` leaves the rule unchanged.
+    pub allocator_patterns: Option<Vec<String>>,
+}
+
+/// Opt-in data collection performed during parsing.
+///
+/// Defaults keep the scan on its fast path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Accumulate a per-operator histogram during the instruction scan.
+    pub collect_histogram: bool,
+
+    /// Maximum byte offsets recorded per tracked operator kind.
+    pub site_limit: usize,
+
+    /// Static memory offset above which accesses count as large-offset.
+    pub large_offset_threshold: u64,
+
+    /// Also aggregate instruction facts over functions reachable from the
+    /// entrypoints (see `wasm::reachability`).
+    pub reachability: bool,
+
+    /// Scan function bodies in parallel. Requires the `parallel` feature;
+    /// ignored otherwise. Results are identical to the serial scan.
+    pub parallel: bool,
+
+    /// Initial memory (pages) above which Stylus targets get a warning.
+    pub stylus_initial_pages_limit: u64,
+
+    /// Target profile used for normalization; `Auto` detects it.
+    pub target: TargetSelection,
+
+    /// Import modules accepted by R-IMPORT-01. `None` uses the profile's
+    /// allowlist (see `TargetProfile::import_allowlist`).
+    pub import_allowlist: Option<Vec<String>>,
+
+    /// Non-MVP proposals accepted by R-FEAT-01. `None` uses the profile's
+    /// set (see `TargetProfile::allowed_features`).
+    pub allowed_features: Option<Vec<String>>,
 }
 
-impl Default for ParseConfig {
+impl Default for ParseOptions {
     fn default() -> Self {
         Self {
-            size_threshold_bytes: 200_000,
+            collect_histogram: false,
+            site_limit: scan::DEFAULT_SITE_LIMIT,
+            large_offset_threshold: scan::DEFAULT_LARGE_OFFSET_THRESHOLD,
+            reachability: false,
+            parallel: false,
+            stylus_initial_pages_limit: stylus::DEFAULT_INITIAL_PAGES_LIMIT,
+            target: TargetSelection::Auto,
+            import_allowlist: None,
+            allowed_features: None,
         }
     }
 }
@@ -41,6 +107,9 @@ pub struct RawWasmFacts {
     /// Instruction-derived facts (operator scanning).
     pub instructions: scan::InstructionFacts,
 
+    /// Reachable-only facts, when requested via `ParseOptions::reachability`.
+    pub reachability: Option<reachability::ReachabilityFacts>,
+
     /// Parsing/compatibility status and deterministic warnings.
     pub analysis: AnalysisInfo,
 
@@ -51,6 +120,29 @@ pub struct RawWasmFacts {
 
     /// Parsing configuration knobs.
     pub config: ParseConfig,
+
+    /// Wall-clock time spent scanning function bodies and building the
+    /// call graph; included in the parse time. Reported only under
+    /// `analysis.stats`.
+    pub scan_time: Duration,
+}
+
+impl RawWasmFacts {
+    /// Functions the module's code can invoke: every direct `call` /
+    /// `return_call` target, plus, when `call_indirect` is present, every
+    /// function placed in an element segment.
+    pub fn referenced_functions(&self) -> BTreeSet<u32> {
+        let mut referenced: BTreeSet<u32> = self
+            .instructions
+            .functions
+            .iter()
+            .flat_map(|f| f.callees.iter().copied())
+            .collect();
+        if self.instructions.has_call_indirect {
+            referenced.extend(self.sections.table_functions.iter().copied());
+        }
+        referenced
+    }
 }
 
 /// Parse a WebAssembly binary and extract raw structural and instruction facts.
@@ -61,28 +153,55 @@ pub struct RawWasmFacts {
 /// 2. Dispatches `CodeSectionEntry` bodies to `wasm::scan` for operator scanning.
 /// 3. Ignores sections that are irrelevant to current signals (custom/name/debug, etc.).
 /// 4. Marks component-model payloads as unsupported (best-effort handling).
-/// 5. Applies a target-specific normalization pass via `wasm::stylus` to emit warnings
-///    or adjust tolerances without introducing policy judgments.
+/// 5. Checks the raw section layout via `wasm::layout`, warning on duplicate or
+///    out-of-order sections.
+/// 6. Applies universal and target-profile normalization via `wasm::profile` to emit warnings
+///    or adjust tolerances without introducing policy judgments, and guesses the
+///    source toolchain via `wasm::toolchain` and the Stylus SDK via `wasm::stylus`.
 ///
 /// Output is an internal representation (`RawWasmFacts`) that is later converted into
 /// schema-defined `Signals` by `signals::extract`.
 pub fn parse_wasm(bytes: &[u8]) -> Result<RawWasmFacts> {
+    parse_wasm_with_options(bytes, &ParseOptions::default())
+}
+
+/// Same as [`parse_wasm`], with opt-in collection controlled by `opts`.
+pub fn parse_wasm_with_options(bytes: &[u8], opts: &ParseOptions) -> Result<RawWasmFacts> {
     let mut facts = RawWasmFacts {
         analysis: AnalysisInfo::ok(),
         rules_catalog: RulesCatalogInfo {
-            catalog_version: "0.1.0".to_string(),
+            catalog_version: crate::RULE_CATALOG_VERSION.to_string(),
             ruleset: "default".to_string(),
+            ..Default::default()
         },
         config: ParseConfig::default(),
         ..Default::default()
     };
 
+    if opts.collect_histogram {
+        facts.instructions.histogram = Some(Default::default());
+    }
+
     let parser = Parser::new(0);
+    let mut scan_ctx = scan::ScanContext::default();
+    // Parallel scans and reachability need per-body results, so bodies are
+    // collected and scanned after the section loop. They borrow from `bytes`,
+    // so deferring them costs no copies.
+    let defer_bodies = opts.reachability || (cfg!(feature = "parallel") && opts.parallel);
+    let mut deferred_bodies = Vec::new();
 
     for payload in parser.parse_all(bytes) {
+        if let Ok(section) = &payload
+            && let Some((id, range)) = section.as_section()
+        {
+            facts.sections.section_bytes.record(id, range.len());
+        }
         match payload {
             Ok(Payload::Version { .. }) => {}
 
+            Ok(Payload::TypeSection(reader)) => {
+                sections::on_type_section(&mut facts.sections, reader)?;
+            }
             Ok(Payload::ImportSection(reader)) => {
                 sections::on_import_section(&mut facts.sections, reader)?;
             }
@@ -95,19 +214,50 @@ pub fn parse_wasm(bytes: &[u8]) -> Result<RawWasmFacts> {
             Ok(Payload::MemorySection(reader)) => {
                 sections::on_memory_section(&mut facts.sections, reader)?;
             }
+            Ok(Payload::GlobalSection(reader)) => {
+                sections::on_global_section(&mut facts.sections, reader)?;
+            }
             Ok(Payload::ExportSection(reader)) => {
                 sections::on_export_section(&mut facts.sections, reader)?;
             }
+            Ok(Payload::ElementSection(reader)) => {
+                sections::on_element_section(&mut facts.sections, reader)?;
+            }
+            Ok(Payload::StartSection { func, .. }) => {
+                facts.sections.start_function = Some(func);
+            }
+            Ok(Payload::TagSection(reader)) => {
+                sections::on_tag_section(&mut facts.sections, reader)?;
+            }
+            Ok(Payload::DataSection(reader)) => {
+                sections::on_data_section(&mut facts.sections, reader)?;
+            }
 
-            Ok(Payload::CodeSectionStart { .. }) => {}
+            // Imports and memories precede the Code section, so the scan context
+            // is complete here.
+            Ok(Payload::CodeSectionStart { .. }) => {
+                scan_ctx = scan::ScanContext {
+                    site_limit: opts.site_limit,
+                    large_offset_threshold: opts.large_offset_threshold,
+                    ..scan::ScanContext::from_sections(&facts.sections)
+                };
+            }
 
             Ok(Payload::CodeSectionEntry(body)) => {
-                scan::on_code_entry(&mut facts.instructions, body)?;
+                if defer_bodies {
+                    deferred_bodies.push(body);
+                    continue;
+                }
+                let started = Instant::now();
+                scan::on_code_entry(&mut facts.instructions, &scan_ctx, body)?;
+                facts.scan_time += started.elapsed();
             }
 
-            // Custom sections are intentionally ignored for v1:
-            // names/producers/debug info do not contribute to execution-boundary signals.
-            Ok(Payload::CustomSection(_)) => {}
+            // Only the `name` section is read, for labeling; producers/debug info
+            // do not contribute to execution-boundary signals.
+            Ok(Payload::CustomSection(reader)) => {
+                sections::on_custom_section(&mut facts.sections, reader);
+            }
 
             Ok(
                 other @ (Payload::ComponentSection { .. }
@@ -135,13 +285,67 @@ pub fn parse_wasm(bytes: &[u8]) -> Result<RawWasmFacts> {
                 break;
             }
 
-            // All other sections are currently ignored by design (Type, Global, Data, etc.).
+            // All other sections are currently ignored by design (DataCount, etc.).
             // They can be added later as new signals without changing rule evaluation logic.
             _ => {}
         }
     }
 
-    stylus::normalize(&mut facts.sections, &mut facts.analysis);
+    let started = Instant::now();
+    if defer_bodies {
+        let partials = scan::scan_bodies(
+            &scan_ctx,
+            deferred_bodies,
+            opts.collect_histogram,
+            opts.parallel,
+        )?;
+        if opts.reachability {
+            facts.reachability = Some(reachability::analyze(
+                &facts.sections,
+                &partials,
+                opts.collect_histogram,
+                opts.site_limit,
+            ));
+        }
+        facts.instructions =
+            scan::merge_partials(partials, opts.collect_histogram, opts.site_limit);
+    }
+
+    facts.instructions.recursion = callgraph::find_recursion(&facts.instructions.functions);
+    facts.scan_time += started.elapsed();
+
+    let referenced = facts.referenced_functions();
+    sections::mark_referenced_imports(&mut facts.sections, &referenced);
+
+    // The parser stops at the first misplaced section; name every one.
+    facts
+        .analysis
+        .warnings
+        .extend(layout::check_section_layout(bytes));
+
+    profile::normalize(
+        &mut facts.sections,
+        &facts.instructions,
+        opts,
+        &mut facts.analysis,
+    );
+    let target_profile = profile::profile_for(facts.analysis.target, opts);
+    facts.config.import_allowlist = opts.import_allowlist.clone().or_else(|| {
+        target_profile
+            .import_allowlist()
+            .map(|modules| modules.iter().map(|m| m.to_string()).collect())
+    });
+    facts.config.allowed_features = opts.allowed_features.clone().unwrap_or_else(|| {
+        target_profile
+            .allowed_features()
+            .iter()
+            .map(|f| f.to_string())
+            .collect()
+    });
+    facts.config.required_exports =
+        profile::resolve_required_exports(target_profile.as_ref(), &facts.sections);
+    facts.analysis.toolchain = toolchain::detect(&facts.sections);
+    facts.analysis.sdk = stylus::detect_sdk(&facts.sections);
 
     Ok(facts)
 }
@@ -207,6 +411,27 @@ mod tests {
         assert_eq!(a.analysis.warnings, b.analysis.warnings);
     }
 
+    #[test]
+    fn histogram_only_collected_when_requested() {
+        assert!(
+            parse_wasm(LOOP_MODULE)
+                .unwrap()
+                .instructions
+                .histogram
+                .is_none()
+        );
+
+        let opts = ParseOptions {
+            collect_histogram: true,
+            ..Default::default()
+        };
+        let facts = parse_wasm_with_options(LOOP_MODULE, &opts).unwrap();
+        let histogram = facts.instructions.histogram.expect("histogram requested");
+
+        assert_eq!(histogram.get("Loop"), Some(&1));
+        assert!(histogram.contains_key("End"));
+    }
+
     #[test]
     fn invalid_wasm_sets_parse_error() {
         let invalid = b"not a wasm file";
@@ -216,6 +441,74 @@ mod tests {
         assert_eq!(facts.analysis.status, "parse_error");
     }
 
+    #[test]
+    fn table_functions_referenced_only_with_call_indirect() {
+        let wat = |dispatch: &str| {
+            format!(
+                r#"(module
+                     (import "env" "a" (func $a))
+                     (import "env" "b" (func $b))
+                     (import "env" "c" (func $c))
+                     (type $t (func))
+                     (table 1 funcref)
+                     (elem (i32.const 0) $b)
+                     (func (call $a) {dispatch}))"#
+            )
+        };
+
+        let with =
+            parse_wasm(&wat::parse_str(wat("(call_indirect (type $t) (i32.const 0))")).unwrap())
+                .unwrap();
+        assert_eq!(with.referenced_functions(), BTreeSet::from([0, 1]));
+
+        let without = parse_wasm(&wat::parse_str(wat("")).unwrap()).unwrap();
+        assert_eq!(without.referenced_functions(), BTreeSet::from([0]));
+        let referenced: Vec<_> = without
+            .sections
+            .imports
+            .iter()
+            .map(|i| (i.name.as_str(), i.referenced))
+            .collect();
+        assert_eq!(
+            referenced,
+            vec![("a", Some(true)), ("b", Some(false)), ("c", Some(false))]
+        );
+    }
+
+    #[test]
+    fn section_bytes_grouped_by_category() {
+        let wasm = wat::parse_str(
+            r#"(module
+                 (@custom "meta" "abcd")
+                 (memory 1)
+                 (func (nop))
+                 (data (i32.const 0) "0123456789"))"#,
+        )
+        .unwrap();
+        let bytes = parse_wasm(&wasm).unwrap().sections.section_bytes;
+
+        // "meta" name (1 + 4) plus the 4-byte payload.
+        assert_eq!(bytes.custom, 9);
+        // Entry count, body size, local count, `nop`, `end`.
+        assert_eq!(bytes.code, 5);
+        assert!(bytes.data > 10);
+        assert!(bytes.other > 0);
+    }
+
+    #[test]
+    fn warns_when_memory64_detected() {
+        let wasm = wat::parse_str("(module (memory i64 1))").unwrap();
+        let facts = parse_wasm(&wasm).unwrap();
+
+        assert!(
+            facts
+                .analysis
+                .warnings
+                .iter()
+                .any(|w| w.contains("memory64"))
+        );
+    }
+
     #[test]
     fn warns_when_no_memory_detected() {
         let facts = parse_wasm(EMPTY_MODULE).unwrap();
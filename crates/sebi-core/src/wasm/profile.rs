@@ -0,0 +1,454 @@
+//! Target profiles for SEBI.
+//!
+//! Post-parse normalization is split into universal checks, which apply to
+//! every module, and a `TargetProfile` that adds what one deployment
+//! target expects: required exports, acceptable import modules, accepted
+//! optional features and target-specific warnings.
+//!
+//! The profile is selected from the detected target unless the caller
+//! picks one (`ParseOptions::target`):
+//! - `stylus` → `StylusProfile` (see `wasm::stylus`)
+//! - `wasi` → `WasiProfile`
+//! - `unknown` → `GenericProfile`, universal checks only
+//!
+//! Profiles annotate analysis output. Their only inputs to rule evaluation
+//! are the default import allowlist R-IMPORT-01 checks against, the
+//! required exports R-EXPORT-01 checks and the default set of proposals
+//! R-FEAT-01 accepts.
+
+use crate::report::model::{AnalysisInfo, Target};
+use crate::wasm::parse::ParseOptions;
+use crate::wasm::scan::InstructionFacts;
+use crate::wasm::sections::{MemoryFact, SectionFacts};
+use crate::wasm::stylus::{STYLUS_EXPORTS, StylusProfile, VM_HOOKS_MODULE};
+
+/// Import module prefixes of WASI namespaces: preview 1 versions
+/// (`wasi_snapshot_preview1`, `wasi_unstable`, ...) and component-model
+/// interfaces (`wasi:cli/stdout`, ...).
+const WASI_MODULE_PREFIXES: &[&str] = &["wasi_", "wasi:"];
+
+/// Import modules WASI runtimes provide.
+const WASI_MODULES: &[&str] = &["wasi_snapshot_preview1", "wasi_unstable"];
+
+/// Proposals every profile accepts by default on top of the MVP.
+pub const BASELINE_FEATURES: &[&str] = &["sign_extension"];
+
+/// Baseline plus bulk memory, which current toolchains emit for
+/// `memcpy`/`memset`.
+const BULK_MEMORY_FEATURES: &[&str] = &["bulk_memory", "sign_extension"];
+
+/// Which profile normalizes the module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TargetSelection {
+    /// Infer the target from imports and exports (`detect_target`).
+    #[default]
+    Auto,
+    Stylus,
+    Wasi,
+    Generic,
+}
+
+/// Optional WebAssembly features a target accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeaturePolicy {
+    pub floats: bool,
+    pub start_function: bool,
+    pub multiple_memories: bool,
+}
+
+impl FeaturePolicy {
+    /// Accepts everything the parser does.
+    pub const PERMISSIVE: Self = Self {
+        floats: true,
+        start_function: true,
+        multiple_memories: true,
+    };
+}
+
+/// An export the host looks up, with the kind it must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequiredExport {
+    pub name: &'static str,
+    /// External kind, spelled as in `ExportFact::kind` (e.g. "func").
+    pub kind: &'static str,
+}
+
+/// A required export and the kind actually exported under its name.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RequiredExportCheck {
+    pub name: String,
+    /// Expected external kind.
+    pub kind: String,
+    /// Kind exported under `name`; `None` when absent.
+    pub found_kind: Option<String>,
+}
+
+impl RequiredExportCheck {
+    /// Exported under the expected kind.
+    pub fn satisfied(&self) -> bool {
+        self.found_kind.as_deref() == Some(self.kind.as_str())
+    }
+}
+
+/// Expectations of one deployment target.
+pub trait TargetProfile {
+    /// Target reported as `analysis.target`.
+    fn target(&self) -> Target;
+
+    /// Exports the host looks up; missing or mistyped ones are warned about
+    /// and trigger R-EXPORT-01.
+    fn required_exports(&self) -> &'static [RequiredExport] {
+        &[]
+    }
+
+    /// Import modules the host provides; `None` accepts any module.
+    fn import_allowlist(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// Optional features the target accepts.
+    fn feature_policy(&self) -> FeaturePolicy {
+        FeaturePolicy::PERMISSIVE
+    }
+
+    /// Non-MVP proposals R-FEAT-01 accepts, in `wasmparser` spelling.
+    fn allowed_features(&self) -> &'static [&'static str] {
+        BASELINE_FEATURES
+    }
+
+    /// Target-specific checks, run after the universal ones.
+    fn normalize(
+        &self,
+        _sections: &mut SectionFacts,
+        _instructions: &InstructionFacts,
+        _analysis: &mut AnalysisInfo,
+    ) {
+    }
+}
+
+/// No target expectations; universal checks only.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenericProfile;
+
+impl TargetProfile for GenericProfile {
+    fn target(&self) -> Target {
+        Target::Unknown
+    }
+}
+
+/// WASI (preview 1) modules: the runtime reads linear memory through the
+/// `memory` export.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WasiProfile;
+
+impl TargetProfile for WasiProfile {
+    fn target(&self) -> Target {
+        Target::Wasi
+    }
+
+    fn required_exports(&self) -> &'static [RequiredExport] {
+        &[RequiredExport {
+            name: "memory",
+            kind: "memory",
+        }]
+    }
+
+    fn import_allowlist(&self) -> Option<&'static [&'static str]> {
+        Some(WASI_MODULES)
+    }
+
+    fn allowed_features(&self) -> &'static [&'static str] {
+        BULK_MEMORY_FEATURES
+    }
+}
+
+/// Infers the deployment target from import modules and export names.
+///
+/// Detection is conservative: only Stylus- or WASI-specific names count,
+/// so modules importing from generic namespaces such as `env` stay
+/// `Unknown`. Stylus wins when both fingerprints are present. Returns the
+/// target and the sorted evidence it was inferred from.
+pub fn detect_target(sections: &SectionFacts) -> (Target, Vec<String>) {
+    let mut stylus = Vec::new();
+    let mut wasi = Vec::new();
+
+    for (module, count) in &sections.import_modules {
+        let plural = if *count == 1 { "" } else { "s" };
+        let evidence = format!("import module {module} ({count} import{plural})");
+        if module == VM_HOOKS_MODULE {
+            stylus.push(evidence);
+        } else if is_wasi_module(module) {
+            wasi.push(evidence);
+        }
+    }
+    for name in STYLUS_EXPORTS {
+        if sections.function_index_for_export(name).is_some() {
+            stylus.push(format!("export {name}"));
+        }
+    }
+
+    let (target, mut evidence) = if !stylus.is_empty() {
+        (Target::Stylus, stylus)
+    } else if !wasi.is_empty() {
+        (Target::Wasi, wasi)
+    } else {
+        (Target::Unknown, Vec::new())
+    };
+    evidence.sort();
+    (target, evidence)
+}
+
+/// Whether `module` is a WASI import namespace.
+pub fn is_wasi_module(module: &str) -> bool {
+    WASI_MODULE_PREFIXES.iter().any(|p| module.starts_with(p))
+}
+
+/// Profile for `target`, configured from the parse options.
+pub fn profile_for(target: Target, opts: &ParseOptions) -> Box<dyn TargetProfile> {
+    match target {
+        Target::Stylus => Box::new(StylusProfile {
+            initial_pages_limit: opts.stylus_initial_pages_limit,
+        }),
+        Target::Wasi => Box::new(WasiProfile),
+        Target::Unknown => Box::new(GenericProfile),
+    }
+}
+
+pub fn normalize(
+    sections: &mut SectionFacts,
+    instructions: &InstructionFacts,
+    opts: &ParseOptions,
+    analysis: &mut AnalysisInfo,
+) {
+    // Apply minimal post-parse normalization and emit analysis warnings.
+    //
+    // This stage exists to annotate cases where the extracted section data
+    // may be incomplete or unconventional, without interpreting risk
+    // or influencing rule evaluation.
+    let (target, evidence) = match opts.target {
+        TargetSelection::Auto => detect_target(sections),
+        TargetSelection::Stylus => (Target::Stylus, vec!["selected explicitly".to_string()]),
+        TargetSelection::Wasi => (Target::Wasi, vec!["selected explicitly".to_string()]),
+        TargetSelection::Generic => (Target::Unknown, vec!["selected explicitly".to_string()]),
+    };
+    let profile = profile_for(target, opts);
+    (analysis.target, analysis.target_evidence) = (profile.target(), evidence);
+
+    check_universal(sections, analysis);
+    check_required_exports(profile.as_ref(), sections, analysis);
+    profile.normalize(sections, instructions, analysis);
+
+    // Ensure deterministic output ordering.
+    analysis.warnings.sort();
+}
+
+/// Checks that apply whatever the target.
+fn check_universal(sections: &SectionFacts, analysis: &mut AnalysisInfo) {
+    if sections.memory_count == 0 {
+        analysis
+            .warnings
+            .push("no memory section or imported memory detected".to_string());
+    }
+
+    // Importing and defining memories at once usually means a linking
+    // mistake; the reported limits come from the imported memory (index 0).
+    if sections.memory_declaration_conflict {
+        let imported = sections.memories.iter().find(|m| m.imported);
+        let defined = sections.memories.iter().find(|m| !m.imported);
+        if let (Some(imported), Some(defined)) = (imported, defined) {
+            analysis.warnings.push(format!(
+                "memory both imported ({}) and defined ({}); the imported memory is index 0 and sets the reported limits",
+                describe_limits(imported),
+                describe_limits(defined)
+            ));
+        }
+    }
+
+    // Page counts of a 64-bit memory are not bounded like 32-bit ones, so
+    // size reasoning from `min_pages`/`max_pages` needs this caveat.
+    if sections.has_memory64 {
+        analysis
+            .warnings
+            .push("memory64 memory detected; page limits use a 64-bit address space".to_string());
+    }
+}
+
+fn check_required_exports(
+    profile: &dyn TargetProfile,
+    sections: &SectionFacts,
+    analysis: &mut AnalysisInfo,
+) {
+    let label = profile.target().label();
+    for check in resolve_required_exports(profile, sections) {
+        let name = &check.name;
+        match &check.found_kind {
+            None => analysis.warnings.push(format!(
+                "{label} target: required export '{name}' is missing"
+            )),
+            Some(kind) if *kind != check.kind => analysis.warnings.push(format!(
+                "{label} target: required export '{name}' is a {kind} export, expected {}",
+                check.kind
+            )),
+            Some(_) => {}
+        }
+    }
+}
+
+/// Checks each required export of `profile` against the complete export
+/// section, for R-EXPORT-01.
+pub fn resolve_required_exports(
+    profile: &dyn TargetProfile,
+    sections: &SectionFacts,
+) -> Vec<RequiredExportCheck> {
+    profile
+        .required_exports()
+        .iter()
+        .map(|required| RequiredExportCheck {
+            name: required.name.to_string(),
+            kind: required.kind.to_string(),
+            found_kind: sections
+                .exports
+                .iter()
+                .find(|e| e.name == required.name)
+                .map(|e| e.kind.clone()),
+        })
+        .collect()
+}
+
+fn describe_limits(memory: &MemoryFact) -> String {
+    match memory.max_pages {
+        Some(max) => format!("min {} pages, max {max} pages", memory.min_pages),
+        None => format!("min {} pages, no max", memory.min_pages),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::parse::{parse_wasm, parse_wasm_with_options};
+
+    fn target_of(wat: &str) -> (Target, Vec<String>) {
+        let facts = parse_wasm(&wat::parse_str(wat).unwrap()).unwrap();
+        (facts.analysis.target, facts.analysis.target_evidence)
+    }
+
+    #[test]
+    fn stylus_detected_from_vm_hooks_and_entrypoint() {
+        let (target, evidence) = target_of(
+            r#"(module
+                 (import "vm_hooks" "read_args" (func (param i32)))
+                 (import "vm_hooks" "write_result" (func (param i32 i32)))
+                 (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))"#,
+        );
+        assert_eq!(target, Target::Stylus);
+        assert_eq!(
+            evidence,
+            vec![
+                "export user_entrypoint",
+                "import module vm_hooks (2 imports)"
+            ]
+        );
+    }
+
+    #[test]
+    fn wasi_detected_unless_stylus_fingerprint_present() {
+        let wasi = r#"(import "wasi_snapshot_preview1" "fd_write"
+                         (func (param i32 i32 i32 i32) (result i32)))"#;
+        let (target, evidence) = target_of(&format!("(module {wasi})"));
+        assert_eq!(target, Target::Wasi);
+        assert_eq!(
+            evidence,
+            vec!["import module wasi_snapshot_preview1 (1 import)"]
+        );
+
+        let (target, _) = target_of(&format!(r#"(module {wasi} (func (export "mark_used")))"#));
+        assert_eq!(target, Target::Stylus);
+    }
+
+    #[test]
+    fn generic_env_module_stays_unknown() {
+        let (target, evidence) = target_of(
+            r#"(module
+                 (import "env" "abort" (func (param i32 i32 i32 i32)))
+                 (func (export "main")))"#,
+        );
+        assert_eq!(target, Target::Unknown);
+        assert!(evidence.is_empty());
+    }
+
+    #[test]
+    fn selected_profile_overrides_detection() {
+        // A Stylus-looking module without memory, a start function or floats.
+        let wasm = wat::parse_str(
+            r#"(module
+                 (func $init)
+                 (start $init)
+                 (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))"#,
+        )
+        .unwrap();
+        let analysis = |target| {
+            let opts = ParseOptions {
+                target,
+                ..Default::default()
+            };
+            parse_wasm_with_options(&wasm, &opts).unwrap().analysis
+        };
+
+        let auto = analysis(TargetSelection::Auto);
+        assert_eq!(auto.target, Target::Stylus);
+        assert!(auto.warnings.len() > 1);
+
+        // The generic profile keeps only the universal warning.
+        let generic = analysis(TargetSelection::Generic);
+        assert_eq!(generic.target, Target::Unknown);
+        assert_eq!(generic.target_evidence, vec!["selected explicitly"]);
+        assert_eq!(
+            generic.warnings,
+            vec!["no memory section or imported memory detected"]
+        );
+        assert!(generic.stylus.is_none());
+
+        let wasi = analysis(TargetSelection::Wasi);
+        assert_eq!(wasi.target, Target::Wasi);
+        assert!(
+            wasi.warnings
+                .contains(&"WASI target: required export 'memory' is missing".to_string())
+        );
+    }
+
+    #[test]
+    fn profiles_describe_their_targets() {
+        let opts = ParseOptions::default();
+
+        let stylus = profile_for(Target::Stylus, &opts);
+        assert_eq!(
+            stylus.required_exports(),
+            &[RequiredExport {
+                name: "user_entrypoint",
+                kind: "func"
+            }]
+        );
+        assert_eq!(
+            stylus.import_allowlist(),
+            Some(&["console", "vm_hooks"][..])
+        );
+        assert!(!stylus.feature_policy().floats);
+        assert_eq!(
+            stylus.allowed_features(),
+            &["bulk_memory", "sign_extension"]
+        );
+
+        let wasi = profile_for(Target::Wasi, &opts);
+        assert!(
+            wasi.import_allowlist()
+                .unwrap()
+                .contains(&"wasi_snapshot_preview1")
+        );
+
+        let generic = profile_for(Target::Unknown, &opts);
+        assert!(generic.required_exports().is_empty());
+        assert_eq!(generic.import_allowlist(), None);
+        assert_eq!(generic.feature_policy(), FeaturePolicy::PERMISSIVE);
+        assert_eq!(generic.allowed_features(), BASELINE_FEATURES);
+    }
+}
@@ -0,0 +1,196 @@
+//! Entrypoint reachability for SEBI.
+//!
+//! Compilers routinely leave dead functions in the artifact (panic
+//! formatting is the classic case). This module determines which defined
+//! functions can run at all and re-aggregates instruction facts over just
+//! those, so reachable-only signals can be reported next to module-wide ones.
+//!
+//! Entrypoints:
+//! - Stylus modules (exporting `user_entrypoint`): that export only
+//! - otherwise: every exported function
+//! - in both cases, the Start section function if present
+//!
+//! Edges are direct calls; indirect calls reach every table-referenced
+//! function (see `callgraph::reachable_from`).
+
+use std::collections::BTreeSet;
+
+use crate::wasm::callgraph::{self, MAX_LISTED_FUNCTIONS};
+use crate::wasm::scan::{self, FunctionFacts, InstructionFacts};
+use crate::wasm::sections::SectionFacts;
+
+/// Export name of the Stylus contract entrypoint.
+pub const STYLUS_ENTRYPOINT: &str = "user_entrypoint";
+
+/// Reachability facts and instruction facts restricted to reachable code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReachabilityFacts {
+    /// True if roots were limited to `user_entrypoint` (Stylus mode).
+    pub stylus_entrypoint: bool,
+
+    /// Root function indices, ascending.
+    pub entrypoints: Vec<u32>,
+
+    /// Defined functions reachable from the entrypoints.
+    pub reachable_function_count: u32,
+
+    /// Defined functions not reachable from any entrypoint.
+    pub dead_function_count: u32,
+
+    /// Dead function indices, ascending (capped at `MAX_LISTED_FUNCTIONS`).
+    pub dead_functions: Vec<u32>,
+
+    /// Instruction facts aggregated over reachable functions only.
+    pub instructions: InstructionFacts,
+}
+
+/// Computes reachability and reachable-only instruction facts.
+///
+/// `partials` are the per-body facts from `scan::scan_bodies`, in Code
+/// section order.
+pub fn analyze(
+    sections: &SectionFacts,
+    partials: &[InstructionFacts],
+    collect_histogram: bool,
+    site_limit: usize,
+) -> ReachabilityFacts {
+    let stylus_entry = sections.function_index_for_export(STYLUS_ENTRYPOINT);
+
+    let mut entrypoints: BTreeSet<u32> = match stylus_entry {
+        Some(index) => [index].into(),
+        None => sections
+            .exports
+            .iter()
+            .filter(|e| e.kind == "func")
+            .map(|e| e.index)
+            .collect(),
+    };
+    entrypoints.extend(sections.start_function);
+
+    let functions: Vec<FunctionFacts> = partials
+        .iter()
+        .flat_map(|p| p.functions.iter().cloned())
+        .collect();
+    let reachable = callgraph::reachable_from(
+        &functions,
+        entrypoints.iter().copied(),
+        &sections.table_functions,
+    );
+
+    let mut instructions = scan::merge_partials(
+        partials
+            .iter()
+            .filter(|p| p.functions.iter().all(|f| reachable.contains(&f.index)))
+            .cloned(),
+        collect_histogram,
+        site_limit,
+    );
+
+    // Dead functions become isolated nodes, so cycles among them (and their
+    // indirect calls) do not count as reachable recursion.
+    let masked: Vec<FunctionFacts> = functions
+        .iter()
+        .map(|f| {
+            if reachable.contains(&f.index) {
+                f.clone()
+            } else {
+                FunctionFacts {
+                    index: f.index,
+                    ..Default::default()
+                }
+            }
+        })
+        .collect();
+    instructions.recursion = callgraph::find_recursion(&masked);
+
+    let dead: Vec<u32> = functions
+        .iter()
+        .map(|f| f.index)
+        .filter(|i| !reachable.contains(i))
+        .collect();
+
+    ReachabilityFacts {
+        stylus_entrypoint: stylus_entry.is_some(),
+        entrypoints: entrypoints.into_iter().collect(),
+        reachable_function_count: reachable.len() as u32,
+        dead_function_count: dead.len() as u32,
+        dead_functions: dead.into_iter().take(MAX_LISTED_FUNCTIONS).collect(),
+        instructions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::parse::{ParseOptions, parse_wasm_with_options};
+
+    fn reachability(wat: &str) -> ReachabilityFacts {
+        let wasm = wat::parse_str(wat).unwrap();
+        let opts = ParseOptions {
+            reachability: true,
+            ..Default::default()
+        };
+        parse_wasm_with_options(&wasm, &opts)
+            .unwrap()
+            .reachability
+            .expect("reachability requested")
+    }
+
+    #[test]
+    fn dead_code_excluded_from_reachable_facts() {
+        let facts = reachability(
+            r#"
+            (module
+              (memory 1)
+              (func $main (export "main") (call $used))
+              (func $used (loop (nop)))
+              (func $panic_fmt (drop (memory.grow (i32.const 1))) (call $panic_fmt)))
+            "#,
+        );
+
+        assert!(!facts.stylus_entrypoint);
+        assert_eq!(facts.entrypoints, vec![0]);
+        assert_eq!(facts.reachable_function_count, 2);
+        assert_eq!(facts.dead_functions, vec![2]);
+        assert_eq!(facts.instructions.loop_count, 1);
+        assert!(!facts.instructions.has_memory_grow);
+        assert!(!facts.instructions.recursion.has_recursion());
+    }
+
+    #[test]
+    fn stylus_mode_roots_at_user_entrypoint_only() {
+        let facts = reachability(
+            r#"
+            (module
+              (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0))
+              (func (export "mark_used") (loop (nop))))
+            "#,
+        );
+
+        assert!(facts.stylus_entrypoint);
+        assert_eq!(facts.entrypoints, vec![0]);
+        assert_eq!(facts.dead_functions, vec![1]);
+        assert!(!facts.instructions.has_loop);
+    }
+
+    #[test]
+    fn indirect_calls_keep_table_functions_and_start_is_a_root() {
+        let facts = reachability(
+            r#"
+            (module
+              (type $t (func))
+              (table 1 funcref)
+              (elem (i32.const 0) $target)
+              (func $init (call_indirect (type $t) (i32.const 0)))
+              (func $target (loop (nop)))
+              (func $dead (loop (nop)))
+              (start $init))
+            "#,
+        );
+
+        assert_eq!(facts.entrypoints, vec![0]);
+        assert_eq!(facts.dead_functions, vec![2]);
+        assert_eq!(facts.instructions.loop_count, 1);
+        assert_eq!(facts.instructions.call_indirect_count, 1);
+    }
+}
@@ -24,6 +24,10 @@ pub struct ArtifactContext {
 
     /// Hex-encoded hash of the artifact bytes.
     pub hash_hex: String,
+
+    /// Brotli-compressed size, as Stylus measures it at activation.
+    /// `None` unless built with the `stylus-size` feature.
+    pub compressed_size_bytes: Option<u64>,
 }
 
 impl ArtifactContext {
@@ -34,6 +38,7 @@ impl ArtifactContext {
         ArtifactInfo {
             path: self.path,
             size_bytes: self.size_bytes,
+            compressed_size_bytes: self.compressed_size_bytes,
             hash: ArtifactHash {
                 algorithm: self.hash_alg,
                 value: self.hash_hex,
@@ -58,12 +63,43 @@ pub fn read_artifact(path: &Path) -> Result<ArtifactContext> {
     Ok(ArtifactContext {
         path: Some(path.display().to_string()),
         size_bytes: bytes.len() as u64,
+        compressed_size_bytes: compressed_size(&bytes),
         bytes,
         hash_alg: "sha256".to_string(),
         hash_hex: hex::encode(digest),
     })
 }
 
+/// Brotli quality used by cargo-stylus when preparing a program for deployment.
+#[cfg(feature = "stylus-size")]
+pub const BROTLI_QUALITY: i32 = 11;
+
+/// Brotli window size (log2 bytes) used by cargo-stylus.
+#[cfg(feature = "stylus-size")]
+pub const BROTLI_WINDOW: i32 = 22;
+
+/// Size of `bytes` after brotli compression with fixed parameters.
+///
+/// The parameters never vary with the environment, so the figure is as
+/// reproducible as the hash.
+#[cfg(feature = "stylus-size")]
+pub fn compressed_size(bytes: &[u8]) -> Option<u64> {
+    let params = brotli::enc::BrotliEncoderParams {
+        quality: BROTLI_QUALITY,
+        lgwin: BROTLI_WINDOW,
+        ..Default::default()
+    };
+    let mut out = Vec::new();
+    brotli::BrotliCompress(&mut &bytes[..], &mut out, &params).ok()?;
+    Some(out.len() as u64)
+}
+
+/// Compression is unavailable without the `stylus-size` feature.
+#[cfg(not(feature = "stylus-size"))]
+pub fn compressed_size(_bytes: &[u8]) -> Option<u64> {
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,10 +158,40 @@ mod tests {
             size_bytes: 4,
             hash_alg: "sha256".into(),
             hash_hex: "abcd".into(),
+            compressed_size_bytes: Some(3),
         };
 
         let artifact = ctx.into_artifact();
         assert_eq!(artifact.path, Some("test.wasm".into()));
         assert_eq!(artifact.hash.value, "abcd");
+        assert_eq!(artifact.compressed_size_bytes, Some(3));
+    }
+
+    #[cfg(feature = "stylus-size")]
+    #[test]
+    fn compressed_size_is_deterministic_and_tracks_entropy() {
+        let repetitive = vec![0u8; 64 * 1024];
+        let size = compressed_size(&repetitive).unwrap();
+        assert_eq!(compressed_size(&repetitive), Some(size));
+        assert!(size < 100, "zeros compress to {size} bytes");
+
+        // xorshift noise is effectively incompressible
+        let mut state = 0x2545_f491_u32;
+        let noise: Vec<u8> = (0..64 * 1024)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+        assert!(compressed_size(&noise).unwrap() > 60 * 1024);
+    }
+
+    #[cfg(not(feature = "stylus-size"))]
+    #[test]
+    fn compressed_size_absent_without_feature() {
+        let ctx = read_artifact(temp_artifact(b"data").path()).unwrap();
+        assert_eq!(ctx.compressed_size_bytes, None);
     }
 }
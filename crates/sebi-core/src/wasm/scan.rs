@@ -1,5 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use anyhow::Result;
-use wasmparser::{FunctionBody, Operator};
+use wasmparser::{FunctionBody, MemArg, Operator, VisitOperator, VisitSimdOperator};
+
+use crate::wasm::callgraph::RecursionFacts;
+use crate::wasm::operators::{self, OperatorInfo};
+use crate::wasm::sections::{SectionFacts, TypeFacts};
+use crate::wasm::stack::StackEstimator;
+use crate::wasm::{control::ControlStack, cost};
+
+/// Upper bound on the number of distinct callee indices tracked per module.
+///
+/// Keeps memory bounded for pathological modules; once reached, further
+/// callees are counted but not recorded and `distinct_callees_capped` is set.
+pub const MAX_TRACKED_CALLEES: usize = 65_536;
+
+/// Upper bound on distinct `call_indirect` type/table indices tracked per module.
+pub const MAX_TRACKED_INDIRECT_INDICES: usize = 4_096;
+
+/// Declared-locals count above which a function is considered locals-heavy.
+///
+/// Some hosts reject or slow-path functions with very large frames.
+pub const LARGE_LOCALS_THRESHOLD: u64 = 256;
+
+/// Default number of byte offsets recorded per tracked operator kind.
+pub const DEFAULT_SITE_LIMIT: usize = 16;
+
+/// Number of functions reported by per-function rankings (e.g. trap-heaviest).
+pub const MAX_RANKED_FUNCTIONS: usize = 8;
+
+/// Default static `memarg` offset (bytes) above which a load/store is
+/// considered a large-offset access, regardless of declared memory size.
+pub const DEFAULT_LARGE_OFFSET_THRESHOLD: u64 = 1 << 20;
+
+/// Number of leading operators of a body inspected for selector dispatch.
+///
+/// Dispatchers load the selector and branch on it before doing any real
+/// work, so only the top of the body is considered.
+pub const DISPATCH_WINDOW: usize = 256;
+
+/// Size of a WebAssembly memory page in bytes.
+const WASM_PAGE_SIZE: u64 = 65_536;
+
+/// Module-level context required while scanning function bodies.
+///
+/// Populated from sections that precede the Code section so the scan can
+/// interpret operator immediates (e.g. function indices) without
+/// reaching back into section parsing.
+#[derive(Debug, Clone)]
+pub struct ScanContext {
+    /// Number of imported functions; these occupy the lowest function indices.
+    pub imported_function_count: u32,
+
+    /// Maximum byte offsets recorded per tracked operator kind.
+    pub site_limit: usize,
+
+    /// Module types, used to resolve operator arities.
+    pub types: TypeFacts,
+
+    /// Declared initial size of memory index 0 in bytes, if it exists.
+    pub initial_memory_bytes: Option<u64>,
+
+    /// Static offset above which any memory access counts as large.
+    pub large_offset_threshold: u64,
+}
+
+impl Default for ScanContext {
+    fn default() -> Self {
+        Self {
+            imported_function_count: 0,
+            site_limit: DEFAULT_SITE_LIMIT,
+            types: TypeFacts::default(),
+            initial_memory_bytes: None,
+            large_offset_threshold: DEFAULT_LARGE_OFFSET_THRESHOLD,
+        }
+    }
+}
+
+impl ScanContext {
+    /// Builds the scan context from section facts gathered so far.
+    pub fn from_sections(sections: &SectionFacts) -> Self {
+        Self {
+            imported_function_count: sections.imported_function_count,
+            types: sections.types.clone(),
+            initial_memory_bytes: sections
+                .memory_min_pages
+                .map(|pages| pages.saturating_mul(WASM_PAGE_SIZE)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Location of a single flagged instruction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstructionSite {
+    /// Module-level index of the containing function.
+    pub function_index: u32,
+
+    /// Module-relative byte offset of the opcode, as printed by
+    /// `wasm-objdump -d` / `wasm-tools print --print-offsets`.
+    pub offset: u64,
+}
 
 /// Aggregated facts about WASM instructions that affect execution boundaries.
 ///
@@ -7,8 +108,21 @@ use wasmparser::{FunctionBody, Operator};
 /// for instructions that complicate static reasoning:
 ///
 /// - `memory.grow`   → dynamic memory expansion
+/// - `memory.size`   → runtime memory introspection (allocator pattern)
 /// - `call_indirect` → dynamic control flow
 /// - `loop`          → potentially unbounded execution
+/// - `call`          → static call-graph size and fan-out
+/// - `f32.*`/`f64.*` → non-deterministic floating point
+/// - `*.atomic.*`    → shared-memory concurrency
+/// - `v128`/`*x*.*`  → SIMD vector operations
+/// - `memory.copy`/`memory.fill`/`memory.init` → operand-sized memory writes
+/// - `table.set`/`table.grow`/`table.copy`/`table.fill` → runtime table mutation
+/// - `unreachable`   → trap density (panicking codegen)
+/// - `br_table`      → switch/dispatcher fan-out
+/// - `return_call*`  → tail calls (recursion without stack growth or `loop`)
+/// - `try`/`throw`/... → exception handling (non-local control flow)
+/// - `loop` + `br 0` with no exit → statically evident infinite loop
+///   (see `wasm::control`)
 ///
 /// These facts are **pure observations**:
 /// - no interpretation
@@ -18,12 +132,405 @@ use wasmparser::{FunctionBody, Operator};
 pub struct InstructionFacts {
     pub has_memory_grow: bool,
     pub memory_grow_count: u64,
+    /// `memory.grow` immediately preceded by a literal `i32.const`/`i64.const`.
+    pub memory_grow_const_count: u64,
+    /// `memory.grow` whose operand comes from anything else (computed at runtime).
+    pub memory_grow_dynamic_count: u64,
+    /// Sum of the literal operands of constant `memory.grow`s, in pages
+    /// (saturating).
+    pub memory_grow_const_pages: u64,
+    /// `memory.grow` nested inside at least one `loop` frame.
+    pub memory_grow_in_loop_count: u64,
+
+    pub has_memory_size: bool,
+    pub memory_size_count: u64,
 
     pub has_call_indirect: bool,
     pub call_indirect_count: u64,
+    /// Distinct `type_index` immediates of `call_indirect`
+    /// (bounded by `MAX_TRACKED_INDIRECT_INDICES`).
+    pub call_indirect_types: BTreeSet<u32>,
+    /// Distinct `table_index` immediates of `call_indirect`.
+    pub call_indirect_tables: BTreeSet<u32>,
+    /// True if either indirect index set reached its cap and is a lower bound.
+    pub call_indirect_indices_capped: bool,
 
     pub has_loop: bool,
     pub loop_count: u64,
+    /// Loops with an unconditional back-edge and no observable exit.
+    pub suspicious_infinite_loop_count: u64,
+
+    pub has_tail_call: bool,
+    /// `return_call` occurrences (direct tail calls).
+    pub return_call_count: u64,
+    /// `return_call_indirect` / `return_call_ref` occurrences.
+    pub return_call_indirect_count: u64,
+
+    /// Any exception-handling operator (legacy `try`/`catch`/`delegate`
+    /// or `try_table`/`throw_ref`) is present.
+    pub has_exception_ops: bool,
+    /// All exception-handling operators.
+    pub exception_op_count: u64,
+    /// `try` and `try_table` blocks.
+    pub try_count: u64,
+    /// `throw`, `throw_ref` and `rethrow`.
+    pub throw_count: u64,
+
+    /// Total number of direct `call` instructions.
+    pub call_count: u64,
+    /// Direct calls whose target is an imported function.
+    pub call_import_count: u64,
+    /// Direct calls whose target is a defined function.
+    pub call_defined_count: u64,
+    /// Distinct direct-call target indices (bounded by `MAX_TRACKED_CALLEES`).
+    pub distinct_callees: BTreeSet<u32>,
+    /// True if the callee set reached its cap and is a lower bound.
+    pub distinct_callees_capped: bool,
+
+    pub has_float: bool,
+    /// Scalar float operators other than constants (arithmetic, comparison,
+    /// conversion, float loads/stores).
+    pub float_op_count: u64,
+    /// `f32.const` / `f64.const` occurrences.
+    pub float_const_count: u64,
+
+    pub has_atomics: bool,
+    /// Atomic loads/stores/RMW, wait/notify, and `atomic.fence`.
+    pub atomic_op_count: u64,
+
+    pub has_simd: bool,
+    /// Vector operators (`simd` and `relaxed_simd` proposals).
+    pub simd_op_count: u64,
+
+    /// Any bulk-memory operator below is present.
+    pub has_bulk_memory: bool,
+    pub memory_copy_count: u64,
+    pub memory_fill_count: u64,
+    pub memory_init_count: u64,
+    pub data_drop_count: u64,
+    pub table_init_count: u64,
+
+    /// Any table-mutating operator below is present (`table.get` excluded).
+    pub has_table_mutation: bool,
+    pub table_set_count: u64,
+    pub table_grow_count: u64,
+    pub table_copy_count: u64,
+    pub table_fill_count: u64,
+
+    /// `unreachable` occurrences (explicit traps).
+    pub unreachable_count: u64,
+
+    pub br_table_count: u64,
+    /// Largest `br_table` target list observed (excluding the default label).
+    pub br_table_max_targets: u32,
+
+    /// Largest number of declared locals in any single function (params excluded).
+    pub max_locals: u64,
+    /// Functions declaring more than `LARGE_LOCALS_THRESHOLD` locals.
+    pub large_locals_function_count: u32,
+
+    /// Highest estimated operand-stack depth in any function (see `wasm::stack`).
+    pub max_estimated_stack_depth: u32,
+
+    /// Loads/stores/atomics whose static `memarg` offset exceeds the initial
+    /// size of memory 0 or the configured threshold (raw pointer arithmetic,
+    /// fixed scratch regions).
+    pub large_offset_access_count: u64,
+    /// Largest static `memarg` offset on any memory access.
+    pub max_static_offset: u64,
+
+    /// Sum of static operator weights across all bodies (see `wasm::cost`).
+    pub estimated_static_cost: u64,
+
+    /// Opt-in count of every operator by `wasmparser` variant name.
+    ///
+    /// `None` unless requested; keys come from a finite static set, so the
+    /// map is bounded by the number of known operators.
+    pub histogram: Option<BTreeMap<&'static str, u64>>,
+
+    /// Operators outside the MVP, counted by originating proposal
+    /// (`wasmparser` spelling, e.g. `"sign_extension"`).
+    pub proposal_op_counts: BTreeMap<&'static str, u64>,
+
+    /// First `site_limit` `memory.grow` locations, in module order.
+    pub memory_grow_sites: Vec<InstructionSite>,
+    /// First `site_limit` `memory.grow` locations inside a `loop`, in module order.
+    pub memory_grow_in_loop_sites: Vec<InstructionSite>,
+    /// First `site_limit` `call_indirect` locations, in module order.
+    pub call_indirect_sites: Vec<InstructionSite>,
+    /// First `site_limit` `loop` locations, in module order.
+    pub loop_sites: Vec<InstructionSite>,
+
+    /// Per-function facts in defined-function order (Code section order).
+    pub functions: Vec<FunctionFacts>,
+
+    /// Recursion derived from the direct call graph once all bodies are scanned.
+    pub recursion: RecursionFacts,
+}
+
+/// Facts about a single defined function body.
+///
+/// Entries are appended in Code section order, so the position of an entry
+/// equals its defined-function index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunctionFacts {
+    /// Module-level function index (imported functions come first).
+    pub index: u32,
+
+    /// Distinct direct-call targets (`call` and `return_call`), ascending.
+    pub callees: Vec<u32>,
+
+    /// `memory.grow` occurrences in this body.
+    pub memory_grow_count: u64,
+
+    /// `memory.grow` occurrences nested inside a `loop` in this body.
+    pub memory_grow_in_loop_count: u64,
+
+    /// `call_indirect` occurrences in this body (tail calls excluded).
+    pub call_indirect_count: u64,
+
+    /// `loop` occurrences in this body.
+    pub loop_count: u64,
+
+    /// Whether the body contains `call_indirect` or an indirect tail call
+    /// (an unknown call edge).
+    pub has_call_indirect: bool,
+
+    /// `unreachable` occurrences in this body.
+    pub unreachable_count: u64,
+
+    /// Sum of static operator weights for this body (see `wasm::cost`).
+    pub static_cost: u64,
+
+    /// Declared locals, summed over all local groups (params excluded).
+    pub local_count: u64,
+
+    /// Encoded body size in bytes, local declarations included.
+    pub body_size: u64,
+
+    /// Deepest `loop` nesting in this body (0 without loops).
+    pub max_loop_depth: u32,
+
+    /// Estimated maximum operand-stack depth (see `wasm::stack`).
+    pub max_stack_depth: u32,
+
+    /// Estimated selector-dispatch branches at the top of the body
+    /// (see `DispatchScan`).
+    pub dispatch_branches: u32,
+}
+
+impl InstructionFacts {
+    /// Ranks functions by `metric`, highest first, ties broken by ascending
+    /// index. Functions with a zero metric are omitted; at most `limit` are
+    /// returned as `(function_index, value)` pairs.
+    pub fn rank_functions(
+        &self,
+        limit: usize,
+        metric: impl Fn(&FunctionFacts) -> u64,
+    ) -> Vec<(u32, u64)> {
+        let mut ranked: Vec<(u32, u64)> = self
+            .functions
+            .iter()
+            .map(|f| (f.index, metric(f)))
+            .filter(|&(_, value)| value > 0)
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Folds the facts of later function bodies into `self`.
+    ///
+    /// Counts add, flags OR, maxima combine, capped sets and site lists are
+    /// filled with the same limits as the serial scan, and `other`'s
+    /// functions are appended. Merging per-body facts in Code section order
+    /// therefore reproduces the serial result. `recursion` is not merged; it
+    /// is derived from the complete function list afterwards.
+    pub fn merge(&mut self, other: InstructionFacts, site_limit: usize) {
+        self.has_memory_grow |= other.has_memory_grow;
+        self.memory_grow_count += other.memory_grow_count;
+        self.memory_grow_const_count += other.memory_grow_const_count;
+        self.memory_grow_dynamic_count += other.memory_grow_dynamic_count;
+        self.memory_grow_const_pages = self
+            .memory_grow_const_pages
+            .saturating_add(other.memory_grow_const_pages);
+        self.memory_grow_in_loop_count += other.memory_grow_in_loop_count;
+        self.has_memory_size |= other.has_memory_size;
+        self.memory_size_count += other.memory_size_count;
+
+        self.has_call_indirect |= other.has_call_indirect;
+        self.call_indirect_count += other.call_indirect_count;
+        self.call_indirect_indices_capped |= other.call_indirect_indices_capped;
+        for (set, incoming) in [
+            (&mut self.call_indirect_types, other.call_indirect_types),
+            (&mut self.call_indirect_tables, other.call_indirect_tables),
+        ] {
+            for index in incoming {
+                if set.len() < MAX_TRACKED_INDIRECT_INDICES {
+                    set.insert(index);
+                } else if !set.contains(&index) {
+                    self.call_indirect_indices_capped = true;
+                }
+            }
+        }
+
+        self.has_loop |= other.has_loop;
+        self.loop_count += other.loop_count;
+        self.suspicious_infinite_loop_count += other.suspicious_infinite_loop_count;
+
+        self.has_tail_call |= other.has_tail_call;
+        self.return_call_count += other.return_call_count;
+        self.return_call_indirect_count += other.return_call_indirect_count;
+
+        self.has_exception_ops |= other.has_exception_ops;
+        self.exception_op_count += other.exception_op_count;
+        self.try_count += other.try_count;
+        self.throw_count += other.throw_count;
+
+        self.call_count += other.call_count;
+        self.call_import_count += other.call_import_count;
+        self.call_defined_count += other.call_defined_count;
+        self.distinct_callees_capped |= other.distinct_callees_capped;
+        for index in other.distinct_callees {
+            if self.distinct_callees.len() < MAX_TRACKED_CALLEES {
+                self.distinct_callees.insert(index);
+            } else if !self.distinct_callees.contains(&index) {
+                self.distinct_callees_capped = true;
+            }
+        }
+
+        self.has_float |= other.has_float;
+        self.float_op_count += other.float_op_count;
+        self.float_const_count += other.float_const_count;
+        self.has_atomics |= other.has_atomics;
+        self.atomic_op_count += other.atomic_op_count;
+        self.has_simd |= other.has_simd;
+        self.simd_op_count += other.simd_op_count;
+
+        self.has_bulk_memory |= other.has_bulk_memory;
+        self.memory_copy_count += other.memory_copy_count;
+        self.memory_fill_count += other.memory_fill_count;
+        self.memory_init_count += other.memory_init_count;
+        self.data_drop_count += other.data_drop_count;
+        self.table_init_count += other.table_init_count;
+
+        self.has_table_mutation |= other.has_table_mutation;
+        self.table_set_count += other.table_set_count;
+        self.table_grow_count += other.table_grow_count;
+        self.table_copy_count += other.table_copy_count;
+        self.table_fill_count += other.table_fill_count;
+
+        self.unreachable_count += other.unreachable_count;
+        self.br_table_count += other.br_table_count;
+        self.br_table_max_targets = self.br_table_max_targets.max(other.br_table_max_targets);
+        self.max_locals = self.max_locals.max(other.max_locals);
+        self.large_locals_function_count += other.large_locals_function_count;
+        self.max_estimated_stack_depth = self
+            .max_estimated_stack_depth
+            .max(other.max_estimated_stack_depth);
+        self.large_offset_access_count += other.large_offset_access_count;
+        self.max_static_offset = self.max_static_offset.max(other.max_static_offset);
+        self.estimated_static_cost = self
+            .estimated_static_cost
+            .saturating_add(other.estimated_static_cost);
+
+        if let (Some(into), Some(from)) = (self.histogram.as_mut(), other.histogram) {
+            for (name, count) in from {
+                *into.entry(name).or_default() += count;
+            }
+        }
+        for (proposal, count) in other.proposal_op_counts {
+            *self.proposal_op_counts.entry(proposal).or_default() += count;
+        }
+
+        for (sites, incoming) in [
+            (&mut self.memory_grow_sites, other.memory_grow_sites),
+            (
+                &mut self.memory_grow_in_loop_sites,
+                other.memory_grow_in_loop_sites,
+            ),
+            (&mut self.call_indirect_sites, other.call_indirect_sites),
+            (&mut self.loop_sites, other.loop_sites),
+        ] {
+            for site in incoming {
+                Self::record_site(sites, site_limit, site);
+            }
+        }
+
+        self.functions.extend(other.functions);
+    }
+
+    /// Appends `site` to `sites` unless the per-kind limit is reached.
+    fn record_site(sites: &mut Vec<InstructionSite>, limit: usize, site: InstructionSite) {
+        if sites.len() < limit {
+            sites.push(site);
+        }
+    }
+
+    /// Records the static offset of a memory access.
+    fn record_memory_offset(&mut self, ctx: &ScanContext, memarg: MemArg) {
+        self.max_static_offset = self.max_static_offset.max(memarg.offset);
+
+        let beyond_initial = memarg.memory == 0
+            && ctx
+                .initial_memory_bytes
+                .is_some_and(|bytes| memarg.offset > bytes);
+        if beyond_initial || memarg.offset > ctx.large_offset_threshold {
+            self.large_offset_access_count += 1;
+        }
+    }
+
+    /// Counts an operator by family: float, atomic or SIMD.
+    fn record_family(&mut self, info: &OperatorInfo) {
+        if info.is_float_const() {
+            self.has_float = true;
+            self.float_const_count += 1;
+        } else if info.is_float() {
+            self.has_float = true;
+            self.float_op_count += 1;
+        } else if info.is_atomic() {
+            self.has_atomics = true;
+            self.atomic_op_count += 1;
+        } else if info.is_simd() {
+            self.has_simd = true;
+            self.simd_op_count += 1;
+        }
+    }
+
+    fn record_exception_op(&mut self) {
+        self.has_exception_ops = true;
+        self.exception_op_count += 1;
+    }
+
+    /// Records the immediates of a `call_indirect`.
+    fn record_call_indirect(&mut self, type_index: u32, table_index: u32) {
+        for (set, index) in [
+            (&mut self.call_indirect_types, type_index),
+            (&mut self.call_indirect_tables, table_index),
+        ] {
+            if set.len() < MAX_TRACKED_INDIRECT_INDICES {
+                set.insert(index);
+            } else if !set.contains(&index) {
+                self.call_indirect_indices_capped = true;
+            }
+        }
+    }
+
+    /// Records a direct call to `function_index`.
+    fn record_call(&mut self, ctx: &ScanContext, function_index: u32) {
+        self.call_count += 1;
+        if function_index < ctx.imported_function_count {
+            self.call_import_count += 1;
+        } else {
+            self.call_defined_count += 1;
+        }
+
+        if self.distinct_callees.len() < MAX_TRACKED_CALLEES {
+            self.distinct_callees.insert(function_index);
+        } else if !self.distinct_callees.contains(&function_index) {
+            self.distinct_callees_capped = true;
+        }
+    }
 }
 
 /// Scans a single WASM function body and updates instruction facts.
@@ -33,30 +540,574 @@ pub struct InstructionFacts {
 /// - does not build a control-flow graph
 /// - does not attempt to reason about termination or semantics
 ///
+/// Operators are dispatched through `wasmparser`'s `VisitOperator`, so most
+/// of them never become an `Operator` value: their name, proposal, arity
+/// and `memarg` are known statically per visit method. Only operators that
+/// some part of the scan matches by variant (control flow, calls, tracked
+/// capabilities, constants) are built and handled by
+/// `BodyScan::on_operator`.
+///
 /// This function is designed to be called once per `CodeSectionEntry`
 /// and accumulates results into the provided `InstructionFacts`.
-pub fn on_code_entry(facts: &mut InstructionFacts, body: FunctionBody) -> Result<()> {
+pub fn on_code_entry(
+    facts: &mut InstructionFacts,
+    ctx: &ScanContext,
+    body: FunctionBody,
+) -> Result<()> {
+    let index = ctx
+        .imported_function_count
+        .saturating_add(facts.functions.len() as u32);
+    scan_function(facts, ctx, index, body)
+}
+
+/// Like [`on_code_entry`], but reads every operator into an `Operator`.
+///
+/// The reference the visitor scan is checked against.
+#[cfg(test)]
+fn on_code_entry_decoded(
+    facts: &mut InstructionFacts,
+    ctx: &ScanContext,
+    body: FunctionBody,
+) -> Result<()> {
+    let index = ctx
+        .imported_function_count
+        .saturating_add(facts.functions.len() as u32);
+    let mut scan = BodyScan::new(facts, ctx, index, &body)?;
     let mut reader = body.get_operators_reader()?;
+    while !reader.eof() {
+        let offset = reader.original_position() as u64;
+        let op = reader.read()?;
+        scan.on_operator(facts, &op, offset);
+    }
+    scan.finish(facts);
+    Ok(())
+}
+
+/// Scans each Code section body into its own `InstructionFacts`.
+///
+/// Partial results are returned in Code section order; fold them with
+/// [`merge_partials`] to obtain the module-wide facts, identical to calling
+/// `on_code_entry` for each body in turn. With the `parallel` feature and
+/// `parallel` set, bodies are scanned on the rayon thread pool.
+/// `collect_histogram` mirrors whether the serial accumulator was created
+/// with a histogram.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+pub fn scan_bodies(
+    ctx: &ScanContext,
+    bodies: Vec<FunctionBody>,
+    collect_histogram: bool,
+    parallel: bool,
+) -> Result<Vec<InstructionFacts>> {
+    let scan_one = |(i, body): (usize, FunctionBody)| {
+        let mut facts = InstructionFacts {
+            histogram: collect_histogram.then(Default::default),
+            ..Default::default()
+        };
+        let index = ctx.imported_function_count.saturating_add(i as u32);
+        scan_function(&mut facts, ctx, index, body)?;
+        Ok(facts)
+    };
+
+    // Collect every result before propagating, so the reported error is the
+    // first failing body in module order, as in the serial scan.
+    #[cfg(feature = "parallel")]
+    if parallel {
+        use rayon::prelude::*;
+        let partials: Vec<Result<InstructionFacts>> =
+            bodies.into_par_iter().enumerate().map(scan_one).collect();
+        return partials.into_iter().collect();
+    }
+
+    bodies.into_iter().enumerate().map(scan_one).collect()
+}
 
+/// Merges per-body facts (see [`scan_bodies`]) in the order given.
+pub fn merge_partials(
+    partials: impl IntoIterator<Item = InstructionFacts>,
+    collect_histogram: bool,
+    site_limit: usize,
+) -> InstructionFacts {
+    let mut facts = InstructionFacts {
+        histogram: collect_histogram.then(Default::default),
+        ..Default::default()
+    };
+    for partial in partials {
+        facts.merge(partial, site_limit);
+    }
+    facts
+}
+
+fn scan_function(
+    facts: &mut InstructionFacts,
+    ctx: &ScanContext,
+    index: u32,
+    body: FunctionBody,
+) -> Result<()> {
+    let scan = BodyScan::new(facts, ctx, index, &body)?;
+    let mut visitor = ScanVisitor {
+        facts,
+        scan,
+        offset: 0,
+    };
+    let mut reader = body.get_operators_reader()?;
     while !reader.eof() {
-        match reader.read()? {
+        visitor.offset = reader.original_position() as u64;
+        reader.visit_operator(&mut visitor)?;
+    }
+    let ScanVisitor { facts, scan, .. } = visitor;
+    scan.finish(facts);
+    Ok(())
+}
+
+/// Per-body state of the scan, fed one operator at a time.
+struct BodyScan<'c> {
+    ctx: &'c ScanContext,
+    func: FunctionFacts,
+    callees: BTreeSet<u32>,
+    control: ControlStack,
+    stack: StackEstimator<'c>,
+    /// Peephole state: the literal pushed by the previous operator, if any.
+    prev_const: Option<u64>,
+    dispatch: DispatchScan,
+}
+
+impl<'c> BodyScan<'c> {
+    /// Starts scanning `body`, recording its locals in `facts`.
+    fn new(
+        facts: &mut InstructionFacts,
+        ctx: &'c ScanContext,
+        index: u32,
+        body: &FunctionBody,
+    ) -> Result<Self> {
+        let func = FunctionFacts {
+            index,
+            local_count: count_locals(body)?,
+            body_size: body.range().len() as u64,
+            ..Default::default()
+        };
+        facts.max_locals = facts.max_locals.max(func.local_count);
+        if func.local_count > LARGE_LOCALS_THRESHOLD {
+            facts.large_locals_function_count += 1;
+        }
+
+        Ok(Self {
+            ctx,
+            func,
+            callees: BTreeSet::new(),
+            control: ControlStack::new(),
+            stack: StackEstimator::new(ctx, index),
+            prev_const: None,
+            dispatch: DispatchScan::default(),
+        })
+    }
+
+    /// Handles one operator at module-relative byte `offset`.
+    fn on_operator(&mut self, facts: &mut InstructionFacts, op: &Operator, offset: u64) {
+        let ctx = self.ctx;
+        let func = &mut self.func;
+        let site = InstructionSite {
+            function_index: func.index,
+            offset,
+        };
+        func.static_cost = func.static_cost.saturating_add(cost::weight(op, ctx));
+        self.control.on_operator(op, ctx);
+        self.stack.on_operator(op);
+        self.dispatch.on_operator(op);
+        // Literals are read as unsigned, matching `memory.grow`'s operand.
+        let literal = match *op {
+            Operator::I32Const { value } => Some(u64::from(value as u32)),
+            Operator::I64Const { value } => Some(value as u64),
+            _ => None,
+        };
+        if let Some(memarg) = operators::memarg(op) {
+            facts.record_memory_offset(ctx, memarg);
+        }
+        let info = operators::describe(op);
+        if let Some(histogram) = facts.histogram.as_mut() {
+            *histogram.entry(info.name).or_default() += 1;
+        }
+        if info.proposal != "mvp" {
+            *facts.proposal_op_counts.entry(info.proposal).or_default() += 1;
+        }
+
+        match *op {
             Operator::MemoryGrow { .. } => {
                 facts.has_memory_grow = true;
                 facts.memory_grow_count += 1;
+                func.memory_grow_count += 1;
+                InstructionFacts::record_site(&mut facts.memory_grow_sites, ctx.site_limit, site);
+                if self.control.in_loop() {
+                    facts.memory_grow_in_loop_count += 1;
+                    func.memory_grow_in_loop_count += 1;
+                    InstructionFacts::record_site(
+                        &mut facts.memory_grow_in_loop_sites,
+                        ctx.site_limit,
+                        site,
+                    );
+                }
+                if let Some(pages) = self.prev_const {
+                    facts.memory_grow_const_count += 1;
+                    facts.memory_grow_const_pages =
+                        facts.memory_grow_const_pages.saturating_add(pages);
+                } else {
+                    facts.memory_grow_dynamic_count += 1;
+                }
             }
-            Operator::CallIndirect { .. } => {
+            Operator::MemorySize { .. } => {
+                facts.has_memory_size = true;
+                facts.memory_size_count += 1;
+            }
+            Operator::CallIndirect {
+                type_index,
+                table_index,
+            } => {
                 facts.has_call_indirect = true;
                 facts.call_indirect_count += 1;
+                facts.record_call_indirect(type_index, table_index);
+                func.has_call_indirect = true;
+                func.call_indirect_count += 1;
+                InstructionFacts::record_site(&mut facts.call_indirect_sites, ctx.site_limit, site);
             }
             Operator::Loop { .. } => {
                 facts.has_loop = true;
                 facts.loop_count += 1;
+                func.loop_count += 1;
+                InstructionFacts::record_site(&mut facts.loop_sites, ctx.site_limit, site);
+            }
+            Operator::Call { function_index } => {
+                facts.record_call(ctx, function_index);
+                self.callees.insert(function_index);
+            }
+            Operator::ReturnCall { function_index } => {
+                facts.has_tail_call = true;
+                facts.return_call_count += 1;
+                self.callees.insert(function_index);
+            }
+            Operator::ReturnCallIndirect {
+                type_index,
+                table_index,
+            } => {
+                facts.has_tail_call = true;
+                facts.return_call_indirect_count += 1;
+                facts.record_call_indirect(type_index, table_index);
+                func.has_call_indirect = true;
+            }
+            Operator::ReturnCallRef { .. } => {
+                facts.has_tail_call = true;
+                facts.return_call_indirect_count += 1;
+                func.has_call_indirect = true;
+            }
+            Operator::Try { .. } | Operator::TryTable { .. } => {
+                facts.record_exception_op();
+                facts.try_count += 1;
+            }
+            Operator::Throw { .. } | Operator::ThrowRef | Operator::Rethrow { .. } => {
+                facts.record_exception_op();
+                facts.throw_count += 1;
+            }
+            Operator::Catch { .. } | Operator::CatchAll | Operator::Delegate { .. } => {
+                facts.record_exception_op();
+            }
+            Operator::Unreachable => {
+                facts.unreachable_count += 1;
+                func.unreachable_count += 1;
+            }
+            Operator::BrTable { ref targets } => {
+                facts.br_table_count += 1;
+                facts.br_table_max_targets = facts.br_table_max_targets.max(targets.len());
+            }
+            Operator::MemoryCopy { .. } => {
+                facts.has_bulk_memory = true;
+                facts.memory_copy_count += 1;
+            }
+            Operator::MemoryFill { .. } => {
+                facts.has_bulk_memory = true;
+                facts.memory_fill_count += 1;
+            }
+            Operator::MemoryInit { .. } => {
+                facts.has_bulk_memory = true;
+                facts.memory_init_count += 1;
+            }
+            Operator::DataDrop { .. } => {
+                facts.has_bulk_memory = true;
+                facts.data_drop_count += 1;
+            }
+            Operator::TableInit { .. } => {
+                facts.has_bulk_memory = true;
+                facts.table_init_count += 1;
+            }
+            Operator::TableSet { .. } => {
+                facts.has_table_mutation = true;
+                facts.table_set_count += 1;
+            }
+            Operator::TableGrow { .. } => {
+                facts.has_table_mutation = true;
+                facts.table_grow_count += 1;
+            }
+            Operator::TableCopy { .. } => {
+                facts.has_table_mutation = true;
+                facts.table_copy_count += 1;
+            }
+            Operator::TableFill { .. } => {
+                facts.has_table_mutation = true;
+                facts.table_fill_count += 1;
+            }
+            _ => facts.record_family(&info),
+        }
+
+        self.prev_const = literal;
+    }
+
+    /// Handles an operator none of the scan's parts matches by variant,
+    /// from its static description alone. Equivalent to `on_operator` for
+    /// such operators.
+    fn on_plain_operator(
+        &mut self,
+        facts: &mut InstructionFacts,
+        info: OperatorInfo,
+        (pops, pushes): (u32, u32),
+        memarg: Option<MemArg>,
+    ) {
+        self.func.static_cost = self
+            .func
+            .static_cost
+            .saturating_add(cost::category_weight(&info));
+        self.stack.on_fixed_arity(pops, pushes);
+        self.dispatch.on_plain_operator();
+        if let Some(memarg) = memarg {
+            facts.record_memory_offset(self.ctx, memarg);
+        }
+        if let Some(histogram) = facts.histogram.as_mut() {
+            *histogram.entry(info.name).or_default() += 1;
+        }
+        if info.proposal != "mvp" {
+            *facts.proposal_op_counts.entry(info.proposal).or_default() += 1;
+        }
+        facts.record_family(&info);
+        self.prev_const = None;
+    }
+
+    /// Folds the per-body results into `facts`.
+    fn finish(self, facts: &mut InstructionFacts) {
+        let mut func = self.func;
+        facts.suspicious_infinite_loop_count += u64::from(self.control.suspicious_infinite_loops());
+        func.max_loop_depth = self.control.max_loop_depth();
+        func.max_stack_depth = self.stack.max_height();
+        func.dispatch_branches = self.dispatch.branches();
+        facts.max_estimated_stack_depth = facts.max_estimated_stack_depth.max(func.max_stack_depth);
+        func.callees = self.callees.into_iter().collect();
+        facts.estimated_static_cost = facts.estimated_static_cost.saturating_add(func.static_cost);
+        facts.functions.push(func);
+    }
+}
+
+/// `VisitOperator` front end of [`BodyScan`].
+struct ScanVisitor<'f, 'c> {
+    facts: &'f mut InstructionFacts,
+    scan: BodyScan<'c>,
+    /// Module-relative byte offset of the operator being visited.
+    offset: u64,
+}
+
+impl ScanVisitor<'_, '_> {
+    fn decoded(&mut self, op: Operator) {
+        self.scan.on_operator(self.facts, &op, self.offset);
+    }
+
+    fn plain(&mut self, info: OperatorInfo, arity: (u32, u32), memarg: Option<MemArg>) {
+        self.scan.on_plain_operator(self.facts, info, arity, memarg);
+    }
+}
+
+/// Operators with a fixed arity that `BodyScan::on_operator`, the cost
+/// table, the control or stack tracking, or the dispatch heuristic match by
+/// variant. Operators with custom arity are always decoded.
+macro_rules! is_decoded {
+    (Nop) => {
+        true
+    };
+    (Unreachable) => {
+        true
+    };
+    (I32Const) => {
+        true
+    };
+    (I64Const) => {
+        true
+    };
+    (I32Eq) => {
+        true
+    };
+    (MemoryGrow) => {
+        true
+    };
+    (MemorySize) => {
+        true
+    };
+    (MemoryCopy) => {
+        true
+    };
+    (MemoryFill) => {
+        true
+    };
+    (MemoryInit) => {
+        true
+    };
+    (DataDrop) => {
+        true
+    };
+    (TableInit) => {
+        true
+    };
+    (TableSet) => {
+        true
+    };
+    (TableGrow) => {
+        true
+    };
+    (TableCopy) => {
+        true
+    };
+    (TableFill) => {
+        true
+    };
+    (ThrowRef) => {
+        true
+    };
+    (Rethrow) => {
+        true
+    };
+    ($op:ident) => {
+        false
+    };
+}
+
+/// Yields `Some(memarg)` if one of the immediates is named `memarg`.
+macro_rules! memarg_immediate {
+    () => { None };
+    ($first:ident $($rest:ident)*) => { memarg_immediate!(@check $first [$first] $($rest)*) };
+    (@check memarg [$arg:ident] $($rest:ident)*) => { Some($arg) };
+    (@check $other:ident [$arg:ident] $($rest:ident)*) => { memarg_immediate!($($rest)*) };
+}
+
+macro_rules! visit_body {
+    ($self:ident @$proposal:ident $op:ident $({ $($arg:ident),* })? (arity custom)) => {
+        $self.decoded(Operator::$op $({ $($arg),* })?)
+    };
+    ($self:ident @$proposal:ident $op:ident $({ $($arg:ident),* })? (arity $pops:literal -> $pushes:literal)) => {
+        if is_decoded!($op) {
+            $self.decoded(Operator::$op $({ $($arg),* })?)
+        } else {
+            let info = OperatorInfo {
+                name: stringify!($op),
+                proposal: stringify!($proposal),
+            };
+            $self.plain(info, ($pops, $pushes), memarg_immediate!($($($arg)*)?))
+        }
+    };
+}
+
+macro_rules! define_visit {
+    ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident ($($ann:tt)*) )*) => {
+        $(
+            fn $visit(&mut self $($(, $arg: $argty)*)?) {
+                visit_body!(self @$proposal $op $({ $($arg),* })? ($($ann)*))
+            }
+        )*
+    };
+}
+
+impl<'a> VisitOperator<'a> for ScanVisitor<'_, '_> {
+    type Output = ();
+
+    fn simd_visitor(&mut self) -> Option<&mut dyn VisitSimdOperator<'a, Output = ()>> {
+        Some(self)
+    }
+
+    wasmparser::for_each_visit_operator!(define_visit);
+}
+
+impl<'a> VisitSimdOperator<'a> for ScanVisitor<'_, '_> {
+    wasmparser::for_each_visit_simd_operator!(define_visit);
+}
+
+/// Selector-dispatch heuristic over the first `DISPATCH_WINDOW` operators.
+///
+/// Two dispatcher shapes are recognised:
+/// - a comparison ladder: `i32.eq` with an `i32.const` among its two
+///   immediately preceding operators, feeding `br_if` or `if`; each such
+///   comparison counts as one branch
+/// - a jump table: `br_table`, counting its targets (default excluded)
+///
+/// The estimate is the larger of the two. It is a proxy for the number of
+/// external methods, not an exact count: selectors compared through locals,
+/// `i32.ne`, or a dispatcher placed in a callee are not recognised.
+#[derive(Debug, Default)]
+struct DispatchScan {
+    seen: usize,
+    prev_is_const: bool,
+    prev2_is_const: bool,
+    pending_compare: bool,
+    compares: u32,
+    table_targets: u32,
+}
+
+impl DispatchScan {
+    fn on_operator(&mut self, op: &Operator) {
+        if self.seen >= DISPATCH_WINDOW {
+            return;
+        }
+        self.seen += 1;
+
+        match op {
+            Operator::BrIf { .. } | Operator::If { .. } if self.pending_compare => {
+                self.compares = self.compares.saturating_add(1);
+            }
+            Operator::BrTable { targets } => {
+                self.table_targets = self.table_targets.max(targets.len());
             }
             _ => {}
         }
+
+        self.advance(
+            matches!(op, Operator::I32Eq),
+            matches!(op, Operator::I32Const { .. }),
+        );
     }
 
-    Ok(())
+    /// Same as `on_operator` for an operator other than `br_if`, `if`,
+    /// `br_table`, `i32.eq` and `i32.const`.
+    fn on_plain_operator(&mut self) {
+        if self.seen >= DISPATCH_WINDOW {
+            return;
+        }
+        self.seen += 1;
+        self.advance(false, false);
+    }
+
+    fn advance(&mut self, is_eq: bool, is_const: bool) {
+        self.pending_compare = is_eq && (self.prev_is_const || self.prev2_is_const);
+        self.prev2_is_const = self.prev_is_const;
+        self.prev_is_const = is_const;
+    }
+
+    fn branches(&self) -> u32 {
+        self.compares.max(self.table_targets)
+    }
+}
+
+/// Sums the declared local counts of a body.
+///
+/// Counts are accumulated arithmetically from the encoded `(count, type)`
+/// groups; nothing is allocated per local, so a declared count of
+/// `u32::MAX` is handled in constant space.
+fn count_locals(body: &FunctionBody) -> Result<u64> {
+    let mut total = 0u64;
+    for group in body.get_locals_reader()? {
+        let (count, _ty) = group?;
+        total = total.saturating_add(u64::from(count));
+    }
+    Ok(total)
 }
 
 #[cfg(test)]
@@ -96,7 +1147,7 @@ mod tests {
 
         let mut facts = InstructionFacts::default();
         for body in extract_bodies(&wasm) {
-            on_code_entry(&mut facts, body).expect("scan failed");
+            on_code_entry(&mut facts, &ScanContext::default(), body).expect("scan failed");
         }
 
         assert_eq!(facts.loop_count, 2);
@@ -109,31 +1160,962 @@ mod tests {
     }
 
     #[test]
-    fn test_deeply_nested_loops() {
+    fn test_merge_of_per_body_scans_matches_serial_scan() {
         let wasm = wat::parse_str(
             r#"
             (module
-              (func (loop (loop (loop (nop)))))
+              (type (func))
+              (table 1 funcref)
+              (memory 1)
+              (func $f1 (loop (br 0)) (loop (nop)) (call $f2))
+              (func $f2 (drop (memory.grow (i32.const 1))) (call $f3))
+              (func $f3 (call_indirect (type 0) (i32.const 0)) (loop (nop)) (unreachable))
             )
             "#,
         )
         .unwrap();
+        // A limit of 1 exercises truncation of site lists spanning bodies.
+        let ctx = ScanContext {
+            site_limit: 1,
+            ..Default::default()
+        };
+        let empty = || InstructionFacts {
+            histogram: Some(Default::default()),
+            ..Default::default()
+        };
 
-        let mut facts = InstructionFacts::default();
-        let body = extract_bodies(&wasm).pop().unwrap();
-        on_code_entry(&mut facts, body).unwrap();
+        let mut serial = empty();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut serial, &ctx, body).unwrap();
+        }
 
-        assert_eq!(facts.loop_count, 3);
+        let mut merged = empty();
+        for (i, body) in extract_bodies(&wasm).into_iter().enumerate() {
+            let mut partial = empty();
+            scan_function(&mut partial, &ctx, i as u32, body).unwrap();
+            merged.merge(partial, ctx.site_limit);
+        }
+
+        assert_eq!(merged, serial);
+        assert_eq!(merged.loop_sites.len(), 1);
     }
 
     #[test]
-    fn test_empty_function_is_noop() {
-        let wasm = wat::parse_str("(module (func))").unwrap();
+    fn test_large_offsets_against_initial_memory_and_threshold() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func
+                (i32.store offset=16 (i32.const 0) (i32.const 1))
+                (drop (i64.load offset=65537 (i32.const 0)))
+                (i32.store8 offset=2000000 (i32.const 0) (i32.const 1))
+                (drop (i32.atomic.load offset=65536 (i32.const 0)))))
+            "#,
+        )
+        .unwrap();
 
-        let mut facts = InstructionFacts::default();
-        let body = extract_bodies(&wasm).pop().unwrap();
-        on_code_entry(&mut facts, body).unwrap();
+        let scan = |ctx: &ScanContext| {
+            let mut facts = InstructionFacts::default();
+            for body in extract_bodies(&wasm) {
+                on_code_entry(&mut facts, ctx, body).unwrap();
+            }
+            facts
+        };
+
+        // One 64 KiB page: offsets 65537 and 2000000 lie beyond it.
+        let facts = scan(&ScanContext {
+            initial_memory_bytes: Some(65_536),
+            ..Default::default()
+        });
+        assert_eq!(facts.large_offset_access_count, 2);
+        assert_eq!(facts.max_static_offset, 2_000_000);
+
+        // Without memory limits only the threshold applies.
+        let facts = scan(&ScanContext {
+            large_offset_threshold: 1_000,
+            ..Default::default()
+        });
+        assert_eq!(facts.large_offset_access_count, 3);
+    }
+
+    #[test]
+    fn test_dispatch_ladder_and_table_estimates() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func $ladder (param $sel i32)
+                (block $a (block $b (block $c
+                  (br_if $a (i32.eq (local.get $sel) (i32.const 0x70a08231)))
+                  (br_if $b (i32.eq (i32.const 0xa9059cbb) (local.get $sel)))
+                  (if (i32.eq (local.get $sel) (i32.const 0x18160ddd)) (then (nop)))
+                  ;; not a selector compare
+                  (br_if $c (i32.eq (local.get $sel) (local.get $sel)))))))
+              (func $table (param $i i32)
+                (block (block (block (block
+                  (br_table 0 1 2 3 (local.get $i)))))))
+              (func $plain (drop (i32.eq (i32.const 1) (i32.const 2)))))
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        let branches: Vec<u32> = facts
+            .functions
+            .iter()
+            .map(|f| f.dispatch_branches)
+            .collect();
+        assert_eq!(branches, vec![3, 3, 0]);
+    }
+
+    #[test]
+    fn test_dispatch_only_considers_top_of_body() {
+        let mut wat = String::from("(module (func (param $s i32) (block");
+        for _ in 0..DISPATCH_WINDOW {
+            wat.push_str("(nop)");
+        }
+        wat.push_str("(br_if 0 (i32.eq (local.get $s) (i32.const 1))))))");
+        let wasm = wat::parse_str(&wat).unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+        assert_eq!(facts.functions[0].dispatch_branches, 0);
+    }
+
+    #[test]
+    fn test_deeply_nested_loops() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func (loop (loop (loop (nop)))) (loop))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        let body_size = body.range().len() as u64;
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.loop_count, 4);
+        assert_eq!(facts.functions[0].max_loop_depth, 3);
+        assert_eq!(facts.functions[0].body_size, body_size);
+    }
+
+    #[test]
+    fn test_empty_function_is_noop() {
+        let wasm = wat::parse_str("(module (func))").unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        // The body is just the local-declaration count and `end`.
+        let expected = InstructionFacts {
+            functions: vec![FunctionFacts {
+                body_size: 2,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(facts, expected);
+    }
+
+    #[test]
+    fn test_direct_calls_split_imports_and_defined() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (import "env" "host" (func $host))
+              (func $a (call $host) (call $b) (call $b))
+              (func $b (call $host))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let ctx = ScanContext {
+            imported_function_count: 1,
+            ..Default::default()
+        };
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ctx, body).unwrap();
+        }
+
+        assert_eq!(facts.call_count, 4);
+        assert_eq!(facts.call_import_count, 2);
+        assert_eq!(facts.call_defined_count, 2);
+        assert_eq!(facts.distinct_callees, BTreeSet::from([0, 2]));
+        assert!(!facts.distinct_callees_capped);
+
+        let indices: Vec<u32> = facts.functions.iter().map(|f| f.index).collect();
+        assert_eq!(indices, vec![1, 2]);
+        assert_eq!(facts.functions[0].callees, vec![0, 2]);
+        assert_eq!(facts.functions[1].callees, vec![0]);
+    }
+
+    #[test]
+    fn test_distinct_callees_are_capped() {
+        let ctx = ScanContext::default();
+        let mut facts = InstructionFacts::default();
+        for idx in 0..=MAX_TRACKED_CALLEES as u32 {
+            facts.record_call(&ctx, idx);
+        }
+
+        assert_eq!(facts.distinct_callees.len(), MAX_TRACKED_CALLEES);
+        assert!(facts.distinct_callees_capped);
+        assert_eq!(facts.call_count, MAX_TRACKED_CALLEES as u64 + 1);
+    }
+
+    #[test]
+    fn test_float_ops_and_constants_counted_separately() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func (result f64)
+                (f64.add (f64.const 1.5) (f64.convert_i32_s (i32.const 2))))
+              (func (result i32)
+                (i32.trunc_f32_s (f32.const 3.0)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        assert!(facts.has_float);
+        assert_eq!(facts.float_const_count, 2);
+        // f64.add, f64.convert_i32_s, i32.trunc_f32_s
+        assert_eq!(facts.float_op_count, 3);
+    }
+
+    #[test]
+    fn test_integer_only_module_has_no_float() {
+        let wasm =
+            wat::parse_str("(module (func (result i32) (i32.add (i32.const 1) (i32.const 2))))")
+                .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(!facts.has_float);
+        assert_eq!(facts.float_op_count, 0);
+    }
+
+    #[test]
+    fn test_atomic_ops_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1 1 shared)
+              (func
+                (drop (i32.atomic.rmw.add (i32.const 0) (i32.const 1)))
+                (drop (memory.atomic.notify (i32.const 0) (i32.const 1)))
+                (atomic.fence))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(facts.has_atomics);
+        assert_eq!(facts.atomic_op_count, 3);
+    }
+
+    #[test]
+    fn test_simd_ops_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func
+                (v128.store (i32.const 0)
+                  (i8x16.add (v128.load (i32.const 0)) (v128.const i64x2 1 2))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(facts.has_simd);
+        assert_eq!(facts.simd_op_count, 4);
+        assert!(!facts.has_float);
+    }
+
+    #[test]
+    fn test_bulk_memory_ops_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (data $d "abcd")
+              (func
+                (memory.copy (i32.const 0) (i32.const 16) (i32.const 4))
+                (memory.fill (i32.const 0) (i32.const 0) (i32.const 4))
+                (memory.fill (i32.const 8) (i32.const 0) (i32.const 4))
+                (memory.init $d (i32.const 0) (i32.const 0) (i32.const 4))
+                (data.drop $d))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(facts.has_bulk_memory);
+        assert_eq!(facts.memory_copy_count, 1);
+        assert_eq!(facts.memory_fill_count, 2);
+        assert_eq!(facts.memory_init_count, 1);
+        assert_eq!(facts.data_drop_count, 1);
+        assert_eq!(facts.table_init_count, 0);
+    }
+
+    #[test]
+    fn test_table_mutation_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (table 2 funcref)
+              (func
+                (table.set (i32.const 0) (ref.null func))
+                (drop (table.grow (ref.null func) (i32.const 1)))
+                (table.copy (i32.const 0) (i32.const 1) (i32.const 1))
+                (table.fill (i32.const 0) (ref.null func) (i32.const 2)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(facts.has_table_mutation);
+        assert_eq!(facts.table_set_count, 1);
+        assert_eq!(facts.table_grow_count, 1);
+        assert_eq!(facts.table_copy_count, 1);
+        assert_eq!(facts.table_fill_count, 1);
+    }
+
+    #[test]
+    fn test_table_get_is_not_mutation() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (table 1 funcref)
+              (func (drop (table.get (i32.const 0))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(!facts.has_table_mutation);
+    }
+
+    #[test]
+    fn test_unreachable_counted_per_function() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func unreachable)
+              (func (block unreachable) unreachable unreachable)
+              (func nop)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        assert_eq!(facts.unreachable_count, 4);
+        let per_fn: Vec<u64> = facts
+            .functions
+            .iter()
+            .map(|f| f.unreachable_count)
+            .collect();
+        assert_eq!(per_fn, vec![1, 3, 0]);
+        assert_eq!(
+            facts.rank_functions(MAX_RANKED_FUNCTIONS, |f| f.unreachable_count),
+            vec![(1, 3), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_br_table_fan_out() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func (param i32)
+                (block (block (block
+                  (br_table 0 1 2 (local.get 0)))))
+                (block (br_table 0 0 (local.get 0))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.br_table_count, 2);
+        assert_eq!(facts.br_table_max_targets, 2);
+    }
+
+    #[test]
+    fn test_histogram_counts_every_operator() {
+        let wasm =
+            wat::parse_str("(module (func (drop (i32.add (i32.const 1) (i32.const 2)))))").unwrap();
+
+        let mut facts = InstructionFacts {
+            histogram: Some(BTreeMap::new()),
+            ..Default::default()
+        };
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        let histogram = facts.histogram.unwrap();
+        let entries: Vec<(&str, u64)> = histogram.into_iter().collect();
+        assert_eq!(
+            entries,
+            vec![("Drop", 1), ("End", 1), ("I32Add", 1), ("I32Const", 2)]
+        );
+    }
+
+    #[test]
+    fn test_static_cost_summed_per_function() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func (drop (i32.load (i32.const 0))))
+              (func (drop (memory.grow (i32.const 1))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        // const + load + drop + end
+        let load_cost = cost::WEIGHT_BASE + cost::WEIGHT_MEMORY + cost::WEIGHT_BASE;
+        // const + memory.grow + drop + end
+        let grow_cost = cost::WEIGHT_BASE + cost::WEIGHT_MEMORY_GROW + cost::WEIGHT_BASE;
+
+        assert_eq!(facts.functions[0].static_cost, load_cost);
+        assert_eq!(facts.functions[1].static_cost, grow_cost);
+        assert_eq!(facts.estimated_static_cost, load_cost + grow_cost);
+        assert_eq!(
+            facts.rank_functions(1, |f| f.static_cost),
+            vec![(1, grow_cost)]
+        );
+    }
+
+    #[test]
+    fn test_locals_counted_per_function() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (func (param i32) (local i32 i64) (local f32))
+              (func)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        assert_eq!(facts.functions[0].local_count, 3);
+        assert_eq!(facts.functions[1].local_count, 0);
+        assert_eq!(facts.max_locals, 3);
+        assert_eq!(facts.large_locals_function_count, 0);
+    }
+
+    #[test]
+    fn test_huge_declared_locals_do_not_allocate() {
+        // (func (local <u32::MAX x i32>)), encoded by hand.
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x0a, 0x0a, 0x01, 0x08, // code section, one 8-byte body
+            0x01, 0xff, 0xff, 0xff, 0xff, 0x0f, 0x7f, // u32::MAX x i32
+            0x0b, // end
+        ];
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.max_locals, u64::from(u32::MAX));
+        assert_eq!(facts.large_locals_function_count, 1);
+    }
+
+    fn suspicious_loops(wat: &str) -> u64 {
+        let wasm = wat::parse_str(wat).unwrap();
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+        facts.suspicious_infinite_loop_count
+    }
+
+    #[test]
+    fn test_unconditional_back_edge_without_exit_is_suspicious() {
+        let wat = r#"
+            (module
+              (memory 1)
+              (func
+                (loop $spin
+                  (i32.store (i32.const 0) (i32.const 1))
+                  (br $spin)))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 1);
+    }
+
+    #[test]
+    fn test_bounded_loop_is_not_suspicious() {
+        let wat = r#"
+            (module
+              (func (param $n i32)
+                (block $done
+                  (loop $next
+                    (br_if $done (i32.eqz (local.get $n)))
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br $next))))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 0);
+    }
+
+    #[test]
+    fn test_conditional_continue_is_not_suspicious() {
+        let wat = r#"
+            (module
+              (func (param $n i32)
+                (loop $next
+                  (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                  (br_if $next (local.get $n))))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 0);
+    }
+
+    #[test]
+    fn test_back_edge_inside_if_is_not_suspicious() {
+        let wat = r#"
+            (module
+              (func (param $n i32)
+                (loop $next
+                  (if (local.get $n) (then (br $next)))))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 0);
+    }
+
+    #[test]
+    fn test_return_or_trap_inside_loop_is_an_exit() {
+        let wat = r#"
+            (module
+              (func (param $n i32)
+                (loop $a
+                  (if (local.get $n) (then return))
+                  (br $a))
+                (loop $b
+                  (if (local.get $n) (then unreachable))
+                  (br $b)))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 0);
+    }
+
+    #[test]
+    fn test_branch_to_inner_block_does_not_exit_loop() {
+        let wat = r#"
+            (module
+              (func (param $n i32)
+                (loop $spin
+                  (block $skip
+                    (br_if $skip (local.get $n)))
+                  (br $spin)))
+            )
+        "#;
+        assert_eq!(suspicious_loops(wat), 1);
+    }
+
+    #[test]
+    fn test_memory_size_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func (result i32)
+                (drop (memory.size))
+                (memory.grow (memory.size)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert!(facts.has_memory_size);
+        assert_eq!(facts.memory_size_count, 2);
+        assert_eq!(facts.memory_grow_count, 1);
+    }
+
+    #[test]
+    fn test_memory_grow_operand_classified() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func (param $n i32)
+                (drop (memory.grow (i32.const 1)))
+                (drop (memory.grow (local.get $n)))
+                (drop (memory.grow (i32.add (i32.const 1) (i32.const 1)))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.memory_grow_count, 3);
+        assert_eq!(facts.memory_grow_const_count, 1);
+        assert_eq!(facts.memory_grow_dynamic_count, 2);
+        assert_eq!(facts.memory_grow_const_pages, 1);
+    }
+
+    #[test]
+    fn test_memory_grow_in_loop_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func (param $n i32)
+                (drop (memory.grow (i32.const 1)))
+                (loop $l
+                  (block
+                    (drop (memory.grow (local.get $n))))
+                  (br_if $l (local.get $n)))
+                (drop (memory.grow (i32.const 1))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.memory_grow_count, 3);
+        assert_eq!(facts.memory_grow_in_loop_count, 1);
+        assert_eq!(facts.functions[0].memory_grow_in_loop_count, 1);
+        assert_eq!(facts.memory_grow_in_loop_sites.len(), 1);
+        assert_eq!(
+            facts.memory_grow_in_loop_sites[0],
+            facts.memory_grow_sites[1]
+        );
+    }
+
+    #[test]
+    fn test_visitor_scan_matches_decoded_scan() {
+        fn scan_with(
+            wasm: &[u8],
+            ctx: &ScanContext,
+            on_entry: fn(&mut InstructionFacts, &ScanContext, FunctionBody) -> Result<()>,
+        ) -> InstructionFacts {
+            let mut facts = InstructionFacts {
+                histogram: Some(Default::default()),
+                ..Default::default()
+            };
+            for body in extract_bodies(wasm) {
+                on_entry(&mut facts, ctx, body).unwrap();
+            }
+            facts
+        }
+
+        let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+        let mut modules: Vec<(String, Vec<u8>)> = std::fs::read_dir(fixtures)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "wat"))
+            .map(|path| {
+                let wasm = wat::parse_file(&path).unwrap();
+                (
+                    path.file_name().unwrap().to_string_lossy().into_owned(),
+                    wasm,
+                )
+            })
+            .collect();
+        modules.sort();
+
+        // Operators from every proposal the scan counts, plus a selector dispatch.
+        let mixed = r#"
+            (module
+              (type $t (func (param i32) (result i32)))
+              (type $s (struct (field (mut i32))))
+              (tag $e)
+              (table 1 funcref)
+              (memory 1 1 shared)
+              (data $d "abc")
+              (elem $el func $f)
+              (func $f (type $t)
+                (if (i32.eq (local.get 0) (i32.const 7)) (then (return (i32.const 1))))
+                (br_if 0 (i32.const 2) (i32.eq (i32.const 3) (local.get 0)))
+                (drop (struct.get $s 0 (struct.new $s (i32.const 4))))
+                (drop (i32.atomic.load offset=70000 (i32.const 0)))
+                (drop (i32x4.extract_lane 0 (i32x4.add (v128.const i64x2 1 2) (v128.load (i32.const 0)))))
+                (drop (f64.sqrt (f64.const 2)))
+                (memory.init $d (i32.const 0) (i32.const 0) (i32.const 1))
+                (data.drop $d)
+                (table.init $el (i32.const 0) (i32.const 0) (i32.const 1))
+                (drop (table.grow (ref.null func) (i32.const 1)))
+                (try_table (catch_all 0) (throw $e))
+                (block (br_table 0 0 0 (local.get 0)))
+                (loop (drop (memory.grow (i32.const 1))))
+                (return_call_indirect (type $t) (local.get 0) (i32.const 0))))
+        "#;
+        modules.push(("mixed".into(), wat::parse_str(mixed).unwrap()));
+
+        let mut wat = String::from("(module (type (func)) (table 1 funcref) (memory 1)");
+        for i in 0..5_000 {
+            let body = match i % 4 {
+                0 => "(loop (nop))",
+                1 => "(drop (memory.grow (i32.const 1)))",
+                2 => "(call_indirect (type 0) (i32.const 0))",
+                _ => "(drop (f32.add (f32.const 1) (f32.const 2)))",
+            };
+            wat.push_str(&format!("(func {body} (call {}))", (i + 1) % 5_000));
+        }
+        wat.push(')');
+        modules.push(("generated".into(), wat::parse_str(&wat).unwrap()));
+
+        for (name, wasm) in &modules {
+            let sections = crate::wasm::parse::parse_wasm(wasm).unwrap().sections;
+            for site_limit in [DEFAULT_SITE_LIMIT, 2] {
+                let ctx = ScanContext {
+                    site_limit,
+                    ..ScanContext::from_sections(&sections)
+                };
+                let visited = scan_with(wasm, &ctx, on_code_entry);
+                let decoded = scan_with(wasm, &ctx, on_code_entry_decoded);
+                assert_eq!(visited, decoded, "{name}: scans differ");
+            }
+        }
+    }
+
+    #[test]
+    fn test_delegate_closes_its_try_frame() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func
+                loop
+                  try
+                    nop
+                  delegate 0
+                end
+                (drop (memory.grow (i32.const 1))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.memory_grow_count, 1);
+        assert_eq!(facts.memory_grow_in_loop_count, 0);
+        assert_eq!(facts.functions[0].max_loop_depth, 1);
+    }
+
+    #[test]
+    fn test_sites_point_at_opcodes_and_are_capped() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (memory 1)
+              (func
+                (loop) (loop) (loop)
+                (drop (memory.grow (i32.const 1))))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let ctx = ScanContext {
+            site_limit: 2,
+            ..Default::default()
+        };
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ctx, body).unwrap();
+
+        assert_eq!(facts.loop_count, 3);
+        assert_eq!(facts.loop_sites.len(), 2);
+        assert!(
+            facts
+                .loop_sites
+                .iter()
+                .all(|s| wasm[s.offset as usize] == 0x03)
+        );
+        assert!(facts.loop_sites[0].offset < facts.loop_sites[1].offset);
+
+        let grow = facts.memory_grow_sites[0];
+        assert_eq!(grow.function_index, 0);
+        assert_eq!(wasm[grow.offset as usize], 0x40);
+    }
+
+    #[test]
+    fn test_call_indirect_type_and_table_indices() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (type $a (func))
+              (type $b (func (param i32)))
+              (table $t0 1 funcref)
+              (table $t1 1 funcref)
+              (func
+                (call_indirect $t1 (type $b) (i32.const 7) (i32.const 0))
+                (call_indirect $t0 (type $a) (i32.const 0))
+                (call_indirect $t0 (type $a) (i32.const 0)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        let body = extract_bodies(&wasm).pop().unwrap();
+        on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+
+        assert_eq!(facts.call_indirect_count, 3);
+        assert_eq!(
+            facts
+                .call_indirect_types
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            facts
+                .call_indirect_tables
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert!(!facts.call_indirect_indices_capped);
+    }
+
+    #[test]
+    fn test_tail_calls_counted_and_feed_call_graph() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (type $t (func (param i32) (result i32)))
+              (table 1 funcref)
+              (func $countdown (param i32) (result i32)
+                (if (result i32) (local.get 0)
+                  (then (return_call $countdown (i32.sub (local.get 0) (i32.const 1))))
+                  (else (i32.const 0))))
+              (func (param i32) (result i32)
+                (return_call_indirect (type $t) (local.get 0) (i32.const 0)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
+
+        assert!(facts.has_tail_call);
+        assert_eq!(facts.return_call_count, 1);
+        assert_eq!(facts.return_call_indirect_count, 1);
+        assert_eq!(facts.call_count, 0);
+        assert!(!facts.has_call_indirect);
+        assert_eq!(facts.functions[0].callees, vec![0]);
+        assert!(facts.functions[1].has_call_indirect);
+        assert_eq!(facts.call_indirect_types.len(), 1);
+    }
+
+    #[test]
+    fn test_exception_ops_counted() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+              (tag $e (param i32))
+              (func (result i32)
+                try (result i32)
+                  i32.const 1
+                  throw $e
+                catch $e
+                catch_all
+                  i32.const 0
+                end)
+              (func (result i32)
+                (block $h (result i32)
+                  (try_table (catch $e $h)
+                    (throw $e (i32.const 2)))
+                  (i32.const 0)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut facts = InstructionFacts::default();
+        for body in extract_bodies(&wasm) {
+            on_code_entry(&mut facts, &ScanContext::default(), body).unwrap();
+        }
 
-        assert_eq!(facts, InstructionFacts::default());
+        assert!(facts.has_exception_ops);
+        assert_eq!(facts.try_count, 2);
+        assert_eq!(facts.throw_count, 2);
+        // try, throw, catch, catch_all, try_table, throw
+        assert_eq!(facts.exception_op_count, 6);
     }
 }
@@ -14,11 +14,16 @@
 //! - rule evaluation
 //! - final risk classification
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use crate::util::deterministic;
 use anyhow::Result;
 use wasmparser::{
-    Export, ExportSectionReader, ExternalKind, FunctionSectionReader, ImportSectionReader,
-    MemorySectionReader, MemoryType, TableSectionReader, TypeRef,
+    CompositeInnerType, CustomSectionReader, DataKind, DataSectionReader, ElementItems,
+    ElementSectionReader, Export, ExportSectionReader, ExternalKind, FuncType,
+    FunctionSectionReader, GlobalSectionReader, GlobalType, ImportSectionReader, KnownCustom,
+    MemorySectionReader, MemoryType, Name, Operator, SubType, TableSectionReader, TableType,
+    TagSectionReader, TypeRef, TypeSectionReader,
 };
 
 /// Aggregated facts derived from WASM *sections*.
@@ -35,6 +40,9 @@ pub struct SectionFacts {
     /// Total number of imports declared
     pub import_count: u32,
 
+    /// Number of imported functions (these occupy the lowest function indices)
+    pub imported_function_count: u32,
+
     /// Total number of exports declared
     pub export_count: u32,
 
@@ -44,6 +52,9 @@ pub struct SectionFacts {
     /// Whether a table section exists (any table)
     pub has_table_section: bool,
 
+    /// Every table (imported first, then defined), in table-index order
+    pub tables: Vec<TableFact>,
+
     /// Total number of memory declarations (imported + internal)
     pub memory_count: u32,
 
@@ -56,26 +67,326 @@ pub struct SectionFacts {
     /// Convenience flag: true iff memory index 0 declares a maximum
     pub memory_has_max: bool,
 
+    /// True if any memory (imported or defined) is declared `shared`
+    pub has_shared_memory: bool,
+
+    /// True if any memory (imported or defined) is 64-bit (memory64 proposal)
+    pub has_memory64: bool,
+
+    /// Every memory (imported first, then defined), in memory-index order
+    pub memories: Vec<MemoryFact>,
+
+    /// Every global (imported first, then defined), in global-index order
+    pub globals: Vec<GlobalFact>,
+
+    /// True if the module both imports a memory and defines one
+    pub memory_declaration_conflict: bool,
+
+    /// Exception tags declared (imported + Tag section)
+    pub tag_count: u32,
+
+    /// Type information needed to interpret function bodies
+    pub types: TypeFacts,
+
     /// Normalized list of import facts
     pub imports: Vec<ImportFact>,
 
+    /// Import count per import module name
+    pub import_modules: BTreeMap<String, u32>,
+
     /// Normalized list of export facts
     pub exports: Vec<ExportFact>,
+
+    /// Function names from the `name` custom section, keyed by function index
+    pub function_names: BTreeMap<u32, String>,
+
+    /// Entries of the `producers` custom section
+    pub producers: BTreeSet<ProducerFact>,
+
+    /// Custom sections whose name starts with `stylus`, keyed by name, with
+    /// their payload as trimmed (lossy) UTF-8
+    pub stylus_custom_sections: BTreeMap<String, String>,
+
+    /// Function run at instantiation (Start section), if any
+    pub start_function: Option<u32>,
+
+    /// Functions referenced by element segments (possible `call_indirect` targets)
+    pub table_functions: BTreeSet<u32>,
+
+    /// Section payload bytes by category
+    pub section_bytes: SectionBytes,
+
+    /// Data segments (Data section)
+    pub data: DataFacts,
 }
 
-/// Normalized representation of a single import.
+impl SectionFacts {
+    /// Module-level function index of the function exported as `name`.
+    ///
+    /// Re-exported imports resolve to their import index.
+    pub fn function_index_for_export(&self, name: &str) -> Option<u32> {
+        self.exports
+            .iter()
+            .find(|e| e.kind == "func" && e.name == name)
+            .map(|e| e.index)
+    }
+
+    /// Exports of mutable globals, as `(export name, global)` in export order.
+    pub fn exported_mutable_globals(&self) -> Vec<(&str, &GlobalFact)> {
+        self.exports
+            .iter()
+            .filter(|e| e.kind == "global")
+            .filter_map(|e| {
+                let global = self.globals.get(e.index as usize)?;
+                global.mutable.then_some((e.name.as_str(), global))
+            })
+            .collect()
+    }
+
+    /// Non-MVP proposals visible in section declarations, as
+    /// `(proposal, section)` pairs sorted by proposal then section.
+    ///
+    /// Proposal names use `wasmparser`'s spelling, matching the operator
+    /// classification in `wasm::operators`.
+    pub fn section_features(&self) -> Vec<(&'static str, &'static str)> {
+        let mut features = Vec::new();
+        if self.has_shared_memory {
+            features.push(("threads", "memory"));
+        }
+        if self.has_memory64 {
+            features.push(("memory64", "memory"));
+        }
+        if self.memory_count > 1 {
+            features.push(("multi_memory", "memory"));
+        }
+        if self.tag_count > 0 {
+            features.push(("exceptions", "tag"));
+        }
+        if self.data.passive_segment_count > 0 {
+            features.push(("bulk_memory", "data"));
+        }
+        if self.tables.len() > 1 || self.tables.iter().any(|t| !t.is_funcref) {
+            features.push(("reference_types", "table"));
+        }
+        let func_types =
+            self.types
+                .sub_types
+                .iter()
+                .filter_map(|t| match &t.composite_type.inner {
+                    CompositeInnerType::Func(func) => Some(func),
+                    _ => None,
+                });
+        if func_types.clone().any(|f| f.results().len() > 1) {
+            features.push(("multi_value", "type"));
+        }
+        if func_types.count() < self.types.sub_types.len() {
+            features.push(("gc", "type"));
+        }
+        features.sort_unstable();
+        features
+    }
+
+    /// Functions exported under more than one name, keyed by function index.
+    ///
+    /// Names keep the export sort order.
+    pub fn export_aliases(&self) -> BTreeMap<u32, Vec<String>> {
+        let mut by_index: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+        for e in self.exports.iter().filter(|e| e.kind == "func") {
+            by_index.entry(e.index).or_default().push(e.name.clone());
+        }
+        by_index.retain(|_, names| names.len() > 1);
+        by_index
+    }
+}
+
+/// Module type information, indexed the way instructions reference it.
+#[derive(Debug, Clone, Default)]
+pub struct TypeFacts {
+    /// Type section entries, flattened across recursion groups
+    pub sub_types: Vec<SubType>,
+
+    /// Type index of every function (imported first, then defined)
+    pub function_types: Vec<u32>,
+
+    /// Function-type index of every tag (imported first, then defined)
+    pub tag_types: Vec<u32>,
+}
+
+impl TypeFacts {
+    /// Signature of a function (imported or defined) by module-level index.
+    ///
+    /// `None` for out-of-range indices and non-function types.
+    pub fn function_type(&self, function_index: u32) -> Option<&FuncType> {
+        let type_index = *self.function_types.get(function_index as usize)?;
+        self.func_type_at(type_index)
+    }
+
+    /// Function type at `type_index` of the Type section, if it is one.
+    pub fn func_type_at(&self, type_index: u32) -> Option<&FuncType> {
+        match &self
+            .sub_types
+            .get(type_index as usize)?
+            .composite_type
+            .inner
+        {
+            CompositeInnerType::Func(func) => Some(func),
+            _ => None,
+        }
+    }
+}
+
+/// Canonical text form of a function signature, e.g. `(i32) -> i32`.
 ///
-/// This intentionally stores only:
-/// - module name
-/// - import name
-/// - external kind
+/// Parameters are always parenthesized; a single result is written bare,
+/// no results as `()`, and multiple results parenthesized.
+pub fn format_signature(func: &FuncType) -> String {
+    let list = |types: &[wasmparser::ValType]| {
+        types
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let results = match func.results() {
+        [single] => single.to_string(),
+        results => format!("({})", list(results)),
+    };
+    format!("({}) -> {}", list(func.params()), results)
+}
+
+/// Normalized representation of a single import.
 ///
-/// Type signatures and indices are handled elsewhere.
+/// Stores module name, import name and external kind; func imports also
+/// carry their function index and, once bodies are scanned, whether any
+/// code refers to them.
 #[derive(Debug, Clone)]
 pub struct ImportFact {
     pub module: String,
     pub name: String,
     pub kind: String, // "func" | "memory" | "table" | "global" | "tag"
+    /// Module-level function index, for func imports
+    pub function_index: Option<u32>,
+    /// For func imports, whether the function is a call target
+    /// (see `mark_referenced_imports`); `None` until marked
+    pub referenced: Option<bool>,
+    /// For func imports, the signature (see `format_signature`)
+    pub signature: Option<String>,
+}
+
+/// One `producers` section entry, e.g. `language: Rust` or
+/// `processed-by: rustc 1.80.0`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProducerFact {
+    /// "language" | "processed-by" | "sdk"
+    pub field: String,
+    pub name: String,
+    pub version: String,
+}
+
+/// Declared shape of a single memory.
+#[derive(Debug, Clone)]
+pub struct MemoryFact {
+    /// Index in the memory index space
+    pub index: u32,
+    pub min_pages: u64,
+    pub max_pages: Option<u64>,
+    /// Declared `shared` (threads proposal)
+    pub shared: bool,
+    /// 64-bit address space (memory64 proposal)
+    pub memory64: bool,
+    pub imported: bool,
+}
+
+/// Aggregates over the module's data segments.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DataFacts {
+    pub segment_count: u32,
+    /// Segments copied into memory at instantiation
+    pub active_segment_count: u32,
+    /// Segments applied only by `memory.init`
+    pub passive_segment_count: u32,
+    /// Initialized bytes summed over all segments (saturating)
+    pub total_bytes: u64,
+    /// Initialized bytes of the largest segment
+    pub largest_segment_bytes: u64,
+}
+
+/// Declared shape of a single table.
+#[derive(Debug, Clone)]
+pub struct TableFact {
+    /// Index in the table index space
+    pub index: u32,
+    /// Element reference type, in text-format spelling (e.g. "funcref")
+    pub element_type: String,
+    /// True for `funcref` tables, the ones `call_indirect` dispatches through
+    pub is_funcref: bool,
+    pub min: u64,
+    pub max: Option<u64>,
+    pub imported: bool,
+    /// `module.name` the table is imported as
+    pub import_name: Option<String>,
+}
+
+impl TableFact {
+    fn new(index: u32, ty: &TableType, imported: bool) -> Self {
+        Self {
+            index,
+            element_type: ty.element_type.to_string(),
+            is_funcref: ty.element_type.is_func_ref(),
+            min: ty.initial,
+            max: ty.maximum,
+            imported,
+            import_name: None,
+        }
+    }
+}
+
+/// Section payload sizes in bytes, grouped by category.
+///
+/// Payload sizes exclude each section's id byte and size prefix, so the
+/// categories sum to slightly less than the module size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SectionBytes {
+    pub code: u64,
+    pub data: u64,
+    pub custom: u64,
+    /// Every other section (types, imports, exports, ...)
+    pub other: u64,
+}
+
+impl SectionBytes {
+    /// Adds a section with id `id` and `len` payload bytes.
+    pub fn record(&mut self, id: u8, len: usize) {
+        let slot = match id {
+            0 => &mut self.custom,
+            10 => &mut self.code,
+            11 => &mut self.data,
+            _ => &mut self.other,
+        };
+        *slot = slot.saturating_add(len as u64);
+    }
+}
+
+/// Declared type of a single global.
+#[derive(Debug, Clone)]
+pub struct GlobalFact {
+    /// Index in the global index space
+    pub index: u32,
+    /// Value type, in text-format spelling (e.g. "i32")
+    pub val_type: String,
+    pub mutable: bool,
+    pub imported: bool,
+}
+
+impl GlobalFact {
+    fn new(index: u32, ty: &GlobalType, imported: bool) -> Self {
+        Self {
+            index,
+            val_type: ty.content_type.to_string(),
+            mutable: ty.mutable,
+            imported,
+        }
+    }
 }
 
 /// Normalized representation of a single export.
@@ -83,6 +394,13 @@ pub struct ImportFact {
 pub struct ExportFact {
     pub name: String,
     pub kind: String,
+    /// Index into the index space of `kind` (e.g. module-level function index)
+    pub index: u32,
+    /// For func exports of defined functions, `index` minus the imported
+    /// function count; `None` for other kinds and re-exported imports
+    pub defined_function_index: Option<u32>,
+    /// For func exports, the resolved signature (see `format_signature`)
+    pub signature: Option<String>,
 }
 
 /// Processes the Import section and records import-related facts.
@@ -130,17 +448,9 @@ pub fn on_import_section(facts: &mut SectionFacts, reader: ImportSectionReader)
 /// - Only memory index 0 determines limits (per WASM semantics)
 /// - Multi-memory is supported without ambiguity
 pub fn on_memory_section(facts: &mut SectionFacts, reader: MemorySectionReader) -> Result<()> {
-    let count = reader.count();
-    facts.memory_count = facts.memory_count.saturating_add(count);
-
-    // Only the first memory sets execution-boundary limits
-    for (i, item) in reader.into_iter().enumerate() {
-        let mem = item?;
-        if i == 0 {
-            record_first_memory_limits(facts, &mem);
-        }
+    for item in reader {
+        record_memory(facts, &item?, false);
     }
-
     Ok(())
 }
 
@@ -159,16 +469,60 @@ fn process_single_import(facts: &mut SectionFacts, module: &str, name: &str, ty:
         TypeRef::Memory(mem) => ("memory", Some(mem)),
     };
 
+    let per_module = facts.import_modules.entry(module.to_string()).or_default();
+    *per_module = per_module.saturating_add(1);
+
+    // The Type section precedes imports, so signatures are resolvable here.
+    let (function_index, signature) = match ty {
+        TypeRef::Func(idx) | TypeRef::FuncExact(idx) => (
+            Some(facts.imported_function_count),
+            facts.types.func_type_at(idx).map(format_signature),
+        ),
+        _ => (None, None),
+    };
     facts.imports.push(ImportFact {
         module: module.to_string(),
         name: name.to_string(),
         kind: kind_str.to_string(),
+        function_index,
+        referenced: None,
+        signature,
     });
 
-    // Imported memory contributes to total memory count
+    match ty {
+        TypeRef::Func(idx) | TypeRef::FuncExact(idx) => {
+            facts.imported_function_count = facts.imported_function_count.saturating_add(1);
+            facts.types.function_types.push(idx);
+        }
+        TypeRef::Tag(tag) => {
+            facts.tag_count = facts.tag_count.saturating_add(1);
+            facts.types.tag_types.push(tag.func_type_idx);
+        }
+        TypeRef::Table(table) => {
+            let index = facts.tables.len() as u32;
+            facts.tables.push(TableFact {
+                import_name: Some(format!("{module}.{name}")),
+                ..TableFact::new(index, &table, true)
+            });
+        }
+        TypeRef::Global(global) => {
+            let index = facts.globals.len() as u32;
+            facts.globals.push(GlobalFact::new(index, &global, true));
+        }
+        _ => {}
+    }
+
+    // Imported memories precede defined ones in the memory index space
     if let Some(mem) = maybe_mem {
-        facts.memory_count = facts.memory_count.saturating_add(1);
-        record_first_memory_limits(facts, &mem);
+        record_memory(facts, &mem, true);
+    }
+}
+
+/// Marks each func import as referenced or not, given the module's
+/// referenced function indices (see `RawWasmFacts::referenced_functions`).
+pub fn mark_referenced_imports(facts: &mut SectionFacts, referenced: &BTreeSet<u32>) {
+    for import in &mut facts.imports {
+        import.referenced = import.function_index.map(|i| referenced.contains(&i));
     }
 }
 
@@ -183,9 +537,26 @@ pub fn on_export_section(facts: &mut SectionFacts, reader: ExportSectionReader)
 
     for item in reader {
         let ex: Export = item?;
+        // Imports precede exports, so the imported function count is final.
+        let defined_function_index = match ex.kind {
+            ExternalKind::Func | ExternalKind::FuncExact => {
+                ex.index.checked_sub(facts.imported_function_count)
+            }
+            _ => None,
+        };
+        // Type and Function sections precede exports, so types are resolvable.
+        let signature = match ex.kind {
+            ExternalKind::Func | ExternalKind::FuncExact => {
+                facts.types.function_type(ex.index).map(format_signature)
+            }
+            _ => None,
+        };
         facts.exports.push(ExportFact {
             name: ex.name.to_string(),
             kind: export_kind_str(ex.kind),
+            index: ex.index,
+            defined_function_index,
+            signature,
         });
     }
 
@@ -195,17 +566,156 @@ pub fn on_export_section(facts: &mut SectionFacts, reader: ExportSectionReader)
 
 pub fn on_function_section(facts: &mut SectionFacts, reader: FunctionSectionReader) -> Result<()> {
     facts.function_count = facts.function_count.saturating_add(reader.count());
+    for type_index in reader {
+        facts.types.function_types.push(type_index?);
+    }
+    Ok(())
+}
+
+/// Processes the Type section.
+///
+/// Recursion groups are flattened so that entries line up with type indices.
+pub fn on_type_section(facts: &mut SectionFacts, reader: TypeSectionReader) -> Result<()> {
+    for group in reader {
+        facts.types.sub_types.extend(group?.into_types());
+    }
     Ok(())
 }
 
 /// Processes the Table section.
 ///
-/// Presence alone is sufficient for execution-boundary reasoning.
-pub fn on_table_section(facts: &mut SectionFacts, _reader: TableSectionReader) -> Result<()> {
+/// Defined tables follow imported ones in the table index space.
+pub fn on_table_section(facts: &mut SectionFacts, reader: TableSectionReader) -> Result<()> {
     facts.has_table_section = true;
+    for table in reader {
+        let index = facts.tables.len() as u32;
+        facts.tables.push(TableFact::new(index, &table?.ty, false));
+    }
+    Ok(())
+}
+
+/// Processes the Global section, recording each global's type.
+pub fn on_global_section(facts: &mut SectionFacts, reader: GlobalSectionReader) -> Result<()> {
+    for global in reader {
+        let index = facts.globals.len() as u32;
+        facts
+            .globals
+            .push(GlobalFact::new(index, &global?.ty, false));
+    }
+    Ok(())
+}
+
+/// Processes the Data section, counting segments and their initialized bytes.
+pub fn on_data_section(facts: &mut SectionFacts, reader: DataSectionReader) -> Result<()> {
+    for segment in reader {
+        let segment = segment?;
+        let len = segment.data.len() as u64;
+        let data = &mut facts.data;
+        data.segment_count += 1;
+        match segment.kind {
+            DataKind::Active { .. } => data.active_segment_count += 1,
+            DataKind::Passive => data.passive_segment_count += 1,
+        }
+        data.total_bytes = data.total_bytes.saturating_add(len);
+        data.largest_segment_bytes = data.largest_segment_bytes.max(len);
+    }
+    Ok(())
+}
+
+/// Processes the Tag section (exception-handling proposal).
+pub fn on_tag_section(facts: &mut SectionFacts, reader: TagSectionReader) -> Result<()> {
+    facts.tag_count = facts.tag_count.saturating_add(reader.count());
+    for tag in reader {
+        facts.types.tag_types.push(tag?.func_type_idx);
+    }
     Ok(())
 }
 
+/// Processes the Element section, recording every referenced function.
+///
+/// Active, passive and declarative segments are all included, so the set
+/// covers anything that can end up in a table or be taken by `ref.func`.
+pub fn on_element_section(facts: &mut SectionFacts, reader: ElementSectionReader) -> Result<()> {
+    for element in reader {
+        match element?.items {
+            ElementItems::Functions(indices) => {
+                for index in indices {
+                    facts.table_functions.insert(index?);
+                }
+            }
+            ElementItems::Expressions(_, exprs) => {
+                for expr in exprs {
+                    for op in expr?.get_operators_reader() {
+                        if let Operator::RefFunc { function_index } = op? {
+                            facts.table_functions.insert(function_index);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Processes a custom section, recording function names from the `name`
+/// section and entries of the `producers` section.
+///
+/// Both are metadata used only for labeling and toolchain detection, so
+/// malformed sections are ignored rather than failing the analysis.
+pub fn on_custom_section(facts: &mut SectionFacts, reader: CustomSectionReader) {
+    match reader.as_known() {
+        KnownCustom::Name(names) => record_function_names(facts, names),
+        KnownCustom::Producers(producers) => {
+            for field in producers.into_iter().map_while(Result::ok) {
+                for value in field.values.into_iter().map_while(Result::ok) {
+                    facts.producers.insert(ProducerFact {
+                        field: field.name.to_string(),
+                        name: value.name.to_string(),
+                        version: value.version.to_string(),
+                    });
+                }
+            }
+        }
+        _ if reader.name().starts_with("stylus") => {
+            let payload = String::from_utf8_lossy(reader.data()).trim().to_string();
+            facts
+                .stylus_custom_sections
+                .insert(reader.name().to_string(), payload);
+        }
+        _ => {}
+    }
+}
+
+fn record_function_names(facts: &mut SectionFacts, names: wasmparser::NameSectionReader) {
+    for subsection in names {
+        let Ok(Name::Function(map)) = subsection else {
+            continue;
+        };
+        for naming in map.into_iter().map_while(Result::ok) {
+            facts
+                .function_names
+                .insert(naming.index, naming.name.to_string());
+        }
+    }
+}
+
+/// Records one memory, imported or defined, in memory-index order.
+fn record_memory(facts: &mut SectionFacts, mem: &MemoryType, imported: bool) {
+    facts.memories.push(MemoryFact {
+        index: facts.memory_count,
+        min_pages: mem.initial,
+        max_pages: mem.maximum,
+        shared: mem.shared,
+        memory64: mem.memory64,
+        imported,
+    });
+    facts.memory_count = facts.memory_count.saturating_add(1);
+    facts.memory_declaration_conflict |= facts.memories.iter().any(|m| m.imported != imported);
+    facts.has_shared_memory |= mem.shared;
+    facts.has_memory64 |= mem.memory64;
+    record_first_memory_limits(facts, mem);
+}
+
 /// Records memory limits for memory index 0.
 ///
 /// This function is idempotent and will not overwrite existing limits.
@@ -245,6 +755,12 @@ mod tests {
                 Payload::MemorySection(r) => on_memory_section(&mut facts, r).unwrap(),
                 Payload::FunctionSection(r) => on_function_section(&mut facts, r).unwrap(),
                 Payload::TableSection(r) => on_table_section(&mut facts, r).unwrap(),
+                Payload::GlobalSection(r) => on_global_section(&mut facts, r).unwrap(),
+                Payload::TagSection(r) => on_tag_section(&mut facts, r).unwrap(),
+                Payload::TypeSection(r) => on_type_section(&mut facts, r).unwrap(),
+                Payload::CustomSection(r) => on_custom_section(&mut facts, r),
+                Payload::ElementSection(r) => on_element_section(&mut facts, r).unwrap(),
+                Payload::DataSection(r) => on_data_section(&mut facts, r).unwrap(),
                 _ => {}
             }
         }
@@ -266,6 +782,287 @@ mod tests {
         assert_eq!(facts.memory_max_pages, Some(3));
         assert!(facts.memory_has_max);
         assert_eq!(facts.memory_count, 2);
+        assert!(facts.memory_declaration_conflict);
+
+        assert!(!parse_wasm("(module (memory 1) (memory 2))").memory_declaration_conflict);
+    }
+
+    #[test]
+    fn test_tables_indexed_imports_first() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "t" (table 1 funcref))
+              (table $defined 2 8 externref)
+            )
+            "#,
+        );
+
+        assert!(facts.has_table_section);
+        let tables: Vec<_> = facts
+            .tables
+            .iter()
+            .map(|t| {
+                (
+                    t.index,
+                    t.element_type.as_str(),
+                    t.is_funcref,
+                    t.min,
+                    t.max,
+                    t.imported,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tables,
+            vec![
+                (0, "funcref", true, 1, None, true),
+                (1, "externref", false, 2, Some(8), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shared_flag_recorded_per_memory() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "mem" (memory 1 4 shared))
+              (memory 2)
+            )
+            "#,
+        );
+
+        assert!(facts.has_shared_memory);
+        let memories: Vec<_> = facts
+            .memories
+            .iter()
+            .map(|m| (m.index, m.shared, m.imported))
+            .collect();
+        assert_eq!(memories, vec![(0, true, true), (1, false, false)]);
+
+        let facts = parse_wasm("(module (memory 1) (memory 1 2 shared))");
+        assert!(facts.has_shared_memory);
+        assert_eq!(facts.memory_min_pages, Some(1));
+        assert!(!facts.memory_has_max);
+    }
+
+    #[test]
+    fn test_memory64_flag_and_limits() {
+        let facts = parse_wasm("(module (memory i64 1 2) (memory 3))");
+
+        assert!(facts.has_memory64);
+        assert_eq!(facts.memory_min_pages, Some(1));
+        assert_eq!(facts.memory_max_pages, Some(2));
+        let flags: Vec<bool> = facts.memories.iter().map(|m| m.memory64).collect();
+        assert_eq!(flags, vec![true, false]);
+
+        assert!(!parse_wasm("(module (memory 1))").has_memory64);
+    }
+
+    #[test]
+    fn test_export_function_indices_offset_imports() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "host_fn" (func $host))
+              (func $own)
+              (memory 1)
+              (export "reexported" (func $host))
+              (export "own" (func $own))
+              (export "memory" (memory 0))
+            )
+            "#,
+        );
+
+        let exports: Vec<_> = facts
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.index, e.defined_function_index))
+            .collect();
+        assert_eq!(
+            exports,
+            vec![
+                ("memory", 0, None),
+                ("own", 1, Some(0)),
+                ("reexported", 0, None),
+            ]
+        );
+
+        assert_eq!(facts.function_index_for_export("own"), Some(1));
+        assert_eq!(facts.function_index_for_export("reexported"), Some(0));
+        assert_eq!(facts.function_index_for_export("memory"), None);
+        assert_eq!(facts.function_index_for_export("missing"), None);
+    }
+
+    #[test]
+    fn test_export_aliases_group_names_per_function() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "host_fn" (func $host))
+              (func $entry)
+              (func $other)
+              (export "user_entrypoint" (func $entry))
+              (export "main" (func $entry))
+              (export "other" (func $other))
+              (export "host_a" (func $host))
+              (export "host_b" (func $host))
+            )
+            "#,
+        );
+
+        let aliases: Vec<_> = facts.export_aliases().into_iter().collect();
+        assert_eq!(
+            aliases,
+            vec![
+                (0, vec!["host_a".to_string(), "host_b".to_string()]),
+                (1, vec!["main".to_string(), "user_entrypoint".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_signatures_resolve_through_imports() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "vm_hooks" "read_args" (func $read (param i32)))
+              (func $entry (param i32) (result i32) (i32.const 0))
+              (func $pair (param i64 f32) (result i32 i64) (i32.const 0) (i64.const 0))
+              (global $g i32 (i32.const 0))
+              (export "user_entrypoint" (func $entry))
+              (export "read_args" (func $read))
+              (export "pair" (func $pair))
+              (export "g" (global $g))
+            )
+            "#,
+        );
+
+        let signatures: Vec<_> = facts
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.signature.as_deref()))
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![
+                ("g", None),
+                ("pair", Some("(i64, f32) -> (i32, i64)")),
+                ("read_args", Some("(i32) -> ()")),
+                ("user_entrypoint", Some("(i32) -> i32")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_signatures_resolved() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (type $call (func (param i32 i32 i32 i32) (result i32)))
+              (import "vm_hooks" "call_contract" (func (type $call)))
+              (import "vm_hooks" "block_timestamp" (func (result i64)))
+              (import "env" "memory" (memory 1))
+            )
+            "#,
+        );
+
+        let signatures: Vec<_> = facts
+            .imports
+            .iter()
+            .map(|i| (i.name.as_str(), i.signature.as_deref()))
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![
+                ("memory", None),
+                ("block_timestamp", Some("() -> i64")),
+                ("call_contract", Some("(i32, i32, i32, i32) -> i32")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stylus_custom_sections_recorded() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (@custom "stylus-sdk-rs" "0.6.0\n")
+              (@custom "unrelated" "ignored"))
+            "#,
+        );
+
+        assert_eq!(
+            facts
+                .stylus_custom_sections
+                .get("stylus-sdk-rs")
+                .map(String::as_str),
+            Some("0.6.0")
+        );
+        assert_eq!(facts.stylus_custom_sections.len(), 1);
+    }
+
+    #[test]
+    fn test_globals_recorded_and_mutable_exports_resolved() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "sp" (global $sp (mut i32)))
+              (global $data_end i32 (i32.const 1024))
+              (global $counter (mut i64) (i64.const 0))
+              (export "__data_end" (global $data_end))
+              (export "counter" (global $counter))
+              (export "sp" (global $sp)))
+            "#,
+        );
+
+        let globals: Vec<_> = facts
+            .globals
+            .iter()
+            .map(|g| (g.index, g.val_type.as_str(), g.mutable, g.imported))
+            .collect();
+        assert_eq!(
+            globals,
+            vec![
+                (0, "i32", true, true),
+                (1, "i32", false, false),
+                (2, "i64", true, false),
+            ]
+        );
+
+        let exported: Vec<_> = facts
+            .exported_mutable_globals()
+            .into_iter()
+            .map(|(name, g)| (name, g.index))
+            .collect();
+        assert_eq!(exported, vec![("counter", 2), ("sp", 0)]);
+    }
+
+    #[test]
+    fn test_producers_section_recorded() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (@producers
+                (language "Rust" "")
+                (processed-by "rustc" "1.80.0"))
+            )
+            "#,
+        );
+
+        let producers: Vec<_> = facts
+            .producers
+            .iter()
+            .map(|p| (p.field.as_str(), p.name.as_str(), p.version.as_str()))
+            .collect();
+        assert_eq!(
+            producers,
+            vec![
+                ("language", "Rust", ""),
+                ("processed-by", "rustc", "1.80.0"),
+            ]
+        );
     }
 
     #[test]
@@ -282,10 +1079,18 @@ mod tests {
         );
 
         assert_eq!(facts.import_count, 4);
+        assert_eq!(facts.imported_function_count, 3);
         assert_eq!(facts.memory_count, 1);
 
         let names: Vec<String> = facts.imports.iter().map(|i| i.name.clone()).collect();
         assert_eq!(names, vec!["f1", "f2", "m1", "exit"]); // "env" module items come before "os"
+
+        let modules: Vec<(&str, u32)> = facts
+            .import_modules
+            .iter()
+            .map(|(module, count)| (module.as_str(), *count))
+            .collect();
+        assert_eq!(modules, vec![("env", 3), ("os", 1)]);
     }
 
     #[test]
@@ -368,6 +1173,164 @@ mod tests {
         assert_eq!(kinds[3], ("e_table".to_string(), "table".to_string()));
     }
 
+    #[test]
+    fn test_shared_memory_detected_on_both_paths() {
+        let defined = parse_wasm("(module (memory 1 2 shared))");
+        assert!(defined.has_shared_memory);
+
+        let imported = parse_wasm(r#"(module (import "env" "mem" (memory 1 2 shared)))"#);
+        assert!(imported.has_shared_memory);
+
+        let private = parse_wasm("(module (memory 1 2))");
+        assert!(!private.has_shared_memory);
+    }
+
+    #[test]
+    fn test_tags_counted_from_section_and_imports() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "panic" (tag (param i32)))
+              (tag $a)
+              (tag $b (param i64))
+            )
+            "#,
+        );
+
+        assert_eq!(facts.tag_count, 3);
+        assert_eq!(facts.imported_function_count, 0);
+    }
+
+    #[test]
+    fn test_export_indices_and_function_names_recorded() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (import "env" "h" (func $host))
+              (func $deploy)
+              (func $helper)
+              (export "deploy" (func $deploy))
+              (export "also_deploy" (func $deploy))
+            )
+            "#,
+        );
+
+        let exports: Vec<(&str, u32)> = facts
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.index))
+            .collect();
+        assert_eq!(exports, vec![("also_deploy", 1), ("deploy", 1)]);
+
+        assert_eq!(
+            facts.function_names.get(&0).map(String::as_str),
+            Some("host")
+        );
+        assert_eq!(
+            facts.function_names.get(&2).map(String::as_str),
+            Some("helper")
+        );
+    }
+
+    #[test]
+    fn test_element_segments_record_table_functions() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (table 4 funcref)
+              (func $a) (func $b) (func $c) (func $d)
+              (elem (i32.const 0) $a $b)
+              (elem funcref (ref.func $c))
+              (elem declare func $d)
+            )
+            "#,
+        );
+
+        assert_eq!(
+            facts.table_functions.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_section_features_detected() {
+        assert!(
+            parse_wasm("(module (memory 1) (table 1 funcref))")
+                .section_features()
+                .is_empty()
+        );
+
+        let facts = parse_wasm(
+            r#"
+            (module
+              (type (func (result i32 i32)))
+              (memory 1 1 shared)
+              (memory i64 1)
+              (table 1 externref)
+              (tag)
+              (data "passive")
+            )
+            "#,
+        );
+        assert_eq!(
+            facts.section_features(),
+            vec![
+                ("bulk_memory", "data"),
+                ("exceptions", "tag"),
+                ("memory64", "memory"),
+                ("multi_memory", "memory"),
+                ("multi_value", "type"),
+                ("reference_types", "table"),
+                ("threads", "memory"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_segments_counted() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (memory 1)
+              (data (i32.const 0) "abcd")
+              (data (i32.const 16) "")
+              (data "0123456789")
+            )
+            "#,
+        );
+
+        assert_eq!(
+            facts.data,
+            DataFacts {
+                segment_count: 3,
+                active_segment_count: 2,
+                passive_segment_count: 1,
+                total_bytes: 14,
+                largest_segment_bytes: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_type_indices_recorded_for_functions_and_tags() {
+        let facts = parse_wasm(
+            r#"
+            (module
+              (type $a (func))
+              (type $b (func (param i32) (result i32)))
+              (import "env" "f" (func (type $b)))
+              (func (type $a))
+              (func (type $b) (local.get 0))
+              (tag (type $b))
+            )
+            "#,
+        );
+
+        assert_eq!(facts.types.sub_types.len(), 2);
+        assert_eq!(facts.types.function_types, vec![1, 0, 1]);
+        assert_eq!(facts.types.tag_types, vec![1]);
+    }
+
     #[test]
     fn test_empty_module_invariants() {
         let facts = parse_wasm(r#"(module)"#);
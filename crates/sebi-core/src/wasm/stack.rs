@@ -0,0 +1,282 @@
+//! Operand-stack depth estimation for the instruction scan.
+//!
+//! Tracks the value-stack height of a single function body while operators
+//! are read in order, using `wasmparser`'s per-operator arity and the module
+//! types recorded by the section pass.
+//!
+//! Approximations (the result is an *estimate*, not a validator):
+//! - Heights are resynchronised at control-flow joins: `else` and `end`
+//!   reset the height to the frame's entry height plus its params/results.
+//! - After an unconditional transfer (`br`, `br_table`, `return`,
+//!   `unreachable`, `throw`, tail calls) the rest of the frame is dead code;
+//!   the height is clamped to the frame's entry height and never underflows.
+//! - Operators whose arity cannot be resolved (unknown types, continuations)
+//!   are treated as stack-neutral.
+//! - Locals are not counted; only operand-stack values are.
+
+use wasmparser::{
+    BlockType, CompositeInnerType, ContType, FrameKind, FuncType, ModuleArity, Operator, RefType,
+    SubType,
+};
+
+use crate::wasm::scan::ScanContext;
+
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    block_type: BlockType,
+    kind: FrameKind,
+    /// Stack height below the frame's params.
+    base: u32,
+}
+
+/// Value-stack height tracker for one function body.
+pub struct StackEstimator<'a> {
+    ctx: &'a ScanContext,
+    frames: Vec<Frame>,
+    height: u32,
+    max_height: u32,
+}
+
+impl<'a> StackEstimator<'a> {
+    /// Starts tracking the body of module-level function `function_index`.
+    pub fn new(ctx: &'a ScanContext, function_index: u32) -> Self {
+        let block_type = ctx
+            .types
+            .function_types
+            .get(function_index as usize)
+            .map_or(BlockType::Empty, |&ty| BlockType::FuncType(ty));
+
+        Self {
+            ctx,
+            frames: vec![Frame {
+                block_type,
+                kind: FrameKind::Block,
+                base: 0,
+            }],
+            height: 0,
+            max_height: 0,
+        }
+    }
+
+    /// Highest operand-stack height observed so far.
+    pub fn max_height(&self) -> u32 {
+        self.max_height
+    }
+
+    /// Updates the estimate for one operator.
+    pub fn on_operator(&mut self, op: &Operator<'_>) {
+        let (pops, pushes) = op.operator_arity(&*self).unwrap_or((0, 0));
+
+        match op {
+            Operator::Block { blockty } => self.enter(*blockty, FrameKind::Block, pops, pushes),
+            Operator::Loop { blockty } => self.enter(*blockty, FrameKind::Loop, pops, pushes),
+            Operator::If { blockty } => self.enter(*blockty, FrameKind::If, pops, pushes),
+            Operator::Try { blockty } => self.enter(*blockty, FrameKind::LegacyTry, pops, pushes),
+            Operator::TryTable { try_table } => {
+                self.enter(try_table.ty, FrameKind::TryTable, pops, pushes)
+            }
+
+            Operator::Else | Operator::Catch { .. } | Operator::CatchAll => {
+                if let Some(frame) = self.frames.last() {
+                    self.height = frame.base;
+                }
+                self.push(pushes);
+            }
+
+            Operator::End | Operator::Delegate { .. } => {
+                if let Some(frame) = self.frames.pop() {
+                    self.height = frame.base;
+                }
+                self.push(pushes);
+            }
+
+            Operator::Br { .. }
+            | Operator::BrTable { .. }
+            | Operator::Return
+            | Operator::Unreachable
+            | Operator::Throw { .. }
+            | Operator::ThrowRef
+            | Operator::Rethrow { .. }
+            | Operator::ReturnCall { .. }
+            | Operator::ReturnCallIndirect { .. }
+            | Operator::ReturnCallRef { .. } => {
+                self.pop(pops);
+                if let Some(frame) = self.frames.last() {
+                    self.height = frame.base;
+                }
+            }
+
+            _ => {
+                self.pop(pops);
+                self.push(pushes);
+            }
+        }
+    }
+
+    /// Updates the estimate for an operator with fixed arity and no effect
+    /// on control frames; equivalent to `on_operator` for such operators.
+    pub fn on_fixed_arity(&mut self, pops: u32, pushes: u32) {
+        self.pop(pops);
+        self.push(pushes);
+    }
+
+    fn enter(&mut self, block_type: BlockType, kind: FrameKind, pops: u32, pushes: u32) {
+        self.pop(pops);
+        self.frames.push(Frame {
+            block_type,
+            kind,
+            base: self.height,
+        });
+        self.push(pushes);
+    }
+
+    fn pop(&mut self, n: u32) {
+        let floor = self.frames.last().map_or(0, |f| f.base);
+        self.height = self.height.saturating_sub(n).max(floor);
+    }
+
+    fn push(&mut self, n: u32) {
+        self.height = self.height.saturating_add(n);
+        self.max_height = self.max_height.max(self.height);
+    }
+}
+
+impl ModuleArity for StackEstimator<'_> {
+    fn sub_type_at(&self, type_idx: u32) -> Option<&SubType> {
+        self.ctx.types.sub_types.get(type_idx as usize)
+    }
+
+    fn tag_type_arity(&self, at: u32) -> Option<(u32, u32)> {
+        let ty = *self.ctx.types.tag_types.get(at as usize)?;
+        self.sub_type_arity(self.sub_type_at(ty)?)
+    }
+
+    fn type_index_of_function(&self, function_idx: u32) -> Option<u32> {
+        self.ctx
+            .types
+            .function_types
+            .get(function_idx as usize)
+            .copied()
+    }
+
+    fn func_type_of_cont_type(&self, c: &ContType) -> Option<&FuncType> {
+        let sub = self.sub_type_at(c.0.as_module_index()?)?;
+        match &sub.composite_type.inner {
+            CompositeInnerType::Func(f) => Some(f),
+            _ => None,
+        }
+    }
+
+    fn sub_type_of_ref_type(&self, rt: &RefType) -> Option<&SubType> {
+        self.sub_type_at(rt.type_index()?.as_module_index()?)
+    }
+
+    fn control_stack_height(&self) -> u32 {
+        self.frames.len() as u32
+    }
+
+    fn label_block(&self, depth: u32) -> Option<(BlockType, FrameKind)> {
+        let index = self.frames.len().checked_sub(depth as usize + 1)?;
+        let frame = self.frames.get(index)?;
+        Some((frame.block_type, frame.kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::sections::{self, SectionFacts};
+    use wasmparser::{FunctionBody, Parser, Payload};
+
+    /// Returns the estimated max depth of every function body in `wat`.
+    fn depths(wat: &str) -> Vec<u32> {
+        let wasm = wat::parse_str(wat).unwrap();
+        let mut facts = SectionFacts::default();
+        let mut bodies: Vec<FunctionBody> = Vec::new();
+
+        for payload in Parser::new(0).parse_all(&wasm) {
+            match payload.unwrap() {
+                Payload::TypeSection(r) => sections::on_type_section(&mut facts, r).unwrap(),
+                Payload::ImportSection(r) => sections::on_import_section(&mut facts, r).unwrap(),
+                Payload::FunctionSection(r) => {
+                    sections::on_function_section(&mut facts, r).unwrap()
+                }
+                Payload::CodeSectionEntry(body) => bodies.push(body),
+                _ => {}
+            }
+        }
+
+        let ctx = ScanContext::from_sections(&facts);
+        bodies
+            .iter()
+            .enumerate()
+            .map(|(i, body)| {
+                let mut est = StackEstimator::new(&ctx, ctx.imported_function_count + i as u32);
+                let mut reader = body.get_operators_reader().unwrap();
+                while !reader.eof() {
+                    est.on_operator(&reader.read().unwrap());
+                }
+                est.max_height()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn flat_expression_depth() {
+        // (1 + 2) needs two slots.
+        let d = depths("(module (func (drop (i32.add (i32.const 1) (i32.const 2)))))");
+        assert_eq!(d, vec![2]);
+    }
+
+    #[test]
+    fn deep_right_nested_expression() {
+        // 1 + (1 + (1 + ... )) keeps every left operand live.
+        let mut expr = String::from("(i32.const 0)");
+        for _ in 0..50 {
+            expr = format!("(i32.add (i32.const 1) {expr})");
+        }
+        let d = depths(&format!("(module (func (drop {expr})))"));
+        assert_eq!(d, vec![51]);
+    }
+
+    #[test]
+    fn call_arity_uses_function_types() {
+        let d = depths(
+            r#"
+            (module
+              (import "env" "h" (func $h (param i32 i32 i32) (result i32)))
+              (func (drop (call $h (i32.const 1) (i32.const 2) (i32.const 3)))))
+            "#,
+        );
+        assert_eq!(d, vec![3]);
+    }
+
+    #[test]
+    fn dead_code_after_branch_does_not_underflow() {
+        let d = depths(
+            r#"
+            (module
+              (func (result i32)
+                (block (result i32)
+                  (br 0 (i32.const 1))
+                  (drop) (drop) (drop)
+                  (i32.const 2))))
+            "#,
+        );
+        assert_eq!(d, vec![1]);
+    }
+
+    #[test]
+    fn if_else_resynchronises_height() {
+        let d = depths(
+            r#"
+            (module
+              (func (param i32) (result i32)
+                (if (result i32) (local.get 0)
+                  (then (i32.add (i32.const 1) (i32.const 2)))
+                  (else (i32.const 3)))))
+            "#,
+        );
+        assert_eq!(d, vec![2]);
+    }
+}
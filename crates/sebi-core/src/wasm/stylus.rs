@@ -1,18 +1,506 @@
-use crate::report::model::AnalysisInfo;
+//! Stylus target profile for SEBI.
+//!
+//! Stylus programs reach the host only through `vm_hooks`, expose a single
+//! linear memory as `memory`, are entered through `user_entrypoint`, and
+//! must avoid features activation rejects (floats, start functions,
+//! multiple memories). `StylusProfile` checks these and records the
+//! outcome in `analysis.stylus`.
+
+use crate::report::model::{AnalysisInfo, SdkInfo, StylusAnalysis, Target};
+use crate::wasm::profile::{FeaturePolicy, RequiredExport, TargetProfile};
+use crate::wasm::reachability::STYLUS_ENTRYPOINT;
+use crate::wasm::scan::InstructionFacts;
 use crate::wasm::sections::SectionFacts;
 
-pub fn normalize(sections: &mut SectionFacts, analysis: &mut AnalysisInfo) {
-    // Apply minimal post-parse normalization and emit analysis warnings.
-    //
-    // This stage exists to annotate cases where the extracted section data
-    // may be incomplete or unconventional, without interpreting risk
-    // or influencing rule evaluation.
-    if sections.memory_count == 0 {
+/// Import module through which Stylus programs reach the host.
+pub const VM_HOOKS_MODULE: &str = "vm_hooks";
+
+/// Exports only Stylus programs define.
+pub const STYLUS_EXPORTS: [&str; 2] = [STYLUS_ENTRYPOINT, "mark_used"];
+
+/// Stylus page limit on current mainnet parameters (128 pages, 8 MiB).
+/// Chains and ArbOS versions differ, so this is only the default.
+pub const DEFAULT_INITIAL_PAGES_LIMIT: u64 = 128;
+
+/// Stylus expectations, with chain-dependent limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StylusProfile {
+    /// Initial memory (pages) above which a warning is emitted.
+    pub initial_pages_limit: u64,
+}
+
+impl Default for StylusProfile {
+    fn default() -> Self {
+        Self {
+            initial_pages_limit: DEFAULT_INITIAL_PAGES_LIMIT,
+        }
+    }
+}
+
+impl TargetProfile for StylusProfile {
+    fn target(&self) -> Target {
+        Target::Stylus
+    }
+
+    fn required_exports(&self) -> &'static [RequiredExport] {
+        &[RequiredExport {
+            name: STYLUS_ENTRYPOINT,
+            kind: "func",
+        }]
+    }
+
+    /// `console` is only linked into debug builds.
+    fn import_allowlist(&self) -> Option<&'static [&'static str]> {
+        Some(&["console", VM_HOOKS_MODULE])
+    }
+
+    /// Stylus activation validates with bulk memory enabled.
+    fn allowed_features(&self) -> &'static [&'static str] {
+        &["bulk_memory", "sign_extension"]
+    }
+
+    fn feature_policy(&self) -> FeaturePolicy {
+        FeaturePolicy {
+            floats: false,
+            start_function: false,
+            multiple_memories: false,
+        }
+    }
+
+    fn normalize(
+        &self,
+        sections: &mut SectionFacts,
+        instructions: &InstructionFacts,
+        analysis: &mut AnalysisInfo,
+    ) {
+        let policy = self.feature_policy();
+        let mut stylus = StylusAnalysis::default();
+        check_stylus_memory(sections, self.initial_pages_limit, policy, analysis);
+        if !policy.floats {
+            check_stylus_floats(instructions, &mut stylus, analysis);
+        }
+        if !policy.start_function {
+            check_stylus_start(sections, &mut stylus, analysis);
+        }
+        check_memory_grow_payment(sections, instructions, &mut stylus, analysis);
+        check_exported_mutable_globals(sections, &mut stylus, analysis);
+        analysis.stylus = Some(stylus);
+    }
+}
+
+/// Name prefix shared by Stylus SDKs and tooling (`stylus-sdk-rs`,
+/// `stylus-sdk-c`, ...).
+const STYLUS_SDK_PREFIX: &str = "stylus";
+
+/// Finds the Stylus SDK the artifact identifies, if any.
+///
+/// Sources, in order of preference:
+/// 1. `producers` `sdk` entries named `stylus*`
+/// 2. `producers` `processed-by` entries named `stylus*`
+/// 3. custom sections named `stylus*`, whose payload is the version
+///
+/// Within a source the lexicographically first entry wins, so the result is
+/// deterministic. Absent metadata yields `None`; stripped artifacts are
+/// common.
+pub fn detect_sdk(sections: &SectionFacts) -> Option<SdkInfo> {
+    let from_producers = |field: &str| {
+        sections
+            .producers
+            .iter()
+            .find(|p| p.field == field && p.name.starts_with(STYLUS_SDK_PREFIX))
+            .map(|p| SdkInfo {
+                name: p.name.clone(),
+                version: p.version.clone(),
+            })
+    };
+
+    from_producers("sdk")
+        .or_else(|| from_producers("processed-by"))
+        .or_else(|| {
+            sections
+                .stylus_custom_sections
+                .iter()
+                .next()
+                .map(|(name, payload)| SdkInfo {
+                    name: name.clone(),
+                    version: payload.clone(),
+                })
+        })
+}
+
+/// Stylus loads linear memory through the `memory` export and accepts a
+/// single memory only.
+fn check_stylus_memory(
+    sections: &SectionFacts,
+    pages_limit: u64,
+    policy: FeaturePolicy,
+    analysis: &mut AnalysisInfo,
+) {
+    let exported = sections
+        .exports
+        .iter()
+        .any(|e| e.kind == "memory" && e.name == "memory");
+    if !exported {
         analysis
             .warnings
-            .push("no memory section or imported memory detected".to_string());
+            .push("Stylus target: linear memory is not exported as 'memory'".to_string());
+    }
+
+    if !policy.multiple_memories && sections.memory_count > 1 {
+        analysis.warnings.push(format!(
+            "Stylus target: {} memories declared; Stylus supports a single memory",
+            sections.memory_count
+        ));
+    }
+
+    // Initial pages are charged at activation and capped by the chain.
+    if let Some(min_pages) = sections.memory_min_pages
+        && min_pages > pages_limit
+    {
+        analysis.warnings.push(format!(
+            "Stylus target: initial memory of {min_pages} pages exceeds the Stylus limit of {pages_limit} pages"
+        ));
     }
+}
+
+/// Stylus activation rejects any floating-point instruction, constants
+/// included; this usually means the program was built without the
+/// soft-float target configuration.
+fn check_stylus_floats(
+    instructions: &InstructionFacts,
+    stylus: &mut StylusAnalysis,
+    analysis: &mut AnalysisInfo,
+) {
+    let count = instructions.float_op_count + instructions.float_const_count;
+    stylus.float_instruction_count = count;
+    stylus.float_blocks_activation = count > 0;
+    if count > 0 {
+        let plural = if count == 1 { "" } else { "s" };
+        analysis.warnings.push(format!(
+            "Stylus target: artifact contains {count} floating-point instruction{plural}; Stylus activation will reject it"
+        ));
+    }
+}
+
+/// Hostio through which Stylus programs pay for `memory.grow`.
+const PAY_FOR_MEMORY_GROW: &str = "pay_for_memory_grow";
 
-    // Ensure deterministic output ordering.
-    analysis.warnings.sort();
+/// `memory.grow` should be paired with the `pay_for_memory_grow` hostio;
+/// either one without the other suggests miswired memory accounting.
+fn check_memory_grow_payment(
+    sections: &SectionFacts,
+    instructions: &InstructionFacts,
+    stylus: &mut StylusAnalysis,
+    analysis: &mut AnalysisInfo,
+) {
+    let hostios: Vec<&str> = sections
+        .imports
+        .iter()
+        .filter(|i| i.module == VM_HOOKS_MODULE)
+        .map(|i| i.name.as_str())
+        .collect();
+    stylus.pays_for_memory_grow = hostios.contains(&PAY_FOR_MEMORY_GROW);
+
+    match (instructions.has_memory_grow, stylus.pays_for_memory_grow) {
+        (true, false) => {
+            let imported = if hostios.is_empty() {
+                "none".to_string()
+            } else {
+                hostios.join(", ")
+            };
+            let count = instructions.memory_grow_count;
+            let plural = if count == 1 { "" } else { "s" };
+            analysis.warnings.push(format!(
+                "Stylus target: memory.grow used {count} time{plural} but {VM_HOOKS_MODULE}.{PAY_FOR_MEMORY_GROW} is not imported ({VM_HOOKS_MODULE} imports: {imported})"
+            ));
+        }
+        (false, true) => analysis.warnings.push(format!(
+            "Stylus target: {VM_HOOKS_MODULE}.{PAY_FOR_MEMORY_GROW} is imported but memory.grow is never used"
+        )),
+        _ => {}
+    }
+}
+
+/// An exported mutable global is state the host can rewrite between calls.
+fn check_exported_mutable_globals(
+    sections: &SectionFacts,
+    stylus: &mut StylusAnalysis,
+    analysis: &mut AnalysisInfo,
+) {
+    for (name, global) in sections.exported_mutable_globals() {
+        stylus.exported_mutable_globals.push(name.to_string());
+        analysis.warnings.push(format!(
+            "Stylus target: mutable global {} ({}) is exported as '{name}'; the host can modify it between calls",
+            global.index, global.val_type
+        ));
+    }
+}
+
+/// Stylus programs may not declare a start function; initialization has
+/// to happen lazily on the first call.
+fn check_stylus_start(
+    sections: &SectionFacts,
+    stylus: &mut StylusAnalysis,
+    analysis: &mut AnalysisInfo,
+) {
+    let Some(index) = sections.start_function else {
+        return;
+    };
+    stylus.has_start_function = true;
+    let name = match sections.function_names.get(&index) {
+        Some(name) => format!(" ({name})"),
+        None => String::new(),
+    };
+    analysis.warnings.push(format!(
+        "Stylus target: start function {index}{name} is not allowed; initialize lazily instead"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::parse::{ParseOptions, parse_wasm, parse_wasm_with_options};
+
+    fn warnings_of(wat: &str) -> Vec<String> {
+        parse_wasm(&wat::parse_str(wat).unwrap())
+            .unwrap()
+            .analysis
+            .warnings
+    }
+
+    #[test]
+    fn sdk_detected_from_producers_then_custom_sections() {
+        let sdk_of = |wat: &str| {
+            parse_wasm(&wat::parse_str(wat).unwrap())
+                .unwrap()
+                .analysis
+                .sdk
+        };
+        let sdk = |name: &str, version: &str| {
+            Some(SdkInfo {
+                name: name.into(),
+                version: version.into(),
+            })
+        };
+
+        assert_eq!(
+            sdk_of(
+                r#"(module
+                     (@producers (sdk "stylus-sdk-rs" "0.6.0") (processed-by "cargo-stylus" "0.5.3"))
+                     (@custom "stylus-sdk-c" "0.1.0"))"#
+            ),
+            sdk("stylus-sdk-rs", "0.6.0")
+        );
+        assert_eq!(
+            sdk_of(r#"(module (@custom "stylus-sdk-c" " 0.1.0 "))"#),
+            sdk("stylus-sdk-c", "0.1.0")
+        );
+        // Non-Stylus producers and bare modules have no SDK.
+        assert_eq!(
+            sdk_of(r#"(module (@producers (sdk "wasi-sdk" "21.0")))"#),
+            None
+        );
+        assert_eq!(sdk_of("(module)"), None);
+    }
+
+    #[test]
+    fn memory_grow_checked_against_payment_hostio() {
+        let module = |imports: &str, body: &str| {
+            parse_wasm(
+                &wat::parse_str(format!(
+                    r#"(module {imports}
+                         (memory (export "memory") 1)
+                         (func (export "user_entrypoint") (param i32) (result i32) {body} (i32.const 0)))"#
+                ))
+                .unwrap(),
+            )
+            .unwrap()
+            .analysis
+        };
+        let read_args = r#"(import "vm_hooks" "read_args" (func (param i32)))"#;
+        let pay = r#"(import "vm_hooks" "pay_for_memory_grow" (func (param i32)))"#;
+        let grow = "(drop (memory.grow (i32.const 1)))";
+
+        let analysis = module(read_args, grow);
+        assert!(!analysis.stylus.unwrap().pays_for_memory_grow);
+        assert_eq!(
+            analysis.warnings,
+            vec![
+                "Stylus target: memory.grow used 1 time but vm_hooks.pay_for_memory_grow is not imported (vm_hooks imports: read_args)"
+            ]
+        );
+
+        let analysis = module(&format!("{read_args} {pay}"), grow);
+        assert!(analysis.stylus.unwrap().pays_for_memory_grow);
+        assert!(analysis.warnings.is_empty());
+
+        let analysis = module(pay, "");
+        assert_eq!(
+            analysis.warnings,
+            vec![
+                "Stylus target: vm_hooks.pay_for_memory_grow is imported but memory.grow is never used"
+            ]
+        );
+
+        assert!(module("", "").warnings.is_empty());
+    }
+
+    #[test]
+    fn exported_mutable_globals_called_out() {
+        let facts = parse_wasm(
+            &wat::parse_str(
+                r#"(module
+                     (memory (export "memory") 1)
+                     (global $sp (mut i32) (i32.const 0))
+                     (global $data_end i32 (i32.const 8))
+                     (global $counter (mut i64) (i64.const 0))
+                     (export "__data_end" (global $data_end))
+                     (export "counter" (global $counter))
+                     (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        // Unexported `$sp` and immutable `__data_end` are fine.
+        assert_eq!(
+            facts.analysis.stylus.unwrap().exported_mutable_globals,
+            vec!["counter"]
+        );
+        assert_eq!(
+            facts.analysis.warnings,
+            vec![
+                "Stylus target: mutable global 2 (i64) is exported as 'counter'; the host can modify it between calls"
+            ]
+        );
+    }
+
+    #[test]
+    fn stylus_memory_must_be_single_and_exported() {
+        let entry = r#"(func (export "user_entrypoint") (param i32) (result i32) (i32.const 0))"#;
+
+        let ok = warnings_of(&format!(r#"(module (memory (export "memory") 1) {entry})"#));
+        assert!(ok.iter().all(|w| !w.starts_with("Stylus target")));
+
+        let warnings = warnings_of(&format!(
+            r#"(module (memory (export "heap") 1) (memory 1) {entry})"#
+        ));
+        assert!(
+            warnings
+                .contains(&"Stylus target: linear memory is not exported as 'memory'".to_string())
+        );
+        assert!(warnings.contains(
+            &"Stylus target: 2 memories declared; Stylus supports a single memory".to_string()
+        ));
+
+        // Not a Stylus module: no Stylus-specific warnings.
+        let other = warnings_of(r#"(module (memory 1) (memory 1) (func (export "main")))"#);
+        assert!(other.iter().all(|w| !w.starts_with("Stylus target")));
+    }
+
+    #[test]
+    fn floats_block_stylus_activation() {
+        let entry = r#"(memory (export "memory") 1)
+            (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0))"#;
+
+        let facts = parse_wasm(
+            &wat::parse_str(format!(
+                r#"(module {entry}
+                     (func (result f64) (f64.add (f64.const 1) (f64.const 2))))"#
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        let stylus = facts.analysis.stylus.expect("Stylus block");
+        assert_eq!(stylus.float_instruction_count, 3);
+        assert!(stylus.float_blocks_activation);
+        assert!(facts.analysis.warnings.contains(
+            &"Stylus target: artifact contains 3 floating-point instructions; Stylus activation will reject it"
+                .to_string()
+        ));
+
+        let facts = parse_wasm(&wat::parse_str(format!("(module {entry})")).unwrap()).unwrap();
+        let stylus = facts.analysis.stylus.expect("Stylus block");
+        assert!(!stylus.float_blocks_activation);
+        assert!(facts.analysis.warnings.is_empty());
+
+        // Floats outside Stylus are not an activation concern.
+        let facts =
+            parse_wasm(&wat::parse_str("(module (func (result f32) (f32.const 1)))").unwrap())
+                .unwrap();
+        assert!(facts.analysis.stylus.is_none());
+        assert!(
+            facts
+                .analysis
+                .warnings
+                .iter()
+                .all(|w| !w.contains("floating-point"))
+        );
+    }
+
+    #[test]
+    fn start_function_rejected_for_stylus() {
+        let entry = r#"(memory (export "memory") 1)
+            (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0))"#;
+
+        let facts = parse_wasm(
+            &wat::parse_str(format!("(module {entry} (func $init) (start $init))")).unwrap(),
+        )
+        .unwrap();
+        assert!(facts.analysis.stylus.unwrap().has_start_function);
+        assert_eq!(
+            facts.analysis.warnings,
+            vec![
+                "Stylus target: start function 1 (init) is not allowed; initialize lazily instead"
+            ]
+        );
+
+        let facts = parse_wasm(&wat::parse_str(format!("(module {entry})")).unwrap()).unwrap();
+        assert!(!facts.analysis.stylus.unwrap().has_start_function);
+
+        // Other targets may use a start function.
+        let facts =
+            parse_wasm(&wat::parse_str("(module (func $init) (start $init))").unwrap()).unwrap();
+        assert!(
+            facts
+                .analysis
+                .warnings
+                .iter()
+                .all(|w| !w.contains("start function"))
+        );
+    }
+
+    #[test]
+    fn initial_pages_checked_against_configurable_limit() {
+        let module = |pages: u64| {
+            wat::parse_str(format!(
+                r#"(module (memory (export "memory") {pages})
+                     (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))"#
+            ))
+            .unwrap()
+        };
+        let warning =
+            "Stylus target: initial memory of 200 pages exceeds the Stylus limit of 128 pages";
+
+        let facts = parse_wasm(&module(200)).unwrap();
+        assert_eq!(facts.analysis.warnings, vec![warning]);
+        assert!(
+            parse_wasm(&module(128))
+                .unwrap()
+                .analysis
+                .warnings
+                .is_empty()
+        );
+
+        let opts = ParseOptions {
+            stylus_initial_pages_limit: 256,
+            ..Default::default()
+        };
+        let facts = parse_wasm_with_options(&module(200), &opts).unwrap();
+        assert!(facts.analysis.warnings.is_empty());
+
+        // Only Stylus targets are held to the limit.
+        let facts = parse_wasm(&wat::parse_str("(module (memory 200))").unwrap()).unwrap();
+        assert!(facts.analysis.warnings.iter().all(|w| !w.contains("limit")));
+    }
 }
@@ -0,0 +1,263 @@
+//! Source-toolchain detection for SEBI.
+//!
+//! Reviewers triage Rust, C/C++ and AssemblyScript artifacts differently,
+//! so this normalization step guesses the toolchain from section facts. It
+//! runs alongside `wasm::profile::normalize` and never influences rules.
+//!
+//! Heuristics live in a single table (`HEURISTICS`); each match adds its
+//! weight to one toolchain and records a line of evidence. The highest
+//! score wins, and its size sets the confidence:
+//! - `high`:   3 or more (e.g. a `producers` entry naming the language)
+//! - `medium`: 2
+//! - `low`:    1
+//!
+//! A tie for the top score yields `unknown`, keeping the evidence.
+
+use self::Matcher::*;
+use crate::report::model::Toolchain::{AssemblyScript, Cpp, Rust};
+use crate::report::model::{Confidence, Toolchain, ToolchainInfo};
+use crate::wasm::sections::SectionFacts;
+
+/// A fact about the module that hints at a toolchain.
+#[derive(Debug, Clone, Copy)]
+enum Matcher {
+    /// `producers` entry `language: <name>`
+    ProducerLanguage(&'static str),
+    /// `producers` entry `processed-by: <name>`
+    ProducerTool(&'static str),
+    /// Any import from the module
+    ImportModule(&'static str),
+    /// An import from `module` whose name starts with `prefix`
+    ImportNamePrefix {
+        module: &'static str,
+        prefix: &'static str,
+    },
+    /// An import `module`.`name` with the exact canonical signature
+    ImportSignature {
+        module: &'static str,
+        name: &'static str,
+        signature: &'static str,
+    },
+    /// A function export with this name
+    ExportName(&'static str),
+    /// A `name`-section function name starting with the prefix
+    FunctionNamePrefix(&'static str),
+    /// A `name`-section function name containing the fragment
+    FunctionNameContains(&'static str),
+}
+
+struct Heuristic {
+    toolchain: Toolchain,
+    matcher: Matcher,
+    weight: u32,
+}
+
+const fn rule(toolchain: Toolchain, matcher: Matcher, weight: u32) -> Heuristic {
+    Heuristic {
+        toolchain,
+        matcher,
+        weight,
+    }
+}
+
+/// Detection table, in evidence order.
+const HEURISTICS: &[Heuristic] = &[
+    // Rust
+    rule(Rust, ProducerLanguage("Rust"), 3),
+    rule(Rust, ProducerTool("rustc"), 3),
+    rule(Rust, FunctionNamePrefix("__rust_"), 2),
+    rule(Rust, FunctionNamePrefix("rust_begin_unwind"), 2),
+    rule(Rust, FunctionNamePrefix("_RN"), 2),
+    rule(Rust, FunctionNameContains("panic_fmt"), 1),
+    // stylus-sdk-rs binds hostios through `vm_hooks`
+    rule(Rust, ImportModule("vm_hooks"), 1),
+    // C/C++
+    rule(Cpp, ProducerLanguage("C++"), 3),
+    rule(Cpp, ProducerLanguage("C"), 3),
+    rule(Cpp, ProducerTool("clang"), 3),
+    rule(
+        Cpp,
+        ImportNamePrefix {
+            module: "env",
+            prefix: "__cxa_",
+        },
+        2,
+    ),
+    rule(Cpp, FunctionNameContains("operator_new"), 2),
+    rule(Cpp, FunctionNamePrefix("__cxa_"), 2),
+    // Itanium mangling; legacy Rust mangling shares the prefix
+    rule(Cpp, FunctionNamePrefix("_Z"), 1),
+    rule(Cpp, FunctionNamePrefix("malloc"), 1),
+    // AssemblyScript
+    rule(
+        AssemblyScript,
+        ImportSignature {
+            module: "env",
+            name: "abort",
+            signature: "(i32, i32, i32, i32) -> ()",
+        },
+        3,
+    ),
+    rule(AssemblyScript, ExportName("__new"), 2),
+    rule(AssemblyScript, ExportName("__pin"), 2),
+    rule(AssemblyScript, ExportName("__collect"), 1),
+];
+
+impl Matcher {
+    fn matches(&self, sections: &SectionFacts) -> bool {
+        let producer = |field: &str, name: &str| {
+            sections
+                .producers
+                .iter()
+                .any(|p| p.field == field && p.name == name)
+        };
+        let mut function_names = sections.function_names.values();
+
+        match *self {
+            ProducerLanguage(name) => producer("language", name),
+            ProducerTool(name) => producer("processed-by", name),
+            ImportModule(module) => sections.import_modules.contains_key(module),
+            ImportNamePrefix { module, prefix } => sections
+                .imports
+                .iter()
+                .any(|i| i.module == module && i.name.starts_with(prefix)),
+            ImportSignature {
+                module,
+                name,
+                signature,
+            } => sections.imports.iter().any(|i| {
+                i.module == module && i.name == name && i.signature.as_deref() == Some(signature)
+            }),
+            ExportName(name) => sections.function_index_for_export(name).is_some(),
+            FunctionNamePrefix(prefix) => function_names.any(|n| n.starts_with(prefix)),
+            FunctionNameContains(fragment) => function_names.any(|n| n.contains(fragment)),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match *self {
+            ProducerLanguage(name) => format!("producers language {name}"),
+            ProducerTool(name) => format!("producers processed-by {name}"),
+            ImportModule(module) => format!("import module {module}"),
+            ImportNamePrefix { module, prefix } => format!("import {module}.{prefix}*"),
+            ImportSignature {
+                module,
+                name,
+                signature,
+            } => format!("import {module}.{name} {signature}"),
+            ExportName(name) => format!("export {name}"),
+            FunctionNamePrefix(prefix) => format!("function name {prefix}*"),
+            FunctionNameContains(fragment) => format!("function name *{fragment}*"),
+        }
+    }
+}
+
+/// Guesses the source toolchain from section facts.
+pub fn detect(sections: &SectionFacts) -> ToolchainInfo {
+    let mut scores: [(Toolchain, u32); 3] = [(Rust, 0), (Cpp, 0), (AssemblyScript, 0)];
+    let mut evidence = Vec::new();
+
+    for heuristic in HEURISTICS {
+        if heuristic.matcher.matches(sections) {
+            let (toolchain, score) = scores
+                .iter_mut()
+                .find(|(t, _)| *t == heuristic.toolchain)
+                .expect("every heuristic toolchain is scored");
+            *score += heuristic.weight;
+            evidence.push(format!(
+                "{} (+{} {})",
+                heuristic.matcher.describe(),
+                heuristic.weight,
+                toolchain.as_str()
+            ));
+        }
+    }
+
+    // Stable, so equal scores keep table order before the tie check.
+    scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    let (best, top) = scores[0];
+    let tied = scores[1].1 == top;
+
+    let (name, confidence) = match top {
+        0 => (Toolchain::Unknown, Confidence::None),
+        _ if tied => (Toolchain::Unknown, Confidence::None),
+        1 => (best, Confidence::Low),
+        2 => (best, Confidence::Medium),
+        _ => (best, Confidence::High),
+    };
+
+    ToolchainInfo {
+        name,
+        confidence,
+        evidence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::parse::parse_wasm;
+
+    fn detect_wat(wat: &str) -> ToolchainInfo {
+        detect(&parse_wasm(&wat::parse_str(wat).unwrap()).unwrap().sections)
+    }
+
+    #[test]
+    fn producers_language_is_high_confidence() {
+        let info = detect_wat(r#"(module (@producers (language "Rust" "")))"#);
+        assert_eq!(info.name, Rust);
+        assert_eq!(info.confidence, Confidence::High);
+        assert_eq!(info.evidence, vec!["producers language Rust (+3 rust)"]);
+
+        let info = detect_wat(r#"(module (@producers (processed-by "clang" "17.0.0")))"#);
+        assert_eq!((info.name, info.confidence), (Cpp, Confidence::High));
+    }
+
+    #[test]
+    fn assemblyscript_abort_signature() {
+        let info = detect_wat(
+            r#"(module
+                 (import "env" "abort" (func (param i32 i32 i32 i32)))
+                 (func (export "__new") (param i32 i32) (result i32) (i32.const 0)))"#,
+        );
+        assert_eq!(info.name, AssemblyScript);
+        assert_eq!(info.confidence, Confidence::High);
+        assert_eq!(info.evidence.len(), 2);
+
+        // `abort` with another shape is not AssemblyScript's.
+        let info = detect_wat(r#"(module (import "env" "abort" (func)))"#);
+        assert_eq!(info.name, Toolchain::Unknown);
+    }
+
+    #[test]
+    fn cxa_imports_and_mangled_names_point_to_cpp() {
+        let info = detect_wat(
+            r#"(module
+                 (import "env" "__cxa_throw" (func (param i32 i32 i32)))
+                 (func $_ZN5Token8transferEv))"#,
+        );
+        assert_eq!(info.name, Cpp);
+        assert_eq!(info.confidence, Confidence::High);
+    }
+
+    #[test]
+    fn tie_and_no_evidence_are_unknown() {
+        let info = detect_wat(
+            r#"(module
+                 (import "vm_hooks" "read_args" (func (param i32)))
+                 (func $malloc))"#,
+        );
+        assert_eq!(
+            (info.name, info.confidence),
+            (Toolchain::Unknown, Confidence::None)
+        );
+        assert_eq!(info.evidence.len(), 2);
+
+        let info = detect_wat("(module)");
+        assert_eq!(
+            (info.name, info.confidence),
+            (Toolchain::Unknown, Confidence::None)
+        );
+        assert!(info.evidence.is_empty());
+    }
+}
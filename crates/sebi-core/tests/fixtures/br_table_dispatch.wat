@@ -0,0 +1,59 @@
+(module
+  ;; Selector dispatcher compiled to a single 16-way br_table
+  (memory (export "memory") 1 1)
+
+  (func (export "user_entrypoint") (param $selector i32) (result i32)
+    (block $c16
+    (block $c15
+    (block $c14
+    (block $c13
+    (block $c12
+    (block $c11
+    (block $c10
+    (block $c9
+    (block $c8
+    (block $c7
+    (block $c6
+    (block $c5
+    (block $c4
+    (block $c3
+    (block $c2
+    (block $c1
+    (block $c0
+      (br_table $c0 $c1 $c2 $c3 $c4 $c5 $c6 $c7 $c8 $c9 $c10 $c11 $c12 $c13 $c14 $c15 $c16 (local.get $selector))
+    ) ;; $c0
+    (return (i32.const 100))
+    ) ;; $c1
+    (return (i32.const 101))
+    ) ;; $c2
+    (return (i32.const 102))
+    ) ;; $c3
+    (return (i32.const 103))
+    ) ;; $c4
+    (return (i32.const 104))
+    ) ;; $c5
+    (return (i32.const 105))
+    ) ;; $c6
+    (return (i32.const 106))
+    ) ;; $c7
+    (return (i32.const 107))
+    ) ;; $c8
+    (return (i32.const 108))
+    ) ;; $c9
+    (return (i32.const 109))
+    ) ;; $c10
+    (return (i32.const 110))
+    ) ;; $c11
+    (return (i32.const 111))
+    ) ;; $c12
+    (return (i32.const 112))
+    ) ;; $c13
+    (return (i32.const 113))
+    ) ;; $c14
+    (return (i32.const 114))
+    ) ;; $c15
+    (return (i32.const 115))
+    ) ;; $c16
+    (return (i32.const 0))
+  )
+)
@@ -0,0 +1,11 @@
+(module
+  ;; Bounded memory, no growth, no loops, no indirect calls.
+  ;; The only construct of interest is a memory.copy whose length
+  ;; comes from the caller.
+  (memory (export "memory") 1 1)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (memory.copy (i32.const 1024) (i32.const 0) (local.get $len))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,23 @@
+;; Diamond-shaped but acyclic call graph: entry -> left, right -> leaf.
+;; The leaf is reached along two paths, which is not recursion.
+(module
+  (memory (export "memory") 1 1)
+
+  (func $leaf (param $x i32) (result i32)
+    (i32.add (local.get $x) (i32.const 1))
+  )
+
+  (func $left (param $x i32) (result i32)
+    (call $leaf (i32.mul (local.get $x) (i32.const 2)))
+  )
+
+  (func $right (param $x i32) (result i32)
+    (call $leaf (i32.sub (local.get $x) (i32.const 1)))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.add
+      (call $left (local.get $len))
+      (call $right (local.get $len)))
+  )
+)
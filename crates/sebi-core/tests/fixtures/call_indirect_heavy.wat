@@ -0,0 +1,179 @@
+;; Pervasive dynamic dispatch: 100 call_indirect sites across two signatures,
+;; exactly the default R-CALL-02 threshold. R-CALL-01 fires as well.
+(module
+  (type $unary (func (param i32) (result i32)))
+  (type $nullary (func (result i32)))
+
+  (memory (export "memory") 1 16)
+
+  (table 2 funcref)
+  (elem (i32.const 0) $inc $zero)
+
+  (func $inc (param $x i32) (result i32)
+    (i32.add (local.get $x) (i32.const 1)))
+
+  (func $zero (result i32)
+    (i32.const 0))
+
+  ;; 50 unary dispatches, folded into a single chain.
+  (func $chain (param $x i32) (result i32)
+    (local.get $x)
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+    (call_indirect (type $unary) (i32.const 0))
+  )
+
+  ;; 50 nullary dispatches, summed.
+  (func $sum (result i32)
+    (call_indirect (type $nullary) (i32.const 1))
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+    (call_indirect (type $nullary) (i32.const 1))
+    (i32.add)
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.add (call $chain (local.get $len)) (call $sum))
+  )
+)
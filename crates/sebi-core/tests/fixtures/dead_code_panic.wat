@@ -0,0 +1,29 @@
+;; Rust contract whose only loop and memory.grow live in dead panic
+;; formatting code left behind by the toolchain.
+;; Module scope: R-MEM-02, R-LOOP-01. Reachable scope: no rules.
+(module
+  (import "vm_hooks" "read_args" (func $read_args (param i32)))
+  (import "vm_hooks" "write_result" (func $write_result (param i32 i32)))
+
+  (memory (export "memory") 1 1)
+
+  ;; Never called: remnants of core::fmt machinery.
+  (func $panic_fmt (param $len i32)
+    (local $i i32)
+    (drop (memory.grow (local.get $len)))
+    (block $done
+      (loop $copy
+        (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+        (i32.store8 (local.get $i) (i32.const 0))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $copy)
+      )
+    )
+  )
+
+  (func $user_entrypoint (export "user_entrypoint") (param $len i32) (result i32)
+    (call $read_args (i32.const 0))
+    (call $write_result (i32.const 0) (i32.const 32))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,20 @@
+(module
+  ;; Exception-handling proposal: non-local control flow via try/throw
+  (tag $revert (param i32))
+  (memory (export "memory") 1 1)
+
+  (func $checked_div (param $a i32) (param $b i32) (result i32)
+    (if (i32.eqz (local.get $b))
+      (then (throw $revert (i32.const 1))))
+    (i32.div_u (local.get $a) (local.get $b))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    try (result i32)
+      i32.const 100
+      local.get $len
+      call $checked_div
+    catch $revert
+    end
+  )
+)
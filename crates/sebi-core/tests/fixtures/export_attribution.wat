@@ -0,0 +1,41 @@
+;; Stylus-style contract with capabilities split across entry points.
+;; `deploy` grows memory, `user_entrypoint` loops, and an internal named
+;; helper performs the dynamic dispatch. An anonymous function also loops.
+;; Used to check `signals.instructions.by_export` attribution.
+(module
+  (type $handler (func (param i32) (result i32)))
+
+  (memory (export "memory") 1 16)
+  (table 2 funcref)
+  (elem (i32.const 0) $h0 $h1)
+
+  (func $h0 (type $handler) (local.get 0))
+  (func $h1 (type $handler) (i32.add (local.get 0) (i32.const 1)))
+
+  (func $dispatch (param $selector i32) (param $arg i32) (result i32)
+    (call_indirect (type $handler) (local.get $arg) (local.get $selector))
+  )
+
+  (func $deploy (export "deploy") (export "init")
+    (drop (memory.grow (i32.const 1)))
+  )
+
+  (func $user_entrypoint (export "user_entrypoint") (param $len i32) (result i32)
+    (local $i i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+        (drop (call $dispatch (i32.const 0) (local.get $i)))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next)
+      )
+    )
+    (i32.const 0)
+  )
+
+  (func (param $n i32)
+    (loop $spin
+      (br_if $spin (local.tee $n (i32.sub (local.get $n) (i32.const 1))))
+    )
+  )
+)
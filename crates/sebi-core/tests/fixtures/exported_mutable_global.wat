@@ -0,0 +1,16 @@
+;; Exports a mutable counter the host can overwrite between calls.
+;; The unexported stack pointer and the immutable `__data_end` are normal
+;; toolchain output and do not count towards R-GLOBAL-01.
+(module
+  (memory (export "memory") 1 1)
+  (global $sp (mut i32) (i32.const 1024))
+  (global $data_end i32 (i32.const 8))
+  (global $counter (mut i64) (i64.const 0))
+  (export "__data_end" (global $data_end))
+  (export "counter" (global $counter))
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (global.set $counter (i64.add (global.get $counter) (i64.const 1)))
+    (i32.const 0)
+  )
+)
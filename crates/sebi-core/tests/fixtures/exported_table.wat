@@ -0,0 +1,15 @@
+;; Exports its funcref table: the host can overwrite dispatch targets
+;; between calls, so R-TABLE-01 fires even without table.set in the module.
+(module
+  (type $handler (func (result i32)))
+  (memory (export "memory") 1 1)
+  (table $handlers (export "handlers") 2 4 funcref)
+  (elem (i32.const 0) $a $b)
+
+  (func $a (result i32) (i32.const 1))
+  (func $b (result i32) (i32.const 2))
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (call_indirect $handlers (type $handler) (local.get $len))
+  )
+)
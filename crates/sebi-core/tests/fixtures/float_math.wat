@@ -0,0 +1,16 @@
+(module
+  (memory (export "memory") 1 4)
+
+  ;; Fixed-point price computed through f64 — a determinism hazard.
+  (func $scale (param $amount i64) (param $bps i32) (result i64)
+    (i64.trunc_f64_u
+      (f64.mul
+        (f64.convert_i64_u (local.get $amount))
+        (f64.div (f64.convert_i32_u (local.get $bps)) (f64.const 10000))))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (drop (call $scale (i64.extend_i32_u (local.get $len)) (i32.const 30)))
+    (i32.const 0)
+  )
+)
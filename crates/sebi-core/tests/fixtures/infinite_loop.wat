@@ -0,0 +1,28 @@
+(module
+  (memory (export "memory") 1 1)
+
+  ;; Bounded: exits via br_if once the counter reaches zero
+  (func $sum (param $n i32) (result i32)
+    (local $acc i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $n)))
+        (local.set $acc (i32.add (local.get $acc) (local.get $n)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br $next)))
+    (local.get $acc)
+  )
+
+  ;; Infinite: unconditional back-edge with no way out
+  (func $spin
+    (loop $forever
+      (i32.store (i32.const 0) (i32.add (i32.load (i32.const 0)) (i32.const 1)))
+      (br $forever))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (if (i32.eqz (call $sum (local.get $len)))
+      (then (call $spin)))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,10 @@
+(module
+  ;; 64-bit linear memory (memory64 proposal): addresses and page counts are i64
+  (memory (export "memory") i64 1 2)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i64.store (i64.const 0) (i64.load (i64.const 8)))
+    (drop (memory.grow (i64.const 1)))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,15 @@
+;; Allocator pattern: memory.grow runs once per iteration of a loop whose
+;; trip count comes from the caller. Bounded maximum keeps R-MEM-01 quiet;
+;; R-MEM-02 and R-LOOP-03 both fire.
+(module
+  (memory (export "memory") 1 16)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (local $i i32)
+    (loop $grow
+      (drop (memory.grow (i32.const 1)))
+      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+      (br_if $grow (i32.lt_u (local.get $i) (local.get $len))))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,14 @@
+;; A single memory.grow on the straight-line path, before any loop:
+;; R-MEM-02 fires, R-LOOP-03 does not.
+(module
+  (memory (export "memory") 1 16)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (local $i i32)
+    (drop (memory.grow (i32.const 1)))
+    (loop $spin
+      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+      (br_if $spin (i32.lt_u (local.get $i) (local.get $len))))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,9 @@
+;; Declares a maximum, but at 65,536 pages (4 GiB) it bounds nothing:
+;; R-MEM-01 passes, R-MEM-04 fires against the default 1,024-page ceiling.
+(module
+  (memory (export "memory") 1 65536)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,19 @@
+;; Direct recursion: a factorial that calls itself. No loop anywhere, so
+;; R-LOOP-01 stays quiet while R-CALL-03 fires.
+(module
+  (memory (export "memory") 1 1)
+
+  (func $factorial (param $n i32) (result i32)
+    (if (result i32) (i32.le_u (local.get $n) (i32.const 1))
+      (then (i32.const 1))
+      (else
+        (i32.mul
+          (local.get $n)
+          (call $factorial (i32.sub (local.get $n) (i32.const 1)))))
+    )
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (call $factorial (local.get $len))
+  )
+)
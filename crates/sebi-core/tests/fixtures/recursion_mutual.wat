@@ -0,0 +1,23 @@
+;; Mutual recursion: is_even and is_odd call each other; neither calls
+;; itself directly.
+(module
+  (memory (export "memory") 1 1)
+
+  (func $is_even (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 1))
+      (else (call $is_odd (i32.sub (local.get $n) (i32.const 1))))
+    )
+  )
+
+  (func $is_odd (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 0))
+      (else (call $is_even (i32.sub (local.get $n) (i32.const 1))))
+    )
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (call $is_even (local.get $len))
+  )
+)
@@ -0,0 +1,35 @@
+(module
+  (memory (export "memory") 1 1)
+
+  ;; Direct recursion: factorial
+  (func $fact (param $n i32) (result i32)
+    (if (result i32) (i32.le_u (local.get $n) (i32.const 1))
+      (then (i32.const 1))
+      (else
+        (i32.mul
+          (local.get $n)
+          (call $fact (i32.sub (local.get $n) (i32.const 1)))))
+    )
+  )
+
+  ;; Mutual recursion: is_even <-> is_odd
+  (func $is_even (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 1))
+      (else (call $is_odd (i32.sub (local.get $n) (i32.const 1))))
+    )
+  )
+
+  (func $is_odd (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 0))
+      (else (call $is_even (i32.sub (local.get $n) (i32.const 1))))
+    )
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.add
+      (call $fact (local.get $len))
+      (call $is_even (local.get $len)))
+  )
+)
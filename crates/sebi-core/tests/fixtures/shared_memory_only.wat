@@ -0,0 +1,10 @@
+(module
+  ;; Shared linear memory (threads proposal) with no atomic operators:
+  ;; synchronization is left to the host.
+  (memory (export "memory") 1 1 shared)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.store (i32.const 0) (local.get $len))
+    (i32.const 0)
+  )
+)
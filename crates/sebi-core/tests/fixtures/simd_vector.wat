@@ -0,0 +1,20 @@
+(module
+  (memory (export "memory") 1 1)
+
+  ;; Byte-wise vector add of two 16-byte buffers into a third
+  (func $add_bytes (param $a i32) (param $b i32) (param $out i32)
+    (v128.store (local.get $out)
+      (i8x16.add (v128.load (local.get $a)) (v128.load (local.get $b))))
+  )
+
+  ;; Lane extraction after a splat
+  (func $lane (param $x i32) (result i32)
+    (i32x4.extract_lane 2 (i32x4.add (i32x4.splat (local.get $x)) (v128.const i32x4 1 2 3 4)))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (call $add_bytes (i32.const 0) (i32.const 16) (i32.const 32))
+    (drop (call $lane (local.get $len)))
+    (i32.const 0)
+  )
+)
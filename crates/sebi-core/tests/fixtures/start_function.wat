@@ -0,0 +1,16 @@
+;; Seeds storage from a start function: the code runs at instantiation,
+;; before the host calls any export. R-START-01 fires on the start section.
+(module
+  (memory (export "memory") 1 1)
+  (global $seeded (mut i32) (i32.const 0))
+
+  (func $init
+    (i32.store (i32.const 0) (i32.const 42))
+    (global.set $seeded (i32.const 1))
+  )
+  (start $init)
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (i32.load (i32.const 0))
+  )
+)
@@ -0,0 +1,16 @@
+(module
+  (type $handler (func (result i32)))
+  (memory (export "memory") 1 1)
+  (table $handlers 2 funcref)
+  (elem (i32.const 0) $a $b)
+
+  (func $a (result i32) (i32.const 1))
+  (func $b (result i32) (i32.const 2))
+
+  ;; Rewrites slot 0 at runtime and appends a slot, then dispatches
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (table.set $handlers (i32.const 0) (table.get $handlers (i32.const 1)))
+    (drop (table.grow $handlers (ref.func $a) (i32.const 1)))
+    (call_indirect $handlers (type $handler) (i32.const 0))
+  )
+)
@@ -0,0 +1,27 @@
+(module
+  ;; Tail-call proposal: unbounded recursion with no loop and no stack growth
+  (type $step (func (param i32 i32) (result i32)))
+  (memory (export "memory") 1 1)
+  (table 1 funcref)
+  (elem (i32.const 0) $accumulate)
+
+  ;; Self-recursive accumulator via return_call
+  (func $accumulate (param $n i32) (param $acc i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (local.get $acc))
+      (else
+        (return_call $accumulate
+          (i32.sub (local.get $n) (i32.const 1))
+          (i32.add (local.get $acc) (local.get $n)))))
+  )
+
+  ;; Trampoline through the table
+  (func $dispatch (param $n i32) (result i32)
+    (return_call_indirect (type $step) (local.get $n) (i32.const 0) (i32.const 0))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (drop (call $dispatch (local.get $len)))
+    (return_call $accumulate (local.get $len) (i32.const 0))
+  )
+)
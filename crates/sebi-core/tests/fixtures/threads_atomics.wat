@@ -0,0 +1,17 @@
+(module
+  ;; Shared linear memory (threads proposal)
+  (memory (export "memory") 1 1 shared)
+
+  ;; Spin-lock style counter using atomics
+  (func $lock_add (param $ptr i32) (param $v i32) (result i32)
+    (drop (i32.atomic.rmw.cmpxchg (local.get $ptr) (i32.const 0) (i32.const 1)))
+    (atomic.fence)
+    (i32.atomic.rmw.add (i32.add (local.get $ptr) (i32.const 4)) (local.get $v))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (drop (call $lock_add (i32.const 0) (local.get $len)))
+    (i32.atomic.store (i32.const 0) (i32.const 0))
+    (i32.const 0)
+  )
+)
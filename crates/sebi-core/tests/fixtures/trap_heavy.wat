@@ -0,0 +1,25 @@
+(module
+  (import "vm_hooks" "read_args" (func $read_args (param i32)))
+  (memory (export "memory") 1 1)
+
+  ;; Checked add: traps on overflow
+  (func $checked_add (param $a i32) (param $b i32) (result i32)
+    (if (i32.lt_u (i32.add (local.get $a) (local.get $b)) (local.get $a))
+      (then unreachable))
+    (i32.add (local.get $a) (local.get $b))
+  )
+
+  ;; Bounds-checked decode with several panic paths
+  (func $decode (param $len i32) (result i32)
+    (if (i32.eqz (local.get $len)) (then unreachable))
+    (if (i32.gt_u (local.get $len) (i32.const 1024)) (then unreachable))
+    (call $read_args (i32.const 0))
+    (if (i32.eqz (i32.load (i32.const 0))) (then unreachable))
+    (i32.load (i32.const 0))
+  )
+
+  (func (export "user_entrypoint") (param $len i32) (result i32)
+    (drop (call $checked_add (call $decode (local.get $len)) (i32.const 1)))
+    (i32.const 0)
+  )
+)
@@ -0,0 +1,18 @@
+;; Built for wasm32-wasi: prints through fd_write, which no contract host
+;; provides. R-WASI-01 fires on the import; the WASI-looking export does not
+;; count.
+(module
+  (import "wasi_snapshot_preview1" "fd_write"
+    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+  (memory (export "memory") 1 1)
+  (data (i32.const 8) "hello\n")
+
+  (func (export "wasi_snapshot_preview1.proc_exit") (param i32))
+
+  (func (export "_start")
+    ;; iovec { buf = 8, len = 6 } at offset 0
+    (i32.store (i32.const 0) (i32.const 8))
+    (i32.store (i32.const 4) (i32.const 6))
+    (drop (call $fd_write (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 16)))
+  )
+)
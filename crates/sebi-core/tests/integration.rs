@@ -1,6 +1,19 @@
-use sebi_core::report::model::{ClassificationLevel, Report, ToolInfo};
+use sebi_core::InspectOptions;
+use sebi_core::report::compat;
+use sebi_core::report::model::{
+    ClassificationLevel, Confidence, Report, Target, ToolInfo, Toolchain,
+};
+use sebi_core::rules::catalog::{self, RuleId, Severity};
+use sebi_core::rules::classify::{Escalation, Policy};
+use sebi_core::rules::eval::{EvaluationContext, RuleEvaluator, RuleScope, TriggeredRule};
+use sebi_core::rules::evidence::RuleEvidence;
+use sebi_core::rules::overrides::CatalogOverrides;
+use sebi_core::rules::suppress::Suppressions;
+use sebi_core::wasm::profile::TargetSelection;
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tempfile::NamedTempFile;
 
 /// Path to the fixtures directory relative to the crate root.
@@ -24,6 +37,11 @@ fn inspect_fixture(name: &str) -> Report {
 
 /// Inspects raw WASM bytes through the full pipeline.
 fn inspect_bytes(wasm: &[u8]) -> Report {
+    inspect_bytes_with(wasm, &InspectOptions::default())
+}
+
+/// Inspects raw WASM bytes with non-default inspect options.
+fn inspect_bytes_with(wasm: &[u8], opts: &InspectOptions) -> Report {
     let mut tmp = NamedTempFile::new().expect("create temp file");
     tmp.write_all(wasm).expect("write wasm bytes");
     tmp.flush().expect("flush");
@@ -34,7 +52,7 @@ fn inspect_bytes(wasm: &[u8]) -> Report {
         commit: None,
     };
 
-    sebi_core::inspect(tmp.path(), tool).expect("inspect should succeed")
+    sebi_core::inspect_with_options(tmp.path(), tool, opts).expect("inspect should succeed")
 }
 
 fn triggered_ids(report: &Report) -> Vec<String> {
@@ -42,7 +60,7 @@ fn triggered_ids(report: &Report) -> Vec<String> {
         .rules
         .triggered
         .iter()
-        .map(|r| r.rule_id.clone())
+        .map(|r| r.rule_id.to_string())
         .collect()
 }
 
@@ -85,6 +103,19 @@ fn rust_safe_storage_signals_correct() {
     assert!(report.signals.imports_exports.export_count >= 3);
 }
 
+#[test]
+fn rust_safe_storage_call_counts() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    let instr = &report.signals.instructions;
+
+    // get: 2 host calls; set: 1 host call; entrypoint: 1 host call + get + set
+    assert_eq!(instr.call_count, 6);
+    assert_eq!(instr.call_import_count, 4);
+    assert_eq!(instr.call_defined_count, 2);
+    assert_eq!(instr.distinct_callee_count, 6);
+    assert!(!instr.distinct_callees_capped);
+}
+
 #[test]
 fn rust_loop_unbounded_mem_classified_risk() {
     let report = inspect_fixture("rust_loop_unbounded_mem.wat");
@@ -167,6 +198,115 @@ fn cpp_vtable_erc20_classified_high_risk() {
         has_rule(&report, "R-LOOP-01"),
         "R-LOOP-01: ABI decoder loop"
     );
+    assert!(
+        has_rule(&report, "R-IMPORT-01"),
+        "R-IMPORT-01: env imports under the detected Stylus profile"
+    );
+}
+
+#[test]
+fn cpp_vtable_erc20_evidence_locates_flagged_instructions() {
+    let report = inspect_fixture("cpp_vtable_erc20.wat");
+    let location = |rule: &str| {
+        let triggered = report
+            .rules
+            .triggered
+            .iter()
+            .find(|r| r.rule_id == rule)
+            .unwrap();
+        let locations = triggered.evidence["locations"].as_array().unwrap();
+        assert_eq!(locations.len(), 1, "{rule}");
+        locations[0].clone()
+    };
+
+    // 5 imported functions precede the 8 vtable methods.
+    let grow = location("R-MEM-02");
+    assert_eq!(grow["function_index"], 13);
+    assert_eq!(grow["function_name"], "malloc");
+    let dispatch = location("R-CALL-01");
+    assert_eq!(dispatch["function_index"], 15);
+    assert_eq!(dispatch["function_name"], "user_entrypoint");
+    let copy = location("R-LOOP-01");
+    assert_eq!(copy["function_index"], 14);
+    assert_eq!(copy["function_name"], "decode_calldata");
+
+    // Offsets are the scanned opcode offsets, ascending within a rule.
+    let instructions = &report.signals.instructions;
+    assert_eq!(
+        grow["byte_offset"],
+        instructions.memory_grow_sites[0].offset
+    );
+    assert_eq!(
+        dispatch["byte_offset"],
+        instructions.call_indirect_sites[0].offset
+    );
+    assert_eq!(copy["byte_offset"], instructions.loop_sites[0].offset);
+}
+
+#[test]
+fn allocator_heuristic_downgrades_allocator_only_growth() {
+    let mem02 = |report: &Report| {
+        report
+            .rules
+            .triggered
+            .iter()
+            .find(|r| r.rule_id == "R-MEM-02")
+            .cloned()
+            .unwrap()
+    };
+    let with_patterns = |patterns: &[&str]| InspectOptions {
+        allocator_patterns: Some(patterns.iter().map(|p| p.to_string()).collect()),
+        ..Default::default()
+    };
+    let defaults = with_patterns(sebi_core::rules::eval::DEFAULT_ALLOCATOR_PATTERNS);
+
+    // The only memory.grow is in `malloc`.
+    let wasm = compile_fixture("cpp_vtable_erc20.wat");
+    assert_eq!(mem02(&inspect_bytes(&wasm)).severity, "High");
+    assert_eq!(mem02(&inspect_bytes(&wasm)).confidence, Confidence::High);
+    let rule = mem02(&inspect_bytes_with(&wasm, &defaults));
+    assert_eq!(rule.severity, "Med");
+    assert_eq!(rule.confidence, Confidence::Medium);
+    assert_eq!(rule.evidence["allocator_only"], true);
+    assert_eq!(
+        rule.evidence["allocator_functions"][0]["function_name"],
+        "malloc"
+    );
+    assert_eq!(
+        rule.evidence["allocator_functions"][0]["function_index"],
+        13
+    );
+    assert!(
+        rule.findings
+            .iter()
+            .all(|f| f.evidence["allocator_pattern"] == "malloc")
+    );
+
+    // Growth outside a matched function is not downgraded.
+    let wasm = compile_fixture("multiple_memory_grow.wat");
+    let rule = mem02(&inspect_bytes_with(&wasm, &with_patterns(&["grow_once"])));
+    assert_eq!(rule.severity, "High");
+    assert!(rule.evidence.get("allocator_only").is_none());
+    assert!(rule.findings.iter().all(|f| f.evidence.is_empty()));
+
+    // Without a name section nothing can match.
+    let wasm = wat::parse_str(
+        "(module (memory 1) (func (export \"malloc\") (drop (memory.grow (i32.const 1)))))",
+    )
+    .unwrap();
+    let rule = mem02(&inspect_bytes_with(&wasm, &with_patterns(&["*"])));
+    assert_eq!(rule.severity, "High");
+
+    // Bad globs are reported and ignored.
+    let wasm = compile_fixture("cpp_vtable_erc20.wat");
+    let report = inspect_bytes_with(&wasm, &with_patterns(&["[", "malloc"]));
+    assert_eq!(mem02(&report).severity, "Med");
+    assert!(
+        report
+            .analysis
+            .warnings
+            .contains(&"invalid allocator pattern `[` ignored".to_string())
+    );
 }
 
 #[test]
@@ -245,6 +385,122 @@ fn minimal_module_triggers_mem01() {
     assert_eq!(report.signals.module.function_count, 0);
 }
 
+#[test]
+fn low_only_findings_classify_as_info() {
+    let wasm = compile_fixture("minimal_module.wat");
+    let opts = InspectOptions {
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: x\nrules:\n  R-MEM-01:\n    severity: LOW\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Info);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(
+        report.classification.reason,
+        "INFO: 1 LOW rule (R-MEM-01) triggered under policy 'default'"
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["classification"]["level"], "INFO");
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains("Classification: Info"));
+
+    let opts = InspectOptions {
+        info_exit_code: 10,
+        ..opts
+    };
+    assert_eq!(
+        inspect_bytes_with(&wasm, &opts).classification.exit_code,
+        10
+    );
+
+    // SAFE is kept for nothing triggered.
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+    assert!(report.rules.triggered.is_empty());
+}
+
+#[test]
+fn policy_decides_how_severities_gate() {
+    let wasm = compile_fixture("minimal_module.wat");
+    let low_mem01 =
+        CatalogOverrides::from_yaml("catalog_version: x\nrules:\n  R-MEM-01:\n    severity: LOW\n")
+            .unwrap();
+    let with_policy = |policy| InspectOptions {
+        catalog_overrides: Some(low_mem01.clone()),
+        policy,
+        ..Default::default()
+    };
+
+    let report = inspect_bytes_with(&wasm, &with_policy(Policy::Default));
+    assert_eq!(triggered_ids(&report), vec!["R-MEM-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Info);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(report.classification.policy, "default");
+
+    let report = inspect_bytes_with(&wasm, &with_policy(Policy::Strict));
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert_eq!(report.classification.exit_code, 1);
+    assert_eq!(report.classification.policy, "strict");
+
+    // At its catalog severity (MED) the rule fails by default only.
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.classification.exit_code, 1);
+    let opts = InspectOptions {
+        policy: Policy::HighOnly,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Info);
+    assert_eq!(report.classification.highest_severity, "Med");
+    assert_eq!(report.classification.policy, "high-only");
+}
+
+#[test]
+fn weighted_policy_records_score_and_table() {
+    let wasm = compile_fixture("all_signals.wat");
+    let report = inspect_bytes(&wasm);
+    assert!(report.classification.score.is_none());
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["classification"].get("score").is_none());
+
+    let opts = InspectOptions {
+        policy: Policy::weighted(),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    let score = report.classification.score.unwrap();
+    let expected: u64 = report
+        .rules
+        .triggered
+        .iter()
+        .map(|r| match r.severity.as_str() {
+            "High" => 7,
+            "Med" => 3,
+            _ => 1,
+        })
+        .sum();
+    assert_eq!(score.total, expected);
+    assert_eq!(report.classification.policy, "weighted");
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["classification"]["score"]["weights"],
+        serde_json::json!({ "low": 1, "med": 3, "high": 7 })
+    );
+    assert_eq!(
+        json["classification"]["score"]["thresholds"],
+        serde_json::json!({ "risk": 3, "high_risk": 7 })
+    );
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains(&format!("Score: {expected} (risk at 3, high risk at 7)")));
+}
+
 #[test]
 fn minimal_module_warns_no_memory() {
     let report = inspect_fixture("minimal_module.wat");
@@ -262,7 +518,13 @@ fn minimal_module_warns_no_memory() {
 
 #[test]
 fn imported_memory_bounded_classified_safe() {
-    let report = inspect_fixture("imported_memory_bounded.wat");
+    // A Stylus-detected stub without `user_entrypoint`, importing from `env`;
+    // the generic profile keeps only the memory rules in play.
+    let opts = InspectOptions {
+        target: TargetSelection::Generic,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("imported_memory_bounded.wat"), &opts);
 
     assert_eq!(report.classification.level, ClassificationLevel::Safe);
     assert_eq!(report.classification.exit_code, 0);
@@ -289,9 +551,142 @@ fn imported_memory_bounded_import_details() {
     assert_eq!(mem.name, "memory");
 }
 
+#[test]
+fn import_modules_counted_per_namespace() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (import "vm_hooks" "read_args" (func (param i32)))
+                 (import "wasi_snapshot_preview1" "fd_write" (func (param i32 i32 i32 i32) (result i32)))
+                 (import "vm_hooks" "write_result" (func (param i32 i32)))
+                 (import "env" "memory" (memory 1)))"#,
+        )
+        .unwrap(),
+    );
+
+    let modules: Vec<(&str, u32)> = report
+        .signals
+        .imports_exports
+        .modules
+        .iter()
+        .map(|m| (m.module.as_str(), m.count))
+        .collect();
+    assert_eq!(
+        modules,
+        vec![("env", 1), ("vm_hooks", 2), ("wasi_snapshot_preview1", 1)]
+    );
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["signals"]["imports_exports"]["modules"][1],
+        serde_json::json!({ "module": "vm_hooks", "count": 2 })
+    );
+}
+
+#[test]
+fn unused_imported_functions_detected() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (import "vm_hooks" "read_args" (func $read (param i32)))
+                 (import "vm_hooks" "emit_log" (func $log (param i32 i32 i32)))
+                 (import "env" "memory" (memory 1))
+                 (func (export "user_entrypoint") (param i32) (result i32)
+                   (call $read (i32.const 0))
+                   (i32.const 0)))"#,
+        )
+        .unwrap(),
+    );
+    let io = &report.signals.imports_exports;
+
+    assert_eq!(io.unused_import_count, 1);
+    let referenced: Vec<_> = io
+        .imports
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|i| (i.name.as_str(), i.referenced))
+        .collect();
+    assert_eq!(
+        referenced,
+        vec![
+            ("memory", None),
+            ("emit_log", Some(false)),
+            ("read_args", Some(true)),
+        ]
+    );
+
+    let signatures: Vec<_> = io
+        .imports
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|i| i.signature.as_deref())
+        .collect();
+    assert_eq!(
+        signatures,
+        vec![None, Some("(i32, i32, i32) -> ()"), Some("(i32) -> ()")]
+    );
+}
+
+#[test]
+fn conflicting_memory_declarations_warned_without_classification_change() {
+    let wasm = wat::parse_str(
+        r#"(module
+             (import "env" "memory" (memory 1 3))
+             (memory 5 10)
+             (func (export "main")))"#,
+    )
+    .unwrap();
+    // Two memories need multi-memory, which R-FEAT-01 flags on its own.
+    let opts = InspectOptions {
+        allowed_features: Some(vec!["multi_memory".to_string()]),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+
+    assert!(report.signals.memory.memory_declaration_conflict);
+    assert!(report.analysis.warnings.contains(
+        &"memory both imported (min 1 pages, max 3 pages) and defined (min 5 pages, max 10 pages); the imported memory is index 0 and sets the reported limits"
+            .to_string()
+    ));
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+
+    let report = inspect_fixture("imported_memory_bounded.wat");
+    assert!(!report.signals.memory.memory_declaration_conflict);
+}
+
+#[test]
+fn memory_origin_distinguishes_imported_and_defined() {
+    let memory = inspect_fixture("imported_memory_bounded.wat")
+        .signals
+        .memory;
+    assert_eq!(memory.memory_is_imported, Some(true));
+    assert_eq!(
+        (memory.imported_memory_count, memory.defined_memory_count),
+        (1, 0)
+    );
+
+    let memory = inspect_fixture("rust_safe_storage.wat").signals.memory;
+    assert_eq!(memory.memory_is_imported, Some(false));
+    assert_eq!(
+        (memory.imported_memory_count, memory.defined_memory_count),
+        (0, 1)
+    );
+
+    let memory = inspect_fixture("minimal_module.wat").signals.memory;
+    assert_eq!(memory.memory_is_imported, None);
+}
+
 #[test]
 fn imported_memory_unbounded_triggers_mem01() {
-    let report = inspect_fixture("imported_memory_unbounded.wat");
+    // A Stylus-detected stub without `user_entrypoint`, importing from `env`;
+    // the generic profile keeps only the memory rules in play.
+    let opts = InspectOptions {
+        target: TargetSelection::Generic,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("imported_memory_unbounded.wat"), &opts);
 
     assert_eq!(report.classification.level, ClassificationLevel::Risk);
     assert_eq!(report.classification.exit_code, 1);
@@ -322,114 +717,2270 @@ fn nested_loops_counted_accurately() {
         report.signals.instructions.loop_count, 3,
         "triple-nested loop should produce loop_count == 3"
     );
+
+    let functions = &report.signals.functions;
+    assert_eq!(functions.max_loop_depth, 3);
+    assert_eq!(functions.functions_with_loops, 1);
+    assert_eq!(
+        functions.largest_functions[0].name.as_deref(),
+        Some("nested")
+    );
 }
 
 #[test]
-fn multiple_memory_grow_counted_accurately() {
-    let report = inspect_fixture("multiple_memory_grow.wat");
+fn nested_loops_trigger_loop02() {
+    let wasm = compile_fixture("nested_loops.wat");
+    let report = inspect_bytes(&wasm);
 
-    assert!(report.signals.instructions.has_memory_grow);
+    assert!(has_rule(&report, "R-LOOP-01"));
+    let loop02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-LOOP-02")
+        .expect("depth 3 reaches the default threshold");
+    assert_eq!(loop02.evidence["signals.functions.max_loop_depth"], 3);
+    assert_eq!(loop02.evidence["LOOP_DEPTH_THRESHOLD"], 3);
     assert_eq!(
-        report.signals.instructions.memory_grow_count, 3,
-        "3 memory.grow calls across 2 functions should produce count == 3"
+        loop02.evidence["signals.functions.deepest_loop_function"],
+        0
     );
+
+    let opts = InspectOptions {
+        loop_depth_threshold: 4,
+        ..Default::default()
+    };
+    assert!(!has_rule(&inspect_bytes_with(&wasm, &opts), "R-LOOP-02"));
+
+    // A single loop stays below the threshold.
+    assert!(!has_rule(
+        &inspect_fixture("rust_loop_unbounded_mem.wat"),
+        "R-LOOP-02"
+    ));
 }
 
 #[test]
-fn large_artifact_triggers_size_rule() {
-    // Generate a WASM module exceeding the default 200KB threshold.
-    // We pad with a large data section to reliably exceed the threshold.
-    let padding = "X".repeat(210_000);
-    let wat = format!("(module (memory 4 16) (data (i32.const 0) \"{padding}\"))");
+fn memory_grow_in_loop_triggers_loop03() {
+    let report = inspect_fixture("memory_grow_in_loop.wat");
 
-    let wasm = wat::parse_str(&wat).expect("large module should compile");
-    assert!(
-        wasm.len() > 200_000,
-        "generated module should exceed 200KB, got {} bytes",
-        wasm.len()
+    assert_eq!(
+        triggered_ids(&report),
+        vec!["R-MEM-02", "R-LOOP-01", "R-LOOP-03"]
     );
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert_eq!(report.signals.instructions.memory_grow_in_loop_count, 1);
 
-    let report = inspect_bytes(&wasm);
-
-    assert!(has_rule(&report, "R-SIZE-01"), "expected R-SIZE-01 to fire");
-    let size_rule = report
+    let loop03 = report
         .rules
         .triggered
         .iter()
-        .find(|r| r.rule_id == "R-SIZE-01")
+        .find(|r| r.rule_id == "R-LOOP-03")
         .unwrap();
-    assert!(
-        size_rule.evidence.get("artifact.size_bytes").is_some(),
-        "R-SIZE-01 evidence should contain artifact.size_bytes"
+    assert_eq!(
+        loop03.evidence["signals.instructions.memory_grow_in_loop_count"],
+        1
     );
-    assert!(
-        size_rule.evidence.get("SIZE_THRESHOLD").is_some(),
-        "R-SIZE-01 evidence should contain SIZE_THRESHOLD"
+    let sites = &report.signals.instructions.memory_grow_in_loop_sites;
+    assert_eq!(sites.len(), 1);
+    assert_eq!(sites[0], report.signals.instructions.memory_grow_sites[0]);
+    assert_eq!(
+        loop03.evidence["signals.instructions.memory_grow_in_loop_sites"][0]["function_index"],
+        0
     );
 }
 
 #[test]
-fn invalid_wasm_reports_parse_error() {
-    let garbage = b"this is not a valid wasm file at all";
-    let report = inspect_bytes(garbage);
+fn startup_memory_grow_triggers_only_mem02() {
+    let report = inspect_fixture("memory_grow_startup.wat");
 
-    assert_eq!(report.analysis.status, "parse_error");
+    assert_eq!(triggered_ids(&report), vec!["R-MEM-02", "R-LOOP-01"]);
+    assert_eq!(report.signals.instructions.memory_grow_count, 1);
+    assert_eq!(report.signals.instructions.memory_grow_in_loop_count, 0);
+    assert!(
+        report
+            .signals
+            .instructions
+            .memory_grow_in_loop_sites
+            .is_empty()
+    );
 }
 
 #[test]
-fn deterministic_json_output_for_same_fixture() {
-    let wasm = compile_fixture("cpp_vtable_erc20.wat");
-    let mut tmp = NamedTempFile::new().unwrap();
-    tmp.write_all(&wasm).unwrap();
-    tmp.flush().unwrap();
+fn call_indirect_heavy_triggers_call02_at_threshold() {
+    let wasm = compile_fixture("call_indirect_heavy.wat");
+    let report = inspect_bytes(&wasm);
 
-    let tool = || ToolInfo {
-        name: "sebi".into(),
-        version: "0.1.0-test".into(),
-        commit: None,
-    };
-    let report_a = sebi_core::inspect(tmp.path(), tool()).unwrap();
-    let report_b = sebi_core::inspect(tmp.path(), tool()).unwrap();
+    assert_eq!(triggered_ids(&report), vec!["R-CALL-01", "R-CALL-02"]);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
 
-    let json_a = serde_json::to_string_pretty(&report_a).unwrap();
-    let json_b = serde_json::to_string_pretty(&report_b).unwrap();
+    let call02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-CALL-02")
+        .unwrap();
+    assert_eq!(
+        call02.evidence["signals.instructions.call_indirect_count"],
+        100
+    );
+    assert_eq!(call02.evidence["CALL_INDIRECT_THRESHOLD"], 100);
+    assert_eq!(
+        call02.evidence["signals.instructions.call_indirect_distinct_types"]
+            .as_array()
+            .map(Vec::len),
+        Some(2)
+    );
 
+    // One site short of the threshold: only the presence rule remains.
+    let opts = InspectOptions {
+        call_indirect_threshold: 101,
+        ..Default::default()
+    };
     assert_eq!(
-        json_a, json_b,
-        "identical input must produce identical JSON"
+        triggered_ids(&inspect_bytes_with(&wasm, &opts)),
+        vec!["R-CALL-01"]
     );
+
+    // A single dispatcher stays below the default threshold.
+    assert!(!has_rule(
+        &inspect_fixture("rust_dynamic_dispatch.wat"),
+        "R-CALL-02"
+    ));
+}
+
+/// A module of `n` trivial functions, none exported.
+fn many_functions_wat(n: usize) -> String {
+    let mut wat = String::from("(module\n");
+    for _ in 0..n {
+        wat.push_str("  (func)\n");
+    }
+    wat.push(')');
+    wat
 }
 
 #[test]
-fn deterministic_json_output_for_safe_contract() {
-    let wasm = compile_fixture("rust_safe_storage.wat");
-    let mut tmp = NamedTempFile::new().unwrap();
-    tmp.write_all(&wasm).unwrap();
-    tmp.flush().unwrap();
+fn function_count_above_threshold_triggers_size02() {
+    let at = wat::parse_str(many_functions_wat(1_000)).unwrap();
+    let report = inspect_bytes(&at);
+    assert_eq!(report.signals.module.function_count, 1_000);
+    assert!(!has_rule(&report, "R-SIZE-02"));
 
-    let tool = || ToolInfo {
-        name: "sebi".into(),
-        version: "0.1.0-test".into(),
-        commit: None,
+    let above = wat::parse_str(many_functions_wat(1_001)).unwrap();
+    let report = inspect_bytes(&above);
+    let size02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-SIZE-02")
+        .expect("1,001 functions exceed the default threshold");
+    assert_eq!(size02.evidence["signals.module.function_count"], 1_001);
+    assert_eq!(size02.evidence["FUNCTION_COUNT_THRESHOLD"], 1_000);
+    assert!(!has_rule(&report, "R-SIZE-01"), "the module stays small");
+
+    let opts = InspectOptions {
+        function_count_threshold: 1_001,
+        ..Default::default()
     };
-    let report_a = sebi_core::inspect(tmp.path(), tool()).unwrap();
-    let report_b = sebi_core::inspect(tmp.path(), tool()).unwrap();
+    assert!(!has_rule(&inspect_bytes_with(&above, &opts), "R-SIZE-02"));
+}
 
-    let json_a = serde_json::to_string(&report_a).unwrap();
-    let json_b = serde_json::to_string(&report_b).unwrap();
+#[test]
+fn non_mvp_features_trigger_feat01_with_sources() {
+    let report = inspect_fixture("threads_atomics.wat");
+    let feat01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-FEAT-01")
+        .expect("threads are outside every default set");
+    assert_eq!(
+        feat01.evidence["ALLOWED_FEATURES"],
+        serde_json::json!(["bulk_memory", "sign_extension"])
+    );
+    assert_eq!(
+        feat01.evidence["disallowed_features"],
+        serde_json::json!([{ "name": "threads", "instruction_count": 4, "sections": ["memory"] }])
+    );
 
-    assert_eq!(json_a, json_b);
+    // Allowing the proposal silences the rule.
+    let wasm = compile_fixture("simd_vector.wat");
+    assert!(has_rule(&inspect_bytes(&wasm), "R-FEAT-01"));
+    let opts = InspectOptions {
+        allowed_features: Some(vec!["simd".to_string()]),
+        ..Default::default()
+    };
+    assert!(!has_rule(&inspect_bytes_with(&wasm, &opts), "R-FEAT-01"));
 }
 
 #[test]
-fn report_schema_version_matches() {
-    let report = inspect_fixture("rust_safe_storage.wat");
-    assert_eq!(report.schema_version, "0.1.0");
+fn catalog_overrides_disable_rescore_and_retune_rules() {
+    let wasm = compile_fixture("nested_loops.wat");
+    let overrides = CatalogOverrides::from_yaml(
+        r#"
+catalog_version: acme-1
+rules:
+  R-MEM-01:
+    disabled: true
+  R-LOOP-01:
+    severity: LOW
+  R-LOOP-02:
+    params:
+      LOOP_DEPTH_THRESHOLD: 10
+"#,
+    )
+    .unwrap();
+    let opts = InspectOptions {
+        catalog_overrides: Some(overrides),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+
+    assert_eq!(report.rules.catalog.catalog_version, "acme-1");
+    assert_eq!(report.rules.catalog.ruleset, "custom");
+    let ids: Vec<_> = report
+        .rules
+        .triggered
+        .iter()
+        .map(|r| r.rule_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["R-LOOP-01"]);
+    assert_eq!(report.rules.triggered[0].severity, "Low");
+    assert_eq!(report.classification.level, ClassificationLevel::Info);
 }
 
 #[test]
-fn report_tool_info_preserved() {
-    let report = inspect_fixture("rust_safe_storage.wat");
+fn catalog_content_hash_tracks_effective_configuration() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let default_hash = inspect_bytes(&wasm).rules.catalog.content_hash;
+    assert_eq!(default_hash, catalog::content_hash(&catalog::catalog()));
+    assert_eq!(
+        inspect_bytes(&wasm).rules.catalog.content_hash,
+        default_hash
+    );
+
+    // A threshold flag and the same threshold from an overrides file are
+    // the same effective catalog.
+    let flag = InspectOptions {
+        data_bytes_threshold: 1,
+        ..Default::default()
+    };
+    let flag_hash = inspect_bytes_with(&wasm, &flag).rules.catalog.content_hash;
+    assert_ne!(flag_hash, default_hash);
+
+    let file = InspectOptions {
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: acme-1\nrules:\n  R-DATA-01:\n    params:\n      DATA_BYTES_THRESHOLD: 1\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    assert_eq!(
+        inspect_bytes_with(&wasm, &file).rules.catalog.content_hash,
+        flag_hash
+    );
+
+    // A reworded message does not change what decides the verdict.
+    let reworded = InspectOptions {
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: acme-1\nrules:\n  R-MEM-01:\n    message: reworded\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    assert_eq!(
+        inspect_bytes_with(&wasm, &reworded)
+            .rules
+            .catalog
+            .content_hash,
+        default_hash
+    );
+}
+
+/// Organization check: the module imports exactly these hostios.
+#[derive(Debug)]
+struct ExactHostios(&'static [&'static str]);
+
+impl RuleEvaluator for ExactHostios {
+    fn evaluate(&self, ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule> {
+        let mut actual: Vec<&str> = ctx
+            .signals
+            .imports_exports
+            .imports
+            .iter()
+            .flatten()
+            .filter(|i| i.module == "vm_hooks")
+            .map(|i| i.name.as_str())
+            .collect();
+        actual.sort_unstable();
+        let mut expected = self.0.to_vec();
+        expected.sort_unstable();
+        if actual == expected {
+            return vec![];
+        }
+        vec![TriggeredRule {
+            rule_id: RuleId::Custom("ACME-HOSTIO-01".to_string()),
+            severity: Severity::High,
+            confidence: Confidence::High,
+            title: "Unexpected hostio set".to_string(),
+            message: "Imported hostios differ from the approved set.".to_string(),
+            remediation: "Import exactly the approved hostios.".to_string(),
+            docs_url: "https://example.com/acme/hostio".to_string(),
+            deprecated: None,
+            evidence: RuleEvidence::Other(
+                serde_json::json!({ "signals.imports_exports.imports": actual }),
+            ),
+            findings: vec![],
+        }]
+    }
+}
+
+#[test]
+fn include_passed_lists_checked_rules_that_did_not_trigger() {
+    let wasm = compile_fixture("all_signals.wat");
+    assert!(inspect_bytes(&wasm).rules.passed.is_empty());
+
+    // With an allowlist R-IMPORT-01 is evaluated too.
+    let opts = InspectOptions {
+        include_passed: true,
+        import_allowlist: Some(vec!["env".to_string()]),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    let passed: Vec<&RuleId> = report.rules.passed.iter().map(|r| &r.rule_id).collect();
+    assert!(!passed.is_empty());
+    assert!(passed.is_sorted());
+    for rule in &report.rules.triggered {
+        assert!(!passed.contains(&&rule.rule_id), "{}", rule.rule_id);
+    }
+    // Every evaluated catalog rule is in exactly one list.
+    assert_eq!(
+        passed.len() + report.rules.triggered.len(),
+        catalog::catalog().len()
+    );
+    let analysis01 = report
+        .rules
+        .passed
+        .iter()
+        .find(|r| r.rule_id == "R-ANALYSIS-01")
+        .unwrap();
+    assert_eq!(analysis01.severity, "Med");
+    assert_eq!(analysis01.evidence["analysis.status"], "ok");
+
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains(&format!(
+        "Rules: {} passed, {} triggered",
+        passed.len(),
+        report.rules.triggered.len()
+    )));
+    assert!(text.contains("  - R-ANALYSIS-01 [Med]"));
+}
+
+#[test]
+fn stats_time_stages_and_rules_only_when_requested() {
+    let wasm = compile_fixture("all_signals.wat");
+    let report = inspect_bytes(&wasm);
+    assert!(report.analysis.stats.is_none());
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["analysis"].get("stats").is_none());
+
+    let opts = InspectOptions {
+        stats: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    let stats = report.analysis.stats.as_ref().unwrap();
+    let s = &stats.stages;
+    assert!(
+        s.read_us + s.parse_us + s.scan_us + s.extract_us + s.eval_us + s.classify_us
+            <= stats.total_us
+    );
+    // R-IMPORT-01 is not evaluated without an allowlist.
+    let timed: Vec<&RuleId> = stats.rules.iter().map(|r| &r.rule_id).collect();
+    let expected: Vec<RuleId> = catalog::catalog()
+        .into_iter()
+        .map(|def| def.id)
+        .filter(|id| *id != RuleId::RImport01)
+        .collect();
+    assert_eq!(timed, expected.iter().collect::<Vec<_>>());
+
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains("Timing: "));
+    assert!(text.contains("(read "));
+}
+
+#[test]
+fn custom_rule_evaluators_report_and_classify_like_catalog_rules() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let baseline = inspect_bytes(&wasm);
+    assert_eq!(baseline.classification.level, ClassificationLevel::Safe);
+
+    let approved = InspectOptions {
+        custom_rules: vec![Arc::new(ExactHostios(&[
+            "read_args",
+            "write_result",
+            "storage_load_bytes32",
+            "storage_store_bytes32",
+        ]))],
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &approved);
+    assert!(report.rules.triggered.is_empty());
+
+    let strict = InspectOptions {
+        custom_rules: vec![Arc::new(ExactHostios(&["read_args", "write_result"]))],
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &strict);
+    assert_eq!(triggered_ids(&report), vec!["ACME-HOSTIO-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert_eq!(
+        report.classification.triggered_rule_ids,
+        vec![RuleId::Custom("ACME-HOSTIO-01".to_string())]
+    );
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains("ACME-HOSTIO-01 [High] Unexpected hostio set"));
+    assert!(text.contains("docs: https://example.com/acme/hostio"));
+
+    // The custom id survives a JSON round trip.
+    let json = serde_json::to_string(&report).unwrap();
+    let loaded = compat::load_report(&json).unwrap().value;
+    assert_eq!(
+        loaded.rules.triggered[0].rule_id,
+        RuleId::Custom("ACME-HOSTIO-01".to_string())
+    );
+}
+
+/// Reports what the evaluation context carried.
+#[derive(Debug)]
+struct ContextProbe;
+
+impl RuleEvaluator for ContextProbe {
+    fn evaluate(&self, ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule> {
+        vec![TriggeredRule {
+            rule_id: RuleId::Custom("PROBE-01".to_string()),
+            severity: Severity::Low,
+            confidence: Confidence::High,
+            title: "Context probe".to_string(),
+            message: String::new(),
+            remediation: String::new(),
+            docs_url: String::new(),
+            deprecated: None,
+            evidence: RuleEvidence::Other(serde_json::json!({
+                "target": ctx.analysis.target,
+                "rules": ctx.rules.iter().map(|def| def.id.as_str()).collect::<Vec<_>>(),
+                "function_count": ctx.facts.map(|f| f.sections.function_count),
+            })),
+            findings: vec![],
+        }]
+    }
+}
+
+#[test]
+fn custom_rule_evaluators_see_analysis_catalog_and_facts() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let opts = InspectOptions {
+        custom_rules: vec![Arc::new(ContextProbe)],
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: x\nrules:\n  R-LOOP-01:\n    disabled: true\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    let probe = &report.rules.triggered[0].evidence;
+
+    assert_eq!(probe["target"], "stylus");
+    let rules = probe["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), catalog::catalog().len() - 1);
+    assert!(!rules.contains(&serde_json::json!("R-LOOP-01")));
+    assert_eq!(
+        probe["function_count"],
+        report.signals.module.function_count
+    );
+}
+
+/// Heuristic check: exported functions named like a self-destruct.
+#[derive(Debug)]
+struct SelfDestructGuess;
+
+impl RuleEvaluator for SelfDestructGuess {
+    fn evaluate(&self, _ctx: &EvaluationContext<'_>) -> Vec<TriggeredRule> {
+        vec![TriggeredRule {
+            rule_id: RuleId::Custom("ACME-GUESS-01".to_string()),
+            severity: Severity::High,
+            confidence: Confidence::Low,
+            title: "Possible self-destruct".to_string(),
+            message: String::new(),
+            remediation: String::new(),
+            docs_url: String::new(),
+            deprecated: None,
+            evidence: RuleEvidence::Other(serde_json::json!({})),
+            findings: vec![],
+        }]
+    }
+}
+
+#[test]
+fn low_confidence_rules_can_be_left_out_of_classification() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let opts = InspectOptions {
+        custom_rules: vec![Arc::new(SelfDestructGuess)],
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["rules"]["triggered"][0]["confidence"], "low");
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains("ACME-GUESS-01 [High] Possible self-destruct (low confidence)"));
+
+    let opts = InspectOptions {
+        ignore_low_confidence: true,
+        ..opts
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(triggered_ids(&report), vec!["ACME-GUESS-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(report.classification.highest_severity, "NONE");
+    assert_eq!(
+        report.classification.triggered_rule_ids,
+        vec![RuleId::Custom("ACME-GUESS-01".to_string())]
+    );
+    assert!(
+        report
+            .classification
+            .reason
+            .ends_with("1 low-confidence rule(s) ignored")
+    );
+}
+
+#[test]
+fn low_confidence_rules_are_ignored_against_a_baseline_too() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let opts = InspectOptions {
+        custom_rules: vec![Arc::new(SelfDestructGuess)],
+        baseline: Some(inspect_bytes(&wasm)),
+        ..Default::default()
+    };
+    // The guess is new relative to the baseline, so it counts...
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+
+    // ...unless low-confidence rules are ignored, as without a baseline.
+    let opts = InspectOptions {
+        ignore_low_confidence: true,
+        ..opts
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(report.classification.new_rule_ids, Some(vec![]));
+    assert_eq!(
+        report.classification.triggered_rule_ids,
+        vec![RuleId::Custom("ACME-GUESS-01".to_string())]
+    );
+    assert!(
+        report
+            .classification
+            .reason
+            .ends_with("1 low-confidence rule(s) ignored")
+    );
+}
+
+#[test]
+fn suppressions_move_findings_out_of_classification() {
+    let wasm = compile_fixture("cpp_vtable_erc20.wat");
+    let baseline = inspect_bytes(&wasm);
+    assert_eq!(baseline.classification.level, ClassificationLevel::HighRisk);
+    let hash = &baseline.artifact.hash.value;
+
+    let opts = InspectOptions {
+        suppressions: Some(
+            Suppressions::from_toml(&format!(
+                r#"
+[[suppressions]]
+rule = "R-CALL-01"
+artifact_sha256 = "{hash}"
+reason = "vtable dispatch reviewed in AUD-142"
+expires = "2999-12-31"
+
+[[suppressions]]
+rule = "R-MEM-02"
+reason = "allocator reviewed"
+
+[[suppressions]]
+rule = "R-IMPORT-01"
+reason = "old review"
+expires = "2000-01-01"
+
+[[suppressions]]
+rule = "R-LOOP-01"
+artifact_sha256 = "{}"
+reason = "other artifact"
+"#,
+                "0".repeat(64)
+            ))
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+
+    assert_eq!(
+        triggered_ids(&report),
+        vec!["R-MEM-01", "R-LOOP-01", "R-IMPORT-01", "R-COMBO-01"]
+    );
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    let suppressed: Vec<(&str, &str)> = report
+        .rules
+        .suppressed
+        .iter()
+        .map(|s| (s.rule.rule_id.as_str(), s.reason.as_str()))
+        .collect();
+    assert_eq!(
+        suppressed,
+        [
+            ("R-MEM-02", "allocator reviewed"),
+            ("R-CALL-01", "vtable dispatch reviewed in AUD-142")
+        ]
+    );
+    assert_eq!(
+        report.rules.suppressed[1].expires.as_deref(),
+        Some("2999-12-31")
+    );
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.contains("suppression for R-IMPORT-01 expired on 2000-01-01"))
+    );
+
+    // Suppressed entries keep the full rule entry in JSON.
+    let json = serde_json::to_value(&report).unwrap();
+    let call01 = &json["rules"]["suppressed"][1];
+    assert_eq!(call01["rule_id"], "R-CALL-01");
+    assert_eq!(call01["severity"], "High");
+    assert!(call01["evidence"].is_object());
+    assert_eq!(
+        report.classification.suppressed_rule_ids,
+        vec![RuleId::RMem02, RuleId::RCall01]
+    );
+    assert_eq!(
+        report.classification.unsuppressed_level,
+        Some(ClassificationLevel::HighRisk)
+    );
+}
+
+#[test]
+fn suppressed_and_disabled_rules_record_the_unsuppressed_level() {
+    let wasm = compile_fixture("minimal_module.wat");
+    let suppressed = InspectOptions {
+        suppressions: Some(
+            Suppressions::from_toml(
+                "[[suppressions]]\nrule = \"R-MEM-01\"\nreason = \"bounded by host\"\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let disabled = InspectOptions {
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: x\nrules:\n  R-MEM-01:\n    disabled: true\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    for opts in [suppressed, disabled] {
+        let report = inspect_bytes_with(&wasm, &opts);
+        assert!(report.rules.triggered.is_empty());
+        assert_eq!(report.classification.level, ClassificationLevel::Safe);
+        assert_eq!(
+            report.classification.suppressed_rule_ids,
+            vec![RuleId::RMem01]
+        );
+        assert_eq!(
+            report.classification.unsuppressed_level,
+            Some(ClassificationLevel::Risk)
+        );
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["classification"]["unsuppressed_level"], "RISK");
+        let text = sebi_core::report::render::render_text(&report);
+        assert!(text.contains("NOTE: would be Risk without suppressions (R-MEM-01)"));
+    }
+
+    // Disabling a rule that would not trigger records nothing.
+    let opts = InspectOptions {
+        catalog_overrides: Some(
+            CatalogOverrides::from_yaml(
+                "catalog_version: x\nrules:\n  R-LOOP-01:\n    disabled: true\n",
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert!(report.classification.suppressed_rule_ids.is_empty());
+    assert!(report.classification.unsuppressed_level.is_none());
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["classification"].get("suppressed_rule_ids").is_none());
+    assert!(json["classification"].get("unsuppressed_level").is_none());
+}
+
+#[test]
+fn classification_reason_summarizes_triggered_rules() {
+    let reason = |name: &str| inspect_fixture(name).classification.reason;
+    assert_eq!(
+        reason("minimal_module.wat"),
+        "RISK: 1 MED rule (R-MEM-01) triggered under policy 'default'"
+    );
+    assert_eq!(
+        reason("all_signals.wat"),
+        "HIGH_RISK: 3 HIGH rules (R-MEM-02, R-CALL-01, R-COMBO-01) and 2 MED rules \
+         triggered under policy 'default'"
+    );
+    assert_eq!(
+        reason("rust_safe_storage.wat"),
+        "no rules triggered (23 evaluated)"
+    );
+
+    let opts = InspectOptions {
+        policy: Policy::Strict,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("nested_loops.wat"), &opts);
+    assert_eq!(
+        report.classification.reason,
+        "RISK: 3 MED rules (R-MEM-01, R-LOOP-01, R-LOOP-02) triggered under policy 'strict'"
+    );
+}
+
+#[test]
+fn classification_reason_counts_suppressed_rules() {
+    let opts = InspectOptions {
+        suppressions: Some(
+            Suppressions::from_toml(
+                r#"
+[[suppressions]]
+rule = "R-MEM-01"
+reason = "static memory reviewed"
+"#,
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("minimal_module.wat"), &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+    assert_eq!(
+        report.classification.reason,
+        "no rules triggered (22 evaluated, 1 suppressed)"
+    );
+}
+
+#[test]
+fn baseline_classifies_only_new_rules() {
+    let wasm = compile_fixture("all_signals.wat");
+    let opts = InspectOptions {
+        baseline: Some(inspect_bytes(&wasm)),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(report.classification.new_rule_ids, Some(vec![]));
+    assert_eq!(
+        report.classification.preexisting_rule_ids.as_ref(),
+        Some(&report.classification.triggered_rule_ids)
+    );
+    assert!(
+        !report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.contains("baseline"))
+    );
+
+    // A baseline of another artifact is accepted, and flagged.
+    let opts = InspectOptions {
+        baseline: Some(inspect_fixture("minimal_module.wat")),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert_eq!(
+        report.classification.preexisting_rule_ids,
+        Some(vec![RuleId::RMem01])
+    );
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("baseline report is for a different artifact (sha256 "))
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["classification"]["preexisting_rule_ids"][0],
+        "R-MEM-01"
+    );
+    assert!(inspect_bytes(&wasm).classification.new_rule_ids.is_none());
+}
+
+#[test]
+fn baseline_from_older_schema_loads_tolerantly() {
+    // Written with legacy rule ids (`RMem01`), no findings, and before
+    // R-COMBO-01 existed.
+    let baseline = compat::load_report(&read_snapshot("reports/report_v0_4_0.json"))
+        .unwrap()
+        .value;
+    let opts = InspectOptions {
+        baseline: Some(baseline),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("all_signals.wat"), &opts);
+    assert_eq!(
+        report.classification.new_rule_ids,
+        Some(vec![RuleId::RCombo01])
+    );
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert!(
+        report
+            .classification
+            .reason
+            .ends_with("; 4 preexisting in baseline")
+    );
+}
+
+#[test]
+fn med_count_escalates_nested_loops() {
+    let wasm = compile_fixture("nested_loops.wat");
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert!(report.classification.escalated_from.is_none());
+
+    let opts = InspectOptions {
+        escalation: Escalation {
+            med_count_for_high: Some(3),
+            low_count_for_med: None,
+        },
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert_eq!(report.classification.exit_code, 2);
+    assert_eq!(
+        report.classification.escalated_from,
+        Some(ClassificationLevel::Risk)
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["classification"]["escalated_from"], "RISK");
+    assert_eq!(
+        json["classification"]["escalation_rule"],
+        "med_count_for_high"
+    );
+    assert!(
+        report
+            .classification
+            .reason
+            .ends_with("; escalated from RISK by med_count_for_high = 3 (3 MED rules)")
+    );
+
+    // Suppressing one of the three MED rules drops below the count.
+    let opts = InspectOptions {
+        suppressions: Some(
+            Suppressions::from_toml(
+                "[[suppressions]]\nrule = \"R-LOOP-02\"\nreason = \"bounded by input size\"\n",
+            )
+            .unwrap(),
+        ),
+        ..opts
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert!(report.classification.escalation_rule.is_none());
+}
+
+#[test]
+fn excluding_deprecated_rules_keeps_current_verdicts() {
+    // No built-in rule is deprecated yet, so opting out changes nothing.
+    assert!(
+        catalog::catalog()
+            .iter()
+            .all(|def| def.deprecated.is_none())
+    );
+    let opts = InspectOptions {
+        exclude_deprecated: true,
+        ..Default::default()
+    };
+    for name in [
+        "all_signals.wat",
+        "cpp_vtable_erc20.wat",
+        "nested_loops.wat",
+    ] {
+        let wasm = compile_fixture(name);
+        let default = inspect_bytes(&wasm);
+        let excluded = inspect_bytes_with(&wasm, &opts);
+        assert_eq!(excluded.classification, default.classification, "{name}");
+        assert_eq!(
+            excluded.rules.catalog.content_hash,
+            default.rules.catalog.content_hash
+        );
+        assert!(
+            excluded
+                .rules
+                .triggered
+                .iter()
+                .all(|r| r.deprecated.is_none())
+        );
+    }
+}
+
+#[test]
+fn bulk_memory_allowed_per_target_profile() {
+    let wasm = compile_fixture("bulk_memory_copy.wat");
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.analysis.target, Target::Stylus);
+    assert_eq!(report.signals.features.used.len(), 1);
+    assert_eq!(report.signals.features.used[0].name, "bulk_memory");
+    assert!(!has_rule(&report, "R-FEAT-01"));
+
+    // The generic profile accepts only MVP + sign-extension.
+    let opts = InspectOptions {
+        target: TargetSelection::Generic,
+        ..Default::default()
+    };
+    let feat01 = inspect_bytes_with(&wasm, &opts)
+        .rules
+        .triggered
+        .into_iter()
+        .find(|r| r.rule_id == "R-FEAT-01")
+        .expect("bulk memory is off for generic targets");
+    assert_eq!(
+        feat01.evidence["disallowed_features"][0]["name"],
+        "bulk_memory"
+    );
+}
+
+#[test]
+fn multiple_memory_grow_counted_accurately() {
+    let report = inspect_fixture("multiple_memory_grow.wat");
+
+    assert!(report.signals.instructions.has_memory_grow);
+    assert_eq!(
+        report.signals.instructions.memory_grow_count, 3,
+        "3 memory.grow calls across 2 functions should produce count == 3"
+    );
+}
+
+#[test]
+fn site_rules_report_one_finding_per_site() {
+    let report = inspect_fixture("multiple_memory_grow.wat");
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .unwrap();
+
+    let sites = &report.signals.instructions.memory_grow_sites;
+    assert_eq!(mem02.findings.len(), 3);
+    for (finding, site) in mem02.findings.iter().zip(sites) {
+        assert_eq!(finding.function_index, site.function_index);
+        assert_eq!(finding.byte_offset, site.offset);
+    }
+    assert_eq!(
+        mem02.findings[1].function_name.as_deref(),
+        Some("grow_twice")
+    );
+    // Still one rule for classification.
+    assert_eq!(
+        report
+            .classification
+            .triggered_rule_ids
+            .iter()
+            .filter(|id| **id == RuleId::RMem02)
+            .count(),
+        1
+    );
+
+    let text = sebi_core::report::render::render_text(&report);
+    assert!(text.contains(&format!(
+        "      at grow_twice (function 1), offset {}\n",
+        sites[1].offset
+    )));
+}
+
+#[test]
+fn recursive_calls_detected_in_call_graph() {
+    let report = inspect_fixture("recursive_calls.wat");
+    let cg = &report.signals.call_graph;
+
+    assert!(cg.has_recursion);
+    assert_eq!(cg.recursive_function_count, 3);
+    assert_eq!(cg.self_recursive_count, 1);
+    assert_eq!(cg.mutual_recursion_group_count, 1);
+    assert_eq!(cg.recursive_functions, vec![0, 1, 2]);
+    assert_eq!(cg.mutual_recursion_groups, vec![vec![1, 2]]);
+}
+
+#[test]
+fn direct_recursion_triggers_call03() {
+    let report = inspect_fixture("recursion_direct.wat");
+
+    assert_eq!(triggered_ids(&report), vec!["R-CALL-03"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+
+    let call03 = &report.rules.triggered[0];
+    assert_eq!(call03.evidence["recursion_kind"], "direct");
+    assert_eq!(
+        call03.evidence["signals.call_graph.self_recursive_functions"],
+        serde_json::json!([0])
+    );
+    assert_eq!(
+        call03.evidence["signals.call_graph.recursive_function_names"],
+        serde_json::json!({ "0": "factorial" })
+    );
+}
+
+#[test]
+fn mutual_recursion_triggers_call03() {
+    let report = inspect_fixture("recursion_mutual.wat");
+
+    assert_eq!(triggered_ids(&report), vec!["R-CALL-03"]);
+
+    let call03 = &report.rules.triggered[0];
+    assert_eq!(call03.evidence["recursion_kind"], "mutual");
+    assert_eq!(
+        call03.evidence["signals.call_graph.self_recursive_functions"],
+        serde_json::json!([])
+    );
+    assert_eq!(
+        call03.evidence["signals.call_graph.mutual_recursion_groups"],
+        serde_json::json!([[0, 1]])
+    );
+    assert_eq!(
+        call03.evidence["signals.call_graph.recursive_function_names"],
+        serde_json::json!({ "0": "is_even", "1": "is_odd" })
+    );
+}
+
+#[test]
+fn acyclic_diamond_does_not_trigger_call03() {
+    let report = inspect_fixture("call_graph_diamond.wat");
+
+    assert!(!report.signals.call_graph.has_recursion);
+    assert!(triggered_ids(&report).is_empty());
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
+}
+
+#[test]
+fn non_recursive_fixture_has_no_recursion() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(!report.signals.call_graph.has_recursion);
+    assert!(report.signals.call_graph.recursive_functions.is_empty());
+}
+
+#[test]
+fn float_math_detected() {
+    let report = inspect_fixture("float_math.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.has_float);
+    assert_eq!(instr.float_const_count, 1);
+    // trunc, mul, 2x convert, div
+    assert_eq!(instr.float_op_count, 5);
+}
+
+#[test]
+fn float_math_blocks_stylus_activation() {
+    let report = inspect_fixture("float_math.wat");
+    assert_eq!(report.analysis.target, Target::Stylus);
+
+    let stylus = report.analysis.stylus.as_ref().expect("Stylus block");
+    assert_eq!(stylus.float_instruction_count, 6);
+    assert!(stylus.float_blocks_activation);
+    assert!(report.analysis.warnings.contains(
+        &"Stylus target: artifact contains 6 floating-point instructions; Stylus activation will reject it"
+            .to_string()
+    ));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["analysis"]["stylus"]["float_blocks_activation"], true);
+
+    // Non-Stylus reports omit the block.
+    let json = serde_json::to_value(inspect_fixture("minimal_module.wat")).unwrap();
+    assert!(json["analysis"].get("stylus").is_none());
+}
+
+#[test]
+fn unpaid_memory_grow_warned_for_stylus_fixture() {
+    let report = inspect_fixture("rust_dynamic_dispatch.wat");
+    assert!(report.signals.instructions.has_memory_grow);
+    assert!(
+        !report
+            .analysis
+            .stylus
+            .as_ref()
+            .unwrap()
+            .pays_for_memory_grow
+    );
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.contains("vm_hooks.pay_for_memory_grow is not imported"))
+    );
+}
+
+#[test]
+fn stylus_sdk_reported_when_stamped() {
+    let wasm = wat::parse_str(
+        r#"(module (@producers (sdk "stylus-sdk-rs" "0.6.0")) (memory (export "memory") 1))"#,
+    )
+    .unwrap();
+    let json = serde_json::to_value(inspect_bytes(&wasm)).unwrap();
+    assert_eq!(
+        json["analysis"]["sdk"],
+        serde_json::json!({"name": "stylus-sdk-rs", "version": "0.6.0"})
+    );
+
+    // Fixtures carry no SDK metadata: the field is omitted.
+    let json = serde_json::to_value(inspect_fixture("rust_safe_storage.wat")).unwrap();
+    assert!(json["analysis"].get("sdk").is_none());
+}
+
+#[test]
+fn stylus_start_function_flagged() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+          (import "vm_hooks" "read_args" (func $read_args (param i32)))
+          (memory (export "memory") 1)
+          (func $init (call $read_args (i32.const 0)))
+          (start $init)
+          (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))
+        "#,
+    )
+    .unwrap();
+
+    let json = serde_json::to_value(inspect_bytes(&wasm)).unwrap();
+    assert_eq!(json["analysis"]["stylus"]["has_start_function"], true);
+    assert!(
+        json["analysis"]["warnings"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!(
+                "Stylus target: start function 1 (init) is not allowed; initialize lazily instead"
+            ))
+    );
+}
+
+#[test]
+fn integer_only_fixture_has_no_float() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(!report.signals.instructions.has_float);
+    assert_eq!(report.signals.instructions.float_op_count, 0);
+}
+
+#[test]
+fn threads_atomics_detected() {
+    let report = inspect_fixture("threads_atomics.wat");
+
+    assert!(report.signals.memory.has_shared_memory);
+    assert!(report.signals.instructions.has_atomics);
+    assert_eq!(report.signals.instructions.atomic_op_count, 4);
+}
+
+#[test]
+fn atomics_trigger_atomic01_high_risk() {
+    let report = inspect_fixture("threads_atomics.wat");
+    let atomic01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-ATOMIC-01")
+        .unwrap();
+
+    assert_eq!(atomic01.severity, "High");
+    assert_eq!(atomic01.evidence["signals.instructions.atomic_op_count"], 4);
+    assert_eq!(atomic01.evidence["signals.memory.has_shared_memory"], true);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+}
+
+#[test]
+fn shared_memory_alone_triggers_atomic01() {
+    let report = inspect_fixture("shared_memory_only.wat");
+    let atomic01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-ATOMIC-01")
+        .expect("a shared memory fires without any atomic operator");
+
+    assert_eq!(atomic01.evidence["signals.instructions.has_atomics"], false);
+    assert_eq!(atomic01.evidence["signals.instructions.atomic_op_count"], 0);
+    assert_eq!(atomic01.evidence["signals.memory.has_shared_memory"], true);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+}
+
+#[test]
+fn imported_shared_memory_detected() {
+    let report = inspect_bytes(
+        &wat::parse_str(r#"(module (import "env" "memory" (memory 1 16 shared)))"#).unwrap(),
+    );
+
+    let memory = &report.signals.memory;
+    assert!(memory.has_shared_memory);
+    assert_eq!(memory.memory_count, 1);
+    assert_eq!(memory.min_pages, Some(1));
+    assert_eq!(memory.max_pages, Some(16));
+}
+
+#[test]
+fn memory64_limits_extracted_with_warning() {
+    let report = inspect_fixture("memory64.wat");
+    let memory = &report.signals.memory;
+
+    assert!(memory.is_memory64);
+    assert_eq!(memory.min_pages, Some(1));
+    assert_eq!(memory.max_pages, Some(2));
+    assert_eq!(memory.estimated_max_pages_static, Some(2));
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.contains("memory64"))
+    );
+
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert!(!report.signals.memory.is_memory64);
+}
+
+#[test]
+fn analysis_target_reported() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(report.analysis.target, Target::Stylus);
+    assert!(
+        report
+            .analysis
+            .target_evidence
+            .iter()
+            .any(|e| e.starts_with("import module vm_hooks"))
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["analysis"]["target"], "stylus");
+
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .all(|w| !w.starts_with("Stylus target"))
+    );
+
+    let report = inspect_fixture("minimal_module.wat");
+    assert_eq!(report.analysis.target, Target::Unknown);
+    assert!(report.analysis.target_evidence.is_empty());
+}
+
+#[test]
+fn hostio_capabilities_profiled_from_vm_hooks() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    let hostio = &report.signals.hostio;
+    assert!(hostio.hostio_count > 0);
+    assert!(hostio.capabilities.storage);
+    assert!(hostio.capabilities.entrypoint_io);
+    assert!(!hostio.capabilities.delegatecall);
+    assert!(!hostio.capabilities.create);
+    assert!(hostio.unknown_hostios.is_empty());
+
+    let wasm = wat::parse_str(
+        r#"
+        (module
+          (import "vm_hooks" "delegate_call_contract" (func (param i32 i32 i32 i64 i32) (result i32)))
+          (import "vm_hooks" "create1" (func (param i32 i32 i32 i32 i32)))
+          (import "vm_hooks" "summon_oracle" (func))
+          (import "env" "storage_load_bytes32" (func (param i32 i32))))
+        "#,
+    )
+    .unwrap();
+    let json = serde_json::to_value(inspect_bytes(&wasm)).unwrap();
+    let hostio = &json["signals"]["hostio"];
+    assert_eq!(hostio["hostio_count"], 3);
+    assert_eq!(hostio["capabilities"]["delegatecall"], true);
+    assert_eq!(hostio["capabilities"]["create"], true);
+    // Only `vm_hooks` imports are hostios.
+    assert_eq!(hostio["capabilities"]["storage"], false);
+    assert_eq!(
+        hostio["unknown_hostios"],
+        serde_json::json!(["summon_oracle"])
+    );
+}
+
+#[test]
+fn toolchain_guessed_for_rust_and_cpp_fixtures() {
+    let cases = [
+        ("rust_safe_storage.wat", Toolchain::Rust, Confidence::Low),
+        (
+            "rust_loop_unbounded_mem.wat",
+            Toolchain::Rust,
+            Confidence::Low,
+        ),
+        (
+            "rust_dynamic_dispatch.wat",
+            Toolchain::Rust,
+            Confidence::Low,
+        ),
+        ("dead_code_panic.wat", Toolchain::Rust, Confidence::Medium),
+        ("cpp_allocator.wat", Toolchain::Cpp, Confidence::Medium),
+        ("cpp_vtable_erc20.wat", Toolchain::Cpp, Confidence::Low),
+        ("minimal_module.wat", Toolchain::Unknown, Confidence::None),
+    ];
+
+    for (fixture, name, confidence) in cases {
+        let toolchain = inspect_fixture(fixture).analysis.toolchain;
+        assert_eq!(
+            (toolchain.name, toolchain.confidence),
+            (name, confidence),
+            "{fixture}: {:?}",
+            toolchain.evidence
+        );
+    }
+
+    let json = serde_json::to_value(inspect_fixture("cpp_allocator.wat")).unwrap();
+    assert_eq!(json["analysis"]["toolchain"]["name"], "cpp");
+    assert_eq!(json["analysis"]["toolchain"]["confidence"], "medium");
+}
+
+#[test]
+fn private_memory_has_no_atomics() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(!report.signals.memory.has_shared_memory);
+    assert!(!report.signals.instructions.has_atomics);
+}
+
+#[test]
+fn simd_vector_detected() {
+    let report = inspect_fixture("simd_vector.wat");
+
+    assert!(report.signals.instructions.has_simd);
+    assert_eq!(report.signals.instructions.simd_op_count, 8);
+    assert!(!report.signals.instructions.has_float);
+}
+
+#[test]
+fn simd_triggers_simd01_even_when_feature_allowed() {
+    let opts = InspectOptions {
+        allowed_features: Some(vec!["simd".to_string()]),
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("simd_vector.wat"), &opts);
+
+    assert!(!has_rule(&report, "R-FEAT-01"));
+    let simd01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-SIMD-01")
+        .unwrap();
+    assert_eq!(simd01.severity, "Med");
+    assert_eq!(simd01.evidence["signals.instructions.simd_op_count"], 8);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+}
+
+#[test]
+fn scalar_fixture_has_no_simd() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(!report.signals.instructions.has_simd);
+    assert_eq!(report.signals.instructions.simd_op_count, 0);
+}
+
+#[test]
+fn memory_copy_detected_as_bulk_memory() {
+    let report = inspect_fixture("bulk_memory_copy.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.has_bulk_memory);
+    assert_eq!(instr.memory_copy_count, 1);
+    assert_eq!(instr.memory_fill_count, 0);
+    assert_eq!(instr.memory_init_count, 0);
+    assert!(!instr.has_memory_grow);
+    assert!(!instr.has_loop);
+}
+
+#[test]
+fn runtime_table_mutation_detected() {
+    let report = inspect_fixture("table_mutation.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.has_table_mutation);
+    assert_eq!(instr.table_set_count, 1);
+    assert_eq!(instr.table_grow_count, 1);
+    assert_eq!(instr.table_copy_count, 0);
+    assert_eq!(instr.table_fill_count, 0);
+
+    let call01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-CALL-01")
+        .expect("R-CALL-01 should trigger");
+    assert_eq!(
+        call01.evidence["signals.instructions.has_table_mutation"],
+        serde_json::json!(true)
+    );
+}
+
+#[test]
+fn unreachable_traps_attributed_per_function() {
+    let report = inspect_fixture("trap_heavy.wat");
+    let instr = &report.signals.instructions;
+
+    assert_eq!(instr.unreachable_count, 4);
+    let ranked: Vec<(u32, u64)> = instr
+        .trap_heaviest_functions
+        .iter()
+        .map(|f| (f.index, f.value))
+        .collect();
+    // Function 0 is the imported host call.
+    assert_eq!(ranked, vec![(2, 3), (1, 1)]);
+}
+
+#[test]
+fn br_table_dispatcher_fan_out_recorded() {
+    let report = inspect_fixture("br_table_dispatch.wat");
+
+    assert_eq!(report.signals.instructions.br_table_count, 1);
+    assert_eq!(report.signals.instructions.br_table_max_targets, 16);
+}
+
+#[test]
+fn entrypoint_dispatch_branches_estimated() {
+    let report = inspect_fixture("br_table_dispatch.wat");
+    assert_eq!(
+        report.signals.instructions.entrypoint_dispatch_branches,
+        Some(16)
+    );
+
+    let wasm = wat::parse_str(
+        r#"(module
+             (func (export "user_entrypoint") (param $sel i32) (result i32)
+               (if (i32.eq (local.get $sel) (i32.const 0x70a08231))
+                 (then (return (i32.const 1))))
+               (if (i32.eq (local.get $sel) (i32.const 0xa9059cbb))
+                 (then (return (i32.const 2))))
+               (i32.const 0)))"#,
+    )
+    .unwrap();
+    let report = inspect_bytes(&wasm);
+    assert_eq!(
+        report.signals.instructions.entrypoint_dispatch_branches,
+        Some(2)
+    );
+
+    let wasm = wat::parse_str(r#"(module (func (export "main")))"#).unwrap();
+    let report = inspect_bytes(&wasm);
+    assert_eq!(
+        report.signals.instructions.entrypoint_dispatch_branches,
+        None
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["signals"]["instructions"]["entrypoint_dispatch_branches"].is_null());
+}
+
+#[test]
+fn histogram_omitted_by_default() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(report.signals.instructions.histogram.is_none());
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["signals"]["instructions"].get("histogram").is_none());
+}
+
+#[test]
+fn histogram_emitted_when_requested() {
+    let opts = InspectOptions {
+        histogram: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("float_math.wat"), &opts);
+    let histogram = report.signals.instructions.histogram.expect("histogram");
+
+    let keys: Vec<&String> = histogram.keys().collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+    assert_eq!(histogram.get("F64Const"), Some(&1));
+    assert!(histogram.values().all(|&c| c > 0));
+}
+
+#[test]
+fn static_cost_ranks_functions() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.estimated_static_cost > 0);
+    assert!(!instr.costliest_functions.is_empty());
+    assert!(
+        instr
+            .costliest_functions
+            .windows(2)
+            .all(|w| w[0].value >= w[1].value)
+    );
+    let listed: u64 = instr.costliest_functions.iter().map(|f| f.value).sum();
+    assert!(listed <= instr.estimated_static_cost);
+}
+
+#[test]
+fn locals_heavy_function_detected() {
+    let locals = "(local i64) ".repeat(300);
+    let wat = format!(
+        r#"(module
+             (memory 1 1)
+             (func {locals})
+             (func (local i32 i32)))"#
+    );
+    let report = inspect_bytes(&wat::parse_str(&wat).unwrap());
+
+    assert_eq!(report.signals.instructions.max_locals, 300);
+    assert_eq!(report.signals.instructions.large_locals_function_count, 1);
+}
+
+#[test]
+fn infinite_loop_flagged_but_bounded_loop_is_not() {
+    let report = inspect_fixture("infinite_loop.wat");
+
+    assert_eq!(report.signals.instructions.loop_count, 2);
+    assert_eq!(
+        report.signals.instructions.suspicious_infinite_loop_count,
+        1
+    );
+}
+
+#[test]
+fn bounded_loops_not_flagged_as_infinite() {
+    let report = inspect_fixture("rust_loop_unbounded_mem.wat");
+
+    assert!(report.signals.instructions.has_loop);
+    assert_eq!(
+        report.signals.instructions.suspicious_infinite_loop_count,
+        0
+    );
+}
+
+#[test]
+fn allocator_pattern_reports_memory_size_in_mem02_evidence() {
+    let wasm = wat::parse_str(
+        r#"(module
+             (memory 1)
+             (func (export "alloc") (param $pages i32) (result i32)
+               (if (i32.lt_u (memory.size) (local.get $pages))
+                 (then (drop (memory.grow (local.get $pages)))))
+               (memory.size)))"#,
+    )
+    .unwrap();
+    let report = inspect_bytes(&wasm);
+
+    assert!(report.signals.instructions.has_memory_size);
+    assert_eq!(report.signals.instructions.memory_size_count, 2);
+    assert_eq!(report.signals.instructions.memory_grow_dynamic_count, 1);
+
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .expect("R-MEM-02 should trigger");
+    assert_eq!(
+        mem02.evidence["signals.instructions.memory_size_count"],
+        serde_json::json!(2)
+    );
+}
+
+#[test]
+fn memory_grow_operands_split_const_and_dynamic() {
+    let report = inspect_fixture("multiple_memory_grow.wat");
+    let instr = &report.signals.instructions;
+
+    assert_eq!(instr.memory_grow_count, 3);
+    assert_eq!(instr.memory_grow_const_count, 3);
+    assert_eq!(instr.memory_grow_dynamic_count, 0);
+
+    // 1 declared page + 1 + 2 + 1 constant grows; no declared maximum.
+    let memory = &report.signals.memory;
+    assert_eq!(memory.estimated_max_pages_static, Some(5));
+    assert!(!memory.growth_open_ended);
+    assert_eq!(memory.estimate_within_max, None);
+
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .expect("R-MEM-02 should trigger");
+    assert!(
+        mem02
+            .evidence
+            .get("signals.instructions.memory_grow_dynamic_count")
+            .is_some()
+    );
+    assert_eq!(
+        mem02.evidence["signals.memory.estimated_max_pages_static"],
+        serde_json::json!(5)
+    );
+    assert!(
+        mem02
+            .evidence
+            .get("signals.memory.estimate_within_max")
+            .is_none()
+    );
+}
+
+#[test]
+fn table_signals_report_shape_and_host_visibility() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (import "env" "dispatch" (table 4 funcref))
+                 (table $own (export "own") 1 2 externref)
+                 (table $private 3 funcref))"#,
+        )
+        .unwrap(),
+    );
+    let tables = &report.signals.tables;
+
+    assert_eq!(tables.table_count, 3);
+    let shape: Vec<_> = tables
+        .tables
+        .iter()
+        .map(|t| {
+            (
+                t.index,
+                t.element_type.as_str(),
+                t.min,
+                t.max,
+                t.imported,
+                t.exported,
+            )
+        })
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            (0, "funcref", 4, None, true, false),
+            (1, "externref", 1, Some(2), false, true),
+            (2, "funcref", 3, None, false, false),
+        ]
+    );
+    assert!(tables.has_host_visible_funcref_table);
+
+    // Private funcref tables and exported externref tables are not host-redirectable.
+    let report = inspect_bytes(
+        &wat::parse_str(r#"(module (table 1 funcref) (table (export "e") 1 externref))"#).unwrap(),
+    );
+    assert!(!report.signals.tables.has_host_visible_funcref_table);
+
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(report.signals.tables.table_count, 0);
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["signals"]["tables"]["tables"], serde_json::json!([]));
+}
+
+#[test]
+fn static_memory_estimate_against_declared_max() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module (memory 2 4)
+                 (func (export "main") (drop (memory.grow (i32.const 2)))))"#,
+        )
+        .unwrap(),
+    );
+    assert_eq!(report.signals.memory.estimated_max_pages_static, Some(4));
+    assert_eq!(report.signals.memory.estimate_within_max, Some(true));
+
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module (memory 2 4)
+                 (func (export "main") (param $n i32)
+                   (drop (memory.grow (i32.const 1)))
+                   (drop (memory.grow (local.get $n)))))"#,
+        )
+        .unwrap(),
+    );
+    let memory = &report.signals.memory;
+    assert_eq!(memory.estimated_max_pages_static, Some(3));
+    assert!(memory.growth_open_ended);
+    assert_eq!(memory.estimate_within_max, Some(false));
+
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .unwrap();
+    assert_eq!(
+        mem02.evidence["signals.memory.growth_open_ended"],
+        serde_json::json!(true)
+    );
+    assert_eq!(
+        mem02.evidence["signals.memory.estimate_within_max"],
+        serde_json::json!(false)
+    );
+}
+
+#[test]
+fn flagged_instruction_offsets_point_at_opcodes() {
+    let wasm = compile_fixture("all_signals.wat");
+    let report = inspect_bytes(&wasm);
+    let instr = &report.signals.instructions;
+
+    assert_eq!(
+        instr.memory_grow_sites.len() as u64,
+        instr.memory_grow_count
+    );
+    for site in &instr.memory_grow_sites {
+        assert_eq!(wasm[site.offset as usize], 0x40, "memory.grow opcode");
+    }
+    for site in &instr.call_indirect_sites {
+        assert_eq!(wasm[site.offset as usize], 0x11, "call_indirect opcode");
+    }
+    for site in &instr.loop_sites {
+        assert_eq!(wasm[site.offset as usize], 0x03, "loop opcode");
+    }
+}
+
+#[test]
+fn list_limit_truncates_import_and_export_lists() {
+    let imports: String = (0..5)
+        .map(|i| format!(r#"(import "env" "f{i}" (func))"#))
+        .collect();
+    let exports: String = (0..5)
+        .map(|i| format!(r#"(func (export "e{i}"))"#))
+        .collect();
+    let wasm = wat::parse_str(format!("(module {imports} (memory 1 1) {exports})")).unwrap();
+
+    let report = inspect_bytes(&wasm);
+    let ie = &report.signals.imports_exports;
+    assert_eq!(ie.imports.as_ref().map(Vec::len), Some(5));
+    assert!(!ie.imports_truncated && !ie.exports_truncated);
+
+    let opts = InspectOptions {
+        list_limit: 2,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    let ie = &report.signals.imports_exports;
+    assert_eq!((ie.import_count, ie.export_count), (5, 5));
+    assert!(ie.imports_truncated && ie.exports_truncated);
+
+    // The first entries in deterministic order are kept.
+    let names: Vec<_> = ie
+        .exports
+        .iter()
+        .flatten()
+        .map(|e| e.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["e0", "e1"]);
+    assert_eq!(ie.imports.as_ref().map(Vec::len), Some(2));
+    assert_eq!(ie.modules[0].count, 5);
+}
+
+#[test]
+fn site_limit_caps_recorded_offsets() {
+    let loops = "(loop) ".repeat(40);
+    let wasm = wat::parse_str(format!("(module (memory 1 1) (func {loops}))")).unwrap();
+
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.signals.instructions.loop_count, 40);
+    assert_eq!(report.signals.instructions.loop_sites.len(), 16);
+
+    let opts = InspectOptions {
+        site_limit: 4,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.signals.instructions.loop_sites.len(), 4);
+
+    let loop01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-LOOP-01")
+        .expect("R-LOOP-01 should trigger");
+    assert_eq!(
+        loop01.evidence["locations"].as_array().map(Vec::len),
+        Some(4)
+    );
+}
+
+#[test]
+fn call_indirect_signature_surface_in_evidence() {
+    let report = inspect_fixture("table_mutation.wat");
+
+    assert_eq!(
+        report.signals.instructions.call_indirect_distinct_types,
+        vec![0]
+    );
+    assert_eq!(report.signals.instructions.call_indirect_tables, vec![0]);
+
+    let call01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-CALL-01")
+        .expect("R-CALL-01 should trigger");
+    assert_eq!(
+        call01.evidence["signals.instructions.call_indirect_distinct_types"],
+        serde_json::json!([0])
+    );
+}
+
+#[test]
+fn tail_calls_detected_and_recursion_found() {
+    let report = inspect_fixture("tail_calls.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.has_tail_call);
+    assert_eq!(instr.return_call_count, 2);
+    assert_eq!(instr.return_call_indirect_count, 1);
+    assert!(!instr.has_loop, "tail-call recursion needs no loop");
+    assert!(report.signals.call_graph.has_recursion);
+    assert_eq!(report.signals.call_graph.self_recursive_count, 1);
+}
+
+#[test]
+fn exception_handling_detected() {
+    let report = inspect_fixture("exceptions.wat");
+    let instr = &report.signals.instructions;
+
+    assert!(instr.has_exceptions);
+    assert_eq!(instr.try_count, 1);
+    assert_eq!(instr.throw_count, 1);
+    assert_eq!(report.signals.module.tag_count, 1);
+    assert_eq!(report.analysis.status, "ok");
+}
+
+#[test]
+fn modules_without_exceptions_unaffected() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    assert!(!report.signals.instructions.has_exceptions);
+    assert_eq!(report.signals.instructions.exception_op_count, 0);
+    assert_eq!(report.signals.module.tag_count, 0);
+}
+
+#[test]
+fn deep_expression_raises_stack_depth_estimate() {
+    let mut expr = String::from("(local.get 0)");
+    for i in 0..200 {
+        expr = format!("(i32.xor (i32.const {i}) {expr})");
+    }
+    let wat = format!("(module (memory 1 1) (func (param i32) (result i32) {expr}))");
+    let report = inspect_bytes(&wat::parse_str(&wat).unwrap());
+
+    assert_eq!(report.signals.instructions.max_estimated_stack_depth, 201);
+}
+
+#[test]
+fn capabilities_attributed_to_exports_and_internal_names() {
+    let report = inspect_fixture("export_attribution.wat");
+    let by_export = report.signals.instructions.by_export.as_ref().unwrap();
+
+    assert_eq!(by_export["memory_grow"], vec!["deploy", "init"]);
+    assert_eq!(by_export["loop"], vec!["func[5]", "user_entrypoint"]);
+    assert_eq!(by_export["call_indirect"], vec!["dispatch"]);
+
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .unwrap();
+    assert_eq!(
+        mem02.evidence["signals.instructions.by_export.memory_grow"],
+        serde_json::json!(["deploy", "init"])
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_scan_matches_serial_scan() {
+    use sebi_core::wasm::parse::{ParseOptions, parse_wasm_with_options};
+
+    let mut modules: Vec<(String, Vec<u8>)> = std::fs::read_dir(fixtures_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(".wat"))
+        .map(|name| {
+            let wasm = compile_fixture(&name);
+            (name, wasm)
+        })
+        .collect();
+    modules.sort();
+
+    // Many small bodies with mixed capabilities, calling each other in a chain.
+    let mut wat = String::from("(module (type (func)) (table 1 funcref) (memory 1)");
+    for i in 0..5_000 {
+        let body = match i % 4 {
+            0 => "(loop (nop))",
+            1 => "(drop (memory.grow (i32.const 1)))",
+            2 => "(call_indirect (type 0) (i32.const 0))",
+            _ => "(drop (f32.add (f32.const 1) (f32.const 2)))",
+        };
+        wat.push_str(&format!("(func {body} (call {}))", (i + 1) % 5_000));
+    }
+    wat.push(')');
+    modules.push(("generated".into(), wat::parse_str(&wat).unwrap()));
+
+    for (name, wasm) in &modules {
+        let serial = ParseOptions {
+            collect_histogram: true,
+            ..Default::default()
+        };
+        let parallel = ParseOptions {
+            parallel: true,
+            ..serial.clone()
+        };
+
+        let a = parse_wasm_with_options(wasm, &serial).unwrap();
+        let b = parse_wasm_with_options(wasm, &parallel).unwrap();
+        assert_eq!(a.instructions, b.instructions, "{name}: scans differ");
+    }
+}
+
+#[test]
+fn large_constant_offsets_counted_against_initial_memory() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+          (memory 2 2)
+          (func (export "scratch")
+            (i32.store offset=1048576 (i32.const 0) (i32.const 7))
+            (drop (i32.load offset=64 (i32.const 0)))))
+        "#,
+    )
+    .unwrap();
+
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.signals.instructions.large_offset_access_count, 1);
+    assert_eq!(report.signals.instructions.max_static_offset, 1_048_576);
+
+    let opts = InspectOptions {
+        large_offset_threshold: 32,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.signals.instructions.large_offset_access_count, 2);
+}
+
+#[test]
+fn dead_code_rules_drop_out_in_reachable_scope() {
+    let wasm = compile_fixture("dead_code_panic.wat");
+
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.rules.scope, "module");
+    assert!(report.signals.reachability.is_none());
+    assert!(has_rule(&report, "R-MEM-02"));
+    assert!(has_rule(&report, "R-LOOP-01"));
+
+    let opts = InspectOptions {
+        rule_scope: RuleScope::Reachable,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert_eq!(report.rules.scope, "reachable");
+    assert!(report.rules.triggered.is_empty());
+    assert_eq!(report.classification.exit_code, 0);
+
+    // Module-wide signals are still reported alongside the reachable view.
+    assert!(report.signals.instructions.has_loop);
+    let reachability = report.signals.reachability.as_ref().unwrap();
+    assert_eq!(reachability.entrypoint_mode, "user_entrypoint");
+    assert_eq!(reachability.entrypoints, vec![3]);
+    assert_eq!(reachability.dead_functions, vec![2]);
+    assert!(!reachability.instructions.has_loop);
+}
+
+#[test]
+fn reachability_without_scope_change_keeps_module_rules() {
+    let opts = InspectOptions {
+        reachability: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("dead_code_panic.wat"), &opts);
+
+    assert_eq!(report.rules.scope, "module");
+    assert!(has_rule(&report, "R-LOOP-01"));
+    assert_eq!(
+        report
+            .signals
+            .reachability
+            .as_ref()
+            .unwrap()
+            .dead_function_count,
+        1
+    );
+}
+
+#[test]
+fn large_artifact_triggers_size_rule() {
+    // Generate a WASM module exceeding the default 200KB threshold.
+    // We pad with a large data section to reliably exceed the threshold.
+    let padding = "X".repeat(210_000);
+    let wat = format!("(module (memory 4 16) (data (i32.const 0) \"{padding}\"))");
+
+    let wasm = wat::parse_str(&wat).expect("large module should compile");
+    assert!(
+        wasm.len() > 200_000,
+        "generated module should exceed 200KB, got {} bytes",
+        wasm.len()
+    );
+
+    let report = inspect_bytes(&wasm);
+
+    assert!(has_rule(&report, "R-SIZE-01"), "expected R-SIZE-01 to fire");
+    let size_rule = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-SIZE-01")
+        .unwrap();
+    assert!(
+        size_rule.evidence.get("artifact.size_bytes").is_some(),
+        "R-SIZE-01 evidence should contain artifact.size_bytes"
+    );
+    assert!(
+        size_rule.evidence.get("SIZE_THRESHOLD").is_some(),
+        "R-SIZE-01 evidence should contain SIZE_THRESHOLD"
+    );
+
+    // The padding is initialized data, so R-DATA-01 fires alongside.
+    let data_rule = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-DATA-01")
+        .expect("expected R-DATA-01 to fire");
+    assert_eq!(data_rule.evidence["signals.data.segment_count"], 1);
+    assert_eq!(data_rule.evidence["signals.data.total_bytes"], 210_000);
+    assert_eq!(data_rule.evidence["DATA_BYTES_THRESHOLD"], 64 * 1024);
+}
+
+#[test]
+fn data_bytes_threshold_is_configurable() {
+    let padding = "X".repeat(64 * 1024);
+    let wasm = wat::parse_str(format!(
+        r#"(module (memory 2 2) (data (i32.const 0) "{padding}") (data (i32.const 0) "Y"))"#
+    ))
+    .unwrap();
+
+    let report = inspect_bytes(&wasm);
+    assert_eq!(report.signals.data.segment_count, 2);
+    assert_eq!(report.signals.data.total_bytes, 64 * 1024 + 1);
+    assert_eq!(report.signals.data.largest_segment_bytes, 64 * 1024);
+    assert!(has_rule(&report, "R-DATA-01"));
+    assert!(!has_rule(&report, "R-SIZE-01"));
+
+    let opts = InspectOptions {
+        data_bytes_threshold: 64 * 1024 + 1,
+        ..Default::default()
+    };
+    assert!(!has_rule(&inspect_bytes_with(&wasm, &opts), "R-DATA-01"));
+}
+
+#[test]
+fn invalid_wasm_reports_parse_error() {
+    let garbage = b"this is not a valid wasm file at all";
+    let report = inspect_bytes(garbage);
+
+    assert_eq!(report.analysis.status, "parse_error");
+    assert!(has_rule(&report, "R-ANALYSIS-01"));
+    assert_eq!(report.classification.level, ClassificationLevel::Unknown);
+    assert_eq!(report.classification.exit_code, 3);
+
+    let rule = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-ANALYSIS-01")
+        .unwrap();
+    assert_eq!(rule.evidence["analysis.status"], "parse_error");
+    assert_eq!(
+        rule.evidence["analysis.warnings"][0],
+        report.analysis.warnings[0].as_str()
+    );
+}
+
+#[test]
+fn incomplete_analysis_classifies_unknown() {
+    // A component parses as `unsupported`; a repeated section as `parse_error`
+    // after the memory was already read, which alone would be RISK.
+    let component = wat::parse_str("(component (core module))").unwrap();
+    let mut duplicate = wat::parse_str("(module (memory 1))").unwrap();
+    duplicate.extend([0x05, 0x03, 0x01, 0x00, 0x02]);
+
+    for (wasm, status) in [(component, "unsupported"), (duplicate, "parse_error")] {
+        let report = inspect_bytes(&wasm);
+        assert_eq!(report.analysis.status, status);
+        assert_eq!(report.classification.level, ClassificationLevel::Unknown);
+        assert_eq!(report.classification.exit_code, 3);
+        assert!(has_rule(&report, "R-ANALYSIS-01"));
+        assert_eq!(
+            serde_json::to_value(&report).unwrap()["classification"]["level"],
+            "UNKNOWN"
+        );
+        let text = sebi_core::report::render::render_text(&report);
+        assert!(text.contains("Classification: Unknown"));
+        assert!(text.contains(&format!(
+            "Analysis {status}: signals are partial, so no verdict is given"
+        )));
+    }
+
+    let ok = inspect_fixture("minimal_module.wat");
+    assert_eq!(ok.classification.level, ClassificationLevel::Risk);
+}
+
+#[test]
+fn unknown_classification_outlives_analysis_rule_and_takes_configured_exit_code() {
+    let garbage = b"this is not a valid wasm file at all";
+    let opts = InspectOptions {
+        suppressions: Some(
+            Suppressions::from_toml(
+                "[[suppressions]]\nrule = \"R-ANALYSIS-01\"\nreason = \"known truncated build\"\n",
+            )
+            .unwrap(),
+        ),
+        unknown_exit_code: 0,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(garbage, &opts);
+
+    assert!(!has_rule(&report, "R-ANALYSIS-01"));
+    assert_eq!(report.classification.level, ClassificationLevel::Unknown);
+    assert_eq!(report.classification.exit_code, 0);
+    assert_eq!(
+        report.classification.reason,
+        "UNKNOWN: analysis status 'parse_error'; partial signals would give RISK under policy 'default'"
+    );
+}
+
+#[test]
+fn composite_rule_joins_unbounded_growth_and_loops() {
+    let report = inspect_fixture("all_signals.wat");
+    let combo = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-COMBO-01")
+        .unwrap();
+    assert_eq!(combo.severity, "High");
+    let constituents = combo.evidence["constituents"].as_array().unwrap();
+    let ids: Vec<&str> = constituents
+        .iter()
+        .map(|c| c["rule_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["R-MEM-01", "R-MEM-02", "R-LOOP-01"]);
+    let mem02 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-MEM-02")
+        .unwrap();
+    assert_eq!(constituents[1]["evidence"], mem02.evidence);
+    // Counted once, next to its constituents.
+    let classified = &report.classification.triggered_rule_ids;
+    assert_eq!(
+        classified.iter().filter(|id| **id == "R-COMBO-01").count(),
+        1
+    );
+    assert_eq!(classified.len(), report.rules.triggered.len());
+
+    // Loops and no maximum, but no `memory.grow`.
+    let opts = InspectOptions {
+        include_passed: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&compile_fixture("rust_loop_unbounded_mem.wat"), &opts);
+    assert!(!has_rule(&report, "R-COMBO-01"));
+    let passed = report
+        .rules
+        .passed
+        .iter()
+        .find(|r| r.rule_id == "R-COMBO-01")
+        .unwrap();
+    assert_eq!(passed.evidence["unmet"], serde_json::json!(["R-MEM-02"]));
+}
+
+#[test]
+fn deterministic_json_output_for_same_fixture() {
+    let wasm = compile_fixture("cpp_vtable_erc20.wat");
+    let mut tmp = NamedTempFile::new().unwrap();
+    tmp.write_all(&wasm).unwrap();
+    tmp.flush().unwrap();
+
+    let tool = || ToolInfo {
+        name: "sebi".into(),
+        version: "0.1.0-test".into(),
+        commit: None,
+    };
+    let report_a = sebi_core::inspect(tmp.path(), tool()).unwrap();
+    let report_b = sebi_core::inspect(tmp.path(), tool()).unwrap();
+
+    let json_a = serde_json::to_string_pretty(&report_a).unwrap();
+    let json_b = serde_json::to_string_pretty(&report_b).unwrap();
+
+    assert_eq!(
+        json_a, json_b,
+        "identical input must produce identical JSON"
+    );
+}
+
+#[test]
+fn deterministic_json_output_for_safe_contract() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let mut tmp = NamedTempFile::new().unwrap();
+    tmp.write_all(&wasm).unwrap();
+    tmp.flush().unwrap();
+
+    let tool = || ToolInfo {
+        name: "sebi".into(),
+        version: "0.1.0-test".into(),
+        commit: None,
+    };
+    let report_a = sebi_core::inspect(tmp.path(), tool()).unwrap();
+    let report_b = sebi_core::inspect(tmp.path(), tool()).unwrap();
+
+    let json_a = serde_json::to_string(&report_a).unwrap();
+    let json_b = serde_json::to_string(&report_b).unwrap();
+
+    assert_eq!(json_a, json_b);
+}
+
+#[test]
+fn report_schema_version_matches() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(report.schema_version, "0.15.0");
+}
+
+#[test]
+fn report_tool_info_preserved() {
+    let report = inspect_fixture("rust_safe_storage.wat");
     assert_eq!(report.tool.name, "sebi");
     assert_eq!(report.tool.version, "0.1.0-test");
     assert!(report.tool.commit.is_none());
@@ -471,7 +3022,7 @@ fn report_json_roundtrip() {
 #[test]
 fn report_rules_catalog_version() {
     let report = inspect_fixture("rust_safe_storage.wat");
-    assert_eq!(report.rules.catalog.catalog_version, "0.1.0");
+    assert_eq!(report.rules.catalog.catalog_version, "0.19.0");
     assert_eq!(report.rules.catalog.ruleset, "default");
 }
 
@@ -590,81 +3141,503 @@ fn loop01_evidence_references_schema_paths() {
 }
 
 #[test]
-fn classification_policy_is_default() {
-    let report = inspect_fixture("rust_safe_storage.wat");
-    assert_eq!(report.classification.policy, "default");
+fn classification_policy_is_default() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(report.classification.policy, "default");
+}
+
+#[test]
+fn triggered_rule_ids_sorted_in_classification() {
+    let report = inspect_fixture("all_signals.wat");
+
+    // Classification triggered_rule_ids are sorted by RuleId enum Ord
+    // (declaration order: RMem01, RMem02, RCall01, RLoop01, RSize01, RTable01).
+    let ids: Vec<String> = report
+        .classification
+        .triggered_rule_ids
+        .iter()
+        .map(|id| id.as_str().to_string())
+        .collect();
+
+    assert_eq!(
+        ids,
+        vec![
+            "R-MEM-01",
+            "R-MEM-02",
+            "R-CALL-01",
+            "R-LOOP-01",
+            "R-COMBO-01"
+        ],
+        "triggered_rule_ids should be sorted by enum variant order"
+    );
+}
+
+#[test]
+fn classification_and_rules_sections_share_rule_order() {
+    for name in [
+        "all_signals.wat",
+        "cpp_vtable_erc20.wat",
+        "nested_loops.wat",
+    ] {
+        let report = inspect_fixture(name);
+        let triggered: Vec<_> = report
+            .rules
+            .triggered
+            .iter()
+            .map(|r| r.rule_id.clone())
+            .collect();
+        assert_eq!(
+            report.classification.triggered_rule_ids, triggered,
+            "{name}"
+        );
+        assert!(triggered.windows(2).all(|w| w[0] < w[1]), "{name}");
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json["classification"]["triggered_rule_ids"][0],
+            json["rules"]["triggered"][0]["rule_id"]
+        );
+    }
+}
+
+#[test]
+fn triggered_rules_sorted_in_rules_section() {
+    let report = inspect_fixture("cpp_vtable_erc20.wat");
+
+    // rules.triggered is sorted by RuleId enum Ord (declaration order).
+    let ids: Vec<&str> = report
+        .rules
+        .triggered
+        .iter()
+        .map(|r| r.rule_id.as_str())
+        .collect();
+
+    assert_eq!(
+        ids,
+        vec![
+            "R-MEM-01",
+            "R-MEM-02",
+            "R-CALL-01",
+            "R-LOOP-01",
+            "R-IMPORT-01",
+            "R-COMBO-01"
+        ],
+        "rules.triggered should be sorted by enum variant order"
+    );
+}
+
+#[test]
+fn imports_sorted_deterministically() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    let imports = report.signals.imports_exports.imports.as_ref().unwrap();
+    for window in imports.windows(2) {
+        let a = (&window[0].module, &window[0].name, &window[0].kind);
+        let b = (&window[1].module, &window[1].name, &window[1].kind);
+        assert!(a <= b, "imports not sorted: {:?} > {:?}", a, b);
+    }
+}
+
+#[test]
+fn exports_sorted_deterministically() {
+    let report = inspect_fixture("rust_safe_storage.wat");
+
+    let exports = report.signals.imports_exports.exports.as_ref().unwrap();
+    for window in exports.windows(2) {
+        let a = (&window[0].name, &window[0].kind);
+        let b = (&window[1].name, &window[1].kind);
+        assert!(a <= b, "exports not sorted: {:?} > {:?}", a, b);
+    }
+}
+
+#[test]
+fn export_items_carry_function_indices() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (import "vm_hooks" "msg_value" (func $host (param i32)))
+                 (func $entry (export "user_entrypoint") (param i32) (result i32) (i32.const 0))
+                 (export "host" (func $host))
+                 (memory (export "memory") 1))"#,
+        )
+        .unwrap(),
+    );
+
+    let exports = report.signals.imports_exports.exports.as_ref().unwrap();
+    let indices: Vec<_> = exports
+        .iter()
+        .map(|e| (e.name.as_str(), e.index, e.defined_function_index))
+        .collect();
+    assert_eq!(
+        indices,
+        vec![
+            ("host", Some(0), None),
+            ("memory", Some(0), None),
+            ("user_entrypoint", Some(1), Some(0)),
+        ]
+    );
+
+    let signatures: Vec<_> = exports.iter().map(|e| e.signature.as_deref()).collect();
+    assert_eq!(
+        signatures,
+        vec![Some("(i32) -> ()"), None, Some("(i32) -> i32")]
+    );
+
+    let json = serde_json::to_value(&report).unwrap();
+    let host = &json["signals"]["imports_exports"]["exports"][0];
+    assert_eq!(host["index"], 0);
+    assert!(host.get("defined_function_index").is_none());
+}
+
+#[test]
+fn duplicate_section_named_in_analysis_warnings() {
+    // (module (memory 1)) with its memory section repeated
+    let mut wasm = wat::parse_str("(module (memory 1))").unwrap();
+    wasm.extend([0x05, 0x03, 0x01, 0x00, 0x02]);
+    let report = inspect_bytes(&wasm);
+
+    assert_eq!(report.analysis.status, "parse_error");
+    assert!(
+        report
+            .analysis
+            .warnings
+            .contains(&"duplicate memory section at offset 0xd".to_string())
+    );
+    // Facts gathered before the duplicate are kept.
+    assert_eq!(report.signals.memory.min_pages, Some(1));
+}
+
+#[test]
+fn export_aliases_reported_for_shared_functions() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (func $entry (param i32) (result i32) (i32.const 0))
+                 (export "user_entrypoint" (func $entry))
+                 (export "main" (func $entry))
+                 (func (export "solo")))"#,
+        )
+        .unwrap(),
+    );
+
+    let aliases = &report.signals.imports_exports.export_aliases;
+    assert_eq!(aliases.len(), 1);
+    assert_eq!(aliases[0].function_index, 0);
+    assert_eq!(aliases[0].names, vec!["main", "user_entrypoint"]);
+
+    let report = inspect_fixture("rust_safe_storage.wat");
+    assert!(report.signals.imports_exports.export_aliases.is_empty());
+}
+
+#[test]
+fn exported_and_internal_function_counts() {
+    let report = inspect_bytes(
+        &wat::parse_str(
+            r#"(module
+                 (import "env" "host" (func $host))
+                 (func $entry (param i32) (result i32) (i32.const 0))
+                 (func $helper)
+                 (func $other)
+                 (export "user_entrypoint" (func $entry))
+                 (export "main" (func $entry))
+                 (export "host" (func $host))
+                 (export "other" (func $other))
+                 (memory (export "memory") 1))"#,
+        )
+        .unwrap(),
+    );
+
+    let module = &report.signals.module;
+    assert_eq!(module.function_count, 3);
+    // Aliases count once; the re-exported import is not a defined function.
+    assert_eq!(module.exported_function_count, 2);
+    assert_eq!(module.internal_function_count, 1);
+    assert_eq!(report.signals.imports_exports.export_count, 5);
+}
+
+#[test]
+fn hash_is_stable_for_same_bytes() {
+    let wasm = compile_fixture("rust_safe_storage.wat");
+    let report_a = inspect_bytes(&wasm);
+    let report_b = inspect_bytes(&wasm);
+
+    assert_eq!(report_a.artifact.hash.value, report_b.artifact.hash.value);
+}
+
+#[test]
+fn size_breakdown_never_exceeds_artifact_size() {
+    let mut names: Vec<_> = std::fs::read_dir(fixtures_dir())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .filter(|n| n.ends_with(".wat"))
+        .collect();
+    names.sort();
+
+    for name in names {
+        let report = inspect_fixture(&name);
+        let sizes = &report.signals.sizes;
+        let total = sizes.code_bytes + sizes.data_bytes + sizes.custom_bytes + sizes.other_bytes;
+        // Only the 8-byte preamble and section headers are left out.
+        assert!(
+            total + 8 <= report.artifact.size_bytes,
+            "{name}: components {total} exceed artifact size {}",
+            report.artifact.size_bytes
+        );
+    }
+}
+
+#[test]
+fn exported_table_triggers_table01() {
+    let report = inspect_fixture("exported_table.wat");
+
+    assert_eq!(triggered_ids(&report), vec!["R-CALL-01", "R-TABLE-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+
+    let table01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-TABLE-01")
+        .unwrap();
+    let table = &table01.evidence["signals.tables.tables"][0];
+    assert_eq!(table["element_type"], "funcref");
+    assert_eq!(table["min"], 2);
+    assert_eq!(table["max"], 4);
+    assert_eq!(table["export_names"], serde_json::json!(["handlers"]));
+    assert_eq!(
+        table01.evidence["signals.instructions.has_table_mutation"],
+        false
+    );
+
+    // An internal table, even a mutated one, stays out of reach of the host.
+    assert!(!has_rule(
+        &inspect_fixture("table_mutation.wat"),
+        "R-TABLE-01"
+    ));
+}
+
+#[test]
+fn env_imports_trigger_import01_under_stylus_profile() {
+    let wasm = compile_fixture("cpp_vtable_erc20.wat");
+
+    // The generic profile has no allowlist, so the rule stays off.
+    let generic = InspectOptions {
+        target: TargetSelection::Generic,
+        ..Default::default()
+    };
+    assert!(!has_rule(
+        &inspect_bytes_with(&wasm, &generic),
+        "R-IMPORT-01"
+    ));
+
+    let stylus = InspectOptions {
+        target: TargetSelection::Stylus,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &stylus);
+    let import01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-IMPORT-01")
+        .expect("R-IMPORT-01 should trigger on env imports");
+    assert_eq!(
+        import01.evidence["IMPORT_ALLOWLIST"],
+        serde_json::json!(["console", "vm_hooks"])
+    );
+    assert_eq!(
+        import01.evidence["signals.imports_exports.modules"][0]["module"],
+        "env"
+    );
+    let pairs = import01.evidence["signals.imports_exports.imports"]
+        .as_array()
+        .unwrap();
+    assert!(!pairs.is_empty());
+    assert!(pairs.iter().all(|p| p["module"] == "env"));
+
+    // An explicit allowlist overrides the profile's.
+    let allow_env = InspectOptions {
+        import_allowlist: Some(vec!["env".into()]),
+        ..stylus
+    };
+    assert!(!has_rule(
+        &inspect_bytes_with(&wasm, &allow_env),
+        "R-IMPORT-01"
+    ));
+}
+
+#[test]
+fn wasi_imports_trigger_wasi01() {
+    let report = inspect_fixture("wasi_fd_write.wat");
+
+    // WASI imports are expected by the detected WASI profile, so only
+    // R-WASI-01 fires.
+    assert_eq!(report.analysis.target, Target::Wasi);
+    assert_eq!(triggered_ids(&report), vec!["R-WASI-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert_eq!(report.classification.exit_code, 1);
+
+    let wasi01 = &report.rules.triggered[0];
+    assert_eq!(
+        wasi01.evidence["signals.imports_exports.imports"],
+        serde_json::json!([{ "module": "wasi_snapshot_preview1", "name": "fd_write" }])
+    );
+}
+
+#[test]
+fn start_function_triggers_start01() {
+    let report = inspect_fixture("start_function.wat");
+
+    assert_eq!(triggered_ids(&report), vec!["R-START-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert_eq!(report.classification.exit_code, 1);
+
+    let module = &report.signals.module;
+    assert!(module.has_start);
+    assert_eq!(module.start_function, Some(0));
+    assert_eq!(module.start_function_name.as_deref(), Some("init"));
+
+    let start01 = &report.rules.triggered[0];
+    assert_eq!(start01.evidence["signals.module.start_function"], 0);
+    assert_eq!(
+        start01.evidence["signals.module.start_function_name"],
+        "init"
+    );
+
+    let minimal = inspect_fixture("minimal_module.wat").signals.module;
+    assert!(!minimal.has_start);
+    assert_eq!(minimal.start_function, None);
+}
+
+#[test]
+fn exported_mutable_global_triggers_global01() {
+    let report = inspect_fixture("exported_mutable_global.wat");
+
+    assert_eq!(triggered_ids(&report), vec!["R-GLOBAL-01"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
+    assert_eq!(report.classification.exit_code, 1);
+
+    let globals = &report.signals.globals;
+    assert_eq!(globals.global_count, 3);
+    assert_eq!(globals.mutable_global_count, 2);
+    assert_eq!(globals.exported_mutable_global_count, 1);
+
+    let global01 = &report.rules.triggered[0];
+    assert_eq!(
+        global01.evidence["signals.globals.exported_mutable_globals"],
+        serde_json::json!([{ "name": "counter", "index": 2, "val_type": "i64" }])
+    );
+}
+
+#[test]
+fn internal_or_immutable_globals_do_not_trigger_global01() {
+    let wasm = wat::parse_str(
+        r#"(module
+             (memory (export "memory") 1 1)
+             (global $sp (mut i32) (i32.const 1024))
+             (global $data_end i32 (i32.const 8))
+             (export "__data_end" (global $data_end))
+             (func (export "user_entrypoint") (param i32) (result i32) (i32.const 0)))"#,
+    )
+    .unwrap();
+    let report = inspect_bytes(&wasm);
+
+    assert!(!has_rule(&report, "R-GLOBAL-01"));
+    assert_eq!(report.signals.globals.mutable_global_count, 1);
+    assert_eq!(report.signals.globals.exported_mutable_global_count, 0);
+    assert_eq!(report.classification.level, ClassificationLevel::Safe);
 }
 
 #[test]
-fn triggered_rule_ids_sorted_in_classification() {
-    let report = inspect_fixture("all_signals.wat");
+fn missing_entrypoint_triggers_export01_under_stylus_profile() {
+    let wasm = compile_fixture("minimal_module.wat");
 
-    // Classification triggered_rule_ids are sorted by RuleId enum Ord
-    // (declaration order: RMem01, RMem02, RCall01, RLoop01, RSize01).
-    let ids: Vec<String> = report
-        .classification
-        .triggered_rule_ids
-        .iter()
-        .map(|id| id.as_str().to_string())
-        .collect();
+    // Detection and the generic profile require no exports.
+    for target in [TargetSelection::Auto, TargetSelection::Generic] {
+        let opts = InspectOptions {
+            target,
+            ..Default::default()
+        };
+        assert!(!has_rule(&inspect_bytes_with(&wasm, &opts), "R-EXPORT-01"));
+    }
 
+    let stylus = InspectOptions {
+        target: TargetSelection::Stylus,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &stylus);
+    let export01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-EXPORT-01")
+        .expect("R-EXPORT-01 should trigger without user_entrypoint");
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
     assert_eq!(
-        ids,
-        vec!["R-MEM-01", "R-MEM-02", "R-CALL-01", "R-LOOP-01"],
-        "triggered_rule_ids should be sorted by enum variant order"
+        export01.evidence["REQUIRED_EXPORTS"],
+        serde_json::json!([{ "name": "user_entrypoint", "kind": "func" }])
+    );
+    assert_eq!(
+        export01.evidence["unmet_required_exports"],
+        serde_json::json!([{ "name": "user_entrypoint", "kind": "func", "found_kind": null }])
     );
 }
 
 #[test]
-fn triggered_rules_sorted_in_rules_section() {
-    let report = inspect_fixture("cpp_vtable_erc20.wat");
+fn mistyped_required_export_triggers_export01() {
+    let wasm = wat::parse_str(
+        r#"(module
+             (memory 1 1)
+             (global (export "user_entrypoint") i32 (i32.const 0))
+             (func (export "mark_used")))"#,
+    )
+    .unwrap();
+    let report = inspect_bytes(&wasm);
 
-    // rules.triggered is sorted by RuleId enum Ord (declaration order).
-    let ids: Vec<&str> = report
+    assert_eq!(report.analysis.target, Target::Stylus);
+    let export01 = report
         .rules
         .triggered
         .iter()
-        .map(|r| r.rule_id.as_str())
-        .collect();
-
+        .find(|r| r.rule_id == "R-EXPORT-01")
+        .unwrap();
     assert_eq!(
-        ids,
-        vec!["R-MEM-01", "R-MEM-02", "R-CALL-01", "R-LOOP-01"],
-        "rules.triggered should be sorted by enum variant order"
+        export01.evidence["unmet_required_exports"][0]["found_kind"],
+        "global"
+    );
+    assert!(
+        report.analysis.warnings.contains(
+            &"Stylus target: required export 'user_entrypoint' is a global export, expected func"
+                .to_string()
+        )
     );
 }
 
 #[test]
-fn imports_sorted_deterministically() {
-    let report = inspect_fixture("rust_safe_storage.wat");
-
-    let imports = report.signals.imports_exports.imports.as_ref().unwrap();
-    for window in imports.windows(2) {
-        let a = (&window[0].module, &window[0].name, &window[0].kind);
-        let b = (&window[1].module, &window[1].name, &window[1].kind);
-        assert!(a <= b, "imports not sorted: {:?} > {:?}", a, b);
-    }
-}
+fn max_pages_above_ceiling_triggers_mem04() {
+    let wasm = compile_fixture("memory_high_max.wat");
+    let report = inspect_bytes(&wasm);
 
-#[test]
-fn exports_sorted_deterministically() {
-    let report = inspect_fixture("rust_safe_storage.wat");
+    assert_eq!(triggered_ids(&report), vec!["R-MEM-04"]);
+    assert_eq!(report.classification.level, ClassificationLevel::Risk);
 
-    let exports = report.signals.imports_exports.exports.as_ref().unwrap();
-    for window in exports.windows(2) {
-        let a = (&window[0].name, &window[0].kind);
-        let b = (&window[1].name, &window[1].kind);
-        assert!(a <= b, "exports not sorted: {:?} > {:?}", a, b);
-    }
-}
+    let mem04 = &report.rules.triggered[0];
+    assert_eq!(mem04.evidence["signals.memory.max_pages"], 65_536);
+    assert_eq!(mem04.evidence["signals.memory.max_bytes"], 4u64 << 30);
+    assert_eq!(mem04.evidence["MAX_PAGES_CEILING"], 1_024);
+    assert_eq!(mem04.evidence["MAX_BYTES_CEILING"], 64u64 << 20);
 
-#[test]
-fn hash_is_stable_for_same_bytes() {
-    let wasm = compile_fixture("rust_safe_storage.wat");
-    let report_a = inspect_bytes(&wasm);
-    let report_b = inspect_bytes(&wasm);
+    // A host backing 4 GiB can raise the ceiling.
+    let opts = InspectOptions {
+        max_pages_ceiling: 65_536,
+        ..Default::default()
+    };
+    assert!(!has_rule(&inspect_bytes_with(&wasm, &opts), "R-MEM-04"));
 
-    assert_eq!(report_a.artifact.hash.value, report_b.artifact.hash.value);
+    // No declared maximum is left to R-MEM-01.
+    let unbounded = inspect_fixture("rust_loop_unbounded_mem.wat");
+    assert!(has_rule(&unbounded, "R-MEM-01"));
+    assert!(!has_rule(&unbounded, "R-MEM-04"));
 }
 
 #[test]
@@ -681,6 +3654,32 @@ fn valid_fixtures_have_ok_status() {
         "all_signals.wat",
         "nested_loops.wat",
         "multiple_memory_grow.wat",
+        "recursive_calls.wat",
+        "float_math.wat",
+        "threads_atomics.wat",
+        "shared_memory_only.wat",
+        "simd_vector.wat",
+        "bulk_memory_copy.wat",
+        "table_mutation.wat",
+        "trap_heavy.wat",
+        "br_table_dispatch.wat",
+        "infinite_loop.wat",
+        "tail_calls.wat",
+        "exceptions.wat",
+        "export_attribution.wat",
+        "dead_code_panic.wat",
+        "memory64.wat",
+        "exported_table.wat",
+        "wasi_fd_write.wat",
+        "start_function.wat",
+        "exported_mutable_global.wat",
+        "memory_high_max.wat",
+        "memory_grow_in_loop.wat",
+        "memory_grow_startup.wat",
+        "call_indirect_heavy.wat",
+        "recursion_direct.wat",
+        "recursion_mutual.wat",
+        "call_graph_diamond.wat",
     ];
 
     for name in fixtures {
@@ -756,3 +3755,487 @@ fn function_count_excludes_imports() {
         "should have at least 4 imports"
     );
 }
+
+/// A module whose raw size exceeds the default threshold but whose bytes
+/// are a zero-filled data segment, so it compresses to almost nothing.
+fn large_compressible_module() -> Vec<u8> {
+    let zeros = "\\00".repeat(250_000);
+    wat::parse_str(format!(
+        r#"(module (memory 4 4) (data (i32.const 0) "{zeros}"))"#
+    ))
+    .unwrap()
+}
+
+#[cfg(feature = "stylus-size")]
+#[test]
+fn compressed_size_reported_and_drives_size_rule_on_request() {
+    let wasm = large_compressible_module();
+    let size_rule = |report: &Report| {
+        report
+            .rules
+            .triggered
+            .iter()
+            .find(|r| r.rule_id == "R-SIZE-01")
+            .cloned()
+    };
+
+    let report = inspect_bytes(&wasm);
+    let compressed = report
+        .artifact
+        .compressed_size_bytes
+        .expect("compressed size");
+    assert!(compressed < 1_000, "compressed to {compressed} bytes");
+    let rule = size_rule(&report).expect("raw size exceeds the threshold");
+    // The raw size decided, so the evidence does not cite the compressed one.
+    assert!(
+        rule.evidence
+            .get("artifact.compressed_size_bytes")
+            .is_none()
+    );
+
+    let opts = InspectOptions {
+        compressed_size_rule: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&wasm, &opts);
+    assert!(size_rule(&report).is_none());
+    assert_eq!(report.artifact.compressed_size_bytes, Some(compressed));
+}
+
+#[cfg(not(feature = "stylus-size"))]
+#[test]
+fn compressed_size_rule_falls_back_to_raw_size_without_feature() {
+    let opts = InspectOptions {
+        compressed_size_rule: true,
+        ..Default::default()
+    };
+    let report = inspect_bytes_with(&large_compressible_module(), &opts);
+
+    assert_eq!(report.artifact.compressed_size_bytes, None);
+    assert!(
+        report
+            .rules
+            .triggered
+            .iter()
+            .any(|r| r.rule_id == "R-SIZE-01")
+    );
+    assert!(
+        report
+            .analysis
+            .warnings
+            .iter()
+            .any(|w| w.contains("built without `stylus-size`"))
+    );
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["artifact"].get("compressed_size_bytes").is_none());
+}
+
+#[test]
+fn signals_diff_between_fixture_builds() {
+    use sebi_core::signals::diff::SignalsDiff;
+
+    let safe = inspect_fixture("rust_safe_storage.wat");
+    let unbounded = inspect_fixture("rust_loop_unbounded_mem.wat");
+
+    let diff = SignalsDiff::between(&safe.signals, &unbounded.signals);
+    let fields: Vec<_> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+    assert!(fields.contains(&"memory.has_max"));
+    assert!(fields.contains(&"instructions.loop_count"));
+    assert!(fields.windows(2).all(|w| w[0] < w[1]));
+
+    assert!(SignalsDiff::between(&safe.signals, &safe.signals).is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Schema compatibility
+// ---------------------------------------------------------------------------
+
+/// Frozen report of `all_signals.wat` in the current schema. A schema change
+/// fails `current_report_matches_frozen_snapshot`; rerun it with
+/// `SEBI_UPDATE_SNAPSHOTS=1` once the new fields default sensibly for older
+/// reports.
+const CURRENT_SNAPSHOT: &str = "reports/report_v0_15_0.json";
+
+fn read_snapshot(name: &str) -> String {
+    std::fs::read_to_string(fixtures_dir().join(name))
+        .unwrap_or_else(|e| panic!("failed to read {name}: {e}"))
+}
+
+#[test]
+fn current_report_matches_frozen_snapshot() {
+    let mut report = inspect_fixture("all_signals.wat");
+    // Temp-file path and feature-dependent fields would make it unstable.
+    report.artifact.path = None;
+    report.artifact.compressed_size_bytes = None;
+    let json = serde_json::to_string_pretty(&report).unwrap() + "\n";
+
+    if std::env::var_os("SEBI_UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(fixtures_dir().join(CURRENT_SNAPSHOT), &json).unwrap();
+    }
+    assert_eq!(
+        json,
+        read_snapshot(CURRENT_SNAPSHOT),
+        "report schema changed; check older reports still load, then refresh the snapshot"
+    );
+}
+
+#[test]
+fn current_snapshot_round_trips_without_defaults() {
+    let json = read_snapshot(CURRENT_SNAPSHOT);
+    let loaded = compat::load_report(&json).unwrap();
+
+    assert_eq!(loaded.value.schema_version, sebi_core::SCHEMA_VERSION);
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+    assert_eq!(
+        serde_json::to_value(&loaded.value).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()
+    );
+}
+
+/// Evidence of every triggered rule across the fixtures, keyed by
+/// `<fixture>/<case>` then rule id. Evidence keys and shapes are part of the
+/// report contract; refresh with `SEBI_UPDATE_SNAPSHOTS=1` only for an
+/// intended change.
+const EVIDENCE_SNAPSHOT: &str = "evidence/triggered_evidence.json";
+
+#[test]
+fn triggered_evidence_matches_snapshot() {
+    let thresholds = CatalogOverrides::from_yaml(
+        "catalog_version: x\nrules:\n  R-SIZE-01:\n    params:\n      SIZE_THRESHOLD: 1\n  \
+         R-SIZE-02:\n    params:\n      FUNCTION_COUNT_THRESHOLD: 1\n  \
+         R-DATA-01:\n    params:\n      DATA_BYTES_THRESHOLD: 1\n",
+    )
+    .unwrap();
+    let cases = [
+        ("default", InspectOptions::default()),
+        (
+            "allowlist",
+            InspectOptions {
+                import_allowlist: Some(vec![]),
+                ..Default::default()
+            },
+        ),
+        (
+            "thresholds",
+            InspectOptions {
+                catalog_overrides: Some(thresholds),
+                ..Default::default()
+            },
+        ),
+    ];
+
+    let mut fixtures: Vec<String> = std::fs::read_dir(fixtures_dir())
+        .unwrap()
+        .filter_map(|e| e.ok()?.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".wat"))
+        .collect();
+    fixtures.sort();
+
+    let mut runs = Vec::new();
+    for name in &fixtures {
+        let wasm = compile_fixture(name);
+        for (case, opts) in &cases {
+            runs.push((format!("{name}/{case}"), inspect_bytes_with(&wasm, opts)));
+        }
+    }
+    // R-ANALYSIS-01 needs a module that fails to parse.
+    let wasm = compile_fixture("minimal_module.wat");
+    runs.push((
+        "minimal_module.wat/truncated".to_string(),
+        inspect_bytes(&wasm[..wasm.len() - 3]),
+    ));
+
+    let mut evidence: BTreeMap<String, BTreeMap<String, serde_json::Value>> = BTreeMap::new();
+    for (run, report) in &runs {
+        let json = serde_json::to_value(report).unwrap();
+        for rule in json["rules"]["triggered"].as_array().unwrap() {
+            evidence.entry(run.clone()).or_default().insert(
+                rule["rule_id"].as_str().unwrap().to_string(),
+                rule["evidence"].clone(),
+            );
+        }
+    }
+    for def in catalog::catalog() {
+        assert!(
+            evidence
+                .values()
+                .any(|rules| rules.contains_key(def.id.as_str())),
+            "{} triggers in no snapshot case",
+            def.id
+        );
+    }
+
+    let json = serde_json::to_string_pretty(&evidence).unwrap() + "\n";
+    if std::env::var_os("SEBI_UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(fixtures_dir().join(EVIDENCE_SNAPSHOT), &json).unwrap();
+    }
+    assert_eq!(
+        json,
+        read_snapshot(EVIDENCE_SNAPSHOT),
+        "rule evidence changed; refresh the snapshot only if the change is intended"
+    );
+}
+
+#[test]
+fn v0_1_0_report_loads_with_defaults() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_1_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.1.0");
+    assert_eq!(report.signals.imports_exports.import_count, 7);
+    assert!(!report.signals.memory.has_max);
+    assert!(report.signals.instructions.has_loop);
+    assert_eq!(report.rules.scope, "module");
+    assert_eq!(report.analysis.target, Target::Unknown);
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+
+    for field in [
+        "analysis.target",
+        "rules.scope",
+        "signals.call_graph",
+        "signals.memory.min_bytes",
+        "signals.imports_exports.imports_truncated",
+    ] {
+        assert!(
+            loaded.missing_fields.iter().any(|f| f == field),
+            "{field} should be reported missing"
+        );
+    }
+    // Fields the old report carried are not reported.
+    assert!(
+        !loaded
+            .missing_fields
+            .iter()
+            .any(|f| f == "signals.memory.has_max")
+    );
+}
+
+#[test]
+fn v0_3_0_report_loads_without_rule_guidance() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_3_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.3.0");
+    assert!(!report.rules.triggered.is_empty());
+    assert!(
+        report
+            .rules
+            .triggered
+            .iter()
+            .all(|r| r.remediation.is_empty())
+    );
+    assert_eq!(
+        loaded.missing_fields,
+        vec![
+            "rules.catalog.content_hash",
+            "rules.suppressed",
+            "rules.triggered[].confidence",
+            "rules.triggered[].docs_url",
+            "rules.triggered[].remediation"
+        ]
+    );
+}
+
+#[test]
+fn v0_4_0_report_loads_legacy_rule_ids() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_4_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.4.0");
+    assert_eq!(
+        loaded.missing_fields,
+        vec!["rules.suppressed", "rules.triggered[].confidence"]
+    );
+    // Variant names (`RMem01`) read back as the external ids.
+    assert_eq!(
+        report.classification.triggered_rule_ids,
+        report
+            .rules
+            .triggered
+            .iter()
+            .map(|r| r.rule_id.clone())
+            .collect::<Vec<_>>()
+    );
+    let json = serde_json::to_value(&report.classification).unwrap();
+    assert_eq!(json["triggered_rule_ids"][0], "R-MEM-01");
+}
+
+#[test]
+fn v0_5_0_report_loads_without_passed_rules() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_5_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.5.0");
+    assert!(report.rules.passed.is_empty());
+    // `rules.passed` is optional, so it does not count as missing.
+    assert_eq!(loaded.missing_fields, vec!["rules.triggered[].confidence"]);
+}
+
+#[test]
+fn v0_6_0_report_loads_with_default_confidence() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_6_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.6.0");
+    assert_eq!(loaded.missing_fields, vec!["rules.triggered[].confidence"]);
+    assert!(!report.rules.triggered.is_empty());
+    assert!(
+        report
+            .rules
+            .triggered
+            .iter()
+            .all(|r| r.confidence == Confidence::High)
+    );
+}
+
+#[test]
+fn v0_7_0_report_loads_without_findings() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_7_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.7.0");
+    assert!(report.rules.triggered.iter().all(|r| r.findings.is_empty()));
+    // `findings` is optional, so it does not count as missing.
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_8_0_report_loads_without_score() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_8_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.8.0");
+    assert!(report.classification.score.is_none());
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_9_0_report_loads_unchanged() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_9_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.9.0");
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_10_0_report_loads_without_baseline_partitions() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_10_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.10.0");
+    assert!(report.classification.new_rule_ids.is_none());
+    assert!(report.classification.preexisting_rule_ids.is_none());
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_11_0_report_loads_without_escalation() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_11_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.11.0");
+    assert!(report.classification.escalated_from.is_none());
+    assert!(report.classification.escalation_rule.is_none());
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_12_0_report_loads_unchanged() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_12_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.12.0");
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_13_0_report_loads_unchanged() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_13_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.13.0");
+    assert_eq!(report.classification.level, ClassificationLevel::HighRisk);
+    assert!(report.classification.unsuppressed_level.is_none());
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn v0_14_0_report_loads_unchanged() {
+    let loaded = compat::load_report(&read_snapshot("reports/report_v0_14_0.json")).unwrap();
+    let report = &loaded.value;
+
+    assert_eq!(report.schema_version, "0.14.0");
+    // Evidence is kept as written, including the site lists 0.15.0 dropped.
+    let call01 = report
+        .rules
+        .triggered
+        .iter()
+        .find(|r| r.rule_id == "R-CALL-01")
+        .unwrap();
+    assert!(call01.evidence["signals.instructions.call_indirect_sites"].is_array());
+    assert!(
+        loaded.missing_fields.is_empty(),
+        "{:?}",
+        loaded.missing_fields
+    );
+}
+
+#[test]
+fn triggered_rules_carry_remediation_and_docs_url() {
+    let report = inspect_fixture("all_signals.wat");
+    for rule in &report.rules.triggered {
+        assert!(!rule.remediation.is_empty(), "{}", rule.rule_id);
+        assert!(rule.docs_url.starts_with("https://"), "{}", rule.rule_id);
+    }
+    let json = serde_json::to_value(&report).unwrap();
+    let mem01 = &json["rules"]["triggered"][0];
+    assert_eq!(mem01["rule_id"], "R-MEM-01");
+    assert!(
+        mem01["remediation"]
+            .as_str()
+            .unwrap()
+            .contains("--max-memory")
+    );
+    assert!(
+        mem01["docs_url"]
+            .as_str()
+            .unwrap()
+            .ends_with("docs/RULES.md#r-mem-01--missing-declared-memory-maximum")
+    );
+}